@@ -72,7 +72,8 @@ WHERE id = ?
         id
     )
     .execute(pool)
-    .await?;
+    .await?
+    .rows_affected();
 
     Ok(rows_affected > 0)
 }
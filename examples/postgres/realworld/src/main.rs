@@ -140,7 +140,7 @@ fn get_token_from_request(req: &Request<PgPool>) -> String {
 async fn authorize(token: &str) -> anyhow::Result<i64> {
     let data = jsonwebtoken::decode::<TokenClaims>(
         token,
-        SECRET_KEY.as_ref(),
+        &jsonwebtoken::DecodingKey::from_secret(SECRET_KEY.as_ref()),
         &jsonwebtoken::Validation::default(),
     )?;
 
@@ -169,7 +169,7 @@ struct TokenClaims {
 }
 
 fn generate_token(user_id: i64) -> anyhow::Result<String> {
-    use jsonwebtoken::Header;
+    use jsonwebtoken::{EncodingKey, Header};
 
     let exp = Utc::now() + Duration::hours(1);
     let token = jsonwebtoken::encode(
@@ -178,7 +178,7 @@ fn generate_token(user_id: i64) -> anyhow::Result<String> {
             sub: user_id,
             exp: exp.timestamp(),
         },
-        SECRET_KEY.as_ref(),
+        &EncodingKey::from_secret(SECRET_KEY.as_ref()),
     )?;
 
     Ok(token)
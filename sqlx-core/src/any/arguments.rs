@@ -0,0 +1,109 @@
+use crate::any::type_info::AnyTypeInfoKind;
+use crate::any::{Any, AnyTypeInfo};
+use crate::arguments::Arguments;
+use crate::database::Database;
+use crate::encode::{Encode, IsNull};
+use crate::types::Type;
+
+/// A value of a parameter of a query of **Any**, which is encoded for its database when the
+/// query is executed.
+#[derive(Debug, Clone)]
+pub enum AnyArgumentValue {
+    /// A `NULL`, with the type of the value it is in place of.
+    Null(AnyTypeInfo),
+
+    Bool(bool),
+
+    Int(i32),
+
+    BigInt(i64),
+
+    Float(f32),
+
+    Double(f64),
+
+    Text(String),
+
+    Blob(Vec<u8>),
+}
+
+/// The parameters of a query of **Any**.
+#[derive(Debug, Default)]
+pub struct AnyArguments {
+    values: Vec<AnyArgumentValue>,
+}
+
+impl Arguments for AnyArguments {
+    type Database = Any;
+
+    // the most parameters that every database can take, as SQLite takes the fewest
+    #[cfg(feature = "sqlite")]
+    const MAX_BINDS: usize = 999;
+    #[cfg(not(feature = "sqlite"))]
+    const MAX_BINDS: usize = 65535;
+
+    fn reserve(&mut self, len: usize, _size_hint: usize) {
+        self.values.reserve(len);
+    }
+
+    fn add<T>(&mut self, value: T)
+    where
+        T: Type<Self::Database>,
+        T: Encode<Self::Database>,
+    {
+        if let IsNull::Yes = value.encode_nullable(&mut self.values) {
+            self.values.push(AnyArgumentValue::Null(T::type_info()));
+        }
+    }
+}
+
+impl AnyArguments {
+    // Encodes the values for the database a query is executed on
+    pub(crate) fn into_arguments<DB>(self) -> DB::Arguments
+    where
+        DB: Database,
+        bool: Type<DB> + Encode<DB>,
+        i32: Type<DB> + Encode<DB>,
+        i64: Type<DB> + Encode<DB>,
+        f32: Type<DB> + Encode<DB>,
+        f64: Type<DB> + Encode<DB>,
+        String: Type<DB> + Encode<DB>,
+        Vec<u8>: Type<DB> + Encode<DB>,
+    {
+        use crate::any::type_info::AnyType;
+
+        let mut arguments = DB::Arguments::default();
+
+        arguments.reserve(self.values.len(), 0);
+
+        for value in self.values {
+            match value {
+                AnyArgumentValue::Null(AnyTypeInfo(AnyTypeInfoKind::Rust(r#type))) => {
+                    match r#type {
+                        AnyType::Bool => arguments.add(None::<bool>),
+                        AnyType::Int => arguments.add(None::<i32>),
+                        AnyType::BigInt => arguments.add(None::<i64>),
+                        AnyType::Float => arguments.add(None::<f32>),
+                        AnyType::Double => arguments.add(None::<f64>),
+                        AnyType::Text => arguments.add(None::<String>),
+                        AnyType::Blob => arguments.add(None::<Vec<u8>>),
+                    }
+                }
+
+                // the type of a `NULL` is always that of a Rust type, as it is only given by
+                // `Type::type_info`
+                AnyArgumentValue::Null(_) => arguments.add(None::<String>),
+
+                AnyArgumentValue::Bool(value) => arguments.add(value),
+                AnyArgumentValue::Int(value) => arguments.add(value),
+                AnyArgumentValue::BigInt(value) => arguments.add(value),
+                AnyArgumentValue::Float(value) => arguments.add(value),
+                AnyArgumentValue::Double(value) => arguments.add(value),
+                AnyArgumentValue::Text(value) => arguments.add(value),
+                AnyArgumentValue::Blob(value) => arguments.add(value),
+            }
+        }
+
+        arguments
+    }
+}
@@ -0,0 +1,180 @@
+use std::convert::TryInto;
+
+use futures_core::future::BoxFuture;
+
+use crate::connection::{Connect, Connection, LogSettings};
+use crate::transaction::TransactionOptions;
+use crate::url::Url;
+
+#[cfg(feature = "mysql")]
+use crate::mysql::MySqlConnection;
+#[cfg(feature = "postgres")]
+use crate::postgres::PgConnection;
+#[cfg(feature = "sqlite")]
+use crate::sqlite::SqliteConnection;
+
+/// A connection to the database of its URL, which can be any of the databases of the enabled
+/// features.
+///
+/// The database is chosen by the scheme of the URL:
+///
+/// | Scheme                        | Database   | Feature    |
+/// |-------------------------------|------------|------------|
+/// | `postgres`, `postgresql`      | PostgreSQL | `postgres` |
+/// | `mysql`                       | MySQL      | `mysql`    |
+/// | `sqlite`                      | SQLite     | `sqlite`   |
+///
+/// The rest of the URL is given to the connection of the database.
+pub struct AnyConnection(pub(crate) AnyConnectionKind);
+
+pub(crate) enum AnyConnectionKind {
+    #[cfg(feature = "postgres")]
+    Postgres(PgConnection),
+
+    #[cfg(feature = "mysql")]
+    MySql(MySqlConnection),
+
+    #[cfg(feature = "sqlite")]
+    Sqlite(SqliteConnection),
+}
+
+/// The database of an [`AnyConnection`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnyKind {
+    #[cfg(feature = "postgres")]
+    Postgres,
+
+    #[cfg(feature = "mysql")]
+    MySql,
+
+    #[cfg(feature = "sqlite")]
+    Sqlite,
+}
+
+impl AnyKind {
+    fn from_url(url: &Url) -> crate::Result<Self> {
+        match url.scheme() {
+            #[cfg(feature = "postgres")]
+            "postgres" | "postgresql" => Ok(AnyKind::Postgres),
+
+            #[cfg(feature = "mysql")]
+            "mysql" => Ok(AnyKind::MySql),
+
+            #[cfg(feature = "sqlite")]
+            "sqlite" => Ok(AnyKind::Sqlite),
+
+            scheme => Err(protocol_err!(
+                "unsupported URL scheme {:?} for an AnyConnection; is the feature of its database enabled?",
+                scheme
+            )
+            .into()),
+        }
+    }
+}
+
+impl AnyConnection {
+    /// The database this is a connection to.
+    pub fn kind(&self) -> AnyKind {
+        match self.0 {
+            #[cfg(feature = "postgres")]
+            AnyConnectionKind::Postgres(_) => AnyKind::Postgres,
+
+            #[cfg(feature = "mysql")]
+            AnyConnectionKind::MySql(_) => AnyKind::MySql,
+
+            #[cfg(feature = "sqlite")]
+            AnyConnectionKind::Sqlite(_) => AnyKind::Sqlite,
+        }
+    }
+
+    // The connection of the driver, for a cursor of a connection of a pool; a pool only has
+    // connections to the database of its URL
+    #[cfg(feature = "postgres")]
+    pub(crate) fn as_postgres(&mut self) -> &mut PgConnection {
+        match &mut self.0 {
+            AnyConnectionKind::Postgres(conn) => conn,
+
+            #[allow(unreachable_patterns)]
+            _ => unreachable!("not a connection to PostgreSQL"),
+        }
+    }
+
+    #[cfg(feature = "mysql")]
+    pub(crate) fn as_mysql(&mut self) -> &mut MySqlConnection {
+        match &mut self.0 {
+            AnyConnectionKind::MySql(conn) => conn,
+
+            #[allow(unreachable_patterns)]
+            _ => unreachable!("not a connection to MySQL"),
+        }
+    }
+
+    #[cfg(feature = "sqlite")]
+    pub(crate) fn as_sqlite(&mut self) -> &mut SqliteConnection {
+        match &mut self.0 {
+            AnyConnectionKind::Sqlite(conn) => conn,
+
+            #[allow(unreachable_patterns)]
+            _ => unreachable!("not a connection to SQLite"),
+        }
+    }
+}
+
+impl Connect for AnyConnection {
+    fn connect<T>(url: T) -> BoxFuture<'static, crate::Result<AnyConnection>>
+    where
+        T: TryInto<Url, Error = url::ParseError>,
+        Self: Sized,
+    {
+        let url = url.try_into();
+
+        Box::pin(async move {
+            let url = url?;
+
+            Ok(AnyConnection(match AnyKind::from_url(&url)? {
+                #[cfg(feature = "postgres")]
+                AnyKind::Postgres => {
+                    AnyConnectionKind::Postgres(PgConnection::connect(url.as_str()).await?)
+                }
+
+                #[cfg(feature = "mysql")]
+                AnyKind::MySql => {
+                    AnyConnectionKind::MySql(MySqlConnection::connect(url.as_str()).await?)
+                }
+
+                #[cfg(feature = "sqlite")]
+                AnyKind::Sqlite => {
+                    AnyConnectionKind::Sqlite(SqliteConnection::connect(url.as_str()).await?)
+                }
+            }))
+        })
+    }
+}
+
+impl Connection for AnyConnection {
+    fn close(self) -> BoxFuture<'static, crate::Result<()>> {
+        match_any!(self.0, AnyConnectionKind(conn) => conn.close())
+    }
+
+    fn ping(&mut self) -> BoxFuture<'_, crate::Result<()>> {
+        match_any!(&mut self.0, AnyConnectionKind(conn) => conn.ping())
+    }
+
+    fn set_log_settings(&mut self, settings: LogSettings) {
+        match_any!(&mut self.0, AnyConnectionKind(conn) => conn.set_log_settings(settings))
+    }
+
+    fn reset(&mut self) -> BoxFuture<'_, crate::Result<()>> {
+        match_any!(&mut self.0, AnyConnectionKind(conn) => conn.reset())
+    }
+
+    #[doc(hidden)]
+    fn rollback_on_next_use(&mut self) {
+        match_any!(&mut self.0, AnyConnectionKind(conn) => conn.rollback_on_next_use())
+    }
+
+    #[doc(hidden)]
+    fn begin_statement(&self, options: &TransactionOptions) -> crate::Result<String> {
+        match_any!(&self.0, AnyConnectionKind(conn) => conn.begin_statement(options))
+    }
+}
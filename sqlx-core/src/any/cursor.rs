@@ -0,0 +1,140 @@
+use futures_core::future::BoxFuture;
+
+use crate::any::connection::AnyConnectionKind;
+use crate::any::executor;
+use crate::any::row::AnyRowKind;
+use crate::any::{Any, AnyArguments, AnyConnection, AnyKind, AnyRow};
+use crate::connection::ConnectionSource;
+use crate::cursor::Cursor;
+use crate::executor::Execute;
+use crate::pool::{Pool, PoolConnection};
+
+#[cfg(feature = "mysql")]
+use crate::mysql::MySqlCursor;
+#[cfg(feature = "postgres")]
+use crate::postgres::PgCursor;
+#[cfg(feature = "sqlite")]
+use crate::sqlite::SqliteCursor;
+
+pub struct AnyCursor<'c, 'q>(AnyCursorKind<'c, 'q>);
+
+enum AnyCursorKind<'c, 'q> {
+    // A query on a pool, until a connection of the pool, and so its database, is acquired
+    Pool(Pool<AnyConnection>, &'q str, Option<AnyArguments>),
+
+    #[cfg(feature = "postgres")]
+    Postgres(PgCursor<'c, 'q>),
+
+    #[cfg(feature = "mysql")]
+    MySql(MySqlCursor<'c, 'q>),
+
+    #[cfg(feature = "sqlite")]
+    Sqlite(SqliteCursor<'c, 'q>),
+}
+
+impl crate::cursor::private::Sealed for AnyCursor<'_, '_> {}
+
+impl<'c, 'q> AnyCursorKind<'c, 'q> {
+    // The cursor of the driver for a connection of a pool, which the cursor keeps until it
+    // is dropped
+    fn from_pool_connection(
+        conn: PoolConnection<AnyConnection>,
+        query: &'q str,
+        arguments: Option<AnyArguments>,
+    ) -> Self {
+        match conn.kind() {
+            #[cfg(feature = "postgres")]
+            AnyKind::Postgres => AnyCursorKind::Postgres(PgCursor::from_source(
+                ConnectionSource::AnyPoolConnection(Box::new(conn), AnyConnection::as_postgres),
+                executor::postgres(query, arguments),
+            )),
+
+            #[cfg(feature = "mysql")]
+            AnyKind::MySql => AnyCursorKind::MySql(MySqlCursor::from_source(
+                ConnectionSource::AnyPoolConnection(Box::new(conn), AnyConnection::as_mysql),
+                executor::mysql(query, arguments),
+            )),
+
+            #[cfg(feature = "sqlite")]
+            AnyKind::Sqlite => AnyCursorKind::Sqlite(SqliteCursor::from_source(
+                ConnectionSource::AnyPoolConnection(Box::new(conn), AnyConnection::as_sqlite),
+                executor::sqlite(query, arguments),
+            )),
+        }
+    }
+}
+
+impl<'c, 'q> Cursor<'c, 'q> for AnyCursor<'c, 'q> {
+    type Database = Any;
+
+    #[doc(hidden)]
+    fn from_pool<E>(pool: &Pool<AnyConnection>, query: E) -> Self
+    where
+        Self: Sized,
+        E: Execute<'q, Any>,
+    {
+        let (query, arguments) = query.into_parts();
+
+        AnyCursor(AnyCursorKind::Pool(pool.clone(), query, arguments))
+    }
+
+    #[doc(hidden)]
+    fn from_connection<E>(conn: &'c mut AnyConnection, query: E) -> Self
+    where
+        Self: Sized,
+        E: Execute<'q, Any>,
+    {
+        let (query, arguments) = query.into_parts();
+
+        AnyCursor(match &mut conn.0 {
+            #[cfg(feature = "postgres")]
+            AnyConnectionKind::Postgres(conn) => AnyCursorKind::Postgres(
+                PgCursor::from_connection(conn, executor::postgres(query, arguments)),
+            ),
+
+            #[cfg(feature = "mysql")]
+            AnyConnectionKind::MySql(conn) => AnyCursorKind::MySql(MySqlCursor::from_connection(
+                conn,
+                executor::mysql(query, arguments),
+            )),
+
+            #[cfg(feature = "sqlite")]
+            AnyConnectionKind::Sqlite(conn) => AnyCursorKind::Sqlite(
+                SqliteCursor::from_connection(conn, executor::sqlite(query, arguments)),
+            ),
+        })
+    }
+
+    fn next(&mut self) -> BoxFuture<'_, crate::Result<Option<AnyRow<'_>>>> {
+        Box::pin(async move {
+            if let AnyCursorKind::Pool(pool, query, arguments) = &mut self.0 {
+                let conn = pool.acquire().await?;
+                let (query, arguments) = (*query, arguments.take());
+
+                self.0 = AnyCursorKind::from_pool_connection(conn, query, arguments);
+            }
+
+            Ok(match &mut self.0 {
+                #[cfg(feature = "postgres")]
+                AnyCursorKind::Postgres(cursor) => cursor
+                    .next()
+                    .await?
+                    .map(|row| AnyRow(AnyRowKind::Postgres(row))),
+
+                #[cfg(feature = "mysql")]
+                AnyCursorKind::MySql(cursor) => cursor
+                    .next()
+                    .await?
+                    .map(|row| AnyRow(AnyRowKind::MySql(row))),
+
+                #[cfg(feature = "sqlite")]
+                AnyCursorKind::Sqlite(cursor) => cursor
+                    .next()
+                    .await?
+                    .map(|row| AnyRow(AnyRowKind::Sqlite(row))),
+
+                AnyCursorKind::Pool(..) => unreachable!(),
+            })
+        })
+    }
+}
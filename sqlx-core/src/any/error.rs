@@ -0,0 +1,79 @@
+use std::error::Error as StdError;
+use std::fmt::{self, Display};
+
+use crate::error::{DatabaseError, DatabaseErrorKind};
+
+/// An error returned by the database of an [`AnyConnection`](crate::any::AnyConnection).
+///
+/// The errors of a query are not wrapped in this type: the error in
+/// [`Error::Database`](crate::Error::Database) is the one of the driver, such as a
+/// [`PgError`](crate::postgres::PgError), and can be downcast to it.
+#[derive(Debug)]
+pub struct AnyError(Box<dyn DatabaseError>);
+
+impl AnyError {
+    /// The error of the driver.
+    pub fn inner(&self) -> &dyn DatabaseError {
+        &*self.0
+    }
+}
+
+impl Display for AnyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl StdError for AnyError {}
+
+impl From<Box<dyn DatabaseError>> for AnyError {
+    fn from(error: Box<dyn DatabaseError>) -> Self {
+        AnyError(error)
+    }
+}
+
+impl DatabaseError for AnyError {
+    fn message(&self) -> &str {
+        self.0.message()
+    }
+
+    fn code(&self) -> Option<&str> {
+        self.0.code()
+    }
+
+    fn kind(&self) -> DatabaseErrorKind {
+        self.0.kind()
+    }
+
+    fn details(&self) -> Option<&str> {
+        self.0.details()
+    }
+
+    fn hint(&self) -> Option<&str> {
+        self.0.hint()
+    }
+
+    fn table_name(&self) -> Option<&str> {
+        self.0.table_name()
+    }
+
+    fn column_name(&self) -> Option<&str> {
+        self.0.column_name()
+    }
+
+    fn constraint_name(&self) -> Option<&str> {
+        self.0.constraint_name()
+    }
+
+    fn as_ref_err(&self) -> &(dyn StdError + Send + Sync + 'static) {
+        self
+    }
+
+    fn as_mut_err(&mut self) -> &mut (dyn StdError + Send + Sync + 'static) {
+        self
+    }
+
+    fn into_box_err(self: Box<Self>) -> Box<dyn StdError + Send + Sync + 'static> {
+        self
+    }
+}
@@ -0,0 +1,156 @@
+use futures_core::future::BoxFuture;
+use futures_core::stream::BoxStream;
+
+use crate::any::connection::AnyConnectionKind;
+use crate::any::{Any, AnyArguments, AnyConnection, AnyCursor, AnyTypeInfo};
+use crate::cursor::Cursor;
+use crate::database::Database;
+use crate::describe::{Column, Describe};
+use crate::executor::{Execute, Executor, QueryResult, RefExecutor};
+
+#[cfg(feature = "mysql")]
+use crate::mysql::MySql;
+#[cfg(feature = "postgres")]
+use crate::postgres::Postgres;
+#[cfg(feature = "sqlite")]
+use crate::sqlite::Sqlite;
+
+// A query of `Any` with its arguments encoded for the database it is executed on
+pub(super) struct DriverQuery<'q, DB>(&'q str, Option<DB::Arguments>)
+where
+    DB: Database;
+
+impl<'q, DB> Execute<'q, DB> for DriverQuery<'q, DB>
+where
+    DB: Database,
+{
+    #[inline]
+    fn into_parts(self) -> (&'q str, Option<DB::Arguments>) {
+        (self.0, self.1)
+    }
+}
+
+#[cfg(feature = "postgres")]
+pub(super) fn postgres(query: &str, arguments: Option<AnyArguments>) -> DriverQuery<'_, Postgres> {
+    DriverQuery(
+        query,
+        arguments.map(AnyArguments::into_arguments::<Postgres>),
+    )
+}
+
+#[cfg(feature = "mysql")]
+pub(super) fn mysql(query: &str, arguments: Option<AnyArguments>) -> DriverQuery<'_, MySql> {
+    DriverQuery(query, arguments.map(AnyArguments::into_arguments::<MySql>))
+}
+
+#[cfg(feature = "sqlite")]
+pub(super) fn sqlite(query: &str, arguments: Option<AnyArguments>) -> DriverQuery<'_, Sqlite> {
+    DriverQuery(query, arguments.map(AnyArguments::into_arguments::<Sqlite>))
+}
+
+// The description of a query by its database, with the types of the database
+fn describe<DB>(describe: Describe<DB>) -> Describe<Any>
+where
+    DB: Database,
+    AnyTypeInfo: From<DB::TypeInfo>,
+{
+    Describe {
+        param_types: describe
+            .param_types
+            .into_vec()
+            .into_iter()
+            .map(|type_info| type_info.map(AnyTypeInfo::from))
+            .collect(),
+
+        result_columns: describe
+            .result_columns
+            .into_vec()
+            .into_iter()
+            .map(|column| Column {
+                name: column.name,
+                table_id: column.table_id.map(|table_id| table_id.to_string()),
+                type_info: column.type_info.map(AnyTypeInfo::from),
+                non_null: column.non_null,
+            })
+            .collect(),
+    }
+}
+
+impl Executor for AnyConnection {
+    type Database = Any;
+
+    fn execute<'e, 'q: 'e, 'c: 'e, E>(
+        &'c mut self,
+        query: E,
+    ) -> BoxFuture<'e, crate::Result<QueryResult>>
+    where
+        E: 'e + Execute<'q, Self::Database>,
+    {
+        let (query, arguments) = query.into_parts();
+
+        match &mut self.0 {
+            #[cfg(feature = "postgres")]
+            AnyConnectionKind::Postgres(conn) => conn.execute(postgres(query, arguments)),
+
+            #[cfg(feature = "mysql")]
+            AnyConnectionKind::MySql(conn) => conn.execute(mysql(query, arguments)),
+
+            #[cfg(feature = "sqlite")]
+            AnyConnectionKind::Sqlite(conn) => conn.execute(sqlite(query, arguments)),
+        }
+    }
+
+    fn execute_many<'e, 'q: 'e, 'c: 'e>(
+        &'c mut self,
+        query: &'q str,
+    ) -> BoxStream<'e, crate::Result<QueryResult>> {
+        match_any!(&mut self.0, AnyConnectionKind(conn) => conn.execute_many(query))
+    }
+
+    fn fetch<'e, 'q, E>(&'e mut self, query: E) -> AnyCursor<'e, 'q>
+    where
+        E: Execute<'q, Self::Database>,
+    {
+        AnyCursor::from_connection(self, query)
+    }
+
+    fn describe<'e, 'q, E>(
+        &'e mut self,
+        query: E,
+    ) -> BoxFuture<'e, crate::Result<Describe<Self::Database>>>
+    where
+        E: 'e + Execute<'q, Self::Database>,
+    {
+        Box::pin(async move {
+            let (query, arguments) = query.into_parts();
+
+            Ok(match &mut self.0 {
+                #[cfg(feature = "postgres")]
+                AnyConnectionKind::Postgres(conn) => {
+                    describe(conn.describe(postgres(query, arguments)).await?)
+                }
+
+                #[cfg(feature = "mysql")]
+                AnyConnectionKind::MySql(conn) => {
+                    describe(conn.describe(mysql(query, arguments)).await?)
+                }
+
+                #[cfg(feature = "sqlite")]
+                AnyConnectionKind::Sqlite(conn) => {
+                    describe(conn.describe(sqlite(query, arguments)).await?)
+                }
+            })
+        })
+    }
+}
+
+impl<'e> RefExecutor<'e> for &'e mut AnyConnection {
+    type Database = Any;
+
+    fn fetch_by_ref<'q, E>(self, query: E) -> AnyCursor<'e, 'q>
+    where
+        E: Execute<'q, Self::Database>,
+    {
+        AnyCursor::from_connection(self, query)
+    }
+}
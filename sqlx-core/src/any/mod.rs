@@ -0,0 +1,71 @@
+//! **Any** database driver, for a connection to the database chosen at runtime from its URL.
+//!
+//! An [`AnyConnection`] is a connection to PostgreSQL, MySQL or SQLite, picked by the scheme
+//! of its URL: `postgres://` (or `postgresql://`), `mysql://` or `sqlite:`. Only the drivers
+//! of the enabled features are available.
+//!
+//! ```rust,ignore
+//! let pool = AnyPool::new(&env::var("DATABASE_URL")?).await?;
+//!
+//! let (id, name): (i64, String) = sqlx::query_as("SELECT id, name FROM users WHERE id = $1")
+//!     .bind(10_i64)
+//!     .fetch_one(&pool)
+//!     .await?;
+//! ```
+//!
+//! The query is sent to the database as it is written, so its SQL, and the placeholders of
+//! its parameters, must be understood by each database it is run on; `$1` is understood by
+//! both PostgreSQL and SQLite but MySQL only takes `?`.
+//!
+//! The types that can be encoded and decoded are those that every database has: `bool`,
+//! `i32`, `i64`, `f32`, `f64`, strings and byte arrays, and an `Option` of any of them. An
+//! error of the database is the one of its driver, e.g. a
+//! [`PgError`](crate::postgres::PgError) for PostgreSQL.
+
+#[cfg(not(any(feature = "postgres", feature = "mysql", feature = "sqlite")))]
+compile_error!("the `any` feature needs one of the `postgres`, `mysql` or `sqlite` features");
+
+// Matches a value of an enum with a variant for each driver and evaluates the same expression
+// for each, with the value of the driver bound to `$value`
+macro_rules! match_any {
+    ($on:expr, $kind:ident($value:ident) => $body:expr) => {
+        match $on {
+            #[cfg(feature = "postgres")]
+            $kind::Postgres($value) => $body,
+
+            #[cfg(feature = "mysql")]
+            $kind::MySql($value) => $body,
+
+            #[cfg(feature = "sqlite")]
+            $kind::Sqlite($value) => $body,
+        }
+    };
+}
+
+mod arguments;
+mod connection;
+mod cursor;
+mod database;
+mod error;
+mod executor;
+mod row;
+mod type_info;
+pub mod types;
+mod value;
+
+pub use arguments::{AnyArgumentValue, AnyArguments};
+pub use connection::{AnyConnection, AnyKind};
+pub use cursor::AnyCursor;
+pub use database::Any;
+pub use error::AnyError;
+pub use row::AnyRow;
+pub use type_info::AnyTypeInfo;
+pub use value::AnyValue;
+
+/// An alias for [`Pool`][crate::pool::Pool], specialized for **Any**.
+#[cfg_attr(docsrs, doc(cfg(feature = "any")))]
+pub type AnyPool = crate::pool::Pool<AnyConnection>;
+
+make_query_as!(AnyQueryAs, Any, AnyRow);
+impl_map_row_for_row!(Any, AnyRow);
+impl_from_row_for_tuples!(Any, AnyRow);
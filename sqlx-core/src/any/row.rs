@@ -0,0 +1,81 @@
+use crate::any::value::AnyValueKind;
+use crate::any::{Any, AnyTypeInfo, AnyValue};
+use crate::row::{self, ColumnIndex, Row};
+
+#[cfg(feature = "mysql")]
+use crate::mysql::MySqlRow;
+#[cfg(feature = "postgres")]
+use crate::postgres::PgRow;
+#[cfg(feature = "sqlite")]
+use crate::sqlite::SqliteRow;
+
+/// A row of the result of a query of **Any**.
+pub struct AnyRow<'c>(pub(crate) AnyRowKind<'c>);
+
+pub(crate) enum AnyRowKind<'c> {
+    #[cfg(feature = "postgres")]
+    Postgres(PgRow<'c>),
+
+    #[cfg(feature = "mysql")]
+    MySql(MySqlRow<'c>),
+
+    #[cfg(feature = "sqlite")]
+    Sqlite(SqliteRow<'c>),
+}
+
+impl crate::row::private_row::Sealed for AnyRow<'_> {}
+
+impl<'c> Row<'c> for AnyRow<'c> {
+    type Database = Any;
+
+    fn len(&self) -> usize {
+        match_any!(&self.0, AnyRowKind(row) => row.len())
+    }
+
+    fn try_get_raw<I>(&self, index: I) -> crate::Result<AnyValue<'c>>
+    where
+        I: ColumnIndex<'c, Self>,
+    {
+        let index = index.index(self)?;
+
+        Ok(AnyValue(match &self.0 {
+            #[cfg(feature = "postgres")]
+            AnyRowKind::Postgres(row) => AnyValueKind::Postgres(row.try_get_raw(index)?),
+
+            #[cfg(feature = "mysql")]
+            AnyRowKind::MySql(row) => AnyValueKind::MySql(row.try_get_raw(index)?),
+
+            #[cfg(feature = "sqlite")]
+            AnyRowKind::Sqlite(row) => AnyValueKind::Sqlite(row.try_get_raw(index)?),
+        }))
+    }
+
+    fn try_column<I>(&self, index: I) -> crate::Result<row::Column<'_, Any>>
+    where
+        I: ColumnIndex<'c, Self>,
+    {
+        let index = index.index(self)?;
+
+        match_any!(&self.0, AnyRowKind(row) => {
+            let column = row.try_column(index)?;
+
+            Ok(row::Column {
+                name: column.name,
+                ordinal: column.ordinal,
+                type_info: column.type_info.map(AnyTypeInfo::from),
+            })
+        })
+    }
+}
+
+impl<'c> ColumnIndex<'c, AnyRow<'c>> for usize {
+    fn index(&self, row: &AnyRow<'c>) -> crate::Result<usize> {
+        match_any!(&row.0, AnyRowKind(row) => ColumnIndex::index(self, row))
+    }
+}
+
+impl<'c> ColumnIndex<'c, AnyRow<'c>> for str {
+    fn index(&self, row: &AnyRow<'c>) -> crate::Result<usize> {
+        match_any!(&row.0, AnyRowKind(row) => ColumnIndex::index(self, row))
+    }
+}
@@ -0,0 +1,170 @@
+use std::fmt::{self, Display};
+
+use crate::database::Database;
+use crate::types::{Type, TypeInfo};
+
+#[cfg(feature = "mysql")]
+use crate::mysql::{MySql, MySqlTypeInfo};
+#[cfg(feature = "postgres")]
+use crate::postgres::{PgTypeInfo, Postgres};
+#[cfg(feature = "sqlite")]
+use crate::sqlite::{Sqlite, SqliteTypeInfo};
+
+/// Type information for a value of **Any**: the type of a column or a parameter as given by
+/// its database, or the type of a Rust value to encode or decode.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnyTypeInfo(pub(crate) AnyTypeInfoKind);
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum AnyTypeInfoKind {
+    // The type of a Rust value, which each database maps to one of its own types
+    Rust(AnyType),
+
+    #[cfg(feature = "postgres")]
+    Postgres(PgTypeInfo),
+
+    #[cfg(feature = "mysql")]
+    MySql(MySqlTypeInfo),
+
+    #[cfg(feature = "sqlite")]
+    Sqlite(SqliteTypeInfo),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum AnyType {
+    Bool,
+    Int,
+    BigInt,
+    Float,
+    Double,
+    Text,
+    Blob,
+}
+
+impl AnyType {
+    // The type of the database for the Rust type
+    pub(crate) fn type_info<DB>(self) -> DB::TypeInfo
+    where
+        DB: Database,
+        bool: Type<DB>,
+        i32: Type<DB>,
+        i64: Type<DB>,
+        f32: Type<DB>,
+        f64: Type<DB>,
+        str: Type<DB>,
+        [u8]: Type<DB>,
+    {
+        match self {
+            AnyType::Bool => <bool as Type<DB>>::type_info(),
+            AnyType::Int => <i32 as Type<DB>>::type_info(),
+            AnyType::BigInt => <i64 as Type<DB>>::type_info(),
+            AnyType::Float => <f32 as Type<DB>>::type_info(),
+            AnyType::Double => <f64 as Type<DB>>::type_info(),
+            AnyType::Text => <str as Type<DB>>::type_info(),
+            AnyType::Blob => <[u8] as Type<DB>>::type_info(),
+        }
+    }
+}
+
+impl AnyTypeInfo {
+    pub(crate) const fn rust(r#type: AnyType) -> Self {
+        AnyTypeInfo(AnyTypeInfoKind::Rust(r#type))
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl From<PgTypeInfo> for AnyTypeInfo {
+    fn from(type_info: PgTypeInfo) -> Self {
+        AnyTypeInfo(AnyTypeInfoKind::Postgres(type_info))
+    }
+}
+
+#[cfg(feature = "mysql")]
+impl From<MySqlTypeInfo> for AnyTypeInfo {
+    fn from(type_info: MySqlTypeInfo) -> Self {
+        AnyTypeInfo(AnyTypeInfoKind::MySql(type_info))
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl From<SqliteTypeInfo> for AnyTypeInfo {
+    fn from(type_info: SqliteTypeInfo) -> Self {
+        AnyTypeInfo(AnyTypeInfoKind::Sqlite(type_info))
+    }
+}
+
+impl Display for AnyTypeInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            AnyTypeInfoKind::Rust(r#type) => f.write_str(match r#type {
+                AnyType::Bool => "BOOLEAN",
+                AnyType::Int => "INTEGER",
+                AnyType::BigInt => "BIGINT",
+                AnyType::Float => "REAL",
+                AnyType::Double => "DOUBLE",
+                AnyType::Text => "TEXT",
+                AnyType::Blob => "BLOB",
+            }),
+
+            #[cfg(feature = "postgres")]
+            AnyTypeInfoKind::Postgres(type_info) => Display::fmt(type_info, f),
+
+            #[cfg(feature = "mysql")]
+            AnyTypeInfoKind::MySql(type_info) => Display::fmt(type_info, f),
+
+            #[cfg(feature = "sqlite")]
+            AnyTypeInfoKind::Sqlite(type_info) => Display::fmt(type_info, f),
+        }
+    }
+}
+
+impl TypeInfo for AnyTypeInfo {
+    fn compatible(&self, other: &Self) -> bool {
+        // A type of a database is compatible with a Rust type if it is compatible with the
+        // type the database maps the Rust type to
+        match (&self.0, &other.0) {
+            (AnyTypeInfoKind::Rust(this), AnyTypeInfoKind::Rust(other)) => this == other,
+
+            #[cfg(feature = "postgres")]
+            (AnyTypeInfoKind::Postgres(this), AnyTypeInfoKind::Postgres(other)) => {
+                this.compatible(other)
+            }
+            #[cfg(feature = "postgres")]
+            (AnyTypeInfoKind::Postgres(this), AnyTypeInfoKind::Rust(other)) => {
+                this.compatible(&other.type_info::<Postgres>())
+            }
+            #[cfg(feature = "postgres")]
+            (AnyTypeInfoKind::Rust(this), AnyTypeInfoKind::Postgres(other)) => {
+                this.type_info::<Postgres>().compatible(other)
+            }
+
+            #[cfg(feature = "mysql")]
+            (AnyTypeInfoKind::MySql(this), AnyTypeInfoKind::MySql(other)) => this.compatible(other),
+            #[cfg(feature = "mysql")]
+            (AnyTypeInfoKind::MySql(this), AnyTypeInfoKind::Rust(other)) => {
+                this.compatible(&other.type_info::<MySql>())
+            }
+            #[cfg(feature = "mysql")]
+            (AnyTypeInfoKind::Rust(this), AnyTypeInfoKind::MySql(other)) => {
+                this.type_info::<MySql>().compatible(other)
+            }
+
+            #[cfg(feature = "sqlite")]
+            (AnyTypeInfoKind::Sqlite(this), AnyTypeInfoKind::Sqlite(other)) => {
+                this.compatible(other)
+            }
+            #[cfg(feature = "sqlite")]
+            (AnyTypeInfoKind::Sqlite(this), AnyTypeInfoKind::Rust(other)) => {
+                this.compatible(&other.type_info::<Sqlite>())
+            }
+            #[cfg(feature = "sqlite")]
+            (AnyTypeInfoKind::Rust(this), AnyTypeInfoKind::Sqlite(other)) => {
+                this.type_info::<Sqlite>().compatible(other)
+            }
+
+            // the types of two different databases
+            #[allow(unreachable_patterns)]
+            _ => false,
+        }
+    }
+}
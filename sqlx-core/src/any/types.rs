@@ -0,0 +1,146 @@
+//! Conversions between Rust and the types of the database of **Any**.
+//!
+//! # Types
+//!
+//! | Rust type                             | Any type                                             |
+//! |---------------------------------------|------------------------------------------------------|
+//! | `bool`                                | BOOLEAN                                              |
+//! | `i32`                                 | INTEGER                                              |
+//! | `i64`                                 | BIGINT                                               |
+//! | `f32`                                 | REAL                                                 |
+//! | `f64`                                 | DOUBLE                                               |
+//! | `&str`, `String`                      | TEXT                                                 |
+//! | `&[u8]`, `Vec<u8>`                    | BLOB                                                 |
+//!
+//! Each is encoded and decoded as the same Rust type is for the database of the connection,
+//! e.g. an `i64` is an `INT8` for PostgreSQL, so a column must have a type that the database
+//! would decode into the Rust type.
+//!
+//! # Nullable
+//!
+//! In addition, `Option<T>` is supported where `T` implements `Type`. An `Option<T>` represents
+//! a potentially `NULL` value from the database.
+//!
+
+use crate::any::type_info::AnyType;
+use crate::any::value::AnyValueKind;
+use crate::any::{Any, AnyArgumentValue, AnyTypeInfo, AnyValue};
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::types::Type;
+
+#[cfg(feature = "mysql")]
+use crate::mysql::MySql;
+#[cfg(feature = "postgres")]
+use crate::postgres::Postgres;
+#[cfg(feature = "sqlite")]
+use crate::sqlite::Sqlite;
+
+macro_rules! impl_any_type {
+    ($($ty:ty => $kind:ident),*) => {$(
+        impl Type<Any> for $ty {
+            fn type_info() -> AnyTypeInfo {
+                AnyTypeInfo::rust(AnyType::$kind)
+            }
+        }
+    )*};
+}
+
+impl_any_type!(
+    bool => Bool,
+    i32 => Int,
+    i64 => BigInt,
+    f32 => Float,
+    f64 => Double,
+    str => Text,
+    String => Text,
+    [u8] => Blob,
+    Vec<u8> => Blob
+);
+
+macro_rules! impl_any_encode {
+    ($($ty:ty => $value:ident),*) => {$(
+        impl Encode<Any> for $ty {
+            fn encode(&self, values: &mut Vec<AnyArgumentValue>) {
+                values.push(AnyArgumentValue::$value(*self));
+            }
+        }
+    )*};
+}
+
+impl_any_encode!(
+    bool => Bool,
+    i32 => Int,
+    i64 => BigInt,
+    f32 => Float,
+    f64 => Double
+);
+
+impl Encode<Any> for str {
+    fn encode(&self, values: &mut Vec<AnyArgumentValue>) {
+        values.push(AnyArgumentValue::Text(self.to_owned()));
+    }
+}
+
+impl Encode<Any> for String {
+    fn encode(&self, values: &mut Vec<AnyArgumentValue>) {
+        <str as Encode<Any>>::encode(self, values)
+    }
+}
+
+impl Encode<Any> for [u8] {
+    fn encode(&self, values: &mut Vec<AnyArgumentValue>) {
+        values.push(AnyArgumentValue::Blob(self.to_vec()));
+    }
+}
+
+impl Encode<Any> for Vec<u8> {
+    fn encode(&self, values: &mut Vec<AnyArgumentValue>) {
+        <[u8] as Encode<Any>>::encode(self, values)
+    }
+}
+
+// Decodes the value as the driver of its database decodes the same type
+macro_rules! impl_any_decode {
+    ($($ty:ty),*) => {$(
+        impl<'de> Decode<'de, Any> for $ty {
+            fn decode(value: AnyValue<'de>) -> crate::Result<Self> {
+                match value.0 {
+                    #[cfg(feature = "postgres")]
+                    AnyValueKind::Postgres(value) => <$ty as Decode<'de, Postgres>>::decode(value),
+
+                    #[cfg(feature = "mysql")]
+                    AnyValueKind::MySql(value) => <$ty as Decode<'de, MySql>>::decode(value),
+
+                    #[cfg(feature = "sqlite")]
+                    AnyValueKind::Sqlite(value) => <$ty as Decode<'de, Sqlite>>::decode(value),
+                }
+            }
+        }
+    )*};
+}
+
+impl_any_decode!(
+    bool,
+    i32,
+    i64,
+    f32,
+    f64,
+    &'de str,
+    String,
+    &'de [u8],
+    Vec<u8>
+);
+
+impl<'de, T> Decode<'de, Any> for Option<T>
+where
+    T: Decode<'de, Any>,
+{
+    fn decode(value: AnyValue<'de>) -> crate::Result<Self> {
+        Ok(if !value.is_null() {
+            Some(<T as Decode<Any>>::decode(value)?)
+        } else {
+            None
+        })
+    }
+}
@@ -0,0 +1,37 @@
+use crate::any::{Any, AnyTypeInfo};
+use crate::value::RawValue;
+
+#[cfg(feature = "mysql")]
+use crate::mysql::MySqlValue;
+#[cfg(feature = "postgres")]
+use crate::postgres::PgValue;
+#[cfg(feature = "sqlite")]
+use crate::sqlite::SqliteValue;
+
+/// A value of a column of an [`AnyRow`](crate::any::AnyRow), as returned by its database.
+pub struct AnyValue<'c>(pub(crate) AnyValueKind<'c>);
+
+pub(crate) enum AnyValueKind<'c> {
+    #[cfg(feature = "postgres")]
+    Postgres(PgValue<'c>),
+
+    #[cfg(feature = "mysql")]
+    MySql(MySqlValue<'c>),
+
+    #[cfg(feature = "sqlite")]
+    Sqlite(SqliteValue<'c>),
+}
+
+impl AnyValue<'_> {
+    pub(crate) fn is_null(&self) -> bool {
+        match_any!(&self.0, AnyValueKind(value) => value.is_null())
+    }
+}
+
+impl<'c> RawValue<'c> for AnyValue<'c> {
+    type Database = Any;
+
+    fn type_info(&self) -> Option<AnyTypeInfo> {
+        match_any!(&self.0, AnyValueKind(value) => value.type_info().map(AnyTypeInfo::from))
+    }
+}
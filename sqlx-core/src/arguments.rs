@@ -1,5 +1,7 @@
 //! Traits for passing arguments to SQL queries.
 
+use std::fmt::{self, Write};
+
 use crate::database::Database;
 use crate::encode::Encode;
 use crate::types::Type;
@@ -8,6 +10,9 @@ use crate::types::Type;
 pub trait Arguments: Send + Sized + Default + 'static {
     type Database: Database + ?Sized;
 
+    /// The most values that can be bound to a single query.
+    const MAX_BINDS: usize;
+
     /// Reserves the capacity for at least `len` more values (of `size` bytes) to
     /// be added to the arguments without a reallocation.  
     fn reserve(&mut self, len: usize, size: usize);
@@ -17,4 +22,51 @@ pub trait Arguments: Send + Sized + Default + 'static {
     where
         T: Type<Self::Database>,
         T: Encode<Self::Database>;
+
+    /// Write the placeholder for the last value added to the arguments, e.g. `$3` for the
+    /// third value in **Postgres** or `?` in **MySQL** and **SQLite**.
+    fn format_placeholder<W: Write>(&self, writer: &mut W) -> fmt::Result {
+        writer.write_char('?')
+    }
+}
+
+/// A record whose fields can be bound, in order, as the parameters of a query.
+///
+/// In order to use [`Query::bind_struct`] the record must implement `ToArguments`.
+///
+/// # Deriving
+/// This trait can be automatically derived by SQLx for any struct with named fields. The
+/// generated implementation binds each field in the order the fields are declared, so they
+/// must be declared in the order of the placeholders of the query.
+///
+/// ```rust,ignore
+/// #[derive(sqlx::ToArguments)]
+/// struct NewUser {
+///     name: String,
+///     email: Option<String>,
+/// }
+///
+/// sqlx::query("INSERT INTO users (name, email) VALUES ($1, $2)")
+///     .bind_struct(&new_user)
+///     .execute(&mut conn)
+///     .await?;
+/// ```
+///
+/// The fields can be changed with attributes:
+///
+///  * `#[sqlx(skip)]` does not bind the field.
+///  * `#[sqlx(flatten)]` binds the fields of the field, a type that also implements
+///    `ToArguments`, in its place.
+///
+/// The same attributes are understood by the derive of [`FromRow`], so one struct can be
+/// written to and read back from a table.
+///
+/// [`Query::bind_struct`]: crate::query::Query::bind_struct
+/// [`FromRow`]: crate::row::FromRow
+pub trait ToArguments<DB>
+where
+    DB: Database,
+{
+    /// Add the values of the fields to the end of the arguments.
+    fn add_to(&self, arguments: &mut DB::Arguments);
 }
@@ -1,12 +1,16 @@
 //! Contains the `Connection` and `Connect` traits.
 
 use std::convert::TryInto;
+use std::time::Duration;
 
 use futures_core::future::BoxFuture;
+use log::LevelFilter;
 
+#[cfg(feature = "any")]
+use crate::any::AnyConnection;
 use crate::executor::Executor;
 use crate::pool::{Pool, PoolConnection};
-use crate::transaction::Transaction;
+use crate::transaction::{Transaction, TransactionOptions};
 use crate::url::Url;
 
 /// Represents a single database connection rather than a pool of database connections.
@@ -43,6 +47,35 @@ where
         Box::pin(Transaction::new(0, self))
     }
 
+    /// Starts a new transaction with the given [`TransactionOptions`].
+    ///
+    /// ```rust,ignore
+    /// let options = TransactionOptions::new().access_mode(AccessMode::ReadOnly);
+    ///
+    /// let mut tx = conn.begin_with(options).await?;
+    /// ```
+    ///
+    /// Returns an error if the database does not support one of the options:
+    ///
+    ///  * **Postgres** supports all options.
+    ///
+    ///  * **MySQL** does not support deferrable transactions.
+    ///
+    ///  * **SQLite** transactions are always serializable so any isolation level is accepted;
+    ///    read only and deferrable transactions are not supported.
+    ///
+    /// Options can not be given to a save point; this returns an error when called on a
+    /// [`Transaction`].
+    fn begin_with(
+        self,
+        options: TransactionOptions,
+    ) -> BoxFuture<'static, crate::Result<Transaction<Self>>>
+    where
+        Self: Sized,
+    {
+        Box::pin(Transaction::new_with(self, options))
+    }
+
     /// Explicitly close this database connection.
     ///
     /// This method is **not required** for safe and consistent operation. However, it is
@@ -52,6 +85,121 @@ where
 
     /// Checks if a connection to the database is still valid.
     fn ping(&mut self) -> BoxFuture<crate::Result<()>>;
+
+    /// Reset the state of the session, as if the connection had just been established.
+    ///
+    /// A [`Pool`] resets a connection that is returned to it if [`Builder::reset_on_release`]
+    /// is turned on, so that the state one user of a connection leaves does not leak to the
+    /// next one. A transaction that was dropped on the connection is rolled back first.
+    ///
+    ///  * **Postgres** resets the parameters set with `SET` to those given when the connection
+    ///    was established, drops the temporary tables, resets the sequences, stops listening to
+    ///    channels, releases the advisory locks and closes the cursors. The prepared statements
+    ///    of the statement cache are kept.
+    ///
+    ///  * **MySQL** sends `COM_RESET_CONNECTION`, which resets the session variables, drops the
+    ///    temporary tables, releases the locks and closes the prepared statements, which are
+    ///    prepared again as they are used; the variables SQLx sets on a new connection are then
+    ///    set again.
+    ///
+    ///  * **SQLite** drops the temporary tables, views and triggers; a `PRAGMA` keeps its value.
+    ///
+    /// Returns an error for a [`Transaction`].
+    ///
+    /// [`Builder::reset_on_release`]: crate::pool::Builder::reset_on_release
+    fn reset(&mut self) -> BoxFuture<'_, crate::Result<()>>;
+
+    /// Set how the queries executed on this connection are logged.
+    ///
+    /// See [`LogSettings`] for the defaults. To log the queries of each connection of a
+    /// [`Pool`], set this in [`Builder::after_connect`].
+    ///
+    /// ```rust,ignore
+    /// conn.set_log_settings(
+    ///     LogSettings::new()
+    ///         .log_statements(LevelFilter::Info)
+    ///         .redact_parameters(true),
+    /// );
+    /// ```
+    ///
+    /// [`Builder::after_connect`]: crate::pool::Builder::after_connect
+    fn set_log_settings(&mut self, settings: LogSettings);
+
+    // Used when a transaction is dropped while in-progress; this can't wait for the database so
+    // the `ROLLBACK` is issued before anything else the next time the connection is used
+    #[doc(hidden)]
+    fn rollback_on_next_use(&mut self);
+
+    // The statement that starts a transaction with the given options
+    #[doc(hidden)]
+    fn begin_statement(&self, options: &TransactionOptions) -> crate::Result<String>;
+}
+
+/// How the queries executed on a connection are logged, set with
+/// [`Connection::set_log_settings`].
+///
+/// Each query is logged with the `log` crate, to the `sqlx::query` target, when it completes:
+/// with its SQL, its bind parameters, the number of rows it affected or returned and how long
+/// it took. The rows of a query fetched with a [`Cursor`] are counted until it returns the
+/// last row or is dropped.
+///
+/// The parameters of **SQLite** queries are logged as their values; those of **Postgres** and
+/// **MySQL** queries as their type and the bytes they are encoded to.
+///
+/// By default queries are logged at the `DEBUG` level, and at the `WARN` level when they take
+/// one second or longer.
+///
+/// With the `tracing` feature, each query is also a `sqlx.query` span, from when it is started
+/// until it is logged, with the `db.system`, `db.statement` and `db.rows_affected` fields of
+/// the OpenTelemetry conventions and an `error` field if it failed. The log of the query is an
+/// event of the span.
+///
+/// [`Cursor`]: crate::cursor::Cursor
+#[derive(Debug, Clone)]
+pub struct LogSettings {
+    pub(crate) statements_level: LevelFilter,
+    pub(crate) slow_statements_level: LevelFilter,
+    pub(crate) slow_statements_duration: Duration,
+    pub(crate) redact_parameters: bool,
+}
+
+impl Default for LogSettings {
+    fn default() -> Self {
+        Self {
+            statements_level: LevelFilter::Debug,
+            slow_statements_level: LevelFilter::Warn,
+            slow_statements_duration: Duration::from_secs(1),
+            redact_parameters: false,
+        }
+    }
+}
+
+impl LogSettings {
+    /// The default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the level queries are logged at; `LevelFilter::Off` does not log them.
+    pub fn log_statements(mut self, level: LevelFilter) -> Self {
+        self.statements_level = level;
+        self
+    }
+
+    /// Set the level queries that take `duration` or longer are logged at, instead of the
+    /// level of [`log_statements`](#method.log_statements).
+    pub fn log_slow_statements(mut self, level: LevelFilter, duration: Duration) -> Self {
+        self.slow_statements_level = level;
+        self.slow_statements_duration = duration;
+        self
+    }
+
+    /// Set whether the values of bind parameters are left out of the logs, e.g. as they may
+    /// contain passwords or personal data. The number of parameters is still logged.
+    pub fn redact_parameters(mut self, redact: bool) -> Self {
+        self.redact_parameters = redact;
+        self
+    }
 }
 
 /// Represents a type that can directly establish a new connection.
@@ -72,6 +220,13 @@ where
     Connection(C),
     PoolConnection(Pool<C>, PoolConnection<C>),
     Pool(Pool<C>),
+    // A connection of a pool of `AnyConnection`, with the function that gets the connection of
+    // the database it is to; it is boxed as it holds the connection of any of the databases
+    #[cfg(feature = "any")]
+    AnyPoolConnection(
+        Box<PoolConnection<AnyConnection>>,
+        fn(&mut AnyConnection) -> &mut C,
+    ),
 }
 
 impl<'c, C> ConnectionSource<'c, C>
@@ -90,6 +245,8 @@ where
             ConnectionSource::ConnectionRef(conn) => conn,
             ConnectionSource::PoolConnection(_, ref mut conn) => conn,
             ConnectionSource::Connection(ref mut conn) => conn,
+            #[cfg(feature = "any")]
+            ConnectionSource::AnyPoolConnection(ref mut conn, project) => project(conn),
             ConnectionSource::Pool(_) => unreachable!(),
         })
     }
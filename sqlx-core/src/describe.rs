@@ -13,13 +13,34 @@ where
     DB: Database + ?Sized,
 {
     // TODO: Describe#param_types should probably be Option<TypeInfo[]> as we either know all the params or we know none
-    /// The expected types for the parameters of the query.
+    /// The expected types for the parameters of the query, in order, or `None` where the
+    /// type is not known.
     pub param_types: Box<[Option<DB::TypeInfo>]>,
 
-    /// The type and table information, if any for the results of the query.
+    /// The type and table information, if any for the results of the query, in the order of
+    /// the columns of the result.
     pub result_columns: Box<[Column<DB>]>,
 }
 
+impl<DB> Describe<DB>
+where
+    DB: Database,
+{
+    /// Whether each column of the result can be `NULL`, in the order of the columns, or `None`
+    /// where that is not known.
+    ///
+    /// This is the inverse of [`Column::non_null`]. **Postgres** knows it for a column read
+    /// directly from a table, from the `NOT NULL` constraint of the table column, but not for
+    /// an expression. It does not see outer joins, so a `NOT NULL` column from the nullable
+    /// side of a `LEFT JOIN` is still reported as not nullable.
+    pub fn nullable(&self) -> Vec<Option<bool>> {
+        self.result_columns
+            .iter()
+            .map(|column| column.non_null.map(|non_null| !non_null))
+            .collect()
+    }
+}
+
 impl<DB> Debug for Describe<DB>
 where
     DB: Database,
@@ -40,8 +61,13 @@ pub struct Column<DB>
 where
     DB: Database + ?Sized,
 {
+    /// The name of the column, as it would be given to [`Row::get`].
+    ///
+    /// [`Row::get`]: crate::row::Row::get
     pub name: Option<Box<str>>,
+    /// The table the column is read from, if it is read directly from a table.
     pub table_id: Option<DB::TableId>,
+    /// The type of the column, if it is known before the query is executed.
     pub type_info: Option<DB::TypeInfo>,
     /// Whether or not the column cannot be `NULL` (or if that is even knowable).
     pub non_null: Option<bool>,
@@ -39,10 +39,22 @@ pub enum Error {
     /// [`query::Map::fetch_one`]: crate::query::Map::fetch_one
     RowNotFound,
 
+    /// More than one row was returned during [`query::Map::fetch_exactly_one`] or
+    /// `QueryAs::fetch_exactly_one`.
+    ///
+    /// [`query::Map::fetch_exactly_one`]: crate::query::Map::fetch_exactly_one
+    TooManyRows,
+
     /// Column was not found by name in a Row (during [`Row::get`]).
     ///
     /// [`Row::get`]: crate::row::Row::get
-    ColumnNotFound(Box<str>),
+    ColumnNotFound {
+        /// The name that was looked up.
+        name: Box<str>,
+
+        /// The names of the columns of the row.
+        columns: Box<[Box<str>]>,
+    },
 
     /// Column index was out of bounds (e.g., asking for column 4 in a 2-column row).
     ColumnIndexOutOfBounds { index: usize, len: usize },
@@ -73,6 +85,20 @@ pub enum Error {
 
     /// An error occurred decoding data received from the database.
     Decode(Box<dyn StdError + Send + Sync>),
+
+    /// An error occurred decoding the value of a column of a row (during [`Row::get`]).
+    ///
+    /// [`Row::get`]: crate::row::Row::get
+    ColumnDecode {
+        /// The column, as its quoted name or its position if it has no name.
+        index: Box<str>,
+
+        /// The SQL type of the column, if it is known.
+        type_name: Option<Box<str>>,
+
+        /// The error from decoding the value.
+        source: Box<dyn StdError + Send + Sync>,
+    },
 }
 
 impl Error {
@@ -84,8 +110,9 @@ impl Error {
         Error::Decode(err.into())
     }
 
-    #[allow(dead_code)]
-    pub(crate) fn mismatched_types<DB: Database, T>(expected: DB::TypeInfo) -> Self
+    // used by the derives to check the type of a value before decoding it
+    #[doc(hidden)]
+    pub fn mismatched_types<DB: Database, T>(expected: DB::TypeInfo) -> Self
     where
         T: Type<DB>,
     {
@@ -98,6 +125,58 @@ impl Error {
             expected
         );
     }
+
+    /// The error returned by the database, if this is an [`Error::Database`].
+    pub fn as_database_error(&self) -> Option<&dyn DatabaseError> {
+        match self {
+            Error::Database(error) => Some(&**error),
+            _ => None,
+        }
+    }
+
+    /// Whether this is a database error for the violation of a unique or primary key
+    /// constraint, e.g. to fall back to an update when an insert finds an existing row:
+    ///
+    /// ```rust,ignore
+    /// match sqlx::query("INSERT INTO users (name) VALUES ($1)").bind(name).execute(&mut conn).await {
+    ///     Err(err) if err.is_unique_violation() => {
+    ///         sqlx::query("UPDATE users SET seen_at = now() WHERE name = $1")
+    ///             .bind(name)
+    ///             .execute(&mut conn)
+    ///             .await?;
+    ///     }
+    ///
+    ///     res => { res?; }
+    /// }
+    /// ```
+    pub fn is_unique_violation(&self) -> bool {
+        matches!(self.as_database_error(), Some(error) if error.is_unique_violation())
+    }
+
+    /// Whether this is a database error for the violation of a foreign key constraint.
+    pub fn is_foreign_key_violation(&self) -> bool {
+        matches!(self.as_database_error(), Some(error) if error.is_foreign_key_violation())
+    }
+
+    /// Whether this is a database error for the violation of a `CHECK` constraint.
+    pub fn is_check_violation(&self) -> bool {
+        matches!(self.as_database_error(), Some(error) if error.is_check_violation())
+    }
+
+    /// Whether this is a database error for the violation of a `NOT NULL` constraint.
+    pub fn is_not_null_violation(&self) -> bool {
+        matches!(self.as_database_error(), Some(error) if error.is_not_null_violation())
+    }
+
+    pub(crate) fn column_not_found<'a, I>(name: &str, columns: I) -> Self
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        Error::ColumnNotFound {
+            name: name.into(),
+            columns: columns.into_iter().map(Into::into).collect(),
+        }
+    }
 }
 
 impl StdError for Error {
@@ -107,6 +186,7 @@ impl StdError for Error {
             Error::UrlParse(error) => Some(error),
             Error::PoolTimedOut(Some(error)) => Some(&**error),
             Error::Decode(error) => Some(&**error),
+            Error::ColumnDecode { source, .. } => Some(&**source),
             Error::Tls(error) => Some(&**error),
             Error::Database(error) => Some(error.as_ref_err()),
 
@@ -130,10 +210,49 @@ impl Display for Error {
 
             Error::RowNotFound => f.write_str("found no row when we expected at least one"),
 
-            Error::ColumnNotFound(ref name) => {
-                write!(f, "no column found with the name {:?}", name)
+            Error::TooManyRows => {
+                f.write_str("found more than one row when we expected exactly one")
             }
 
+            Error::ColumnNotFound {
+                ref name,
+                ref columns,
+            } => {
+                write!(f, "no column found with the name {:?}; ", name)?;
+
+                if columns.is_empty() {
+                    f.write_str("the row has no columns")
+                } else {
+                    f.write_str("the columns are ")?;
+
+                    for (index, column) in columns.iter().enumerate() {
+                        if index > 0 {
+                            f.write_str(", ")?;
+                        }
+
+                        write!(f, "{:?}", column)?;
+                    }
+
+                    Ok(())
+                }
+            }
+
+            Error::ColumnDecode {
+                ref index,
+                type_name: Some(ref type_name),
+                ref source,
+            } => write!(
+                f,
+                "error decoding column {} of SQL type {}: {}",
+                index, type_name, source
+            ),
+
+            Error::ColumnDecode {
+                ref index,
+                type_name: None,
+                ref source,
+            } => write!(f, "error decoding column {}: {}", index, source),
+
             Error::ColumnIndexOutOfBounds { index, len } => write!(
                 f,
                 "column index out of bounds: there are {} columns but the index is {}",
@@ -208,16 +327,55 @@ impl From<TlsError<'_>> for Error {
     }
 }
 
+/// The kind of a [`DatabaseError`], for the errors that are handled the same way whatever the
+/// database, such as the violation of a unique constraint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DatabaseErrorKind {
+    /// A unique or primary key constraint was violated.
+    UniqueViolation,
+
+    /// A foreign key constraint was violated.
+    ForeignKeyViolation,
+
+    /// A `NOT NULL` constraint was violated.
+    NotNullViolation,
+
+    /// A `CHECK` constraint was violated.
+    CheckViolation,
+
+    /// Any other error.
+    Other,
+}
+
 /// An error that was returned by the database.
 pub trait DatabaseError: StdError + Send + Sync + 'static {
     /// The primary, human-readable error message.
     fn message(&self) -> &str;
 
     /// The (SQLSTATE) code for the error.
+    ///
+    /// This is the SQLSTATE for Postgres and MySQL and the extended result code for SQLite,
+    /// e.g. `"23505"` or `"2067"` for a unique violation.
     fn code(&self) -> Option<&str> {
         None
     }
 
+    /// The kind of the error, from its code.
+    ///
+    /// ```rust,ignore
+    /// match sqlx::query("INSERT INTO users (name) VALUES ($1)").bind(name).execute(&mut conn).await {
+    ///     Err(sqlx::Error::Database(err)) if err.kind() == DatabaseErrorKind::UniqueViolation => {
+    ///         // the name is taken
+    ///     }
+    ///
+    ///     res => { res?; }
+    /// }
+    /// ```
+    fn kind(&self) -> DatabaseErrorKind {
+        DatabaseErrorKind::Other
+    }
+
     fn details(&self) -> Option<&str> {
         None
     }
@@ -249,6 +407,30 @@ pub trait DatabaseError: StdError + Send + Sync + 'static {
 }
 
 impl dyn DatabaseError {
+    /// Whether the error is the violation of a unique or primary key constraint.
+    ///
+    /// This is the same for every database, from the [`kind`](DatabaseError::kind) of the
+    /// error: SQLSTATE `23505` for Postgres, `ER_DUP_ENTRY` for MySQL and
+    /// `SQLITE_CONSTRAINT_UNIQUE` or `SQLITE_CONSTRAINT_PRIMARYKEY` for SQLite.
+    pub fn is_unique_violation(&self) -> bool {
+        self.kind() == DatabaseErrorKind::UniqueViolation
+    }
+
+    /// Whether the error is the violation of a foreign key constraint.
+    pub fn is_foreign_key_violation(&self) -> bool {
+        self.kind() == DatabaseErrorKind::ForeignKeyViolation
+    }
+
+    /// Whether the error is the violation of a `CHECK` constraint.
+    pub fn is_check_violation(&self) -> bool {
+        self.kind() == DatabaseErrorKind::CheckViolation
+    }
+
+    /// Whether the error is the violation of a `NOT NULL` constraint.
+    pub fn is_not_null_violation(&self) -> bool {
+        self.kind() == DatabaseErrorKind::NotNullViolation
+    }
+
     /// Downcast this `&dyn DatabaseError` to a specific database error type:
     ///
     /// * [PgError][crate::postgres::PgError] (if the `postgres` feature is active)
@@ -1,4 +1,5 @@
 use futures_core::future::BoxFuture;
+use futures_core::stream::BoxStream;
 
 use crate::cursor::HasCursor;
 use crate::database::Database;
@@ -24,14 +25,41 @@ where
     /// Executes the query for its side-effects and
     /// discarding any potential result rows.
     ///
-    /// Returns the number of rows affected, or 0 if not applicable.
+    /// Returns the number of rows affected, or 0 if not applicable, and the ID of the last
+    /// inserted row for databases that report it.
     fn execute<'e, 'q: 'e, 'c: 'e, E: 'e>(
         &'c mut self,
         query: E,
-    ) -> BoxFuture<'e, crate::Result<u64>>
+    ) -> BoxFuture<'e, crate::Result<QueryResult>>
     where
         E: Execute<'q, Self::Database>;
 
+    /// Executes each of the statements in the query, separated by `;`, and returns the result
+    /// of each one as it completes.
+    ///
+    /// Unlike [`execute`](#tymethod.execute), which prepares a query with bind parameters,
+    /// the statements are sent together (to **Postgres** and **MySQL**, in a single
+    /// round-trip) and take no parameters. This can run e.g. a script of migrations.
+    ///
+    /// Execution stops at the first statement that fails and the stream returns its error.
+    /// **Postgres** runs the statements in a single transaction unless they contain
+    /// `BEGIN` / `COMMIT`, so the statements before it are rolled back; **MySQL** and
+    /// **SQLite** keep them.
+    ///
+    /// ```rust,ignore
+    /// let mut results = conn.execute_many(
+    ///     "CREATE TABLE users (id INTEGER PRIMARY KEY); INSERT INTO users (id) VALUES (1), (2);",
+    /// );
+    ///
+    /// while let Some(result) = results.try_next().await? {
+    ///     println!("{} rows affected", result.rows_affected());
+    /// }
+    /// ```
+    fn execute_many<'e, 'q: 'e, 'c: 'e>(
+        &'c mut self,
+        query: &'q str,
+    ) -> BoxStream<'e, crate::Result<QueryResult>>;
+
     /// Executes a query for its result.
     ///
     /// Returns a [`Cursor`] that can be used to iterate through the [`Row`]s
@@ -43,12 +71,30 @@ where
     where
         E: Execute<'q, Self::Database>;
 
-    /// Prepare the SQL query and return type information about its parameters
-    /// and results.
+    /// Prepare the SQL query, without executing it, and return type information about its
+    /// parameters and result columns.
+    ///
+    /// This is used by the query macros during compilation to power their type inference. It
+    /// can also be used to inspect a query at run time, e.g. in a tool that runs any query.
     ///
-    /// This is used by the query macros during compilation to
-    /// power their type inference.
-    #[doc(hidden)]
+    /// ```rust,ignore
+    /// let describe = conn.describe("SELECT id, name FROM users WHERE id = $1").await?;
+    ///
+    /// for column in describe.result_columns.iter() {
+    ///     println!("{:?}: {:?}", column.name, column.type_info);
+    /// }
+    /// ```
+    ///
+    /// What is known about a query depends on the database:
+    ///
+    ///  * **Postgres** reports the type of each parameter and column, and whether a column
+    ///    that is read from a table can be `NULL`.
+    ///
+    ///  * **MySQL** reports the type of each column and whether it can be `NULL`.
+    ///
+    ///  * **SQLite** reports the number of parameters, without their types, and the declared
+    ///    type of each column that is read from a table; the type of an expression is only
+    ///    known once a row is returned.
     fn describe<'e, 'q, E: 'e>(
         &'e mut self,
         query: E,
@@ -57,6 +103,33 @@ where
         E: Execute<'q, Self::Database>;
 }
 
+/// The result of executing a query with [`Executor::execute`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct QueryResult {
+    pub(crate) rows_affected: u64,
+    pub(crate) last_insert_id: Option<i64>,
+}
+
+impl QueryResult {
+    /// The number of rows inserted, updated or deleted by the query, or 0 if not applicable.
+    pub fn rows_affected(&self) -> u64 {
+        self.rows_affected
+    }
+
+    /// The ID of the last row inserted by the query.
+    ///
+    ///  * **MySQL** reports the first `AUTO_INCREMENT` value generated by the last statement
+    ///    of the query, or `0` if it did not generate one.
+    ///
+    ///  * **SQLite** reports the `ROWID` of the most recent successful `INSERT` on the
+    ///    connection, which may be from an earlier query.
+    ///
+    ///  * **Postgres** does not report this and always returns `None`; use `RETURNING` instead.
+    pub fn last_insert_id(&self) -> Option<i64> {
+        self.last_insert_id
+    }
+}
+
 // HACK: Generic Associated Types (GATs) will enable us to rework how the Executor bound is done
 //       in Query to remove the need for this.
 pub trait RefExecutor<'e> {
@@ -100,13 +173,20 @@ where
     fn execute<'e, 'q: 'e, 'c: 'e, E: 'e>(
         &'c mut self,
         query: E,
-    ) -> BoxFuture<'e, crate::Result<u64>>
+    ) -> BoxFuture<'e, crate::Result<QueryResult>>
     where
         E: Execute<'q, Self::Database>,
     {
         (**self).execute(query)
     }
 
+    fn execute_many<'e, 'q: 'e, 'c: 'e>(
+        &'c mut self,
+        query: &'q str,
+    ) -> BoxStream<'e, crate::Result<QueryResult>> {
+        (**self).execute_many(query)
+    }
+
     fn fetch<'e, 'q, E>(&'e mut self, query: E) -> <Self::Database as HasCursor<'_, 'q>>::Cursor
     where
         E: Execute<'q, Self::Database>,
@@ -114,7 +194,6 @@ where
         (**self).fetch(query)
     }
 
-    #[doc(hidden)]
     fn describe<'e, 'q, E: 'e>(
         &'e mut self,
         query: E,
@@ -4,6 +4,7 @@ mod buf_stream;
 mod buf;
 mod buf_mut;
 mod byte_str;
+mod tcp;
 mod tls;
 
 pub use self::{
@@ -11,9 +12,13 @@ pub use self::{
     buf_mut::BufMut,
     buf_stream::BufStream,
     byte_str::ByteStr,
+    tcp::TcpOptions,
     tls::MaybeTlsStream,
 };
 
+#[cfg(any(feature = "tls", feature = "tls-rustls"))]
+pub use self::tls::TlsConfig;
+
 #[cfg(test)]
 #[doc(hidden)]
 macro_rules! bytes (
@@ -0,0 +1,215 @@
+use std::io;
+use std::time::Duration;
+
+use crate::runtime::TcpStream;
+use crate::url::Url;
+
+/// The options of the socket of a TCP connection.
+///
+/// An option that is `None` keeps the default of the platform.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TcpOptions {
+    /// Send keepalive probes once the connection has been idle for this long.
+    pub keepalive: Option<Duration>,
+    /// Set `TCP_NODELAY`, which disables Nagle's algorithm.
+    pub nodelay: Option<bool>,
+}
+
+impl TcpOptions {
+    /// These options, with those that are not set taken from the `tcp_keepalive` (in seconds)
+    /// and `tcp_nodelay` parameters of the URL.
+    pub(crate) fn or_url(&self, url: &Url) -> crate::Result<Self> {
+        let keepalive = match (self.keepalive, url.param("tcp_keepalive")) {
+            (Some(keepalive), _) => Some(keepalive),
+
+            (None, Some(secs)) => {
+                Some(Duration::from_secs(secs.parse().ok().ok_or(
+                    protocol_err!("invalid `tcp_keepalive` value: {:?}", secs),
+                )?))
+            }
+
+            (None, None) => None,
+        };
+
+        let nodelay = match (self.nodelay, url.param("tcp_nodelay").as_deref()) {
+            (Some(nodelay), _) => Some(nodelay),
+            (None, Some("true")) => Some(true),
+            (None, Some("false")) => Some(false),
+
+            (None, Some(value)) => {
+                return Err(protocol_err!("invalid `tcp_nodelay` value: {:?}", value).into());
+            }
+
+            (None, None) => None,
+        };
+
+        Ok(TcpOptions { keepalive, nodelay })
+    }
+
+    /// Connect to `host` on `port`, with a socket that has these options.
+    pub(crate) async fn connect(&self, host: &str, port: u16) -> io::Result<TcpStream> {
+        let stream = connect(host, port, self.keepalive).await?;
+
+        if let Some(nodelay) = self.nodelay {
+            stream.set_nodelay(nodelay)?;
+        }
+
+        Ok(stream)
+    }
+}
+
+#[cfg(feature = "runtime-tokio")]
+async fn connect(host: &str, port: u16, keepalive: Option<Duration>) -> io::Result<TcpStream> {
+    let stream = TcpStream::connect((host, port)).await?;
+
+    if keepalive.is_some() {
+        stream.set_keepalive(keepalive)?;
+    }
+
+    Ok(stream)
+}
+
+// async-std can not set keepalive, so a socket with keepalive is connected in a blocking task
+// and then made asynchronous
+#[cfg(feature = "runtime-async-std")]
+async fn connect(host: &str, port: u16, keepalive: Option<Duration>) -> io::Result<TcpStream> {
+    use async_std::net::ToSocketAddrs;
+
+    let keepalive = match keepalive {
+        Some(keepalive) => keepalive,
+        None => return TcpStream::connect((host, port)).await,
+    };
+
+    let mut error = None;
+
+    // the addresses are tried in order, as by `TcpStream::connect`
+    for addr in (host, port).to_socket_addrs().await? {
+        let res =
+            async_std::task::spawn_blocking(move || connect_with_keepalive(addr, keepalive)).await;
+
+        match res {
+            Ok(stream) => return Ok(TcpStream::from(stream)),
+            Err(e) => error = Some(e),
+        }
+    }
+
+    Err(error.unwrap_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "could not resolve to any addresses",
+        )
+    }))
+}
+
+#[cfg(feature = "runtime-async-std")]
+fn connect_with_keepalive(
+    addr: std::net::SocketAddr,
+    keepalive: Duration,
+) -> io::Result<std::net::TcpStream> {
+    use net2::TcpStreamExt;
+
+    let stream = std::net::TcpStream::connect(addr)?;
+    stream.set_keepalive(Some(keepalive))?;
+
+    Ok(stream)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn it_connects_with_the_options() -> io::Result<()> {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+        let port = listener.local_addr()?.port();
+
+        let options = TcpOptions {
+            keepalive: Some(Duration::from_secs(30)),
+            nodelay: Some(true),
+        };
+
+        let stream = options.connect("127.0.0.1", port).await?;
+
+        assert!(stream.nodelay()?);
+
+        #[cfg(feature = "runtime-tokio")]
+        assert_eq!(stream.keepalive()?, Some(Duration::from_secs(30)));
+
+        // the defaults are kept
+        let stream = TcpOptions::default().connect("127.0.0.1", port).await?;
+
+        assert!(!stream.nodelay()?);
+
+        #[cfg(feature = "runtime-tokio")]
+        assert_eq!(stream.keepalive()?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_options_or_url() -> crate::Result<()> {
+        use std::convert::TryFrom;
+
+        let url = Url::try_from("postgres://localhost/db?tcp_keepalive=60&tcp_nodelay=true")?;
+
+        let options = TcpOptions::default().or_url(&url)?;
+
+        assert_eq!(options.keepalive, Some(Duration::from_secs(60)));
+        assert_eq!(options.nodelay, Some(true));
+
+        // an option that is set overrides the parameter of the URL
+        let options = TcpOptions {
+            keepalive: Some(Duration::from_secs(30)),
+            nodelay: Some(false),
+        }
+        .or_url(&url)?;
+
+        assert_eq!(options.keepalive, Some(Duration::from_secs(30)));
+        assert_eq!(options.nodelay, Some(false));
+
+        let url = Url::try_from("postgres://localhost/db")?;
+        let options = TcpOptions::default().or_url(&url)?;
+
+        assert_eq!(options.keepalive, None);
+        assert_eq!(options.nodelay, None);
+
+        for url in &[
+            "postgres://localhost/db?tcp_keepalive=1m",
+            "postgres://localhost/db?tcp_nodelay=yes",
+        ] {
+            assert!(TcpOptions::default().or_url(&Url::try_from(*url)?).is_err());
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "runtime-async-std")]
+    #[test]
+    fn test_connect_with_options() -> io::Result<()> {
+        async_std::task::block_on(it_connects_with_the_options())
+    }
+
+    #[cfg(feature = "runtime-tokio")]
+    #[test]
+    fn test_connect_with_options() -> io::Result<()> {
+        tokio::runtime::Builder::new()
+            .basic_scheduler()
+            .enable_all()
+            .build()?
+            .block_on(it_connects_with_the_options())
+    }
+
+    // the socket of an async-std stream can not be read, so that of the blocking connection is
+    #[cfg(feature = "runtime-async-std")]
+    #[test]
+    fn test_connect_with_keepalive() -> io::Result<()> {
+        use net2::TcpStreamExt;
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+
+        let stream = connect_with_keepalive(listener.local_addr()?, Duration::from_secs(30))?;
+
+        assert_eq!(stream.keepalive()?, Some(Duration::from_secs(30)));
+
+        Ok(())
+    }
+}
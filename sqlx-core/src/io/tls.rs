@@ -3,79 +3,351 @@ use std::net::Shutdown;
 use std::pin::Pin;
 use std::task::{Context, Poll};
 
+use crate::io::TcpOptions;
+#[cfg(unix)]
+use crate::runtime::UnixStream;
 use crate::runtime::{AsyncRead, AsyncWrite, TcpStream};
 use crate::url::Url;
 
 use self::Inner::*;
 
+#[cfg(all(feature = "tls", feature = "tls-rustls"))]
+compile_error!("only one of the `tls` feature and the `runtime-*-rustls` features can be enabled");
+
+#[cfg(feature = "tls")]
+type TlsStream = async_native_tls::TlsStream<TcpStream>;
+
+#[cfg(all(feature = "tls-rustls", feature = "runtime-async-std"))]
+type TlsStream = async_rustls::client::TlsStream<TcpStream>;
+
+#[cfg(all(feature = "tls-rustls", feature = "runtime-tokio"))]
+type TlsStream = tokio_rustls::client::TlsStream<TcpStream>;
+
+/// How the certificate of the server is verified when a connection is upgraded to TLS.
+#[cfg(any(feature = "tls", feature = "tls-rustls"))]
+pub struct TlsConfig<'a> {
+    pub accept_invalid_certs: bool,
+    pub accept_invalid_hostnames: bool,
+    /// A PEM encoded certificate to trust in addition to those of the system (or, with
+    /// rustls, the Mozilla root certificates).
+    pub root_cert: Option<&'a [u8]>,
+    /// A PEM encoded certificate and private key to present to the server; only used when
+    /// both are set.
+    pub client_cert: Option<&'a [u8]>,
+    pub client_key: Option<&'a [u8]>,
+}
+
 pub struct MaybeTlsStream {
     inner: Inner,
 }
 
 enum Inner {
     NotTls(TcpStream),
-    #[cfg(feature = "tls")]
-    Tls(async_native_tls::TlsStream<TcpStream>),
-    #[cfg(feature = "tls")]
+    #[cfg(unix)]
+    Unix(UnixStream),
+    #[cfg(any(feature = "tls", feature = "tls-rustls"))]
+    Tls(Box<TlsStream>),
+    #[cfg(any(feature = "tls", feature = "tls-rustls"))]
     Upgrading,
 }
 
 impl MaybeTlsStream {
-    pub async fn connect(url: &Url, default_port: u16) -> crate::Result<Self> {
-        let conn = TcpStream::connect((url.host(), url.port(default_port))).await?;
+    pub async fn connect(url: &Url, default_port: u16, tcp: &TcpOptions) -> crate::Result<Self> {
+        let tcp = tcp.or_url(url)?;
+        let conn = tcp.connect(url.host(), url.port(default_port)).await?;
         Ok(Self {
             inner: Inner::NotTls(conn),
         })
     }
 
+    /// Connect to the Unix domain socket at `path`; the connection can not be upgraded to TLS.
+    #[cfg(unix)]
+    pub async fn connect_unix(path: &std::path::Path) -> crate::Result<Self> {
+        let conn = UnixStream::connect(path).await?;
+        Ok(Self {
+            inner: Inner::Unix(conn),
+        })
+    }
+
+    #[allow(dead_code)]
+    pub fn is_unix(&self) -> bool {
+        match self.inner {
+            #[cfg(unix)]
+            Inner::Unix(_) => true,
+            _ => false,
+        }
+    }
+
     #[allow(dead_code)]
     pub fn is_tls(&self) -> bool {
         match self.inner {
             Inner::NotTls(_) => false,
-            #[cfg(feature = "tls")]
+            #[cfg(unix)]
+            Inner::Unix(_) => false,
+            #[cfg(any(feature = "tls", feature = "tls-rustls"))]
             Inner::Tls(_) => true,
-            #[cfg(feature = "tls")]
+            #[cfg(any(feature = "tls", feature = "tls-rustls"))]
             Inner::Upgrading => false,
         }
     }
 
-    #[cfg(feature = "tls")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "tls")))]
-    pub async fn upgrade(
-        &mut self,
-        url: &Url,
-        connector: async_native_tls::TlsConnector,
-    ) -> crate::Result<()> {
+    #[cfg(any(feature = "tls", feature = "tls-rustls"))]
+    pub async fn upgrade(&mut self, url: &Url, config: TlsConfig<'_>) -> crate::Result<()> {
         let conn = match std::mem::replace(&mut self.inner, Upgrading) {
             NotTls(conn) => conn,
+            #[cfg(unix)]
+            Unix(conn) => {
+                self.inner = Unix(conn);
+                return Err(tls_err!("a Unix domain socket can not be upgraded to TLS").into());
+            }
             Tls(_) => return Err(tls_err!("connection already upgraded").into()),
             Upgrading => return Err(tls_err!("connection already failed to upgrade").into()),
         };
 
-        self.inner = Tls(connector.connect(url.host(), conn).await?);
+        self.inner = Tls(Box::new(connect_tls(conn, url, config).await?));
 
         Ok(())
     }
 
+    /// The DER encoded certificate of the server, for a connection upgraded to TLS.
+    #[allow(dead_code)]
+    pub fn peer_certificate(&self) -> crate::Result<Option<Vec<u8>>> {
+        match self.inner {
+            #[cfg(feature = "tls")]
+            Tls(ref conn) => match conn.peer_certificate()? {
+                Some(cert) => Ok(Some(cert.to_der()?)),
+                None => Ok(None),
+            },
+
+            #[cfg(feature = "tls-rustls")]
+            Tls(ref conn) => {
+                use rustls::Session;
+
+                Ok(conn
+                    .get_ref()
+                    .1
+                    .get_peer_certificates()
+                    .and_then(|certs| certs.into_iter().next())
+                    .map(|cert| cert.0))
+            }
+
+            _ => Ok(None),
+        }
+    }
+
     pub fn shutdown(&self, how: Shutdown) -> io::Result<()> {
         match self.inner {
             NotTls(ref conn) => conn.shutdown(how),
+            #[cfg(unix)]
+            Unix(ref conn) => conn.shutdown(how),
             #[cfg(feature = "tls")]
             Tls(ref conn) => conn.get_ref().shutdown(how),
-            #[cfg(feature = "tls")]
+            #[cfg(feature = "tls-rustls")]
+            Tls(ref conn) => conn.get_ref().0.shutdown(how),
+            #[cfg(any(feature = "tls", feature = "tls-rustls"))]
             // connection already closed
             Upgrading => Ok(()),
         }
     }
 }
 
+#[cfg(feature = "tls")]
+async fn connect_tls(
+    conn: TcpStream,
+    url: &Url,
+    config: TlsConfig<'_>,
+) -> crate::Result<TlsStream> {
+    use async_native_tls::{Certificate, Identity, TlsConnector};
+
+    let mut connector = TlsConnector::new()
+        .danger_accept_invalid_certs(config.accept_invalid_certs)
+        .danger_accept_invalid_hostnames(config.accept_invalid_hostnames);
+
+    if let Some(root_cert) = config.root_cert {
+        connector = connector.add_root_certificate(Certificate::from_pem(root_cert)?);
+    }
+
+    if let (Some(cert), Some(key)) = (config.client_cert, config.client_key) {
+        connector = connector.identity(Identity::from_pkcs8(cert, key)?);
+    }
+
+    Ok(connector.connect(url.host(), conn).await?)
+}
+
+#[cfg(feature = "tls-rustls")]
+async fn connect_tls(
+    conn: TcpStream,
+    url: &Url,
+    config: TlsConfig<'_>,
+) -> crate::Result<TlsStream> {
+    use std::sync::Arc;
+
+    use rustls::ClientConfig;
+    use webpki::DNSNameRef;
+
+    #[cfg(feature = "runtime-async-std")]
+    use async_rustls::TlsConnector;
+    #[cfg(feature = "runtime-tokio")]
+    use tokio_rustls::TlsConnector;
+
+    let mut client = ClientConfig::new();
+
+    if config.accept_invalid_certs {
+        client
+            .dangerous()
+            .set_certificate_verifier(Arc::new(rustls_verifier::AcceptAnyCertificate));
+    } else {
+        client
+            .root_store
+            .add_server_trust_anchors(&webpki_roots::TLS_SERVER_ROOTS);
+
+        if let Some(root_cert) = config.root_cert {
+            match client
+                .root_store
+                .add_pem_file(&mut io::Cursor::new(root_cert))
+            {
+                Ok((valid, _)) if valid > 0 => {}
+
+                _ => return Err(tls_err!("invalid root certificate").into()),
+            }
+        }
+
+        if config.accept_invalid_hostnames {
+            client
+                .dangerous()
+                .set_certificate_verifier(Arc::new(rustls_verifier::AcceptAnyHostname));
+        }
+    }
+
+    if let (Some(cert), Some(key)) = (config.client_cert, config.client_key) {
+        use rustls::internal::pemfile;
+
+        let certs = match pemfile::certs(&mut io::Cursor::new(cert)) {
+            Ok(certs) if !certs.is_empty() => certs,
+            _ => return Err(tls_err!("invalid client certificate").into()),
+        };
+
+        // PKCS #8 (`BEGIN PRIVATE KEY`) or RSA (`BEGIN RSA PRIVATE KEY`)
+        let key = pemfile::pkcs8_private_keys(&mut io::Cursor::new(key))
+            .ok()
+            .and_then(|keys| keys.into_iter().next())
+            .or_else(|| {
+                pemfile::rsa_private_keys(&mut io::Cursor::new(key))
+                    .ok()
+                    .and_then(|keys| keys.into_iter().next())
+            })
+            .ok_or_else(|| tls_err!("invalid client key"))?;
+
+        client
+            .set_single_client_cert(certs, key)
+            .map_err(|err| crate::Error::Tls(err.into()))?;
+    }
+
+    // The host is sent to the server with SNI; an IP address is not a name that webpki
+    // can check the certificate against, so the connection goes without it
+    let host = match DNSNameRef::try_from_ascii_str(url.host()) {
+        Ok(host) => host,
+
+        Err(_) if config.accept_invalid_hostnames => {
+            client.enable_sni = false;
+
+            DNSNameRef::try_from_ascii_str("localhost").unwrap()
+        }
+
+        Err(_) => {
+            return Err(tls_err!(
+                "the certificate of {:?} can not be verified against its host name",
+                url.host()
+            )
+            .into());
+        }
+    };
+
+    TlsConnector::from(Arc::new(client))
+        .connect(host, conn)
+        .await
+        .map_err(|err| {
+            let not_valid_for_name = matches!(
+                err.get_ref()
+                    .and_then(|err| err.downcast_ref::<rustls::TLSError>()),
+                Some(rustls::TLSError::WebPKIError(
+                    webpki::Error::CertNotValidForName
+                ))
+            );
+
+            if not_valid_for_name {
+                tls_err!(
+                    "the certificate of the server is not valid for the host {:?}",
+                    url.host()
+                )
+                .into()
+            } else {
+                crate::Error::Tls(err.into())
+            }
+        })
+}
+
+#[cfg(feature = "tls-rustls")]
+mod rustls_verifier {
+    use rustls::{
+        Certificate, RootCertStore, ServerCertVerified, ServerCertVerifier, TLSError,
+        WebPKIVerifier,
+    };
+    use webpki::DNSNameRef;
+
+    // For `sslmode=require` or a MySQL `ssl-mode` of `PREFERRED` or `REQUIRED`, where the
+    // connection is encrypted but the server is not authenticated
+    pub(super) struct AcceptAnyCertificate;
+
+    impl ServerCertVerifier for AcceptAnyCertificate {
+        fn verify_server_cert(
+            &self,
+            _roots: &RootCertStore,
+            _presented_certs: &[Certificate],
+            _dns_name: DNSNameRef<'_>,
+            _ocsp_response: &[u8],
+        ) -> Result<ServerCertVerified, TLSError> {
+            Ok(ServerCertVerified::assertion())
+        }
+    }
+
+    // For `sslmode=verify-ca` or a MySQL `ssl-mode` of `VERIFY_CA`, where the certificate must
+    // be signed by a trusted authority but may be for any host
+    pub(super) struct AcceptAnyHostname;
+
+    impl ServerCertVerifier for AcceptAnyHostname {
+        fn verify_server_cert(
+            &self,
+            roots: &RootCertStore,
+            presented_certs: &[Certificate],
+            dns_name: DNSNameRef<'_>,
+            ocsp_response: &[u8],
+        ) -> Result<ServerCertVerified, TLSError> {
+            match WebPKIVerifier::new().verify_server_cert(
+                roots,
+                presented_certs,
+                dns_name,
+                ocsp_response,
+            ) {
+                Err(TLSError::WebPKIError(webpki::Error::CertNotValidForName)) => {
+                    Ok(ServerCertVerified::assertion())
+                }
+
+                result => result,
+            }
+        }
+    }
+}
+
 macro_rules! forward_pin (
     ($self:ident.$method:ident($($arg:ident),*)) => (
         match &mut $self.inner {
             NotTls(ref mut conn) => Pin::new(conn).$method($($arg),*),
-            #[cfg(feature = "tls")]
+            #[cfg(unix)]
+            Unix(ref mut conn) => Pin::new(conn).$method($($arg),*),
+            #[cfg(any(feature = "tls", feature = "tls-rustls"))]
             Tls(ref mut conn) => Pin::new(conn).$method($($arg),*),
-            #[cfg(feature = "tls")]
+            #[cfg(any(feature = "tls", feature = "tls-rustls"))]
             Upgrading => Err(io::Error::new(io::ErrorKind::Other, "connection broken; TLS upgrade failed")).into(),
         }
     )
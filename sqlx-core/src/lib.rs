@@ -34,6 +34,7 @@ pub mod value;
 #[macro_use]
 pub mod executor;
 
+mod logger;
 pub mod transaction;
 mod url;
 
@@ -41,7 +42,6 @@ mod url;
 pub mod arguments;
 pub mod decode;
 
-#[doc(hidden)]
 pub mod describe;
 
 pub mod encode;
@@ -51,6 +51,8 @@ pub mod query;
 #[macro_use]
 pub mod query_as;
 
+pub mod query_builder;
+
 pub mod types;
 
 #[macro_use]
@@ -68,4 +70,8 @@ pub mod postgres;
 #[cfg_attr(docsrs, doc(cfg(feature = "sqlite")))]
 pub mod sqlite;
 
+#[cfg(feature = "any")]
+#[cfg_attr(docsrs, doc(cfg(feature = "any")))]
+pub mod any;
+
 pub use error::{Error, Result};
@@ -0,0 +1,324 @@
+use std::fmt::Debug;
+use std::time::Instant;
+
+use log::Level;
+
+use crate::connection::LogSettings;
+
+// The bind parameters of a query as they are written to the log
+pub(crate) trait LogArguments: Debug {
+    // The `db.system` of the database the arguments are for, in a trace
+    const DB_SYSTEM: &'static str;
+
+    fn count(&self) -> usize;
+}
+
+// Logs a query to the `sqlx::query` target when it is dropped, after the query completed or
+// was abandoned, with the rows it affected or returned and the time since it was started
+pub(crate) struct QueryLogger<'q> {
+    sql: &'q str,
+    parameters: Option<String>,
+    rows: u64,
+    start: Instant,
+    settings: LogSettings,
+    span: QuerySpan,
+}
+
+impl<'q> QueryLogger<'q> {
+    pub(crate) fn new<A>(sql: &'q str, arguments: Option<&A>, settings: &LogSettings) -> Self
+    where
+        A: LogArguments,
+    {
+        let span = QuerySpan::new(A::DB_SYSTEM, sql);
+
+        // The parameters are formatted now as the arguments are consumed by the query
+        let parameters = match arguments {
+            Some(arguments) if settings.is_enabled() || span.is_enabled() => {
+                Some(if settings.redact_parameters {
+                    format!("{} redacted", arguments.count())
+                } else {
+                    format!("{:?}", arguments)
+                })
+            }
+
+            _ => None,
+        };
+
+        Self {
+            sql,
+            parameters,
+            rows: 0,
+            start: Instant::now(),
+            settings: settings.clone(),
+            span,
+        }
+    }
+
+    pub(crate) fn add_rows(&mut self, rows: u64) {
+        self.rows += rows;
+    }
+
+    // For a cursor, which can not get to its logger once a call to `next` failed
+    pub(crate) fn span(&self) -> QuerySpan {
+        self.span.clone()
+    }
+
+    pub(crate) fn record_error(&self, error: &crate::Error) {
+        self.span.record_error(error);
+    }
+}
+
+impl Drop for QueryLogger<'_> {
+    fn drop(&mut self) {
+        self.span.record_rows(self.rows);
+
+        let elapsed = self.start.elapsed();
+        let slow = elapsed >= self.settings.slow_statements_duration;
+
+        let level = if slow {
+            self.settings.slow_statements_level
+        } else {
+            self.settings.statements_level
+        };
+
+        let level = match level.to_level() {
+            Some(level) => level,
+            None => return,
+        };
+
+        if level > log::max_level() && !self.span.is_enabled() {
+            return;
+        }
+
+        let mut message = format!(
+            "{}{}; rows: {}, elapsed: {:.3?}",
+            if slow { "slow statement: " } else { "" },
+            self.sql.trim(),
+            self.rows,
+            elapsed
+        );
+
+        if let Some(parameters) = &self.parameters {
+            message.push_str(", parameters: ");
+            message.push_str(parameters);
+        }
+
+        log::log!(target: "sqlx::query", level, "{}", message);
+
+        self.span.event(level, &message);
+    }
+}
+
+// The `tracing` span of a query, with the fields of the OpenTelemetry conventions for
+// database calls; it covers the query from when it is started until it is logged
+#[derive(Clone)]
+pub(crate) struct QuerySpan {
+    #[cfg(feature = "tracing")]
+    span: tracing::Span,
+}
+
+#[cfg(feature = "tracing")]
+impl QuerySpan {
+    fn new(db_system: &str, sql: &str) -> Self {
+        Self {
+            span: tracing::info_span!(
+                target: "sqlx::query",
+                "sqlx.query",
+                db.system = db_system,
+                db.statement = sql.trim(),
+                db.rows_affected = tracing::field::Empty,
+                error = tracing::field::Empty,
+            ),
+        }
+    }
+
+    fn is_enabled(&self) -> bool {
+        !self.span.is_disabled()
+    }
+
+    pub(crate) fn record_error(&self, error: &crate::Error) {
+        self.span.record("error", tracing::field::display(error));
+    }
+
+    fn record_rows(&self, rows: u64) {
+        self.span.record("db.rows_affected", rows);
+    }
+
+    // The message of the log is also an event of the span
+    fn event(&self, level: Level, message: &str) {
+        let span = &self.span;
+
+        match level {
+            Level::Error => tracing::error!(target: "sqlx::query", parent: span, "{}", message),
+            Level::Warn => tracing::warn!(target: "sqlx::query", parent: span, "{}", message),
+            Level::Info => tracing::info!(target: "sqlx::query", parent: span, "{}", message),
+            Level::Debug => tracing::debug!(target: "sqlx::query", parent: span, "{}", message),
+            Level::Trace => tracing::trace!(target: "sqlx::query", parent: span, "{}", message),
+        }
+    }
+}
+
+#[cfg(not(feature = "tracing"))]
+impl QuerySpan {
+    fn new(_db_system: &str, _sql: &str) -> Self {
+        Self {}
+    }
+
+    fn is_enabled(&self) -> bool {
+        false
+    }
+
+    pub(crate) fn record_error(&self, _error: &crate::Error) {}
+
+    fn record_rows(&self, _rows: u64) {}
+
+    fn event(&self, _level: Level, _message: &str) {}
+}
+
+impl LogSettings {
+    // Is a query logged at one of the levels, by the `log` crate
+    fn is_enabled(&self) -> bool {
+        let enabled = |level: log::LevelFilter| match level.to_level() {
+            Some(level) => level <= log::max_level(),
+            None => false,
+        };
+
+        enabled(self.statements_level) || enabled(self.slow_statements_level)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Mutex, Once};
+    use std::time::Duration;
+
+    use log::{Level, LevelFilter, Log, Metadata, Record};
+
+    use super::{LogArguments, QueryLogger};
+    use crate::connection::LogSettings;
+
+    // Keeps the messages logged to `sqlx::query`; the tests look for their own query as they
+    // run at the same time
+    struct TestLogger(Mutex<Vec<(Level, String)>>);
+
+    impl Log for TestLogger {
+        fn enabled(&self, _: &Metadata) -> bool {
+            true
+        }
+
+        fn log(&self, record: &Record) {
+            if record.target() == "sqlx::query" {
+                self.0
+                    .lock()
+                    .unwrap()
+                    .push((record.level(), record.args().to_string()));
+            }
+        }
+
+        fn flush(&self) {}
+    }
+
+    static LOGGER: TestLogger = TestLogger(Mutex::new(Vec::new()));
+
+    fn init() {
+        static INIT: Once = Once::new();
+
+        INIT.call_once(|| {
+            log::set_logger(&LOGGER).unwrap();
+            log::set_max_level(LevelFilter::Trace);
+        });
+    }
+
+    fn logged(sql: &str) -> Vec<(Level, String)> {
+        LOGGER
+            .0
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(_, message)| message.contains(sql))
+            .cloned()
+            .collect()
+    }
+
+    #[derive(Debug)]
+    struct Arguments(Vec<i32>);
+
+    impl LogArguments for Arguments {
+        const DB_SYSTEM: &'static str = "other_sql";
+
+        fn count(&self) -> usize {
+            self.0.len()
+        }
+    }
+
+    #[test]
+    fn it_logs_a_query() {
+        init();
+
+        let mut logger = QueryLogger::new(
+            "SELECT 1 + ?",
+            Some(&Arguments(vec![10])),
+            &LogSettings::new(),
+        );
+
+        logger.add_rows(1);
+        drop(logger);
+
+        let logs = logged("SELECT 1 + ?");
+
+        assert_eq!(logs.len(), 1);
+        assert_eq!(logs[0].0, Level::Debug);
+        assert!(logs[0].1.starts_with("SELECT 1 + ?; rows: 1, elapsed: "));
+        assert!(logs[0].1.ends_with(", parameters: Arguments([10])"));
+    }
+
+    #[test]
+    fn it_logs_a_slow_query() {
+        init();
+
+        let settings = LogSettings::new()
+            .log_statements(LevelFilter::Trace)
+            .log_slow_statements(LevelFilter::Error, Duration::from_millis(0));
+
+        drop(QueryLogger::new::<Arguments>("SELECT 2", None, &settings));
+
+        let logs = logged("SELECT 2");
+
+        assert_eq!(logs.len(), 1);
+        assert_eq!(logs[0].0, Level::Error);
+        assert!(logs[0].1.starts_with("slow statement: SELECT 2; rows: 0"));
+        assert!(!logs[0].1.contains("parameters"));
+    }
+
+    #[test]
+    fn it_redacts_parameters() {
+        init();
+
+        let settings = LogSettings::new().redact_parameters(true);
+
+        drop(QueryLogger::new(
+            "SELECT 3 + ? + ?",
+            Some(&Arguments(vec![42, 43])),
+            &settings,
+        ));
+
+        let logs = logged("SELECT 3 + ? + ?");
+
+        assert_eq!(logs.len(), 1);
+        assert!(logs[0].1.ends_with(", parameters: 2 redacted"));
+        assert!(!logs[0].1.contains("Arguments"));
+    }
+
+    #[test]
+    fn it_does_not_log_when_off() {
+        init();
+
+        let settings = LogSettings::new()
+            .log_statements(LevelFilter::Off)
+            .log_slow_statements(LevelFilter::Off, Duration::from_millis(0));
+
+        drop(QueryLogger::new::<Arguments>("SELECT 4", None, &settings));
+
+        assert!(logged("SELECT 4").is_empty());
+    }
+}
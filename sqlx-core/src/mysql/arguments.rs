@@ -1,5 +1,8 @@
+use std::fmt;
+
 use crate::arguments::Arguments;
 use crate::encode::{Encode, IsNull};
+use crate::logger::LogArguments;
 use crate::mysql::type_info::MySqlTypeInfo;
 use crate::mysql::MySql;
 use crate::types::Type;
@@ -9,13 +12,20 @@ pub struct MySqlArguments {
     pub(crate) param_types: Vec<MySqlTypeInfo>,
     pub(crate) params: Vec<u8>,
     pub(crate) null_bitmap: Vec<u8>,
+
+    // Where each value starts in `params`, to log them
+    param_offsets: Vec<usize>,
 }
 
 impl Arguments for MySqlArguments {
     type Database = MySql;
 
+    // the number of parameters is sent as a `u16`
+    const MAX_BINDS: usize = 65535;
+
     fn reserve(&mut self, len: usize, size: usize) {
         self.param_types.reserve(len);
+        self.param_offsets.reserve(len);
         self.params.reserve(size);
 
         // ensure we have enough size in the bitmap to hold at least `len` extra bits
@@ -34,6 +44,7 @@ impl Arguments for MySqlArguments {
         let index = self.param_types.len();
 
         self.param_types.push(type_id);
+        self.param_offsets.push(self.params.len());
         self.null_bitmap.resize((index / 8) + 1, 0);
 
         if let IsNull::Yes = value.encode_nullable(&mut self.params) {
@@ -41,3 +52,39 @@ impl Arguments for MySqlArguments {
         }
     }
 }
+
+// Each value is logged with its type and the bytes it is encoded to
+impl fmt::Debug for MySqlArguments {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut list = f.debug_list();
+
+        for (index, ty) in self.param_types.iter().enumerate() {
+            if self.null_bitmap[index / 8] & (1 << (index % 8)) != 0 {
+                list.entry(&format_args!("{} NULL", ty));
+            } else {
+                let start = self.param_offsets[index];
+                let end = self
+                    .param_offsets
+                    .get(index + 1)
+                    .copied()
+                    .unwrap_or(self.params.len());
+
+                list.entry(&format_args!(
+                    "{} 0x{}",
+                    ty,
+                    hex::encode(&self.params[start..end])
+                ));
+            }
+        }
+
+        list.finish()
+    }
+}
+
+impl LogArguments for MySqlArguments {
+    const DB_SYSTEM: &'static str = "mysql";
+
+    fn count(&self) -> usize {
+        self.param_types.len()
+    }
+}
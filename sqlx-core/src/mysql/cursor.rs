@@ -6,6 +6,7 @@ use futures_core::future::BoxFuture;
 use crate::connection::ConnectionSource;
 use crate::cursor::Cursor;
 use crate::executor::Execute;
+use crate::logger::QueryLogger;
 use crate::mysql::protocol::{ColumnCount, ColumnDefinition, Row, Status};
 use crate::mysql::{MySql, MySqlArguments, MySqlConnection, MySqlRow, MySqlTypeInfo};
 use crate::pool::Pool;
@@ -14,29 +15,43 @@ pub struct MySqlCursor<'c, 'q> {
     source: ConnectionSource<'c, MySqlConnection>,
     query: Option<(&'q str, Option<MySqlArguments>)>,
     column_names: Arc<HashMap<Box<str>, u16>>,
+    column_labels: Arc<[Option<Box<str>>]>,
     column_types: Vec<MySqlTypeInfo>,
     binary: bool,
+    logger: Option<QueryLogger<'q>>,
 }
 
 impl crate::cursor::private::Sealed for MySqlCursor<'_, '_> {}
 
-impl<'c, 'q> Cursor<'c, 'q> for MySqlCursor<'c, 'q> {
-    type Database = MySql;
-
-    #[doc(hidden)]
-    fn from_pool<E>(pool: &Pool<MySqlConnection>, query: E) -> Self
+impl<'c, 'q> MySqlCursor<'c, 'q> {
+    // Also used by the cursor of `Any` for a connection of a pool of `AnyConnection`
+    pub(crate) fn from_source<E>(source: ConnectionSource<'c, MySqlConnection>, query: E) -> Self
     where
-        Self: Sized,
         E: Execute<'q, MySql>,
     {
         Self {
-            source: ConnectionSource::Pool(pool.clone()),
+            source,
             column_names: Arc::default(),
+            column_labels: Arc::new([]),
             column_types: Vec::new(),
             binary: true,
             query: Some(query.into_parts()),
+            logger: None,
         }
     }
+}
+
+impl<'c, 'q> Cursor<'c, 'q> for MySqlCursor<'c, 'q> {
+    type Database = MySql;
+
+    #[doc(hidden)]
+    fn from_pool<E>(pool: &Pool<MySqlConnection>, query: E) -> Self
+    where
+        Self: Sized,
+        E: Execute<'q, MySql>,
+    {
+        Self::from_source(ConnectionSource::Pool(pool.clone()), query)
+    }
 
     #[doc(hidden)]
     fn from_connection<E>(conn: &'c mut MySqlConnection, query: E) -> Self
@@ -44,17 +59,30 @@ impl<'c, 'q> Cursor<'c, 'q> for MySqlCursor<'c, 'q> {
         Self: Sized,
         E: Execute<'q, MySql>,
     {
-        Self {
-            source: ConnectionSource::ConnectionRef(conn),
-            column_names: Arc::default(),
-            column_types: Vec::new(),
-            binary: true,
-            query: Some(query.into_parts()),
-        }
+        Self::from_source(ConnectionSource::ConnectionRef(conn), query)
     }
 
     fn next(&mut self) -> BoxFuture<crate::Result<Option<MySqlRow<'_>>>> {
-        Box::pin(next(self))
+        Box::pin(async move {
+            if let Some((query, arguments)) = &self.query {
+                let conn = self.source.resolve().await?;
+
+                self.logger = Some(QueryLogger::new(
+                    query,
+                    arguments.as_ref(),
+                    &conn.log_settings,
+                ));
+            }
+
+            let span = self.logger.as_ref().map(QueryLogger::span);
+            let result = next(self).await;
+
+            if let (Err(error), Some(span)) = (&result, span) {
+                span.record_error(error);
+            }
+
+            result
+        })
     }
 }
 
@@ -96,6 +124,9 @@ async fn next<'a, 'c: 'a, 'q: 'a>(
                     initial = true;
                 } else {
                     conn.is_ready = true;
+
+                    // all of the rows were returned
+                    cursor.logger = None;
                     return Ok(None);
                 }
             }
@@ -119,6 +150,7 @@ async fn next<'a, 'c: 'a, 'q: 'a>(
                 cursor.column_types.reserve(cc.columns as usize);
 
                 let mut column_names = HashMap::with_capacity(cc.columns as usize);
+                let mut column_labels = Vec::with_capacity(cc.columns as usize);
 
                 for i in 0..cc.columns {
                     let column = ColumnDefinition::read(conn.stream.receive().await?)?;
@@ -130,6 +162,8 @@ async fn next<'a, 'c: 'a, 'q: 'a>(
                     if let Some(name) = column.name() {
                         column_names.insert(name.to_owned().into_boxed_str(), i as u16);
                     }
+
+                    column_labels.push(column.name().map(Into::into));
                 }
 
                 if cc.columns > 0 {
@@ -137,6 +171,7 @@ async fn next<'a, 'c: 'a, 'q: 'a>(
                 }
 
                 cursor.column_names = Arc::new(column_names);
+                cursor.column_labels = column_labels.into();
                 initial = false;
             }
 
@@ -148,9 +183,14 @@ async fn next<'a, 'c: 'a, 'q: 'a>(
                     cursor.binary,
                 )?;
 
+                if let Some(logger) = &mut cursor.logger {
+                    logger.add_rows(1);
+                }
+
                 let row = MySqlRow {
                     row,
                     names: Arc::clone(&cursor.column_names),
+                    labels: Arc::clone(&cursor.column_labels),
                 };
 
                 return Ok(Some(row));
@@ -1,12 +1,20 @@
 use std::error::Error as StdError;
 use std::fmt::{self, Display};
 
-use crate::error::DatabaseError;
+use crate::error::{DatabaseError, DatabaseErrorKind};
 use crate::mysql::protocol::ErrPacket;
 
 #[derive(Debug)]
 pub struct MySqlError(pub(super) ErrPacket);
 
+impl MySqlError {
+    /// The number of the error, e.g. `1062` for `ER_DUP_ENTRY`, which is more precise than its
+    /// SQLSTATE.
+    pub fn number(&self) -> u16 {
+        self.0.error_code
+    }
+}
+
 impl Display for MySqlError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.pad(self.message())
@@ -22,6 +30,27 @@ impl DatabaseError for MySqlError {
         self.0.sql_state.as_deref()
     }
 
+    // the SQLSTATE of all of these is 23000, so they are told apart by their number
+    // https://dev.mysql.com/doc/mysql-errors/8.0/en/server-error-reference.html
+    // https://mariadb.com/kb/en/mariadb-error-codes/
+    fn kind(&self) -> DatabaseErrorKind {
+        match self.0.error_code {
+            // ER_DUP_ENTRY, ER_DUP_ENTRY_WITH_KEY_NAME
+            1062 | 1586 => DatabaseErrorKind::UniqueViolation,
+
+            // ER_ROW_IS_REFERENCED, ER_NO_REFERENCED_ROW and their `_2` variants
+            1216 | 1217 | 1451 | 1452 => DatabaseErrorKind::ForeignKeyViolation,
+
+            // ER_BAD_NULL_ERROR
+            1048 => DatabaseErrorKind::NotNullViolation,
+
+            // ER_CHECK_CONSTRAINT_VIOLATED (MySQL), ER_CONSTRAINT_FAILED (MariaDB)
+            3819 | 4025 => DatabaseErrorKind::CheckViolation,
+
+            _ => DatabaseErrorKind::Other,
+        }
+    }
+
     fn as_ref_err(&self) -> &(dyn StdError + Send + Sync + 'static) {
         self
     }
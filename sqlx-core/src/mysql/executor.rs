@@ -1,11 +1,15 @@
+use async_stream::try_stream;
 use futures_core::future::BoxFuture;
+use futures_core::stream::BoxStream;
+use futures_util::TryStreamExt;
 
 use crate::cursor::Cursor;
 use crate::describe::{Column, Describe};
-use crate::executor::{Execute, Executor, RefExecutor};
+use crate::executor::{Execute, Executor, QueryResult, RefExecutor};
+use crate::logger::QueryLogger;
 use crate::mysql::protocol::{
-    self, ColumnDefinition, ComQuery, ComStmtExecute, ComStmtPrepare, ComStmtPrepareOk, FieldFlags,
-    Status,
+    self, ColumnCount, ColumnDefinition, ComQuery, ComStmtExecute, ComStmtPrepare,
+    ComStmtPrepareOk, FieldFlags, Status,
 };
 use crate::mysql::{MySql, MySqlArguments, MySqlCursor, MySqlTypeInfo};
 
@@ -93,8 +97,9 @@ impl super::MySqlConnection {
         }
     }
 
-    async fn affected_rows(&mut self) -> crate::Result<u64> {
+    async fn affected_rows(&mut self) -> crate::Result<QueryResult> {
         let mut rows = 0;
+        let mut last_insert_id = 0;
 
         loop {
             let id = self.stream.receive().await?[0];
@@ -110,6 +115,7 @@ impl super::MySqlConnection {
                         let ok = self.stream.handle_ok()?;
 
                         rows += ok.affected_rows;
+                        last_insert_id = ok.last_insert_id;
                         ok.status
                     };
 
@@ -127,7 +133,10 @@ impl super::MySqlConnection {
             }
         }
 
-        Ok(rows)
+        Ok(QueryResult {
+            rows_affected: rows,
+            last_insert_id: Some(last_insert_id as i64),
+        })
     }
 
     // method is not named describe to work around an intellijrust bug
@@ -178,18 +187,97 @@ impl Executor for super::MySqlConnection {
     fn execute<'e, 'q: 'e, 'c: 'e, E: 'e>(
         &'c mut self,
         query: E,
-    ) -> BoxFuture<'e, crate::Result<u64>>
+    ) -> BoxFuture<'e, crate::Result<QueryResult>>
     where
         E: Execute<'q, Self::Database>,
     {
         Box::pin(async move {
             let (query, arguments) = query.into_parts();
+            let mut logger = QueryLogger::new(query, arguments.as_ref(), &self.log_settings);
+
+            let result = async {
+                self.run(query, arguments).await?;
+                self.affected_rows().await
+            }
+            .await;
+
+            match &result {
+                Ok(result) => logger.add_rows(result.rows_affected),
+                Err(error) => logger.record_error(error),
+            }
 
-            self.run(query, arguments).await?;
-            self.affected_rows().await
+            result
         })
     }
 
+    fn execute_many<'e, 'q: 'e, 'c: 'e>(
+        &'c mut self,
+        query: &'q str,
+    ) -> BoxStream<'e, crate::Result<QueryResult>> {
+        let mut logger = QueryLogger::new::<MySqlArguments>(query, None, &self.log_settings);
+        let span = logger.span();
+
+        let stream = try_stream! {
+            self.run(query, None).await?;
+
+            // Each statement returns an OK packet, or a result set that ends with one
+            let mut initial = true;
+
+            loop {
+                let packet_id = self.stream.receive().await?[0];
+
+                match packet_id {
+                    0x00 | 0xFE
+                        if self.stream.packet().len() < 0xFF_FF_FF
+                            && (packet_id != 0x00 || initial) =>
+                    {
+                        let (status, result) = if let Some(eof) = self.stream.maybe_handle_eof()? {
+                            (eof.status, QueryResult::default())
+                        } else {
+                            let ok = self.stream.handle_ok()?;
+
+                            (
+                                ok.status,
+                                QueryResult {
+                                    rows_affected: ok.affected_rows,
+                                    last_insert_id: Some(ok.last_insert_id as i64),
+                                },
+                            )
+                        };
+
+                        logger.add_rows(result.rows_affected);
+
+                        yield result;
+
+                        if !status.contains(Status::SERVER_MORE_RESULTS_EXISTS) {
+                            self.is_ready = true;
+                            break;
+                        }
+
+                        initial = true;
+                    }
+
+                    0xFF => {
+                        self.is_ready = true;
+                        self.stream.handle_err::<()>()?;
+                    }
+
+                    _ if initial => {
+                        // the rows of a result set are ignored
+                        let cc = ColumnCount::read(self.stream.packet())?;
+
+                        self.drop_column_defs(cc.columns as usize).await?;
+                        initial = false;
+                    }
+
+                    _ => {}
+                }
+            }
+        };
+
+        Box::pin(stream.inspect_err(move |error| span.record_error(error)))
+    }
+
     fn fetch<'q, E>(&mut self, query: E) -> MySqlCursor<'_, 'q>
     where
         E: Execute<'q, Self::Database>,
@@ -197,7 +285,6 @@ impl Executor for super::MySqlConnection {
         MySqlCursor::from_connection(self, query)
     }
 
-    #[doc(hidden)]
     fn describe<'e, 'q, E: 'e>(
         &'e mut self,
         query: E,
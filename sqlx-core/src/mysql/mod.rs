@@ -5,6 +5,7 @@ pub use connection::MySqlConnection;
 pub use cursor::MySqlCursor;
 pub use database::MySql;
 pub use error::MySqlError;
+pub use options::MySqlConnectOptions;
 pub use row::MySqlRow;
 pub use type_info::MySqlTypeInfo;
 pub use value::{MySqlData, MySqlValue};
@@ -16,6 +17,7 @@ mod database;
 mod error;
 mod executor;
 mod io;
+mod options;
 mod protocol;
 mod row;
 mod rsa;
@@ -0,0 +1,53 @@
+use std::time::Duration;
+
+use crate::io::TcpOptions;
+
+/// Options for a new connection, set with [`MySqlConnection::connect_with`].
+///
+/// An option that is set overrides the parameter of the URL for the same thing.
+///
+/// ```rust,ignore
+/// let options = MySqlConnectOptions::new()
+///     .tcp_keepalive(Duration::from_secs(60))
+///     .tcp_nodelay(true);
+///
+/// let mut conn = MySqlConnection::connect_with("mysql://root@localhost/db", &options).await?;
+/// ```
+///
+/// [`MySqlConnection::connect_with`]: crate::mysql::MySqlConnection::connect_with
+#[derive(Debug, Clone, Default)]
+pub struct MySqlConnectOptions {
+    pub(crate) tcp: TcpOptions,
+}
+
+impl MySqlConnectOptions {
+    /// The default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enable TCP keepalive, sending probes once the connection has been idle for `time`
+    /// (in whole seconds), so that a connection through a NAT or a firewall is not
+    /// dropped while it is idle and a dead peer is detected.
+    ///
+    /// The same as the `tcp_keepalive` parameter of the URL, e.g. `?tcp_keepalive=60`, which
+    /// also sets it for the connections of a pool.
+    ///
+    /// This is not set for a connection over a Unix domain socket. With `async-std` it is only
+    /// supported on Unix.
+    pub fn tcp_keepalive(mut self, time: Duration) -> Self {
+        self.tcp.keepalive = Some(time);
+        self
+    }
+
+    /// Set `TCP_NODELAY` on the socket, which disables Nagle's algorithm when `true`, so that
+    /// small messages are sent at once.
+    ///
+    /// The same as the `tcp_nodelay` parameter of the URL, e.g. `?tcp_nodelay=true`.
+    ///
+    /// This is not set for a connection over a Unix domain socket.
+    pub fn tcp_nodelay(mut self, nodelay: bool) -> Self {
+        self.tcp.nodelay = Some(nodelay);
+        self
+    }
+}
@@ -22,7 +22,13 @@ impl AuthSwitch {
         }
 
         let auth_plugin = AuthPlugin::from_opt_str(Some(buf.get_str_nul()?))?;
-        let auth_plugin_data = buf.get_bytes(buf.len())?.to_owned().into_boxed_slice();
+        // The data of the plugin, the nonce, is terminated by a NUL that is not a part of it
+        let auth_plugin_data = buf.get_bytes(buf.len())?;
+        let auth_plugin_data = auth_plugin_data
+            .strip_suffix(b"\0")
+            .unwrap_or(auth_plugin_data)
+            .to_owned()
+            .into_boxed_slice();
 
         Ok(Self {
             auth_plugin_data,
@@ -30,3 +36,24 @@ impl AuthSwitch {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{AuthPlugin, AuthSwitch};
+
+    const AUTH_SWITCH_CACHING_SHA2_PASSWORD: &[u8] =
+        b"\xfecaching_sha2_password\x00\x1a!\x13Jx`.X\x05t:\x1cn\x08\x13R\x15\x0cG@\x00";
+
+    #[test]
+    fn it_reads_auth_switch_caching_sha2_password() {
+        let p = AuthSwitch::read(AUTH_SWITCH_CACHING_SHA2_PASSWORD).unwrap();
+
+        assert!(matches!(p.auth_plugin, AuthPlugin::CachingSha2Password));
+
+        // without the trailing NUL
+        assert_eq!(
+            &*p.auth_plugin_data,
+            &b"\x1a!\x13Jx`.X\x05t:\x1cn\x08\x13R\x15\x0cG@"[..]
+        );
+    }
+}
@@ -12,12 +12,14 @@ pub(crate) use status::Status;
 
 mod com_ping;
 mod com_query;
+mod com_reset_connection;
 mod com_stmt_execute;
 mod com_stmt_prepare;
 mod handshake;
 
 pub(crate) use com_ping::ComPing;
 pub(crate) use com_query::ComQuery;
+pub(crate) use com_reset_connection::ComResetConnection;
 pub(crate) use com_stmt_execute::{ComStmtExecute, Cursor};
 pub(crate) use com_stmt_prepare::ComStmtPrepare;
 pub(crate) use handshake::Handshake;
@@ -31,7 +33,10 @@ mod err;
 mod handshake_response;
 mod ok;
 mod row;
-#[cfg_attr(not(feature = "tls"), allow(unused_imports, dead_code))]
+#[cfg_attr(
+    not(any(feature = "tls", feature = "tls-rustls")),
+    allow(unused_imports, dead_code)
+)]
 mod ssl_request;
 
 pub(crate) use auth_switch::AuthSwitch;
@@ -43,7 +48,10 @@ pub(crate) use err::ErrPacket;
 pub(crate) use handshake_response::HandshakeResponse;
 pub(crate) use ok::OkPacket;
 pub(crate) use row::Row;
-#[cfg_attr(not(feature = "tls"), allow(unused_imports, dead_code))]
+#[cfg_attr(
+    not(any(feature = "tls", feature = "tls-rustls")),
+    allow(unused_imports, dead_code)
+)]
 pub(crate) use ssl_request::SslRequest;
 
 pub(crate) trait Encode {
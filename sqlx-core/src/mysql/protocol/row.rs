@@ -115,8 +115,8 @@ impl<'c> Row<'c> {
             } else {
                 let (offset, size) = match columns[column_idx].id {
                     TypeId::TINY_INT => (0, 1),
-                    TypeId::SMALL_INT => (0, 2),
-                    TypeId::INT | TypeId::FLOAT => (0, 4),
+                    TypeId::SMALL_INT | TypeId::YEAR => (0, 2),
+                    TypeId::MEDIUM_INT | TypeId::INT | TypeId::FLOAT => (0, 4),
                     TypeId::BIG_INT | TypeId::DOUBLE => (0, 8),
 
                     TypeId::DATE => (0, 5),
@@ -130,13 +130,16 @@ impl<'c> Row<'c> {
                     | TypeId::CHAR
                     | TypeId::TEXT
                     | TypeId::ENUM
-                    | TypeId::VAR_CHAR => {
+                    | TypeId::SET
+                    | TypeId::VAR_CHAR
+                    | TypeId::JSON
+                    | TypeId::BIT => {
                         let (len_size, len) = get_lenenc(&buffer[index..]);
 
                         (len_size, len.unwrap_or_default())
                     }
 
-                    TypeId::NEWDECIMAL => (0, 1 + buffer[index] as usize),
+                    TypeId::DECIMAL | TypeId::NEWDECIMAL => (0, 1 + buffer[index] as usize),
 
                     id => {
                         unimplemented!("encountered unknown field type id: {:?}", id);
@@ -13,8 +13,12 @@ impl TypeId {
     pub const VAR_CHAR: TypeId = TypeId(253); // or VAR_BINARY
     pub const TEXT: TypeId = TypeId(252); // or BLOB
 
-    // Enum
+    // Enum and Set: sent by the server as CHAR with the ENUM or SET flag
     pub const ENUM: TypeId = TypeId(247);
+    pub const SET: TypeId = TypeId(248);
+
+    // JSON: sent as text
+    pub const JSON: TypeId = TypeId(245);
 
     // More Bytes
     pub const TINY_BLOB: TypeId = TypeId(249);
@@ -26,18 +30,23 @@ impl TypeId {
     pub const SMALL_INT: TypeId = TypeId(2);
     pub const INT: TypeId = TypeId(3);
     pub const BIG_INT: TypeId = TypeId(8);
-    // pub const MEDIUM_INT: TypeId = TypeId(9);
+    pub const MEDIUM_INT: TypeId = TypeId(9);
 
     // Numeric: FLOAT, DOUBLE
     pub const FLOAT: TypeId = TypeId(4);
     pub const DOUBLE: TypeId = TypeId(5);
+    pub const DECIMAL: TypeId = TypeId(0);
     pub const NEWDECIMAL: TypeId = TypeId(246);
 
+    // Bits: BIT
+    pub const BIT: TypeId = TypeId(16);
+
     // Date/Time: DATE, TIME, DATETIME, TIMESTAMP
     pub const DATE: TypeId = TypeId(10);
     pub const TIME: TypeId = TypeId(11);
     pub const DATETIME: TypeId = TypeId(12);
     pub const TIMESTAMP: TypeId = TypeId(7);
+    pub const YEAR: TypeId = TypeId(13);
 }
 
 impl Default for TypeId {
@@ -3,11 +3,12 @@ use std::sync::Arc;
 
 use crate::mysql::protocol;
 use crate::mysql::{MySql, MySqlValue};
-use crate::row::{ColumnIndex, Row};
+use crate::row::{self, ColumnIndex, Row};
 
 pub struct MySqlRow<'c> {
     pub(super) row: protocol::Row<'c>,
     pub(super) names: Arc<HashMap<Box<str>, u16>>,
+    pub(super) labels: Arc<[Option<Box<str>>]>,
 }
 
 impl crate::row::private_row::Sealed for MySqlRow<'_> {}
@@ -19,7 +20,6 @@ impl<'c> Row<'c> for MySqlRow<'c> {
         self.row.len()
     }
 
-    #[doc(hidden)]
     fn try_get_raw<I>(&self, index: I) -> crate::Result<MySqlValue<'c>>
     where
         I: ColumnIndex<'c, Self>,
@@ -35,6 +35,19 @@ impl<'c> Row<'c> for MySqlRow<'c> {
 
         Ok(value)
     }
+
+    fn try_column<I>(&self, index: I) -> crate::Result<row::Column<'_, MySql>>
+    where
+        I: ColumnIndex<'c, Self>,
+    {
+        let ordinal = index.index(self)?;
+
+        Ok(row::Column {
+            name: self.labels[ordinal].as_deref(),
+            ordinal,
+            type_info: Some(self.row.columns[ordinal].clone()),
+        })
+    }
 }
 
 impl<'c> ColumnIndex<'c, MySqlRow<'c>> for usize {
@@ -53,7 +66,12 @@ impl<'c> ColumnIndex<'c, MySqlRow<'c>> for str {
     fn index(&self, row: &MySqlRow<'c>) -> crate::Result<usize> {
         row.names
             .get(self)
-            .ok_or_else(|| crate::Error::ColumnNotFound((*self).into()))
+            .ok_or_else(|| {
+                crate::Error::column_not_found(
+                    self,
+                    row.labels.iter().filter_map(|name| name.as_deref()),
+                )
+            })
             .map(|&index| index as usize)
     }
 }
@@ -1,9 +1,10 @@
 use std::net::Shutdown;
+use std::path::PathBuf;
 
 use byteorder::{ByteOrder, LittleEndian};
 
-use crate::io::{Buf, BufMut, BufStream, MaybeTlsStream};
-use crate::mysql::protocol::{Capabilities, Encode, EofPacket, ErrPacket, OkPacket};
+use crate::io::{Buf, BufMut, BufStream, MaybeTlsStream, TcpOptions};
+use crate::mysql::protocol::{Capabilities, ComQuery, Encode, EofPacket, ErrPacket, OkPacket};
 
 use crate::mysql::MySqlError;
 use crate::url::Url;
@@ -18,6 +19,9 @@ pub(crate) struct MySqlStream {
     // Put another way, are we still expecting an EOF or OK packet to terminate
     pub(super) is_ready: bool,
 
+    // Is a dropped transaction waiting to be rolled back before the next command
+    pub(super) pending_rollback: bool,
+
     // Active capabilities
     pub(super) capabilities: Capabilities,
 
@@ -33,8 +37,20 @@ pub(crate) struct MySqlStream {
 }
 
 impl MySqlStream {
-    pub(super) async fn new(url: &Url) -> crate::Result<Self> {
-        let stream = MaybeTlsStream::connect(&url, 3306).await?;
+    pub(super) async fn new(url: &Url, tcp: &TcpOptions) -> crate::Result<Self> {
+        let stream = match socket_path(url) {
+            #[cfg(unix)]
+            Some(path) => MaybeTlsStream::connect_unix(&path).await?,
+
+            #[cfg(not(unix))]
+            Some(_) => {
+                return Err(
+                    protocol_err!("Unix domain sockets are not supported on this platform").into(),
+                )
+            }
+
+            None => MaybeTlsStream::connect(&url, 3306, tcp).await?,
+        };
 
         let mut capabilities = Capabilities::PROTOCOL_41
             | Capabilities::IGNORE_SPACE
@@ -51,7 +67,8 @@ impl MySqlStream {
             capabilities |= Capabilities::CONNECT_WITH_DB;
         }
 
-        if cfg!(feature = "tls") {
+        // A connection over a Unix domain socket is not upgraded to TLS
+        if cfg!(any(feature = "tls", feature = "tls-rustls")) && !stream.is_unix() {
             capabilities |= Capabilities::SSL;
         }
 
@@ -62,11 +79,14 @@ impl MySqlStream {
             packet_len: 0,
             seq_no: 0,
             is_ready: true,
+            pending_rollback: false,
         })
     }
 
-    pub(super) fn is_tls(&self) -> bool {
-        self.stream.is_tls()
+    // Is the connection secure enough for the password to be sent as it is; as for MySQL, a
+    // connection over a Unix domain socket is as secure as one over TLS
+    pub(super) fn is_secure(&self) -> bool {
+        self.stream.is_tls() || self.stream.is_unix()
     }
 
     pub(super) fn shutdown(&self) -> crate::Result<()> {
@@ -221,6 +241,31 @@ impl MySqlStream {
             }
         }
 
+        if self.pending_rollback {
+            self.pending_rollback = false;
+
+            self.send(ComQuery { query: "ROLLBACK" }, true).await?;
+
+            match self.receive().await?[0] {
+                0x00 | 0xFE => {
+                    self.handle_ok()?;
+                }
+
+                0xFF => return self.handle_err(),
+
+                _ => return self.handle_unexpected(),
+            }
+        }
+
         Ok(())
     }
 }
+
+// The path of the Unix domain socket of the server, given by the `socket` parameter or as a
+// percent-encoded host, e.g. `mysql://root@%2Fvar%2Frun%2Fmysqld%2Fmysqld.sock/db`
+fn socket_path(url: &Url) -> Option<PathBuf> {
+    match url.param("socket") {
+        Some(socket) => Some(PathBuf::from(&*socket)),
+        None => url.host_path(),
+    }
+}
@@ -1,9 +1,15 @@
 use crate::mysql::stream::MySqlStream;
 use crate::url::Url;
 
-#[cfg_attr(not(feature = "tls"), allow(unused_variables))]
+#[cfg_attr(
+    not(any(feature = "tls", feature = "tls-rustls")),
+    allow(unused_variables)
+)]
 pub(super) async fn upgrade_if_needed(stream: &mut MySqlStream, url: &Url) -> crate::Result<()> {
-    #[cfg_attr(not(feature = "tls"), allow(unused_imports))]
+    #[cfg_attr(
+        not(any(feature = "tls", feature = "tls-rustls")),
+        allow(unused_imports)
+    )]
     use crate::mysql::protocol::Capabilities;
 
     let ca_file = url.param("ssl-ca");
@@ -13,38 +19,38 @@ pub(super) async fn upgrade_if_needed(stream: &mut MySqlStream, url: &Url) -> cr
     match ssl_mode.as_deref() {
         Some("DISABLED") => {}
 
-        #[cfg(feature = "tls")]
+        #[cfg(any(feature = "tls", feature = "tls-rustls"))]
         Some("PREFERRED") | None if !stream.capabilities.contains(Capabilities::SSL) => {}
 
-        #[cfg(feature = "tls")]
+        #[cfg(any(feature = "tls", feature = "tls-rustls"))]
         Some("PREFERRED") => {
             if let Err(_error) = try_upgrade(stream, &url, None, true).await {
                 // TLS upgrade failed; fall back to a normal connection
             }
         }
 
-        #[cfg(feature = "tls")]
+        #[cfg(any(feature = "tls", feature = "tls-rustls"))]
         None => {
             if let Err(_error) = try_upgrade(stream, &url, ca_file.as_deref(), true).await {
                 // TLS upgrade failed; fall back to a normal connection
             }
         }
 
-        #[cfg(feature = "tls")]
+        #[cfg(any(feature = "tls", feature = "tls-rustls"))]
         Some("REQUIRED") | Some("VERIFY_CA") | Some("VERIFY_IDENTITY")
             if !stream.capabilities.contains(Capabilities::SSL) =>
         {
             return Err(tls_err!("server does not support TLS").into());
         }
 
-        #[cfg(feature = "tls")]
+        #[cfg(any(feature = "tls", feature = "tls-rustls"))]
         Some("VERIFY_CA") | Some("VERIFY_IDENTITY") if ca_file.is_none() => {
             return Err(
                 tls_err!("`ssl-mode` of {:?} requires `ssl-ca` to be set", ssl_mode).into(),
             );
         }
 
-        #[cfg(feature = "tls")]
+        #[cfg(any(feature = "tls", feature = "tls-rustls"))]
         Some(mode @ "REQUIRED") | Some(mode @ "VERIFY_CA") | Some(mode @ "VERIFY_IDENTITY") => {
             try_upgrade(
                 stream,
@@ -57,19 +63,19 @@ pub(super) async fn upgrade_if_needed(stream: &mut MySqlStream, url: &Url) -> cr
             .await?;
         }
 
-        #[cfg(not(feature = "tls"))]
+        #[cfg(not(any(feature = "tls", feature = "tls-rustls")))]
         None => {
             // The user neither explicitly enabled TLS in the connection string
-            // nor did they turn the `tls` feature on
+            // nor did they compile in TLS support
         }
 
-        #[cfg(not(feature = "tls"))]
+        #[cfg(not(any(feature = "tls", feature = "tls-rustls")))]
         Some(mode @ "PREFERRED")
         | Some(mode @ "REQUIRED")
         | Some(mode @ "VERIFY_CA")
         | Some(mode @ "VERIFY_IDENTITY") => {
             return Err(tls_err!(
-                "ssl-mode {:?} unsupported; SQLx was compiled without `tls` feature",
+                "ssl-mode {:?} unsupported; SQLx was compiled without TLS support",
                 mode
             )
             .into());
@@ -83,27 +89,21 @@ pub(super) async fn upgrade_if_needed(stream: &mut MySqlStream, url: &Url) -> cr
     Ok(())
 }
 
-#[cfg(feature = "tls")]
+#[cfg(any(feature = "tls", feature = "tls-rustls"))]
 async fn try_upgrade(
     stream: &mut MySqlStream,
     url: &Url,
     ca_file: Option<&str>,
     accept_invalid_hostnames: bool,
 ) -> crate::Result<()> {
+    use crate::io::TlsConfig;
     use crate::mysql::protocol::SslRequest;
     use crate::runtime::fs;
 
-    use async_native_tls::{Certificate, TlsConnector};
-
-    let mut connector = TlsConnector::new()
-        .danger_accept_invalid_certs(ca_file.is_none())
-        .danger_accept_invalid_hostnames(accept_invalid_hostnames);
-
-    if let Some(ca_file) = ca_file {
-        let root_cert = fs::read(ca_file).await?;
-
-        connector = connector.add_root_certificate(Certificate::from_pem(&root_cert)?);
-    }
+    let root_cert = match ca_file {
+        Some(ca_file) => Some(fs::read(ca_file).await?),
+        None => None,
+    };
 
     // send upgrade request and then immediately try TLS handshake
     stream
@@ -116,5 +116,17 @@ async fn try_upgrade(
         )
         .await?;
 
-    stream.stream.upgrade(url, connector).await
+    stream
+        .stream
+        .upgrade(
+            url,
+            TlsConfig {
+                accept_invalid_certs: ca_file.is_none(),
+                accept_invalid_hostnames,
+                root_cert: root_cert.as_deref(),
+                client_cert: None,
+                client_key: None,
+            },
+        )
+        .await
 }
@@ -41,8 +41,17 @@ impl MySqlTypeInfo {
     }
 
     pub(crate) fn from_nullable_column_def(def: &ColumnDefinition) -> Self {
+        // ENUM and SET columns are sent as CHAR with a flag to tell them apart
+        let id = if def.flags.contains(FieldFlags::ENUM) {
+            TypeId::ENUM
+        } else if def.flags.contains(FieldFlags::SET) {
+            TypeId::SET
+        } else {
+            def.type_id
+        };
+
         Self {
-            id: def.type_id,
+            id,
             is_unsigned: def.flags.contains(FieldFlags::UNSIGNED),
             is_binary: def.flags.contains(FieldFlags::BINARY),
             char_set: def.char_set,
@@ -73,16 +82,21 @@ impl Display for MySqlTypeInfo {
 
             TypeId::TINY_INT if self.is_unsigned => f.write_str("TINYINT UNSIGNED"),
             TypeId::SMALL_INT if self.is_unsigned => f.write_str("SMALLINT UNSIGNED"),
+            TypeId::MEDIUM_INT if self.is_unsigned => f.write_str("MEDIUMINT UNSIGNED"),
             TypeId::INT if self.is_unsigned => f.write_str("INT UNSIGNED"),
             TypeId::BIG_INT if self.is_unsigned => f.write_str("BIGINT UNSIGNED"),
 
             TypeId::TINY_INT => f.write_str("TINYINT"),
             TypeId::SMALL_INT => f.write_str("SMALLINT"),
+            TypeId::MEDIUM_INT => f.write_str("MEDIUMINT"),
             TypeId::INT => f.write_str("INT"),
             TypeId::BIG_INT => f.write_str("BIGINT"),
 
             TypeId::FLOAT => f.write_str("FLOAT"),
             TypeId::DOUBLE => f.write_str("DOUBLE"),
+            TypeId::DECIMAL | TypeId::NEWDECIMAL => f.write_str("DECIMAL"),
+
+            TypeId::BIT => f.write_str("BIT"),
 
             TypeId::CHAR if self.is_binary => f.write_str("BINARY"),
             TypeId::VAR_CHAR if self.is_binary => f.write_str("VARBINARY"),
@@ -96,6 +110,12 @@ impl Display for MySqlTypeInfo {
             TypeId::TIME => f.write_str("TIME"),
             TypeId::DATETIME => f.write_str("DATETIME"),
             TypeId::TIMESTAMP => f.write_str("TIMESTAMP"),
+            TypeId::YEAR => f.write_str("YEAR"),
+
+            TypeId::JSON => f.write_str("JSON"),
+
+            TypeId::ENUM => f.write_str("ENUM"),
+            TypeId::SET => f.write_str("SET"),
 
             id => write!(f, "<{:#x}>", id.0),
         }
@@ -112,6 +132,7 @@ impl PartialEq<MySqlTypeInfo> for MySqlTypeInfo {
             | TypeId::MEDIUM_BLOB
             | TypeId::LONG_BLOB
             | TypeId::ENUM
+            | TypeId::SET
                 if (self.is_binary == other.is_binary)
                     && match other.id {
                         TypeId::VAR_CHAR
@@ -120,7 +141,8 @@ impl PartialEq<MySqlTypeInfo> for MySqlTypeInfo {
                         | TypeId::TINY_BLOB
                         | TypeId::MEDIUM_BLOB
                         | TypeId::LONG_BLOB
-                        | TypeId::ENUM => true,
+                        | TypeId::ENUM
+                        | TypeId::SET => true,
 
                         _ => false,
                     } =>
@@ -136,7 +158,11 @@ impl PartialEq<MySqlTypeInfo> for MySqlTypeInfo {
         }
 
         match self.id {
-            TypeId::TINY_INT | TypeId::SMALL_INT | TypeId::INT | TypeId::BIG_INT => {
+            TypeId::TINY_INT
+            | TypeId::SMALL_INT
+            | TypeId::MEDIUM_INT
+            | TypeId::INT
+            | TypeId::BIG_INT => {
                 return self.is_unsigned == other.is_unsigned;
             }
 
@@ -160,12 +186,20 @@ impl TypeInfo for MySqlTypeInfo {
 
         match self.id {
             // All integer types should be considered compatible
-            TypeId::TINY_INT | TypeId::SMALL_INT | TypeId::INT | TypeId::BIG_INT
+            // YEAR is sent as an (unsigned) integer
+            TypeId::TINY_INT
+            | TypeId::SMALL_INT
+            | TypeId::MEDIUM_INT
+            | TypeId::INT
+            | TypeId::BIG_INT
+            | TypeId::YEAR
                 if (self.is_unsigned == other.is_unsigned)
                     && match other.id {
-                        TypeId::TINY_INT | TypeId::SMALL_INT | TypeId::INT | TypeId::BIG_INT => {
-                            true
-                        }
+                        TypeId::TINY_INT
+                        | TypeId::SMALL_INT
+                        | TypeId::MEDIUM_INT
+                        | TypeId::INT
+                        | TypeId::BIG_INT => true,
 
                         _ => false,
                     } =>
@@ -173,20 +207,41 @@ impl TypeInfo for MySqlTypeInfo {
                 true
             }
 
+            // BIT is decoded to `u64` or, for a single bit, `bool`
+            TypeId::BIT
+                if match other.id {
+                    TypeId::BIG_INT => other.is_unsigned,
+                    TypeId::TINY_INT => !other.is_unsigned,
+                    _ => false,
+                } =>
+            {
+                true
+            }
+
+            // DECIMAL is only sent by very old servers; newer servers send NEWDECIMAL
+            TypeId::DECIMAL | TypeId::NEWDECIMAL
+                if matches!(other.id, TypeId::DECIMAL | TypeId::NEWDECIMAL) =>
+            {
+                true
+            }
+
             // All textual types should be considered compatible
+            // SET is sent as its labels separated by commas
             TypeId::VAR_CHAR
             | TypeId::TEXT
             | TypeId::CHAR
             | TypeId::TINY_BLOB
             | TypeId::MEDIUM_BLOB
             | TypeId::LONG_BLOB
+            | TypeId::SET
                 if match other.id {
                     TypeId::VAR_CHAR
                     | TypeId::TEXT
                     | TypeId::CHAR
                     | TypeId::TINY_BLOB
                     | TypeId::MEDIUM_BLOB
-                    | TypeId::LONG_BLOB => true,
+                    | TypeId::LONG_BLOB
+                    | TypeId::SET => true,
 
                     _ => false,
                 } =>
@@ -194,6 +249,22 @@ impl TypeInfo for MySqlTypeInfo {
                 true
             }
 
+            // JSON is sent as text
+            TypeId::JSON
+                if matches!(
+                    other.id,
+                    TypeId::VAR_CHAR
+                        | TypeId::TEXT
+                        | TypeId::CHAR
+                        | TypeId::TINY_BLOB
+                        | TypeId::MEDIUM_BLOB
+                        | TypeId::LONG_BLOB
+                        | TypeId::JSON
+                ) =>
+            {
+                true
+            }
+
             // Enums are considered compatible with other text/binary types
             TypeId::ENUM
                 if match other.id {
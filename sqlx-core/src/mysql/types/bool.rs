@@ -2,6 +2,7 @@ use crate::decode::Decode;
 use crate::encode::Encode;
 use crate::mysql::protocol::TypeId;
 use crate::mysql::type_info::MySqlTypeInfo;
+use crate::mysql::types::uint::decode_bit;
 use crate::mysql::{MySql, MySqlData, MySqlValue};
 use crate::types::Type;
 
@@ -19,6 +20,14 @@ impl Encode<MySql> for bool {
 
 impl<'de> Decode<'de, MySql> for bool {
     fn decode(value: MySqlValue<'de>) -> crate::Result<Self> {
+        if value.type_id() == Some(TypeId::BIT) {
+            return match decode_bit(value.try_get()?)? {
+                0 => Ok(false),
+                1 => Ok(true),
+                bits => Err(decode_err!("unexpected value {:#b} for boolean", bits)),
+            };
+        }
+
         match value.try_get()? {
             MySqlData::Binary(buf) => Ok(buf.get(0).map(|&b| b != 0).unwrap_or_default()),
 
@@ -8,6 +8,7 @@ use crate::encode::Encode;
 use crate::io::{Buf, BufMut};
 use crate::mysql::protocol::TypeId;
 use crate::mysql::type_info::MySqlTypeInfo;
+use crate::mysql::types::zero::{is_zero_date, zero_date_err};
 use crate::mysql::{MySql, MySqlData, MySqlValue};
 use crate::types::Type;
 use crate::Error;
@@ -72,6 +73,11 @@ impl<'de> Decode<'de, MySql> for NaiveTime {
                 // data length, expecting 8 or 12 (fractional seconds)
                 let len = buf.get_u8()?;
 
+                // if all the components are 0, length is 0 and no other field is sent
+                if len == 0 {
+                    return Ok(NaiveTime::from_hms(0, 0, 0));
+                }
+
                 // is negative : int<1>
                 let is_negative = buf.get_u8()?;
                 assert_eq!(is_negative, 0, "Negative dates/times are not supported");
@@ -111,6 +117,10 @@ impl Encode<MySql> for NaiveDate {
 
 impl<'de> Decode<'de, MySql> for NaiveDate {
     fn decode(buf: MySqlValue<'de>) -> crate::Result<Self> {
+        if is_zero_date(&buf)? {
+            return Err(zero_date_err());
+        }
+
         match buf.try_get()? {
             MySqlData::Binary(buf) => Ok(decode_date(&buf[1..])),
 
@@ -164,6 +174,10 @@ impl Encode<MySql> for NaiveDateTime {
 
 impl<'de> Decode<'de, MySql> for NaiveDateTime {
     fn decode(buf: MySqlValue<'de>) -> crate::Result<Self> {
+        if is_zero_date(&buf)? {
+            return Err(zero_date_err());
+        }
+
         match buf.try_get()? {
             MySqlData::Binary(buf) => {
                 let len = buf[0];
@@ -0,0 +1,182 @@
+use std::str::from_utf8;
+
+use byteorder::LittleEndian;
+use serde::{Deserialize, Serialize};
+use serde_json::value::RawValue as JsonRawValue;
+use serde_json::Value as JsonValue;
+
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::mysql::io::BufMutExt;
+use crate::mysql::protocol::TypeId;
+use crate::mysql::type_info::MySqlTypeInfo;
+use crate::mysql::{MySql, MySqlData, MySqlRow, MySqlValue};
+use crate::row::Row;
+use crate::types::json::JsonRow;
+use crate::types::{Json, Type};
+
+// <https://dev.mysql.com/doc/refman/8.0/en/json.html>
+
+// MySQL stores JSON in a binary format but always sends it to the client as text. The server
+// does not accept JSON as the type of a parameter so it is sent as a string, which MySQL
+// converts when it is stored in a JSON column or passed to a JSON function.
+
+impl Type<MySql> for JsonValue {
+    fn type_info() -> MySqlTypeInfo {
+        <Json<Self> as Type<MySql>>::type_info()
+    }
+}
+
+impl Encode<MySql> for JsonValue {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        Encode::<MySql>::encode(&Json(self), buf)
+    }
+}
+
+impl<'de> Decode<'de, MySql> for JsonValue {
+    fn decode(value: MySqlValue<'de>) -> crate::Result<Self> {
+        <Json<Self> as Decode<MySql>>::decode(value).map(|item| item.0)
+    }
+}
+
+impl Type<MySql> for &'_ JsonRawValue {
+    fn type_info() -> MySqlTypeInfo {
+        <Json<Self> as Type<MySql>>::type_info()
+    }
+}
+
+impl Encode<MySql> for &'_ JsonRawValue {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        Encode::<MySql>::encode(&Json(self), buf)
+    }
+}
+
+impl<'de> Decode<'de, MySql> for &'de JsonRawValue {
+    fn decode(value: MySqlValue<'de>) -> crate::Result<Self> {
+        <Json<Self> as Decode<MySql>>::decode(value).map(|item| item.0)
+    }
+}
+
+impl<T> Type<MySql> for Json<T> {
+    fn type_info() -> MySqlTypeInfo {
+        <str as Type<MySql>>::type_info()
+    }
+}
+
+impl<T> Encode<MySql> for Json<T>
+where
+    T: Serialize,
+{
+    fn encode(&self, buf: &mut Vec<u8>) {
+        let json = serde_json::to_string(&self.0)
+            .expect("failed to serialize json for encoding to database");
+
+        buf.put_str_lenenc::<LittleEndian>(&json);
+    }
+}
+
+impl<'de, T> Decode<'de, MySql> for Json<T>
+where
+    T: 'de,
+    T: Deserialize<'de>,
+{
+    fn decode(value: MySqlValue<'de>) -> crate::Result<Self> {
+        match value.try_get()? {
+            MySqlData::Binary(buf) | MySqlData::Text(buf) => serde_json::from_slice(buf)
+                .map(Json)
+                .map_err(crate::Error::decode),
+        }
+    }
+}
+
+#[test]
+fn test_encode_json() {
+    let mut buf = Vec::new();
+    Encode::<MySql>::encode(&serde_json::json!({ "a": [1, null] }), &mut buf);
+
+    assert_eq!(buf, b"\x0e{\"a\":[1,null]}");
+}
+
+#[test]
+fn test_decode_json() {
+    let buf = br#"{"a": [1, null], "b": "c"}"#;
+    let value = <JsonValue as Decode<MySql>>::decode(MySqlValue::text(
+        <JsonValue as Type<MySql>>::type_info(),
+        buf,
+    ))
+    .unwrap();
+
+    assert_eq!(value, serde_json::json!({ "a": [1, null], "b": "c" }));
+}
+
+// the character set of binary strings; <https://dev.mysql.com/doc/refman/8.0/en/charset-binary-set.html>
+const BINARY_CHAR_SET: u16 = 63;
+
+impl<'c> JsonRow<'c> for MySqlRow<'c> {
+    fn column_to_json(&self, index: usize) -> crate::Result<JsonValue> {
+        let value = self.try_get_raw(index)?;
+
+        let data = match value.get() {
+            Some(data) => data,
+            None => return Ok(JsonValue::Null),
+        };
+
+        let type_info = &self.row.columns[index];
+        let unsigned = type_info.is_unsigned;
+
+        Ok(match type_info.id {
+            TypeId::TINY_INT if unsigned => <u8 as Decode<MySql>>::decode(value)?.into(),
+            TypeId::SMALL_INT if unsigned => <u16 as Decode<MySql>>::decode(value)?.into(),
+            TypeId::INT | TypeId::MEDIUM_INT if unsigned => {
+                <u32 as Decode<MySql>>::decode(value)?.into()
+            }
+            TypeId::BIG_INT if unsigned => <u64 as Decode<MySql>>::decode(value)?.into(),
+
+            TypeId::TINY_INT => <i8 as Decode<MySql>>::decode(value)?.into(),
+            TypeId::SMALL_INT => <i16 as Decode<MySql>>::decode(value)?.into(),
+            TypeId::INT | TypeId::MEDIUM_INT => <i32 as Decode<MySql>>::decode(value)?.into(),
+            TypeId::BIG_INT => <i64 as Decode<MySql>>::decode(value)?.into(),
+
+            TypeId::FLOAT => <f32 as Decode<MySql>>::decode(value)?.into(),
+            TypeId::DOUBLE => <f64 as Decode<MySql>>::decode(value)?.into(),
+
+            TypeId::JSON => Decode::<MySql>::decode(value)?,
+
+            // numbers have the binary character set, but decimals are sent as text
+            TypeId::DECIMAL | TypeId::NEWDECIMAL => {
+                JsonValue::String(Decode::<MySql>::decode(value)?)
+            }
+
+            // strings are sent in the same way in both formats
+            TypeId::CHAR
+            | TypeId::VAR_CHAR
+            | TypeId::TEXT
+            | TypeId::TINY_BLOB
+            | TypeId::MEDIUM_BLOB
+            | TypeId::LONG_BLOB
+            | TypeId::ENUM
+            | TypeId::SET => {
+                let bytes: &[u8] = Decode::<MySql>::decode(value)?;
+
+                if type_info.char_set == BINARY_CHAR_SET {
+                    JsonValue::String(base64::encode(bytes))
+                } else {
+                    JsonValue::String(from_utf8(bytes).map_err(crate::Error::decode)?.to_owned())
+                }
+            }
+
+            _ => match data {
+                MySqlData::Text(buf) => {
+                    JsonValue::String(from_utf8(buf).map_err(crate::Error::decode)?.to_owned())
+                }
+
+                MySqlData::Binary(_) => {
+                    return Err(decode_err!(
+                        "unsupported conversion of {} in the binary format to JSON",
+                        type_info
+                    ));
+                }
+            },
+        })
+    }
+}
@@ -4,19 +4,26 @@
 //!
 //! | Rust type                             | MySQL type(s)                                        |
 //! |---------------------------------------|------------------------------------------------------|
-//! | `bool`                                | TINYINT(1)                                           |
+//! | `bool`                                | TINYINT(1), BIT(1)                                   |
 //! | `i8`                                  | TINYINT                                              |
 //! | `i16`                                 | SMALLINT                                             |
-//! | `i32`                                 | INT                                                  |
+//! | `i32`                                 | INT, MEDIUMINT                                       |
 //! | `i64`                                 | BIGINT                                               |
 //! | `u8`                                  | TINYINT UNSIGNED                                     |
-//! | `u16`                                 | SMALLINT UNSIGNED                                    |
-//! | `u32`                                 | INT UNSIGNED                                         |
-//! | `u64`                                 | BIGINT UNSIGNED                                      |
+//! | `u16`                                 | SMALLINT UNSIGNED, YEAR                              |
+//! | `u32`                                 | INT UNSIGNED, MEDIUMINT UNSIGNED                     |
+//! | `u64`                                 | BIGINT UNSIGNED, BIT                                 |
 //! | `f32`                                 | FLOAT                                                |
 //! | `f64`                                 | DOUBLE                                               |
 //! | `&str`, `String`                      | VARCHAR, CHAR, TEXT                                  |
 //! | `&[u8]`, `Vec<u8>`                    | VARBINARY, BINARY, BLOB                              |
+//! | `Vec<String>`                         | SET                                                  |
+//!
+//! An ENUM column can be decoded into a Rust enum that derives [`Type`] by its labels, or into
+//! a `String`. A SET is decoded into a `Vec<String>` of its labels, which is empty for an
+//! empty SET. Both are sent to MySQL as strings.
+//!
+//! [`Type`]: crate::types::Type
 //!
 //! ### [`chrono`](https://crates.io/crates/chrono)
 //!
@@ -48,18 +55,42 @@
 //! |---------------------------------------|------------------------------------------------------|
 //! | `bigdecimal::BigDecimal`              | DECIMAL                                              |
 //!
+//! ### [`json`](https://crates.io/crates/serde_json)
+//!
+//! Requires the `json` Cargo feature flag.
+//!
+//! | Rust type                             | MySQL type(s)                                        |
+//! |---------------------------------------|------------------------------------------------------|
+//! | [`Json<T>`]                           | JSON                                                 |
+//! | `serde_json::Value`                   | JSON                                                 |
+//! | `&serde_json::value::RawValue`        | JSON                                                 |
+//!
+//! JSON is sent to MySQL as a string so these can also be used with textual types.
+//!
+//! [`Json<T>`]: crate::types::Json
+//!
 //! # Nullable
 //!
 //! In addition, `Option<T>` is supported where `T` implements `Type`. An `Option<T>` represents
 //! a potentially `NULL` value from MySQL.
 //!
+//! # Zero dates
+//!
+//! The zero date of MySQL, `0000-00-00` or `0000-00-00 00:00:00`, is not a valid date and is
+//! an error when it is decoded as one of the date types above. [`MySqlZeroable<T>`] decodes it as
+//! `None` instead.
+//!
+//! [`MySqlZeroable<T>`]: crate::mysql::types::MySqlZeroable
+//!
 
 mod bool;
 mod bytes;
 mod float;
 mod int;
+mod set;
 mod str;
 mod uint;
+mod zero;
 
 #[cfg(feature = "bigdecimal")]
 mod bigdecimal;
@@ -70,6 +101,11 @@ mod chrono;
 #[cfg(feature = "time")]
 mod time;
 
+#[cfg(feature = "json")]
+mod json;
+
+pub use zero::MySqlZeroable;
+
 use crate::decode::Decode;
 use crate::mysql::{MySql, MySqlValue};
 
@@ -78,7 +114,7 @@ where
     T: Decode<'de, MySql>,
 {
     fn decode(value: MySqlValue<'de>) -> crate::Result<Self> {
-        Ok(if value.get().is_some() {
+        Ok(if !value.is_null() {
             Some(<T as Decode<MySql>>::decode(value)?)
         } else {
             None
@@ -0,0 +1,67 @@
+use byteorder::LittleEndian;
+
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::mysql::io::BufMutExt;
+use crate::mysql::type_info::MySqlTypeInfo;
+use crate::mysql::{MySql, MySqlValue};
+use crate::types::Type;
+
+// <https://dev.mysql.com/doc/refman/8.0/en/set.html>
+
+// A SET is sent as its labels separated by commas, and an empty SET as an empty string. A label
+// can not contain a comma so the labels can be split without any escaping. Like an ENUM, the
+// value is sent to MySQL as a string; a label that is not in the SET is rejected by the server
+// in strict mode.
+
+impl Type<MySql> for Vec<String> {
+    fn type_info() -> MySqlTypeInfo {
+        <str as Type<MySql>>::type_info()
+    }
+}
+
+impl Encode<MySql> for Vec<String> {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        buf.put_str_lenenc::<LittleEndian>(&self.join(","));
+    }
+}
+
+impl<'de> Decode<'de, MySql> for Vec<String> {
+    fn decode(value: MySqlValue<'de>) -> crate::Result<Self> {
+        let labels = <&'de str as Decode<MySql>>::decode(value)?;
+
+        if labels.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        Ok(labels.split(',').map(ToOwned::to_owned).collect())
+    }
+}
+
+#[test]
+fn test_encode_set() {
+    let mut buf = Vec::new();
+    Encode::<MySql>::encode(&vec!["a".to_owned(), "c".to_owned()], &mut buf);
+
+    assert_eq!(buf, b"\x03a,c");
+
+    buf.clear();
+    Encode::<MySql>::encode(&Vec::<String>::new(), &mut buf);
+
+    assert_eq!(buf, b"\x00");
+}
+
+#[test]
+fn test_decode_set() {
+    let decode = |buf: &[u8]| {
+        <Vec<String> as Decode<MySql>>::decode(MySqlValue::text(
+            <Vec<String> as Type<MySql>>::type_info(),
+            buf,
+        ))
+        .unwrap()
+    };
+
+    assert_eq!(decode(b"a,c"), vec!["a", "c"]);
+    assert_eq!(decode(b"b"), vec!["b"]);
+    assert_eq!(decode(b""), Vec::<String>::new());
+}
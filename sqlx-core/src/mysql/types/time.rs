@@ -9,6 +9,7 @@ use crate::encode::Encode;
 use crate::io::{Buf, BufMut};
 use crate::mysql::protocol::TypeId;
 use crate::mysql::type_info::MySqlTypeInfo;
+use crate::mysql::types::zero::{is_zero_date, zero_date_err};
 use crate::mysql::{MySql, MySqlData, MySqlValue};
 use crate::types::Type;
 
@@ -74,6 +75,11 @@ impl<'de> Decode<'de, MySql> for Time {
                 // data length, expecting 8 or 12 (fractional seconds)
                 let len = buf.get_u8()?;
 
+                // if all the components are 0, length is 0 and no other field is sent
+                if len == 0 {
+                    return Ok(Time::midnight());
+                }
+
                 // is negative : int<1>
                 let is_negative = buf.get_u8()?;
                 assert_eq!(is_negative, 0, "Negative dates/times are not supported");
@@ -92,8 +98,12 @@ impl<'de> Decode<'de, MySql> for Time {
                 // We need to zero-pad
                 // TODO: Ask [time] to add a parse % for less-than-fixed-9 nanos
 
-                let s = if s.len() < 20 {
-                    Cow::Owned(format!("{:0<19}", s))
+                let s = if s.len() < 18 {
+                    if s.contains('.') {
+                        Cow::Owned(format!("{:0<18}", s))
+                    } else {
+                        Cow::Owned(format!("{}.000000000", s))
+                    }
                 } else {
                     Cow::Borrowed(s)
                 };
@@ -124,6 +134,10 @@ impl Encode<MySql> for Date {
 
 impl<'de> Decode<'de, MySql> for Date {
     fn decode(value: MySqlValue<'de>) -> crate::Result<Self> {
+        if is_zero_date(&value)? {
+            return Err(zero_date_err());
+        }
+
         match value.try_get()? {
             MySqlData::Binary(buf) => decode_date(&buf[1..]),
             MySqlData::Text(buf) => {
@@ -171,6 +185,10 @@ impl Encode<MySql> for PrimitiveDateTime {
 
 impl<'de> Decode<'de, MySql> for PrimitiveDateTime {
     fn decode(value: MySqlValue<'de>) -> crate::Result<Self> {
+        if is_zero_date(&value)? {
+            return Err(zero_date_err());
+        }
+
         match value.try_get()? {
             MySqlData::Binary(buf) => {
                 let len = buf[0];
@@ -322,3 +340,77 @@ fn test_decode_date() {
         .unwrap();
     assert_eq!(date, date!(2010 - 10 - 17));
 }
+
+#[test]
+fn test_decode_text_time() {
+    let decode = |s: &str| {
+        <Time as Decode<MySql>>::decode(MySqlValue::text(MySqlTypeInfo::default(), s.as_bytes()))
+    };
+
+    assert_eq!(decode("05:10:20.115100").unwrap(), time!(5:10:20.115100));
+    assert_eq!(decode("05:10:20").unwrap(), time!(5:10:20));
+}
+
+#[test]
+fn test_decode_text_date_time() {
+    let decode = |s: &str| {
+        <PrimitiveDateTime as Decode<MySql>>::decode(MySqlValue::text(
+            MySqlTypeInfo::default(),
+            s.as_bytes(),
+        ))
+    };
+
+    assert_eq!(
+        decode("2019-01-02 05:10:20.115100").unwrap(),
+        date!(2019 - 01 - 02).with_time(time!(5:10:20.115100))
+    );
+
+    assert_eq!(
+        decode("2019-01-02 05:10:20").unwrap(),
+        date!(2019 - 01 - 02).with_time(time!(5:10:20))
+    );
+}
+
+#[test]
+fn test_decode_zero_date() {
+    use crate::mysql::types::MySqlZeroable;
+
+    // the zero date is sent with a length of 0
+    let zero = |id| MySqlValue::binary(MySqlTypeInfo::new(id), &[0]);
+
+    assert!(<Date as Decode<MySql>>::decode(zero(TypeId::DATE)).is_err());
+    assert!(<PrimitiveDateTime as Decode<MySql>>::decode(zero(TypeId::DATETIME)).is_err());
+    assert!(<OffsetDateTime as Decode<MySql>>::decode(zero(TypeId::TIMESTAMP)).is_err());
+
+    let date = <MySqlZeroable<Date> as Decode<MySql>>::decode(zero(TypeId::DATE)).unwrap();
+    assert_eq!(date, MySqlZeroable(None));
+
+    let text = MySqlValue::text(MySqlTypeInfo::new(TypeId::DATETIME), b"0000-00-00 00:00:00");
+    let date_time = <MySqlZeroable<PrimitiveDateTime> as Decode<MySql>>::decode(text).unwrap();
+    assert_eq!(date_time, MySqlZeroable(None));
+
+    let buf = [4, 218, 7, 10, 17];
+    let date = <MySqlZeroable<Date> as Decode<MySql>>::decode(MySqlValue::binary(
+        MySqlTypeInfo::new(TypeId::DATE),
+        &buf,
+    ))
+    .unwrap();
+    assert_eq!(date, MySqlZeroable(Some(date!(2010 - 10 - 17))));
+
+    // and a TIME of length 0 is midnight
+    let time = <Time as Decode<MySql>>::decode(zero(TypeId::TIME)).unwrap();
+    assert_eq!(time, time!(0:00));
+}
+
+#[test]
+fn test_encode_zero_date() {
+    use crate::mysql::types::MySqlZeroable;
+
+    let mut buf = Vec::new();
+    Encode::<MySql>::encode(&MySqlZeroable::<Date>(None), &mut buf);
+    assert_eq!(*buf, [0]);
+
+    buf.clear();
+    Encode::<MySql>::encode(&MySqlZeroable(Some(date!(2010 - 10 - 17))), &mut buf);
+    assert_eq!(*buf, [4, 218, 7, 10, 17]);
+}
@@ -99,6 +99,10 @@ impl Encode<MySql> for u64 {
 
 impl<'de> Decode<'de, MySql> for u64 {
     fn decode(value: MySqlValue<'de>) -> crate::Result<Self> {
+        if value.type_id() == Some(TypeId::BIT) {
+            return decode_bit(value.try_get()?);
+        }
+
         match value.try_get()? {
             MySqlData::Binary(mut buf) => buf.read_u64::<LittleEndian>().map_err(Into::into),
 
@@ -109,3 +113,35 @@ impl<'de> Decode<'de, MySql> for u64 {
         }
     }
 }
+
+// A BIT value is sent as big-endian bytes in both the text and binary protocols
+pub(super) fn decode_bit(data: MySqlData<'_>) -> crate::Result<u64> {
+    let buf = match data {
+        MySqlData::Binary(buf) | MySqlData::Text(buf) => buf,
+    };
+
+    if buf.len() > 8 {
+        return Err(decode_err!(
+            "BIT value of {} bytes is too large for a u64",
+            buf.len()
+        ));
+    }
+
+    Ok(buf
+        .iter()
+        .fold(0_u64, |value, byte| (value << 8) | u64::from(*byte)))
+}
+
+#[test]
+fn test_decode_bit() {
+    let bit = MySqlTypeInfo::unsigned(TypeId::BIT);
+
+    let value = <u64 as Decode<MySql>>::decode(MySqlValue::text(bit.clone(), b"\x01\x05"));
+    assert_eq!(value.unwrap(), 0x0105);
+
+    let value = <u64 as Decode<MySql>>::decode(MySqlValue::binary(bit.clone(), b"\x00"));
+    assert_eq!(value.unwrap(), 0);
+
+    let value = <u64 as Decode<MySql>>::decode(MySqlValue::text(bit, &[0xFF; 9]));
+    assert!(value.is_err());
+}
@@ -0,0 +1,124 @@
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::mysql::protocol::TypeId;
+use crate::mysql::type_info::MySqlTypeInfo;
+use crate::mysql::{MySql, MySqlData, MySqlValue};
+use crate::types::Type;
+
+// <https://dev.mysql.com/doc/refman/8.0/en/date-and-time-types.html>
+
+// MySQL has a "zero" value for DATE, DATETIME and TIMESTAMP, `0000-00-00` and
+// `0000-00-00 00:00:00`, which is not a valid date. It is sent in the binary protocol as a value
+// of length 0. SQLx sets `NO_ZERO_DATE` in the `sql_mode` of its connections so that zero dates
+// are not stored, but a table may already have some.
+
+/// A MySQL `DATE`, `DATETIME` or `TIMESTAMP` that may be the zero date `0000-00-00`, which is
+/// `None`.
+///
+/// Decoding a zero date as the date type itself, e.g. a `time::Date` or `chrono::NaiveDate`,
+/// is an error as the zero date is not a valid date; decoding it as a `MySqlZeroable` of that
+/// type is not:
+///
+/// ```rust,ignore
+/// let (due,): (MySqlZeroable<Date>,) = sqlx::query_as("SELECT due FROM tasks")
+///     .fetch_one(&mut conn)
+///     .await?;
+///
+/// if let Some(due) = due.0 { ... }
+/// ```
+///
+/// `None` is sent as the zero date, which is only accepted by MySQL if `NO_ZERO_DATE` has been
+/// removed from the `sql_mode` of the session. A nullable column is an
+/// `Option<MySqlZeroable<T>>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct MySqlZeroable<T>(pub Option<T>);
+
+impl<T> From<Option<T>> for MySqlZeroable<T> {
+    fn from(value: Option<T>) -> Self {
+        MySqlZeroable(value)
+    }
+}
+
+impl<T> From<MySqlZeroable<T>> for Option<T> {
+    fn from(value: MySqlZeroable<T>) -> Self {
+        value.0
+    }
+}
+
+impl<T> Type<MySql> for MySqlZeroable<T>
+where
+    T: Type<MySql>,
+{
+    fn type_info() -> MySqlTypeInfo {
+        T::type_info()
+    }
+}
+
+impl<T> Encode<MySql> for MySqlZeroable<T>
+where
+    T: Encode<MySql>,
+{
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match &self.0 {
+            Some(value) => value.encode(buf),
+
+            // the zero date has no components
+            None => buf.push(0),
+        }
+    }
+
+    fn size_hint(&self) -> usize {
+        self.0.as_ref().map_or(1, Encode::size_hint)
+    }
+}
+
+impl<'de, T> Decode<'de, MySql> for MySqlZeroable<T>
+where
+    T: Decode<'de, MySql>,
+{
+    fn decode(value: MySqlValue<'de>) -> crate::Result<Self> {
+        if is_zero_date(&value)? {
+            return Ok(MySqlZeroable(None));
+        }
+
+        T::decode(value).map(|value| MySqlZeroable(Some(value)))
+    }
+}
+
+/// Whether a value is the zero date of a `DATE`, `DATETIME` or `TIMESTAMP`.
+pub(crate) fn is_zero_date(value: &MySqlValue<'_>) -> crate::Result<bool> {
+    Ok(match value.try_get()? {
+        MySqlData::Binary(buf) => {
+            let is_date = matches!(
+                value.type_id(),
+                Some(TypeId::DATE) | Some(TypeId::DATETIME) | Some(TypeId::TIMESTAMP)
+            );
+
+            is_date && buf.first() == Some(&0)
+        }
+
+        MySqlData::Text(buf) => buf.starts_with(b"0000-00-00"),
+    })
+}
+
+pub(crate) fn zero_date_err() -> crate::Error {
+    crate::Error::Decode(
+        "the zero date 0000-00-00 is not a valid date; decode it as MySqlZeroable to get None"
+            .into(),
+    )
+}
+
+#[test]
+fn test_is_zero_date() {
+    let date = MySqlTypeInfo::new(TypeId::DATE);
+    let time = MySqlTypeInfo::new(TypeId::TIME);
+
+    assert!(is_zero_date(&MySqlValue::binary(date.clone(), &[0])).unwrap());
+    assert!(!is_zero_date(&MySqlValue::binary(date.clone(), &[4, 218, 7, 10, 17])).unwrap());
+
+    // a TIME of length 0 is midnight
+    assert!(!is_zero_date(&MySqlValue::binary(time, &[0])).unwrap());
+
+    assert!(is_zero_date(&MySqlValue::text(date.clone(), b"0000-00-00 00:00:00")).unwrap());
+    assert!(!is_zero_date(&MySqlValue::text(date, b"2010-10-17")).unwrap());
+}
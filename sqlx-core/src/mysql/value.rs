@@ -1,4 +1,5 @@
 use crate::error::UnexpectedNullError;
+use crate::mysql::protocol::TypeId;
 use crate::mysql::{MySql, MySqlTypeInfo};
 use crate::value::RawValue;
 
@@ -31,6 +32,16 @@ impl<'c> MySqlValue<'c> {
         self.data
     }
 
+    /// Returns true if the value is `NULL`.
+    #[inline]
+    pub(crate) fn is_null(&self) -> bool {
+        self.data.is_none()
+    }
+
+    pub(crate) fn type_id(&self) -> Option<TypeId> {
+        self.type_info.as_ref().map(|ty| ty.id)
+    }
+
     pub(crate) fn null() -> Self {
         Self {
             type_info: None,
@@ -4,8 +4,10 @@ use std::ops::{Deref, DerefMut};
 use std::sync::Arc;
 use std::time::Instant;
 
-use super::inner::{DecrementSizeGuard, SharedPool};
-use crate::connection::{Connect, Connection};
+use super::inner::{spawn_reset, DecrementSizeGuard, SharedPool};
+use super::Options;
+use crate::connection::{Connect, Connection, LogSettings};
+use crate::transaction::TransactionOptions;
 
 /// A connection checked out from [`Pool`][crate::pool::Pool].
 ///
@@ -89,6 +91,26 @@ where
     fn ping(&mut self) -> BoxFuture<crate::Result<()>> {
         Box::pin(self.deref_mut().ping())
     }
+
+    #[inline]
+    fn set_log_settings(&mut self, settings: LogSettings) {
+        self.deref_mut().set_log_settings(settings)
+    }
+
+    #[inline]
+    fn reset(&mut self) -> BoxFuture<'_, crate::Result<()>> {
+        self.deref_mut().reset()
+    }
+
+    #[doc(hidden)]
+    fn rollback_on_next_use(&mut self) {
+        self.deref_mut().rollback_on_next_use()
+    }
+
+    #[doc(hidden)]
+    fn begin_statement(&self, options: &TransactionOptions) -> crate::Result<String> {
+        self.deref().begin_statement(options)
+    }
 }
 
 /// Returns the connection to the [`Pool`][crate::pool::Pool] it was checked-out from.
@@ -98,7 +120,11 @@ where
 {
     fn drop(&mut self) {
         if let Some(live) = self.live.take() {
-            self.pool.release(live.float(&self.pool));
+            if self.pool.options().reset_on_release {
+                spawn_reset(Arc::clone(&self.pool), live);
+            } else {
+                self.pool.release(live.float(&self.pool));
+            }
         }
     }
 }
@@ -175,6 +201,20 @@ impl<'s, C> Floating<'s, Live<C>> {
             guard: self.guard,
         }
     }
+
+    // Reset the session of the connection, and set it up again with `after_reset`
+    pub async fn reset(&mut self, options: &Options<C>) -> crate::Result<()>
+    where
+        C: Connection,
+    {
+        self.inner.raw.reset().await?;
+
+        if let Some(callback) = &options.after_reset {
+            callback(&mut self.inner.raw).await?;
+        }
+
+        Ok(())
+    }
 }
 
 impl<'s, C> Floating<'s, Idle<C>> {
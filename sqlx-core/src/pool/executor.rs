@@ -1,4 +1,7 @@
+use async_stream::try_stream;
 use futures_core::future::BoxFuture;
+use futures_core::stream::BoxStream;
+use futures_util::StreamExt;
 
 use super::PoolConnection;
 use crate::connection::Connect;
@@ -6,7 +9,7 @@ use crate::cursor::{Cursor, HasCursor};
 use crate::database::Database;
 use crate::describe::Describe;
 use crate::executor::Execute;
-use crate::executor::{Executor, RefExecutor};
+use crate::executor::{Executor, QueryResult, RefExecutor};
 use crate::pool::Pool;
 
 impl<'p, C, DB> Executor for &'p Pool<C>
@@ -20,13 +23,29 @@ where
     fn execute<'e, 'q: 'e, 'c: 'e, E: 'e>(
         &'c mut self,
         query: E,
-    ) -> BoxFuture<'e, crate::Result<u64>>
+    ) -> BoxFuture<'e, crate::Result<QueryResult>>
     where
         E: Execute<'q, Self::Database>,
     {
         Box::pin(async move { self.acquire().await?.execute(query).await })
     }
 
+    fn execute_many<'e, 'q: 'e, 'c: 'e>(
+        &'c mut self,
+        query: &'q str,
+    ) -> BoxStream<'e, crate::Result<QueryResult>> {
+        Box::pin(try_stream! {
+            let mut conn = self.acquire().await?;
+            let mut results = conn.execute_many(query);
+
+            while let Some(result) = results.next().await {
+                let result = result?;
+
+                yield result;
+            }
+        })
+    }
+
     fn fetch<'e, 'q, E>(&'e mut self, query: E) -> <Self::Database as HasCursor<'_, 'q>>::Cursor
     where
         E: Execute<'q, DB>,
@@ -34,7 +53,6 @@ where
         DB::Cursor::from_pool(self, query)
     }
 
-    #[doc(hidden)]
     fn describe<'e, 'q, E: 'e>(
         &'e mut self,
         query: E,
@@ -72,13 +90,20 @@ where
     fn execute<'e, 'q: 'e, 'c: 'e, E: 'e>(
         &'c mut self,
         query: E,
-    ) -> BoxFuture<'e, crate::Result<u64>>
+    ) -> BoxFuture<'e, crate::Result<QueryResult>>
     where
         E: Execute<'q, Self::Database>,
     {
         (**self).execute(query)
     }
 
+    fn execute_many<'e, 'q: 'e, 'c: 'e>(
+        &'c mut self,
+        query: &'q str,
+    ) -> BoxStream<'e, crate::Result<QueryResult>> {
+        (**self).execute_many(query)
+    }
+
     fn fetch<'e, 'q, E>(&'e mut self, query: E) -> <C::Database as HasCursor<'_, 'q>>::Cursor
     where
         E: Execute<'q, Self::Database>,
@@ -86,7 +111,6 @@ where
         (**self).fetch(query)
     }
 
-    #[doc(hidden)]
     fn describe<'e, 'q, E: 'e>(
         &'e mut self,
         query: E,
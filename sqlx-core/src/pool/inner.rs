@@ -3,7 +3,7 @@ use std::mem;
 use std::ptr;
 use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use crossbeam_queue::{ArrayQueue, SegQueue};
 use futures_core::task::{Poll, Waker};
@@ -24,15 +24,16 @@ pub(crate) struct SharedPool<C> {
     idle_conns: ArrayQueue<Idle<C>>,
     waiters: SegQueue<Waker>,
     pub(super) size: AtomicU32,
+    num_waiting: AtomicU32,
     is_closed: AtomicBool,
-    options: Options,
+    options: Options<C>,
 }
 
 impl<C> SharedPool<C>
 where
     C: Connection,
 {
-    pub fn options(&self) -> &Options {
+    pub fn options(&self) -> &Options<C> {
         &self.options
     }
 
@@ -45,20 +46,56 @@ where
     }
 
     pub(super) fn num_idle(&self) -> usize {
-        // NOTE: This is very expensive
+        // NOTE: This is a few atomic loads, it does not block other users of the queue
         self.idle_conns.len()
     }
 
+    pub(super) fn num_waiting(&self) -> u32 {
+        self.num_waiting.load(Ordering::Acquire)
+    }
+
     pub(super) fn is_closed(&self) -> bool {
         self.is_closed.load(Ordering::Acquire)
     }
 
     pub(super) async fn close(&self) {
         self.is_closed.store(true, Ordering::Release);
-        while let Ok(_) = self.idle_conns.pop() {}
+
+        // wake the tasks waiting for a connection so they return `Error::PoolClosed`
         while let Ok(waker) = self.waiters.pop() {
             waker.wake();
         }
+
+        // close connections as they are returned to the pool until there are none left
+        loop {
+            while let Ok(idle) = self.idle_conns.pop() {
+                // the pool size is decremented when the connection is closed
+                let _ = Floating::from_idle(idle, self).close().await;
+            }
+
+            if self.size() == 0 {
+                break;
+            }
+
+            // we're woken when a connection is released or dropped, but that could go to
+            // another waiting task so check again every so often
+            let _ = timeout(Duration::from_millis(100), self.wait_for_release()).await;
+        }
+    }
+
+    async fn wait_for_release(&self) {
+        let mut waker_pushed = false;
+
+        future::poll_fn(|ctx| -> Poll<()> {
+            if !waker_pushed {
+                self.waiters.push(ctx.waker().to_owned());
+                waker_pushed = true;
+                Poll::Pending
+            } else {
+                Poll::Ready(())
+            }
+        })
+        .await
     }
 
     #[inline]
@@ -75,6 +112,14 @@ where
     }
 
     pub(super) fn release(&self, floating: Floating<Live<C>>) {
+        // Retire connections that have outlived `max_lifetime` instead of returning them
+        if is_beyond_lifetime(&floating, &self.options) {
+            // Dropping the connection closes it, and the guard decrements the pool size
+            // so a waiting task can open a new connection
+            drop(floating);
+            return;
+        }
+
         self.idle_conns
             .push(floating.into_idle().into_leakable())
             .expect("BUG: connection queue overflow in release()");
@@ -109,6 +154,10 @@ where
     async fn wait_for_conn(&self, deadline: Instant) -> crate::Result<()> {
         let mut waker_pushed = false;
 
+        // count this task as waiting until we return or the acquire is cancelled
+        self.num_waiting.fetch_add(1, Ordering::AcqRel);
+        let _waiting = DecrementGuard(&self.num_waiting);
+
         timeout(
             deadline_as_timeout::<C::Database>(deadline)?,
             // `poll_fn` gets us easy access to a `Waker` that we can push to our queue
@@ -132,12 +181,13 @@ impl<C> SharedPool<C>
 where
     C: Connect,
 {
-    pub(super) async fn new_arc(url: &str, options: Options) -> crate::Result<Arc<Self>> {
+    pub(super) async fn new_arc(url: &str, options: Options<C>) -> crate::Result<Arc<Self>> {
         let mut pool = Self {
             url: url.to_owned(),
             idle_conns: ArrayQueue::new(options.max_size as usize),
             waiters: SegQueue::new(),
             size: AtomicU32::new(0),
+            num_waiting: AtomicU32::new(0),
             is_closed: AtomicBool::new(false),
             options,
         };
@@ -153,7 +203,10 @@ where
 
     pub(super) async fn acquire<'s>(&'s self) -> crate::Result<Floating<'s, Live<C>>> {
         let start = Instant::now();
-        let deadline = start + self.options.connect_timeout;
+        let deadline = start + self.options.acquire_timeout;
+
+        // opening a new connection is bounded by both timeouts
+        let connect_deadline = cmp::min(deadline, start + self.options.connect_timeout);
 
         // Unless the pool has been closed ...
         while !self.is_closed() {
@@ -168,7 +221,7 @@ where
 
             if let Some(guard) = self.try_increment_size() {
                 // pool has slots available; open a new connection
-                match self.connect(deadline, guard).await {
+                match self.connect(connect_deadline, guard).await {
                     Ok(Some(conn)) => return Ok(conn),
                     // [size] is internally decremented on _retry_ and _error_
                     Ok(None) => continue,
@@ -218,7 +271,17 @@ where
         // result here is `Result<Result<C, Error>, TimeoutError>`
         match crate::runtime::timeout(timeout, C::connect(&self.url)).await {
             // successfully established connection
-            Ok(Ok(raw)) => Ok(Some(Floating::new_live(raw, guard))),
+            Ok(Ok(mut raw)) => {
+                if let Some(callback) = &self.options.after_connect {
+                    if let Err(e) = callback(&mut raw).await {
+                        // the connection is discarded either way
+                        let _ = raw.close().await;
+                        return Err(e);
+                    }
+                }
+
+                Ok(Some(Floating::new_live(raw, guard)))
+            }
 
             // an IO error while connecting is assumed to be the system starting up
             Ok(Err(crate::Error::Io(_))) => Ok(None),
@@ -241,14 +304,14 @@ where
 
 // NOTE: Function names here are bizzare. Helpful help would be appreciated.
 
-fn is_beyond_lifetime<C>(live: &Live<C>, options: &Options) -> bool {
+fn is_beyond_lifetime<C>(live: &Live<C>, options: &Options<C>) -> bool {
     // check if connection was within max lifetime (or not set)
     options
         .max_lifetime
         .map_or(false, |max| live.created.elapsed() > max)
 }
 
-fn is_beyond_idle<C>(idle: &Idle<C>, options: &Options) -> bool {
+fn is_beyond_idle<C>(idle: &Idle<C>, options: &Options<C>) -> bool {
     // if connection wasn't idle too long (or not set)
     options
         .idle_timeout
@@ -257,7 +320,7 @@ fn is_beyond_idle<C>(idle: &Idle<C>, options: &Options) -> bool {
 
 async fn check_conn<'s: 'p, 'p, C>(
     mut conn: Floating<'s, Idle<C>>,
-    options: &'p Options,
+    options: &'p Options<C>,
 ) -> Option<Floating<'s, Live<C>>>
 where
     C: Connection,
@@ -269,8 +332,7 @@ where
         // close the connection but don't really care about the result
         let _ = conn.close().await;
         return None;
-    } else if options.test_on_acquire {
-        // TODO: Check on acquire should be a configuration setting
+    } else if options.test_before_acquire {
         // Check that the connection is still live
         if let Err(e) = conn.ping().await {
             // an error here means the other end has hung up or we lost connectivity
@@ -286,6 +348,28 @@ where
     Some(conn.into_live())
 }
 
+/// spawn a task that resets the session of a released connection before it is made idle
+pub(super) fn spawn_reset<C>(pool: Arc<SharedPool<C>>, live: Live<C>)
+where
+    C: Connection,
+{
+    spawn(async move {
+        let mut conn = live.float(&pool);
+
+        // a connection that is retired by `release` is not worth resetting
+        if !is_beyond_lifetime(&conn, &pool.options) {
+            if let Err(e) = conn.reset(&pool.options).await {
+                // the session may still have the state of the last user, so the connection is
+                // closed; the guard decrements the pool size so a waiting task can open a new one
+                log::info!("reset of released connection returned error: {}", e);
+                return;
+            }
+        }
+
+        pool.release(conn);
+    });
+}
+
 /// if `max_lifetime` or `idle_timeout` is set, spawn a task that reaps senescent connections
 fn spawn_reaper<C>(pool: &Arc<SharedPool<C>>)
 where
@@ -369,3 +453,12 @@ impl Drop for DecrementSizeGuard<'_> {
         }
     }
 }
+
+// Decrements a counter when dropped
+struct DecrementGuard<'a>(&'a AtomicU32);
+
+impl Drop for DecrementGuard<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::AcqRel);
+    }
+}
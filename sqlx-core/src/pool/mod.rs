@@ -6,9 +6,12 @@ use std::{
     time::{Duration, Instant},
 };
 
+use futures_core::future::BoxFuture;
+
 use crate::connection::Connect;
 use crate::database::Database;
-use crate::transaction::Transaction;
+use crate::runtime::sleep;
+use crate::transaction::{Transaction, TransactionOptions};
 
 use self::inner::SharedPool;
 use self::options::Options;
@@ -39,7 +42,7 @@ where
         Self::builder().build(url).await
     }
 
-    async fn with_options(url: &str, options: Options) -> crate::Result<Self> {
+    async fn with_options(url: &str, options: Options<C>) -> crate::Result<Self> {
         let inner = SharedPool::<C>::new_arc(url, options).await?;
 
         Ok(Pool(inner))
@@ -52,14 +55,16 @@ where
 
     /// Retrieves a connection from the pool.
     ///
-    /// Waits for at most the configured connection timeout before returning an error.
+    /// Waits for at most the configured acquire timeout before returning an error.
     pub async fn acquire(&self) -> crate::Result<PoolConnection<C>> {
         self.0.acquire().await.map(|conn| conn.attach(&self.0))
     }
 
     /// Attempts to retrieve a connection from the pool if there is one available.
     ///
-    /// Returns `None` immediately if there are no idle connections available in the pool.
+    /// Returns `None` immediately if there are no idle connections available in the pool. With
+    /// [`Builder::reset_on_release`], a connection that was just returned is not idle until its
+    /// reset is done.
     pub fn try_acquire(&self) -> Option<PoolConnection<C>> {
         self.0.try_acquire().map(|conn| conn.attach(&self.0))
     }
@@ -69,10 +74,95 @@ where
         Ok(Transaction::new(0, self.acquire().await?).await?)
     }
 
+    /// Retrieves a new connection and immediately begins a new transaction with the given
+    /// options.
+    ///
+    /// See [`Connection::begin_with`] for the options supported by each database.
+    ///
+    /// [`Connection::begin_with`]: crate::connection::Connection::begin_with
+    pub async fn begin_with(
+        &self,
+        options: TransactionOptions,
+    ) -> crate::Result<Transaction<PoolConnection<C>>> {
+        Transaction::new_with(self.acquire().await?, options).await
+    }
+
+    /// Runs `f` in a new transaction with the given options and commits it, running it again in
+    /// another transaction when the database could not serialize it with concurrent
+    /// transactions.
+    ///
+    /// When `f` or the commit fails with a serialization failure (`40001`) or a deadlock
+    /// (`40P01`), the transaction is rolled back and retried up to `max_retries` times, waiting
+    /// twice as long before each retry. Any other error, or the error of the last retry, is
+    /// returned.
+    ///
+    /// ```rust,ignore
+    /// let options = TransactionOptions::new().isolation_level(IsolationLevel::Serializable);
+    ///
+    /// let total: i64 = pool
+    ///     .transaction_with_retry(options, 5, |tx| {
+    ///         Box::pin(async move {
+    ///             sqlx::query("UPDATE accounts SET balance = balance - 10 WHERE id = 1")
+    ///                 .execute(&mut *tx)
+    ///                 .await?;
+    ///
+    ///             let (total,) = sqlx::query_as("SELECT SUM(balance) FROM accounts")
+    ///                 .fetch_one(tx)
+    ///                 .await?;
+    ///
+    ///             Ok(total)
+    ///         })
+    ///     })
+    ///     .await?;
+    /// ```
+    pub async fn transaction_with_retry<F, T>(
+        &self,
+        options: TransactionOptions,
+        max_retries: u32,
+        mut f: F,
+    ) -> crate::Result<T>
+    where
+        for<'c> F: FnMut(&'c mut Transaction<PoolConnection<C>>) -> BoxFuture<'c, crate::Result<T>>,
+    {
+        let mut retries = 0;
+        let mut delay = RETRY_DELAY;
+
+        loop {
+            let mut tx = self.begin_with(options.clone()).await?;
+
+            let res = match f(&mut tx).await {
+                Ok(value) => tx.commit().await.map(|_| value),
+
+                Err(error) => {
+                    let _ = tx.rollback().await;
+                    Err(error)
+                }
+            };
+
+            match res {
+                Err(error) if retries < max_retries && is_serialization_failure(&error) => {
+                    retries += 1;
+
+                    sleep(delay).await;
+                    delay *= 2;
+                }
+
+                res => return res,
+            }
+        }
+    }
+
     /// Ends the use of a connection pool. Prevents any new connections
     /// and will close all active connections when they are returned to the pool.
     ///
-    /// Does not resolve until all connections are closed.
+    /// Does not resolve until all connections are closed. Any call to [`acquire`] after
+    /// this returns [`Error::PoolClosed`], including those already waiting for a connection.
+    ///
+    /// To only wait for connections in use for so long, wrap this in a timeout; the
+    /// connections that are returned after the timeout are closed once the pool is dropped.
+    ///
+    /// [`acquire`]: #method.acquire
+    /// [`Error::PoolClosed`]: crate::Error::PoolClosed
     pub async fn close(&self) {
         self.0.close().await;
     }
@@ -88,20 +178,40 @@ where
     }
 
     /// Returns the number of idle connections.
-    pub fn idle(&self) -> usize {
+    pub fn num_idle(&self) -> usize {
         self.0.num_idle()
     }
 
+    #[doc(hidden)]
+    #[deprecated(note = "renamed to `num_idle`")]
+    pub fn idle(&self) -> usize {
+        self.num_idle()
+    }
+
+    /// Returns the number of tasks waiting for a connection to be returned to the pool.
+    ///
+    /// This does not include tasks that are opening a new connection.
+    pub fn num_waiting(&self) -> u32 {
+        self.0.num_waiting()
+    }
+
     /// Returns the configured maximum pool size.
     pub fn max_size(&self) -> u32 {
         self.0.options().max_size
     }
 
-    /// Returns the maximum time spent acquiring a new connection before an error is returned.
+    /// Returns the maximum time spent opening a new connection before an error is returned.
     pub fn connect_timeout(&self) -> Duration {
         self.0.options().connect_timeout
     }
 
+    /// Returns the maximum time spent in [`acquire`] before an error is returned.
+    ///
+    /// [`acquire`]: #method.acquire
+    pub fn acquire_timeout(&self) -> Duration {
+        self.0.options().acquire_timeout
+    }
+
     /// Returns the configured minimum idle connection count.
     pub fn min_size(&self) -> u32 {
         self.0.options().min_size
@@ -134,12 +244,24 @@ where
             .field("url", &self.0.url())
             .field("size", &self.0.size())
             .field("num_idle", &self.0.num_idle())
+            .field("num_waiting", &self.0.num_waiting())
             .field("is_closed", &self.0.is_closed())
             .field("options", self.0.options())
             .finish()
     }
 }
 
+// the wait before the first retry of [Pool::transaction_with_retry]
+const RETRY_DELAY: Duration = Duration::from_millis(10);
+
+fn is_serialization_failure(error: &crate::Error) -> bool {
+    match error {
+        crate::Error::Database(error) => matches!(error.code(), Some("40001") | Some("40P01")),
+
+        _ => false,
+    }
+}
+
 /// get the time between the deadline and now and use that as our timeout
 ///
 /// returns `Error::PoolTimedOut` if the deadline is in the past
@@ -1,5 +1,8 @@
+use std::fmt::{self, Debug};
 use std::{marker::PhantomData, time::Duration};
 
+use futures_core::future::BoxFuture;
+
 use super::Pool;
 use crate::connection::Connect;
 use crate::database::Database;
@@ -7,7 +10,7 @@ use crate::database::Database;
 /// Builder for [Pool].
 pub struct Builder<C> {
     phantom: PhantomData<C>,
-    options: Options,
+    options: Options<C>,
 }
 
 impl<C> Builder<C>
@@ -25,8 +28,10 @@ where
                 max_size: 10,
                 // don't open connections until necessary
                 min_size: 0,
-                // try to connect for 10 seconds before erroring
+                // try to connect for 60 seconds before erroring
                 connect_timeout: Duration::from_secs(60),
+                // wait for a connection for 60 seconds before erroring
+                acquire_timeout: Duration::from_secs(60),
                 // reap connections that have been alive > 30 minutes
                 // prevents unbounded live-leaking of memory due to naive prepared statement caching
                 // see src/cache.rs for context
@@ -34,7 +39,13 @@ where
                 // don't reap connections based on idle time
                 idle_timeout: None,
                 // If true, test the health of a connection on acquire
-                test_on_acquire: true,
+                test_before_acquire: true,
+                // keep the session of a connection when it is returned to the pool
+                reset_on_release: false,
+                // don't run anything on new connections
+                after_connect: None,
+                // nor on connections that were reset
+                after_reset: None,
             },
         }
     }
@@ -47,18 +58,30 @@ where
 
     /// Set the amount of time to attempt connecting to the database.
     ///
-    /// If this timeout elapses, [`Pool::acquire`] will return an error.
+    /// If this timeout elapses while opening a new connection, [`Pool::acquire`] will return
+    /// an error.
     pub fn connect_timeout(mut self, connect_timeout: Duration) -> Self {
         self.options.connect_timeout = connect_timeout;
         self
     }
 
+    /// Set the maximum amount of time to wait in [`Pool::acquire`] for a connection, including
+    /// waiting for a connection to be returned to the pool when all of them are in use.
+    ///
+    /// If this timeout elapses, [`Pool::acquire`] will return [`Error::PoolTimedOut`].
+    ///
+    /// [`Error::PoolTimedOut`]: crate::Error::PoolTimedOut
+    pub fn acquire_timeout(mut self, acquire_timeout: Duration) -> Self {
+        self.options.acquire_timeout = acquire_timeout;
+        self
+    }
+
     /// Set the minimum number of connections to maintain at all times.
     ///
     /// When the pool is built, this many connections will be automatically spun up.
     ///
-    /// If any connection is reaped by [`max_lifetime`] or [`idle_timeout`] and it brings
-    /// the connection count below this amount, a new connection will be opened to replace it.
+    /// Idle connections are not reaped by [`max_lifetime`] or [`idle_timeout`] if that would
+    /// bring the connection count below this amount.
     ///
     /// [`max_lifetime`]: #method.max_lifetime
     /// [`idle_timeout`]: #method.idle_timeout
@@ -69,7 +92,8 @@ where
 
     /// Set the maximum lifetime of individual connections.
     ///
-    /// Any connection with a lifetime greater than this will be closed.
+    /// Any connection with a lifetime greater than this will be closed when it is returned to
+    /// the pool, or by a background task while it is idle.
     ///
     /// When set to `None`, all connections live until either reaped by [`idle_timeout`]
     /// or explicitly disconnected.
@@ -88,7 +112,8 @@ where
 
     /// Set a maximum idle duration for individual connections.
     ///
-    /// Any connection with an idle duration longer than this will be closed.
+    /// Any connection with an idle duration longer than this will be closed by a background task,
+    /// which checks the idle connections periodically.
     ///
     /// For usage-based database server billing, this can be a cost saver.
     pub fn idle_timeout(mut self, idle_timeout: impl Into<Option<Duration>>) -> Self {
@@ -96,14 +121,81 @@ where
         self
     }
 
-    /// If true, the health of a connection will be verified by a call to [`Connection::ping`]
-    /// before returning the connection.
+    /// If true, the health of an idle connection will be verified by a call to
+    /// [`Connection::ping`] before returning the connection.
+    ///
+    /// A connection that fails the check, such as one that was closed by the server or a
+    /// proxy while it was idle, is discarded and replaced with another connection. This adds a
+    /// round-trip to the database to every acquire of an idle connection.
     ///
     /// Defaults to `true`.
     ///
     /// [`Connection::ping`]: crate::connection::Connection::ping
-    pub fn test_on_acquire(mut self, test: bool) -> Self {
-        self.options.test_on_acquire = test;
+    pub fn test_before_acquire(mut self, test: bool) -> Self {
+        self.options.test_before_acquire = test;
+        self
+    }
+
+    /// If true, a connection that is returned to the pool is reset with [`Connection::reset`]
+    /// before it is made idle again, so that the state of its session, such as the variables
+    /// set with `SET` or its temporary tables, does not leak to the next user.
+    ///
+    /// The reset runs in the background once the connection is dropped and is followed by the
+    /// [`after_reset`](Self::after_reset) callback; the connection can be acquired again when
+    /// both are done, until then [`Pool::try_acquire`] may return `None`. A connection that
+    /// can not be reset is closed.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// [`Connection::reset`]: crate::connection::Connection::reset
+    pub fn reset_on_release(mut self, reset: bool) -> Self {
+        self.options.reset_on_release = reset;
+        self
+    }
+
+    #[doc(hidden)]
+    #[deprecated(note = "renamed to `test_before_acquire`")]
+    pub fn test_on_acquire(self, test: bool) -> Self {
+        self.test_before_acquire(test)
+    }
+
+    /// Set a callback that is run on every new connection before it is used by the pool.
+    ///
+    /// This runs once for each connection that is opened, not each time a connection is
+    /// acquired, which makes it the place to set up the session of the connection. If the
+    /// callback returns an error the connection is closed and the error is returned by
+    /// [`Pool::acquire`].
+    ///
+    /// ```rust,ignore
+    /// let pool = PgPool::builder()
+    ///     .after_connect(|conn| {
+    ///         Box::pin(async move {
+    ///             conn.execute("SET search_path = app, public").await?;
+    ///
+    ///             Ok(())
+    ///         })
+    ///     })
+    ///     .build(&url)
+    ///     .await?;
+    /// ```
+    pub fn after_connect<F>(mut self, callback: F) -> Self
+    where
+        for<'c> F: Fn(&'c mut C) -> BoxFuture<'c, crate::Result<()>> + Send + Sync + 'static,
+    {
+        self.options.after_connect = Some(Box::new(callback));
+        self
+    }
+
+    /// Set a callback that is run on a connection after its session was reset by
+    /// [`reset_on_release`](Self::reset_on_release).
+    ///
+    /// A reset undoes the `SET` of [`after_connect`](Self::after_connect), which can be done
+    /// again here. If the callback returns an error the connection is closed.
+    pub fn after_reset<F>(mut self, callback: F) -> Self
+    where
+        for<'c> F: Fn(&'c mut C) -> BoxFuture<'c, crate::Result<()>> + Send + Sync + 'static,
+    {
+        self.options.after_reset = Some(Box::new(callback));
         self
     }
 
@@ -131,12 +223,35 @@ where
     }
 }
 
-#[derive(Debug)]
-pub(crate) struct Options {
+pub(crate) struct Options<C> {
     pub max_size: u32,
     pub connect_timeout: Duration,
+    pub acquire_timeout: Duration,
     pub min_size: u32,
     pub max_lifetime: Option<Duration>,
     pub idle_timeout: Option<Duration>,
-    pub test_on_acquire: bool,
+    pub test_before_acquire: bool,
+    pub reset_on_release: bool,
+    pub after_connect: Option<AfterConnect<C>>,
+    pub after_reset: Option<AfterConnect<C>>,
+}
+
+pub(crate) type AfterConnect<C> =
+    Box<dyn for<'c> Fn(&'c mut C) -> BoxFuture<'c, crate::Result<()>> + Send + Sync + 'static>;
+
+impl<C> Debug for Options<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Options")
+            .field("max_size", &self.max_size)
+            .field("connect_timeout", &self.connect_timeout)
+            .field("acquire_timeout", &self.acquire_timeout)
+            .field("min_size", &self.min_size)
+            .field("max_lifetime", &self.max_lifetime)
+            .field("idle_timeout", &self.idle_timeout)
+            .field("test_before_acquire", &self.test_before_acquire)
+            .field("reset_on_release", &self.reset_on_release)
+            .field("after_connect", &self.after_connect.is_some())
+            .field("after_reset", &self.after_reset.is_some())
+            .finish()
+    }
 }
@@ -0,0 +1,204 @@
+use std::ops::{Deref, DerefMut};
+
+use crate::postgres::{PgConnection, PgRow, Postgres};
+use crate::query::{query, Query};
+use crate::row::Row;
+
+/// An advisory lock of Postgres, to coordinate the work of several connections or processes
+/// with a lock whose meaning is up to the application.
+///
+/// A lock is identified by its key, a 64-bit integer or a pair of 32-bit integers, which is
+/// shared by every database of the server.
+///
+/// A session lock is held until it is released, by dropping the [`PgAdvisoryLockGuard`]
+/// returned when it is acquired, or until the connection is closed. A transaction lock,
+/// acquired with [`acquire_xact`](Self::acquire_xact), is held until the end of the
+/// transaction the connection is in and can not be released before.
+///
+/// ```rust,ignore
+/// let lock = PgAdvisoryLock::new(42);
+///
+/// let mut guard = lock.acquire(&mut conn).await?;
+///
+/// // the guard dereferences to the connection
+/// guard.execute("UPDATE jobs SET ...").await?;
+///
+/// guard.release().await?;
+/// ```
+///
+/// <https://www.postgresql.org/docs/current/explicit-locking.html#ADVISORY-LOCKS>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PgAdvisoryLock {
+    key: Key,
+}
+
+// The two functions of each kind of lock take either key
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Key {
+    BigInt(i64),
+    Pair(i32, i32),
+}
+
+impl PgAdvisoryLock {
+    /// The lock with the given key.
+    pub fn new(key: i64) -> Self {
+        Self {
+            key: Key::BigInt(key),
+        }
+    }
+
+    /// The lock with the given pair of keys.
+    ///
+    /// The locks with a pair of keys are distinct from those with a single key, even if the
+    /// keys would make the same 64-bit integer.
+    pub fn with_key_pair(key1: i32, key2: i32) -> Self {
+        Self {
+            key: Key::Pair(key1, key2),
+        }
+    }
+
+    /// Acquire the lock as a session lock, waiting for it if another session holds it.
+    ///
+    /// The lock is held until the returned guard is released or dropped. A session lock can be
+    /// acquired more than once by the same connection, and is then held until it has been
+    /// released as many times.
+    pub async fn acquire<'c>(
+        &self,
+        conn: &'c mut PgConnection,
+    ) -> crate::Result<PgAdvisoryLockGuard<'c>> {
+        self.call(conn, "pg_advisory_lock").await?;
+
+        Ok(PgAdvisoryLockGuard {
+            lock: *self,
+            conn: Some(conn),
+        })
+    }
+
+    /// Acquire the lock as a session lock if no other session holds it, without waiting.
+    ///
+    /// Returns `None` if the lock is held by another session.
+    pub async fn try_acquire<'c>(
+        &self,
+        conn: &'c mut PgConnection,
+    ) -> crate::Result<Option<PgAdvisoryLockGuard<'c>>> {
+        Ok(if self.call_bool(conn, "pg_try_advisory_lock").await? {
+            Some(PgAdvisoryLockGuard {
+                lock: *self,
+                conn: Some(conn),
+            })
+        } else {
+            None
+        })
+    }
+
+    /// Acquire the lock as a transaction lock, waiting for it if another session holds it.
+    ///
+    /// The lock is held until the end of the transaction the connection is in, e.g. a
+    /// [`Transaction`]; outside of a transaction it would be released as soon as it is
+    /// acquired.
+    ///
+    /// [`Transaction`]: crate::Transaction
+    pub async fn acquire_xact(&self, conn: &mut PgConnection) -> crate::Result<()> {
+        self.call(conn, "pg_advisory_xact_lock").await
+    }
+
+    /// Acquire the lock as a transaction lock if no other session holds it, without waiting.
+    ///
+    /// Returns whether the lock was acquired.
+    pub async fn try_acquire_xact(&self, conn: &mut PgConnection) -> crate::Result<bool> {
+        self.call_bool(conn, "pg_try_advisory_xact_lock").await
+    }
+
+    // The call of a function of advisory locks with the key of this lock, which is bound by
+    // [query] so that each function is a single prepared statement
+    fn sql(&self, function: &str) -> String {
+        match self.key {
+            Key::BigInt(_) => format!("SELECT {}($1)", function),
+            Key::Pair(..) => format!("SELECT {}($1, $2)", function),
+        }
+    }
+
+    fn query<'q>(&self, sql: &'q str) -> Query<'q, Postgres> {
+        match self.key {
+            Key::BigInt(key) => query(sql).bind(key),
+            Key::Pair(key1, key2) => query(sql).bind(key1).bind(key2),
+        }
+    }
+
+    async fn call(&self, conn: &mut PgConnection, function: &str) -> crate::Result<()> {
+        let sql = self.sql(function);
+
+        self.query(&sql).execute(conn).await?;
+
+        Ok(())
+    }
+
+    async fn call_bool(&self, conn: &mut PgConnection, function: &str) -> crate::Result<bool> {
+        let sql = self.sql(function);
+
+        self.query(&sql)
+            .map(|row: PgRow| row.get(0))
+            .fetch_one(conn)
+            .await
+    }
+}
+
+/// A session lock acquired with [`PgAdvisoryLock::acquire`], which is released when it is
+/// dropped.
+///
+/// The guard dereferences to the connection, which can be used while the lock is held.
+/// Dropping the guard queues the release of the lock to be sent before the next query of the
+/// connection; [`release`](Self::release) releases it at once.
+pub struct PgAdvisoryLockGuard<'c> {
+    lock: PgAdvisoryLock,
+
+    // `None` once the lock is released
+    conn: Option<&'c mut PgConnection>,
+}
+
+impl PgAdvisoryLockGuard<'_> {
+    /// The lock that is held.
+    pub fn lock(&self) -> &PgAdvisoryLock {
+        &self.lock
+    }
+
+    /// Release the lock.
+    pub async fn release(mut self) -> crate::Result<()> {
+        if let Some(conn) = self.conn.take() {
+            // postgres warns and returns `false` for a lock that was not held, which can only
+            // happen if it was released with a query of its own
+            self.lock.call_bool(conn, "pg_advisory_unlock").await?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Deref for PgAdvisoryLockGuard<'_> {
+    type Target = PgConnection;
+
+    fn deref(&self) -> &Self::Target {
+        self.conn.as_ref().expect("lock already released")
+    }
+}
+
+impl DerefMut for PgAdvisoryLockGuard<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.conn.as_mut().expect("lock already released")
+    }
+}
+
+impl Drop for PgAdvisoryLockGuard<'_> {
+    fn drop(&mut self) {
+        if let Some(conn) = self.conn.take() {
+            let sql = match self.lock.key {
+                Key::BigInt(key) => format!("SELECT pg_advisory_unlock({})", key),
+                Key::Pair(key1, key2) => format!("SELECT pg_advisory_unlock({}, {})", key1, key2),
+            };
+
+            // this is flushed (and its response skipped) before the next query
+            conn.write_simple_query(&sql);
+            conn.pending_queries += 1;
+        }
+    }
+}
@@ -1,8 +1,12 @@
+use std::fmt::{self, Display, Write};
+
 use byteorder::{ByteOrder, NetworkEndian};
 
 use crate::arguments::Arguments;
 use crate::encode::{Encode, IsNull};
 use crate::io::BufMut;
+use crate::logger::LogArguments;
+use crate::postgres::protocol::TypeFormat;
 use crate::postgres::{PgRawBuffer, PgTypeInfo, Postgres};
 use crate::types::Type;
 
@@ -11,6 +15,9 @@ pub struct PgArguments {
     // Types of the bind parameters
     pub(super) types: Vec<PgTypeInfo>,
 
+    // Formats of the bind parameters, empty while all of them are binary
+    pub(super) formats: Vec<TypeFormat>,
+
     // Write buffer for serializing bind values
     pub(super) buffer: PgRawBuffer,
 }
@@ -18,6 +25,9 @@ pub struct PgArguments {
 impl Arguments for PgArguments {
     type Database = super::Postgres;
 
+    // the number of parameters is sent as a `u16`
+    const MAX_BINDS: usize = 65535;
+
     fn reserve(&mut self, len: usize, size: usize) {
         self.types.reserve(len);
         self.buffer.reserve(size);
@@ -32,6 +42,10 @@ impl Arguments for PgArguments {
 
         self.types.push(<T as Type<Postgres>>::type_info());
 
+        if !self.formats.is_empty() {
+            self.formats.push(TypeFormat::Binary);
+        }
+
         // Reserves space for the length of the value
         let pos = self.buffer.len();
         self.buffer.put_i32::<NetworkEndian>(0);
@@ -48,4 +62,74 @@ impl Arguments for PgArguments {
         // Write-back the len to the beginning of this frame (not including the len of len)
         NetworkEndian::write_i32(&mut self.buffer[pos..], len as i32);
     }
+
+    fn format_placeholder<W: Write>(&self, writer: &mut W) -> fmt::Result {
+        write!(writer, "${}", self.types.len())
+    }
+}
+
+impl PgArguments {
+    // Add a value as text, the format postgres parses with the input function of the type of
+    // `T` instead of expecting the binary format of that type
+    pub(crate) fn add_as<T>(&mut self, value: &dyn Display)
+    where
+        T: Type<Postgres>,
+    {
+        if self.formats.is_empty() {
+            self.formats.resize(self.types.len(), TypeFormat::Binary);
+        }
+
+        self.types.push(<T as Type<Postgres>>::type_info());
+        self.formats.push(TypeFormat::Text);
+
+        let text = value.to_string();
+
+        self.buffer.put_i32::<NetworkEndian>(text.len() as i32);
+        self.buffer.extend_from_slice(text.as_bytes());
+    }
+
+    // The format codes of the parameters for [Bind]
+    pub(super) fn formats(&self) -> &[TypeFormat] {
+        if self.formats.is_empty() {
+            &[TypeFormat::Binary]
+        } else {
+            &self.formats
+        }
+    }
+}
+
+// Each value is logged with its type and the bytes it is encoded to
+impl fmt::Debug for PgArguments {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut list = f.debug_list();
+        let mut buffer = &self.buffer[..];
+
+        for (i, ty) in self.types.iter().enumerate() {
+            let len = NetworkEndian::read_i32(buffer);
+            buffer = &buffer[4..];
+
+            if len < 0 {
+                list.entry(&format_args!("{} NULL", ty));
+            } else {
+                let (value, rest) = buffer.split_at(len as usize);
+                buffer = rest;
+
+                if let Some(TypeFormat::Text) = self.formats.get(i) {
+                    list.entry(&format_args!("{} {:?}", ty, String::from_utf8_lossy(value)));
+                } else {
+                    list.entry(&format_args!("{} 0x{}", ty, hex::encode(value)));
+                }
+            }
+        }
+
+        list.finish()
+    }
+}
+
+impl LogArguments for PgArguments {
+    const DB_SYSTEM: &'static str = "postgresql";
+
+    fn count(&self) -> usize {
+        self.types.len()
+    }
 }
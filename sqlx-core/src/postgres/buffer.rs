@@ -25,6 +25,19 @@ impl PgRawBuffer {
         self.type_holes.push((offset, type_name.clone()));
     }
 
+    // Appends the contents of `other` starting at `offset`, keeping the type holes in that range
+    pub(crate) fn extend_from_buffer(&mut self, other: &PgRawBuffer, offset: usize) {
+        let start = self.len();
+
+        self.extend_from_slice(&other[offset..]);
+
+        for (hole, name) in &other.type_holes {
+            if *hole >= offset {
+                self.type_holes.push((start + hole - offset, name.clone()));
+            }
+        }
+    }
+
     // Patch all remembered type holes
     // This should only go out and ask postgres if we have not seen the type name yet
     pub(crate) async fn patch_type_holes(
@@ -38,6 +51,36 @@ impl PgRawBuffer {
 
         Ok(())
     }
+
+    // Patch the type holes with the OIDs the connection has looked up before, for when it can
+    // not run a query to look up the others, as during a `COPY`
+    pub(crate) fn patch_known_type_holes(
+        &mut self,
+        connection: &PgConnection,
+    ) -> crate::Result<()> {
+        for (offset, name) in &self.type_holes {
+            let oid = match connection.cache_type_oid.get(name) {
+                Some(oid) => *oid,
+
+                None => {
+                    return Err(protocol_err!(
+                    "the OID of the type {:?} is not known; bind a value of it in a query first",
+                    &**name
+                )
+                    .into())
+                }
+            };
+
+            NetworkEndian::write_u32(&mut self.inner[*offset..], oid);
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.inner.clear();
+        self.type_holes.clear();
+    }
 }
 
 impl Deref for PgRawBuffer {
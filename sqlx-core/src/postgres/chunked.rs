@@ -0,0 +1,187 @@
+use std::sync::Arc;
+
+use async_stream::try_stream;
+use futures_core::stream::BoxStream;
+use futures_util::TryStreamExt;
+
+use crate::executor::Execute;
+use crate::logger::QueryLogger;
+use crate::postgres::protocol::{self, DataRow, Message, ReadyForQuery};
+use crate::postgres::{PgConnection, PgRow, Postgres};
+use crate::query_as::QueryAs;
+use crate::row::FromRow;
+
+impl PgConnection {
+    /// Run a query and fetch its rows in chunks of at most `chunk_size` rows, returning a
+    /// `Stream` of the chunks.
+    ///
+    /// The query is bound to a portal, a cursor on the server, from which each chunk is only
+    /// fetched once the previous one has been taken from the stream, so a query that returns
+    /// millions of rows can be read with the memory for a single chunk.
+    ///
+    /// ```rust,ignore
+    /// let mut chunks = conn.fetch_all_chunked(
+    ///     sqlx::query_as::<_, (i64, String)>("SELECT id, name FROM events"),
+    ///     10_000,
+    /// );
+    ///
+    /// while let Some(events) = chunks.try_next().await? {
+    ///     archive(events).await?;
+    /// }
+    /// ```
+    ///
+    /// The query runs in an implicit transaction, or in the transaction the connection is in,
+    /// which stays open until the last chunk has been fetched or the stream is dropped; a
+    /// stream that is dropped before it is finished closes the portal.
+    pub fn fetch_all_chunked<'c, 'q: 'c, O>(
+        &'c mut self,
+        query: QueryAs<'q, Postgres, O>,
+        chunk_size: u32,
+    ) -> BoxStream<'c, crate::Result<Vec<O>>>
+    where
+        O: 'c + Send + Unpin + for<'r> FromRow<'r, PgRow<'r>>,
+    {
+        let (query, arguments) = query.into_parts();
+        let mut arguments = arguments.unwrap_or_default();
+
+        let mut logger = QueryLogger::new(query, Some(&arguments), &self.log_settings);
+        let span = logger.span();
+
+        let stream = try_stream! {
+            let statement = self.write_bind_portal(query, &mut arguments).await?;
+
+            // Closing a statement closes its portals as well, so a statement that is not
+            // cached is closed when the next one is prepared instead of after this query
+            let statement = if self.cache_statement_id.is_enabled() {
+                Arc::clone(&self.cache_statement[&statement])
+            } else {
+                if !self.unnamed_statements {
+                    self.uncached_statement = Some(statement);
+                }
+
+                self.cache_statement.remove(&statement).unwrap_or_default()
+            };
+
+            self.wait_until_ready().await?;
+
+            let mut portal = Portal {
+                conn: self,
+                done: false,
+            };
+
+            loop {
+                // A [Flush] instead of a [Sync] has postgres send the rows of the [Execute]
+                // without ending the transaction, which would close the portal
+                portal.conn.write_execute("", chunk_size as i32);
+                portal.conn.stream.write(protocol::Flush);
+                portal.conn.stream.flush().await?;
+                portal.conn.is_ready = false;
+
+                let mut chunk = Vec::with_capacity(chunk_size as usize);
+
+                let finished = loop {
+                    match portal.conn.stream.receive().await? {
+                        Message::ParseComplete
+                        | Message::BindComplete
+                        | Message::CloseComplete
+                        | Message::ParameterStatus => {}
+
+                        Message::DataRow => {
+                            let data = DataRow::read(
+                                portal.conn.stream.buffer(),
+                                &mut portal.conn.current_row_values,
+                            )?;
+
+                            let row = PgRow {
+                                statement: Arc::clone(&statement),
+                                data,
+                            };
+
+                            chunk.push(O::from_row(&row)?);
+                        }
+
+                        // there are more rows to fetch from the portal
+                        Message::PortalSuspended => break false,
+
+                        Message::CommandComplete | Message::EmptyQueryResponse => break true,
+
+                        message => {
+                            let error: crate::Error =
+                                protocol_err!("fetch_all_chunked: unexpected message: {:?}", message).into();
+
+                            Err(error)?;
+                        }
+                    }
+                };
+
+                logger.add_rows(chunk.len() as u64);
+
+                if finished {
+                    portal.finish().await?;
+
+                    if !chunk.is_empty() {
+                        yield chunk;
+                    }
+
+                    break;
+                }
+
+                yield chunk;
+            }
+        };
+
+        Box::pin(stream.inspect_err(move |error| span.record_error(error)))
+    }
+}
+
+// The unnamed portal of a query that is being fetched in chunks
+struct Portal<'c> {
+    conn: &'c mut PgConnection,
+    done: bool,
+}
+
+impl Portal<'_> {
+    // End the query once all of its rows were fetched
+    async fn finish(&mut self) -> crate::Result<()> {
+        self.done = true;
+
+        self.conn.write_sync();
+        self.conn.stream.flush().await?;
+
+        loop {
+            match self.conn.stream.receive().await? {
+                Message::ReadyForQuery => {
+                    let _ready = ReadyForQuery::read(self.conn.stream.buffer())?;
+
+                    self.conn.is_ready = true;
+                    break;
+                }
+
+                Message::ParameterStatus => {}
+
+                message => {
+                    return Err(protocol_err!(
+                        "fetch_all_chunked: unexpected message: {:?}",
+                        message
+                    )
+                    .into());
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for Portal<'_> {
+    fn drop(&mut self) {
+        if !self.done {
+            // this is flushed before the connection is used again, which then skips the rest
+            // of the rows of the last [Execute]; after an error postgres ignores everything
+            // until the [Sync]
+            self.conn.stream.write(protocol::Close::Portal(""));
+            self.conn.write_sync();
+            self.conn.is_ready = false;
+        }
+    }
+}
@@ -0,0 +1,349 @@
+use async_stream::try_stream;
+use byteorder::{ByteOrder, NetworkEndian};
+use bytes::Bytes;
+use futures_core::stream::Stream;
+
+use crate::encode::{Encode, IsNull};
+use crate::io::BufMut;
+use crate::postgres::protocol::{CommandComplete, CopyData, CopyDone, CopyFail, Message};
+use crate::postgres::{PgConnection, PgRawBuffer, Postgres};
+
+// https://www.postgresql.org/docs/current/sql-copy.html#id-1.9.3.55.9.4.5
+const BINARY_SIGNATURE: &[u8] = b"PGCOPY\n\xFF\r\n\0";
+
+// The rows are sent in chunks of at least this many bytes
+const BINARY_CHUNK_SIZE: usize = 64 * 1024;
+
+impl PgConnection {
+    /// Start a `COPY ... FROM STDIN` statement to stream data into a table.
+    ///
+    /// The data is sent with [`PgCopyIn::send`] in the format given in the statement, e.g. tab
+    /// separated rows for the default text format. The copy must be completed with
+    /// [`PgCopyIn::finish`]; if it is dropped without finishing the copy is aborted and none
+    /// of the rows are inserted.
+    ///
+    /// ```rust,ignore
+    /// let mut copy = conn.copy_in("COPY users (id, name) FROM STDIN").await?;
+    ///
+    /// copy.send(b"1\tAlice\n").await?;
+    /// copy.send(b"2\tBob\n").await?;
+    ///
+    /// let rows = copy.finish().await?;
+    /// ```
+    pub async fn copy_in(&mut self, statement: &str) -> crate::Result<PgCopyIn<'_>> {
+        self.run(statement, None).await?;
+
+        loop {
+            match self.stream.receive().await? {
+                Message::CopyInResponse => break,
+
+                Message::ParameterStatus => {}
+
+                message => {
+                    return Err(protocol_err!(
+                        "copy_in: expected a COPY ... FROM STDIN statement but received: {:?}",
+                        message
+                    )
+                    .into());
+                }
+            }
+        }
+
+        Ok(PgCopyIn {
+            conn: self,
+            done: false,
+        })
+    }
+
+    /// Start a `COPY ... FROM STDIN (FORMAT binary)` statement to send typed rows into a table.
+    ///
+    /// The fields of each row are encoded with their [`Encode`] impls, as they are for the
+    /// arguments of a query, which is faster to write and to parse than text and keeps the
+    /// exact values. The fields must be in the order of the columns of the statement and of
+    /// the types of those columns, e.g. an `i64` for a `BIGINT` column, as postgres takes the
+    /// binary data as it is.
+    ///
+    /// ```rust,ignore
+    /// let mut copy = conn
+    ///     .copy_in_binary("COPY users (id, name) FROM STDIN (FORMAT binary)")
+    ///     .await?;
+    ///
+    /// for user in &users {
+    ///     copy.send_row((user.id, &user.name)).await?;
+    /// }
+    ///
+    /// let rows = copy.finish().await?;
+    /// ```
+    ///
+    /// [`Encode`]: crate::encode::Encode
+    pub async fn copy_in_binary(&mut self, statement: &str) -> crate::Result<PgBinaryCopyIn<'_>> {
+        let copy = self.copy_in(statement).await?;
+
+        let mut buffer = PgRawBuffer::default();
+
+        // the signature, the flags (of which none are set) and the length of the header
+        // extension (which is empty)
+        buffer.extend_from_slice(BINARY_SIGNATURE);
+        buffer.put_i32::<NetworkEndian>(0);
+        buffer.put_i32::<NetworkEndian>(0);
+
+        Ok(PgBinaryCopyIn { copy, buffer })
+    }
+
+    /// Run a `COPY ... TO STDOUT` statement, returning a `Stream` of the data as it is sent by
+    /// Postgres.
+    ///
+    /// The data is in the format given in the statement; for the text and CSV formats each
+    /// chunk is a single row. An error that occurs while the copy is running ends the stream.
+    ///
+    /// ```rust,ignore
+    /// let mut rows = conn.copy_out("COPY users TO STDOUT (FORMAT csv)");
+    ///
+    /// while let Some(row) = rows.try_next().await? {
+    ///     file.write_all(&row).await?;
+    /// }
+    /// ```
+    pub fn copy_out<'c>(
+        &'c mut self,
+        statement: &'c str,
+    ) -> impl Stream<Item = crate::Result<Bytes>> + Unpin + 'c {
+        Box::pin(try_stream! {
+            self.run(statement, None).await?;
+
+            loop {
+                match self.stream.receive().await? {
+                    Message::CopyOutResponse => break,
+
+                    Message::ParameterStatus => {}
+
+                    message => {
+                        Err(protocol_err!(
+                            "copy_out: expected a COPY ... TO STDOUT statement but received: {:?}",
+                            message
+                        ))?;
+                    }
+                }
+            }
+
+            loop {
+                match self.stream.receive().await? {
+                    Message::CopyData => {
+                        yield Bytes::copy_from_slice(self.stream.buffer());
+                    }
+
+                    Message::CopyDone | Message::CommandComplete => {}
+
+                    Message::ReadyForQuery => {
+                        self.is_ready = true;
+                        break;
+                    }
+
+                    message => {
+                        Err(protocol_err!("copy_out: unexpected message: {:?}", message))?;
+                    }
+                }
+            }
+        })
+    }
+}
+
+/// A `COPY ... FROM STDIN` in progress, started with [`PgConnection::copy_in`].
+pub struct PgCopyIn<'c> {
+    conn: &'c mut PgConnection,
+    done: bool,
+}
+
+impl PgCopyIn<'_> {
+    /// Send a chunk of data to Postgres.
+    ///
+    /// Chunks do not have to line up with rows.
+    pub async fn send(&mut self, data: &[u8]) -> crate::Result<()> {
+        self.conn.stream.write(CopyData(data));
+        self.conn.stream.flush().await?;
+
+        Ok(())
+    }
+
+    /// Complete the copy, returning the number of rows copied.
+    ///
+    /// Any error in the data that was sent is returned here.
+    pub async fn finish(mut self) -> crate::Result<u64> {
+        self.done = true;
+
+        self.conn.stream.write(CopyDone);
+        self.conn.stream.flush().await?;
+
+        let mut rows = 0;
+
+        loop {
+            match self.conn.stream.receive().await? {
+                Message::CommandComplete => {
+                    rows = CommandComplete::read(self.conn.stream.buffer())?.affected_rows;
+                }
+
+                Message::ReadyForQuery => {
+                    self.conn.is_ready = true;
+                    break;
+                }
+
+                message => {
+                    return Err(protocol_err!("copy_in: unexpected message: {:?}", message).into());
+                }
+            }
+        }
+
+        Ok(rows)
+    }
+
+    /// Abort the copy with the given message; none of the rows are inserted.
+    pub async fn abort(mut self, message: &str) -> crate::Result<()> {
+        self.done = true;
+
+        self.conn.stream.write(CopyFail(message));
+
+        // postgres responds with an error for our message which is skipped here
+        self.conn.wait_until_ready().await
+    }
+}
+
+impl Drop for PgCopyIn<'_> {
+    fn drop(&mut self) {
+        if !self.done {
+            // this is flushed before the connection is used again
+            self.conn
+                .stream
+                .write(CopyFail("COPY was dropped before it was finished"));
+        }
+    }
+}
+
+/// A binary `COPY ... FROM STDIN` in progress, started with [`PgConnection::copy_in_binary`].
+///
+/// The rows are buffered and sent in chunks. As for [`PgCopyIn`], the copy must be completed
+/// with [`finish`](Self::finish); if it is dropped without finishing the copy is aborted and
+/// none of the rows are inserted.
+pub struct PgBinaryCopyIn<'c> {
+    copy: PgCopyIn<'c>,
+    buffer: PgRawBuffer,
+}
+
+impl PgBinaryCopyIn<'_> {
+    /// Add a row to the copy.
+    ///
+    /// The row is sent with the ones before it once enough of them are buffered; an error in
+    /// the data is only returned by [`finish`](Self::finish).
+    pub async fn send_row<R>(&mut self, row: R) -> crate::Result<()>
+    where
+        R: PgCopyRow,
+    {
+        row.encode_row(&mut self.buffer);
+
+        if self.buffer.len() >= BINARY_CHUNK_SIZE {
+            self.send_buffer().await?;
+        }
+
+        Ok(())
+    }
+
+    /// Complete the copy, returning the number of rows copied.
+    pub async fn finish(mut self) -> crate::Result<u64> {
+        // the trailer is a field count of -1
+        self.buffer.put_i16::<NetworkEndian>(-1);
+        self.send_buffer().await?;
+
+        self.copy.finish().await
+    }
+
+    /// Abort the copy with the given message; none of the rows are inserted.
+    pub async fn abort(self, message: &str) -> crate::Result<()> {
+        self.copy.abort(message).await
+    }
+
+    async fn send_buffer(&mut self) -> crate::Result<()> {
+        // the OIDs in arrays and records can not be looked up while the copy is running
+        self.buffer.patch_known_type_holes(self.copy.conn)?;
+
+        self.copy.send(&self.buffer).await?;
+        self.buffer.clear();
+
+        Ok(())
+    }
+}
+
+/// A row of a binary copy, sent with [`PgBinaryCopyIn::send_row`].
+///
+/// This is implemented for tuples of up to 16 values that can be encoded, whose values are
+/// the fields of the row. A struct can be sent as a tuple of its fields, or of references to
+/// them, e.g. `(user.id, &user.name)`.
+pub trait PgCopyRow {
+    #[doc(hidden)]
+    fn encode_row(&self, buf: &mut PgRawBuffer);
+}
+
+// Each field is its length, or -1 for NULL, followed by its value as it is encoded for a
+// query argument
+fn encode_field<T>(buf: &mut PgRawBuffer, value: &T)
+where
+    T: Encode<Postgres> + ?Sized,
+{
+    let pos = buf.len();
+    buf.put_i32::<NetworkEndian>(0);
+
+    let len = if let IsNull::No = value.encode_nullable(buf) {
+        (buf.len() - pos - 4) as i32
+    } else {
+        -1
+    };
+
+    NetworkEndian::write_i32(&mut buf[pos..], len);
+}
+
+macro_rules! impl_pg_copy_row_for_tuple {
+    ($len:literal; $($idx:tt -> $T:ident),+) => {
+        impl<$($T),+> PgCopyRow for ($($T,)+)
+        where
+            $($T: Encode<Postgres>,)+
+        {
+            fn encode_row(&self, buf: &mut PgRawBuffer) {
+                buf.put_i16::<NetworkEndian>($len);
+
+                $(encode_field(buf, &self.$idx);)+
+            }
+        }
+    };
+}
+
+impl_pg_copy_row_for_tuple!(1; 0 -> T1);
+impl_pg_copy_row_for_tuple!(2; 0 -> T1, 1 -> T2);
+impl_pg_copy_row_for_tuple!(3; 0 -> T1, 1 -> T2, 2 -> T3);
+impl_pg_copy_row_for_tuple!(4; 0 -> T1, 1 -> T2, 2 -> T3, 3 -> T4);
+impl_pg_copy_row_for_tuple!(5; 0 -> T1, 1 -> T2, 2 -> T3, 3 -> T4, 4 -> T5);
+impl_pg_copy_row_for_tuple!(6; 0 -> T1, 1 -> T2, 2 -> T3, 3 -> T4, 4 -> T5, 5 -> T6);
+impl_pg_copy_row_for_tuple!(7; 0 -> T1, 1 -> T2, 2 -> T3, 3 -> T4, 4 -> T5, 5 -> T6, 6 -> T7);
+impl_pg_copy_row_for_tuple!(8; 0 -> T1, 1 -> T2, 2 -> T3, 3 -> T4, 4 -> T5, 5 -> T6, 6 -> T7, 7 -> T8);
+impl_pg_copy_row_for_tuple!(9; 0 -> T1, 1 -> T2, 2 -> T3, 3 -> T4, 4 -> T5, 5 -> T6, 6 -> T7, 7 -> T8, 8 -> T9);
+impl_pg_copy_row_for_tuple!(10; 0 -> T1, 1 -> T2, 2 -> T3, 3 -> T4, 4 -> T5, 5 -> T6, 6 -> T7, 7 -> T8, 8 -> T9, 9 -> T10);
+impl_pg_copy_row_for_tuple!(11; 0 -> T1, 1 -> T2, 2 -> T3, 3 -> T4, 4 -> T5, 5 -> T6, 6 -> T7, 7 -> T8, 8 -> T9, 9 -> T10, 10 -> T11);
+impl_pg_copy_row_for_tuple!(12; 0 -> T1, 1 -> T2, 2 -> T3, 3 -> T4, 4 -> T5, 5 -> T6, 6 -> T7, 7 -> T8, 8 -> T9, 9 -> T10, 10 -> T11, 11 -> T12);
+impl_pg_copy_row_for_tuple!(13; 0 -> T1, 1 -> T2, 2 -> T3, 3 -> T4, 4 -> T5, 5 -> T6, 6 -> T7, 7 -> T8, 8 -> T9, 9 -> T10, 10 -> T11, 11 -> T12, 12 -> T13);
+impl_pg_copy_row_for_tuple!(14; 0 -> T1, 1 -> T2, 2 -> T3, 3 -> T4, 4 -> T5, 5 -> T6, 6 -> T7, 7 -> T8, 8 -> T9, 9 -> T10, 10 -> T11, 11 -> T12, 12 -> T13, 13 -> T14);
+impl_pg_copy_row_for_tuple!(15; 0 -> T1, 1 -> T2, 2 -> T3, 3 -> T4, 4 -> T5, 5 -> T6, 6 -> T7, 7 -> T8, 8 -> T9, 9 -> T10, 10 -> T11, 11 -> T12, 12 -> T13, 13 -> T14, 14 -> T15);
+impl_pg_copy_row_for_tuple!(16; 0 -> T1, 1 -> T2, 2 -> T3, 3 -> T4, 4 -> T5, 5 -> T6, 6 -> T7, 7 -> T8, 8 -> T9, 9 -> T10, 10 -> T11, 11 -> T12, 12 -> T13, 13 -> T14, 14 -> T15, 15 -> T16);
+
+#[cfg(test)]
+mod tests {
+    use super::PgCopyRow;
+    use crate::postgres::PgRawBuffer;
+
+    #[test]
+    fn it_encodes_a_binary_copy_row() {
+        let mut buf = PgRawBuffer::default();
+
+        (1_i32, None::<i64>, "ab").encode_row(&mut buf);
+
+        assert_eq!(
+            &buf[..],
+            b"\0\x03\0\0\0\x04\0\0\0\x01\xFF\xFF\xFF\xFF\0\0\0\x02ab"
+        );
+    }
+}
@@ -5,6 +5,7 @@ use futures_core::future::BoxFuture;
 use crate::connection::ConnectionSource;
 use crate::cursor::Cursor;
 use crate::executor::Execute;
+use crate::logger::QueryLogger;
 use crate::pool::Pool;
 use crate::postgres::protocol::{DataRow, Message, ReadyForQuery, RowDescription};
 use crate::postgres::row::Statement;
@@ -14,10 +15,26 @@ pub struct PgCursor<'c, 'q> {
     source: ConnectionSource<'c, PgConnection>,
     query: Option<(&'q str, Option<PgArguments>)>,
     statement: Arc<Statement>,
+    logger: Option<QueryLogger<'q>>,
 }
 
 impl crate::cursor::private::Sealed for PgCursor<'_, '_> {}
 
+impl<'c, 'q> PgCursor<'c, 'q> {
+    // Also used by the cursor of `Any` for a connection of a pool of `AnyConnection`
+    pub(crate) fn from_source<E>(source: ConnectionSource<'c, PgConnection>, query: E) -> Self
+    where
+        E: Execute<'q, Postgres>,
+    {
+        Self {
+            source,
+            statement: Arc::default(),
+            query: Some(query.into_parts()),
+            logger: None,
+        }
+    }
+}
+
 impl<'c, 'q> Cursor<'c, 'q> for PgCursor<'c, 'q> {
     type Database = Postgres;
 
@@ -27,11 +44,7 @@ impl<'c, 'q> Cursor<'c, 'q> for PgCursor<'c, 'q> {
         Self: Sized,
         E: Execute<'q, Postgres>,
     {
-        Self {
-            source: ConnectionSource::Pool(pool.clone()),
-            statement: Arc::default(),
-            query: Some(query.into_parts()),
-        }
+        Self::from_source(ConnectionSource::Pool(pool.clone()), query)
     }
 
     #[doc(hidden)]
@@ -40,15 +53,30 @@ impl<'c, 'q> Cursor<'c, 'q> for PgCursor<'c, 'q> {
         Self: Sized,
         E: Execute<'q, Postgres>,
     {
-        Self {
-            source: ConnectionSource::ConnectionRef(conn),
-            statement: Arc::default(),
-            query: Some(query.into_parts()),
-        }
+        Self::from_source(ConnectionSource::ConnectionRef(conn), query)
     }
 
     fn next(&mut self) -> BoxFuture<crate::Result<Option<PgRow<'_>>>> {
-        Box::pin(next(self))
+        Box::pin(async move {
+            if let Some((query, arguments)) = &self.query {
+                let conn = self.source.resolve().await?;
+
+                self.logger = Some(QueryLogger::new(
+                    query,
+                    arguments.as_ref(),
+                    &conn.log_settings,
+                ));
+            }
+
+            let span = self.logger.as_ref().map(QueryLogger::span);
+            let result = next(self).await;
+
+            if let (Err(error), Some(span)) = (&result, span) {
+                span.record_error(error);
+            }
+
+            result
+        })
     }
 }
 
@@ -63,10 +91,10 @@ async fn next<'a, 'c: 'a, 'q: 'a>(
     if let Some((query, arguments)) = cursor.query.take() {
         let statement = conn.run(query, arguments).await?;
 
-        // If there is a statement ID, this is a non-simple or prepared query
+        // If there is a statement, this is a non-simple or prepared query
         if let Some(statement) = statement {
             // A prepared statement will re-use the previous column map
-            cursor.statement = Arc::clone(&conn.cache_statement[&statement]);
+            cursor.statement = statement;
         }
 
         // A non-prepared query must be described each time
@@ -77,17 +105,23 @@ async fn next<'a, 'c: 'a, 'q: 'a>(
         match conn.stream.receive().await? {
             // Indicates that a phase of the extended query flow has completed
             // We as SQLx don't generally care as long as it is happening
-            Message::ParseComplete | Message::BindComplete => {}
+            Message::ParseComplete | Message::BindComplete | Message::CloseComplete => {}
 
             // Indicates that _a_ query has finished executing
             Message::CommandComplete => {}
 
+            // Indicates that a `SET` has changed a run-time parameter
+            Message::ParameterStatus => {}
+
             // Indicates that all queries have finished executing
             Message::ReadyForQuery => {
                 // TODO: How should we handle an ERROR status form ReadyForQuery
                 let _ready = ReadyForQuery::read(conn.stream.buffer())?;
 
                 conn.is_ready = true;
+
+                // all of the rows were returned
+                cursor.logger = None;
                 break;
             }
 
@@ -103,6 +137,10 @@ async fn next<'a, 'c: 'a, 'q: 'a>(
             Message::DataRow => {
                 let data = DataRow::read(conn.stream.buffer(), &mut conn.current_row_values)?;
 
+                if let Some(logger) = &mut cursor.logger {
+                    logger.add_rows(1);
+                }
+
                 return Ok(Some(PgRow {
                     statement: Arc::clone(&cursor.statement),
                     data,
@@ -1,7 +1,7 @@
 use std::error::Error as StdError;
 use std::fmt::{self, Display};
 
-use crate::error::DatabaseError;
+use crate::error::{DatabaseError, DatabaseErrorKind};
 use crate::postgres::protocol::Response;
 
 #[derive(Debug)]
@@ -16,6 +16,17 @@ impl DatabaseError for PgError {
         Some(&self.0.code)
     }
 
+    // https://www.postgresql.org/docs/current/errcodes-appendix.html
+    fn kind(&self) -> DatabaseErrorKind {
+        match &*self.0.code {
+            "23505" => DatabaseErrorKind::UniqueViolation,
+            "23503" => DatabaseErrorKind::ForeignKeyViolation,
+            "23502" => DatabaseErrorKind::NotNullViolation,
+            "23514" => DatabaseErrorKind::CheckViolation,
+            _ => DatabaseErrorKind::Other,
+        }
+    }
+
     fn details(&self) -> Option<&str> {
         self.0.detail.as_ref().map(|s| &**s)
     }
@@ -2,13 +2,16 @@ use std::collections::HashMap;
 use std::fmt::Write;
 use std::sync::Arc;
 
+use async_stream::try_stream;
 use futures_core::future::BoxFuture;
+use futures_core::stream::BoxStream;
 use futures_util::{stream, StreamExt, TryStreamExt};
 
 use crate::arguments::Arguments;
 use crate::cursor::Cursor;
 use crate::describe::{Column, Describe};
-use crate::executor::{Execute, Executor, RefExecutor};
+use crate::executor::{Execute, Executor, QueryResult, RefExecutor};
+use crate::logger::QueryLogger;
 use crate::postgres::protocol::{
     self, CommandComplete, Message, ParameterDescription, ReadyForQuery, RowDescription,
     StatementId, TypeFormat, TypeId,
@@ -33,24 +36,32 @@ impl PgConnection {
         query: &str,
         args: &PgArguments,
     ) -> crate::Result<StatementId> {
-        if let Some(&id) = self.cache_statement_id.get(query) {
+        if let Some(id) = self.cache_statement_id.get(query) {
             Ok(id)
         } else {
-            let id = StatementId(self.next_statement_id);
+            let id = if self.unnamed_statements {
+                StatementId(0)
+            } else {
+                self.next_statement_id += 1;
+                StatementId(self.next_statement_id - 1)
+            };
 
-            self.next_statement_id += 1;
+            let types = self.param_type_ids(args).await?;
 
-            // Build a list of type OIDs from the type info array provided by PgArguments
-            // This may need to query Postgres for an OID of a user-defined type
+            let mut closed = 0;
 
-            let mut types = Vec::with_capacity(args.types.len());
+            // Make room in the statement cache by closing the least recently used statement
+            if let Some(evicted) = self.cache_statement_id.remove_lru_if_full() {
+                self.stream.write(protocol::Close::Statement(evicted));
+                self.cache_statement.remove(&evicted);
+                closed += 1;
+            }
 
-            for ty in &args.types {
-                types.push(if let Some(oid) = ty.id {
-                    oid.0
-                } else {
-                    self.get_type_id_by_name(&*ty.name).await?
-                });
+            // An uncached statement is closed right after it is executed but postgres skips
+            // that if the execution failed; closing it twice is harmless
+            if let Some(uncached) = self.uncached_statement.take() {
+                self.stream.write(protocol::Close::Statement(uncached));
+                closed += 1;
             }
 
             self.stream.write(protocol::Parse {
@@ -68,6 +79,16 @@ impl PgConnection {
             self.stream.flush().await?;
             self.is_ready = false;
 
+            // wait for `CloseComplete`
+            for _ in 0..closed {
+                match self.stream.receive().await? {
+                    Message::CloseComplete => {}
+                    message => {
+                        return Err(protocol_err!("run: unexpected message: {:?}", message).into());
+                    }
+                }
+            }
+
             // wait for `ParseComplete`
             match self.stream.receive().await? {
                 Message::ParseComplete => {}
@@ -83,13 +104,32 @@ impl PgConnection {
             let statement = self.expect_row_desc(pd).await?;
 
             // cache statement ID and statement description
-            self.cache_statement_id.insert(query.into(), id);
+            if self.cache_statement_id.is_enabled() {
+                self.cache_statement_id.insert(query, id);
+            }
+
             self.cache_statement.insert(id, Arc::new(statement));
 
             Ok(id)
         }
     }
 
+    // Build a list of type OIDs from the type info array provided by PgArguments
+    // This may need to query Postgres for an OID of a user-defined type
+    pub(super) async fn param_type_ids(&mut self, args: &PgArguments) -> crate::Result<Vec<u32>> {
+        let mut types = Vec::with_capacity(args.types.len());
+
+        for ty in &args.types {
+            types.push(if let Some(oid) = ty.id {
+                oid.0
+            } else {
+                self.get_type_id_by_name(&*ty.name).await?
+            });
+        }
+
+        Ok(types)
+    }
+
     async fn parse_parameter_description(
         &mut self,
         pd: ParameterDescription,
@@ -205,7 +245,7 @@ impl PgConnection {
         self.stream.write(protocol::Bind {
             portal,
             statement,
-            formats: &[TypeFormat::Binary],
+            formats: args.formats(),
             values_len: args.types.len() as i16,
             values: &*args.buffer,
             result_formats: &[TypeFormat::Binary],
@@ -222,26 +262,56 @@ impl PgConnection {
         self.stream.write(protocol::Sync);
     }
 
-    async fn wait_until_ready(&mut self) -> crate::Result<()> {
+    pub(crate) async fn wait_until_ready(&mut self) -> crate::Result<()> {
         // depending on how the previous query finished we may need to continue
         // pulling messages from the stream until we receive a [ReadyForQuery] message
 
         // postgres sends the [ReadyForQuery] message when it's fully complete with processing
         // the previous query
 
+        if !self.is_ready || self.pending_queries > 0 || self.pending_rollback {
+            // a [PgCopyIn] that was dropped before it finished leaves a [CopyFail] in the
+            // write buffer; postgres will not become ready until it has received it
+            self.stream.flush().await?;
+        }
+
         if !self.is_ready {
-            loop {
-                if let Message::ReadyForQuery = self.stream.receive().await? {
-                    // we are now ready to go
-                    self.is_ready = true;
-                    break;
-                }
-            }
+            self.skip_until_ready().await?;
+
+            // we are now ready to go
+            self.is_ready = true;
+        }
+
+        // a pipeline that was dropped before all of its results were read, or queries that
+        // were queued by a drop
+        while self.pending_queries > 0 {
+            self.skip_until_ready().await?;
+            self.pending_queries -= 1;
+        }
+
+        // the [ReadyForQuery] for a dropped transaction's `ROLLBACK` comes after the one for
+        // the previous query
+        if self.pending_rollback {
+            self.skip_until_ready().await?;
+            self.pending_rollback = false;
         }
 
         Ok(())
     }
 
+    async fn skip_until_ready(&mut self) -> crate::Result<()> {
+        loop {
+            match self.stream.receive().await {
+                Ok(Message::ReadyForQuery) => return Ok(()),
+
+                // an error from the previous query, which is no longer being read
+                Ok(_) | Err(crate::Error::Database(_)) => {}
+
+                Err(error) => return Err(error),
+            }
+        }
+    }
+
     // Write out the query to the connection stream, ensure that we are synchronized at the
     // most recent [ReadyForQuery] and flush our buffer to postgres.
     //
@@ -252,24 +322,30 @@ impl PgConnection {
         &mut self,
         query: &str,
         arguments: Option<PgArguments>,
-    ) -> crate::Result<Option<StatementId>> {
+    ) -> crate::Result<Option<Arc<Statement>>> {
         let statement = if let Some(mut arguments) = arguments {
-            // Check the statement cache for a statement ID that matches the given query
-            // If it doesn't exist, we generate a new statement ID and write out [Parse] to the
-            // connection command buffer
-            let statement = self.write_prepare(query, &arguments).await?;
-
-            // Next, [Bind] attaches the arguments to the statement and creates a named portal
-            self.write_bind("", statement, &mut arguments).await?;
+            let statement = self.write_bind_portal(query, &mut arguments).await?;
 
-            // Next, [Execute] then executes the named portal
+            // Next, [Execute] then executes the unnamed portal
             self.write_execute("", 0);
 
+            // Without a statement cache, the statement is closed as soon as it is executed
+            let statement = if self.cache_statement_id.is_enabled() {
+                Arc::clone(&self.cache_statement[&statement])
+            } else if self.unnamed_statements {
+                self.cache_statement.remove(&statement).unwrap_or_default()
+            } else {
+                self.stream.write(protocol::Close::Statement(statement));
+                self.uncached_statement = Some(statement);
+
+                self.cache_statement.remove(&statement).unwrap_or_default()
+            };
+
             // Finally, [Sync] asks postgres to process the messages that we sent and respond with
             // a [ReadyForQuery] message when it's completely done. Theoretically, we could send
             // dozens of queries before a [Sync] and postgres can handle that. Execution on the server
-            // is still serial but it would reduce round-trips. Some kind of builder pattern that is
-            // termed batching might suit this.
+            // is still serial but it would reduce round-trips. [PgConnection::pipeline] does this,
+            // with a [Sync] after each of its queries.
             self.write_sync();
 
             Some(statement)
@@ -288,12 +364,62 @@ impl PgConnection {
         Ok(statement)
     }
 
+    // Prepare the query, unless its statement is in the cache, and write the [Bind] of its
+    // arguments to the unnamed portal
+    pub(super) async fn write_bind_portal(
+        &mut self,
+        query: &str,
+        arguments: &mut PgArguments,
+    ) -> crate::Result<StatementId> {
+        // Behind PgBouncer the description of the unnamed statement would be replaced by
+        // that of a query run to patch the type holes, so those are patched first
+        if self.unnamed_statements {
+            arguments.buffer.patch_type_holes(self).await?;
+        }
+
+        // Check the statement cache for a statement ID that matches the given query
+        // If it doesn't exist, we generate a new statement ID and write out [Parse] to the
+        // connection command buffer
+        let statement = self.write_prepare(query, arguments).await?;
+
+        // The unnamed statement may have been parsed on another connection of PgBouncer to
+        // Postgres, so it is parsed again in the same transaction as its execution
+        if self.unnamed_statements {
+            let types = self.param_type_ids(arguments).await?;
+
+            self.stream.write(protocol::Parse {
+                statement,
+                param_types: &types,
+                query,
+            });
+        }
+
+        // Next, [Bind] attaches the arguments to the statement and creates the unnamed portal
+        self.write_bind("", statement, arguments).await?;
+
+        Ok(statement)
+    }
+
     async fn do_describe<'e, 'q: 'e>(
         &'e mut self,
         query: &'q str,
     ) -> crate::Result<Describe<Postgres>> {
         let statement_id = self.write_prepare(query, &Default::default()).await?;
-        let statement = &self.cache_statement[&statement_id];
+
+        let statement = if self.cache_statement_id.is_enabled() {
+            Arc::clone(&self.cache_statement[&statement_id])
+        } else {
+            // postgres responds to this before the next query is run
+            self.wait_until_ready().await?;
+            self.stream.write(protocol::Close::Statement(statement_id));
+            self.write_sync();
+            self.is_ready = false;
+
+            self.cache_statement
+                .remove(&statement_id)
+                .unwrap_or_default()
+        };
+
         let columns = statement.columns.to_vec();
 
         Ok(Describe {
@@ -313,9 +439,9 @@ impl PgConnection {
         }
 
         // language=SQL
-        let (oid,): (u32,) = query_as(
+        let (oid, is_domain): (u32, bool) = query_as(
             "
-SELECT oid FROM pg_catalog.pg_type WHERE typname ILIKE $1
+SELECT oid, typtype = 'd' FROM pg_catalog.pg_type WHERE typname ILIKE $1
                 ",
         )
         .bind(name)
@@ -325,7 +451,12 @@ SELECT oid FROM pg_catalog.pg_type WHERE typname ILIKE $1
         let shared = SharedStr::from(name.to_owned());
 
         self.cache_type_oid.insert(shared.clone(), oid);
-        self.cache_type_name.insert(oid, shared.clone());
+
+        // values of a domain are described as values of its base type, which is not known here
+        if !is_domain {
+            self.cache_type_info
+                .insert(oid, PgTypeInfo::new(TypeId(oid), shared));
+        }
 
         Ok(oid)
     }
@@ -339,34 +470,70 @@ SELECT oid FROM pg_catalog.pg_type WHERE typname ILIKE $1
             return Ok(PgTypeInfo::new(TypeId(oid), name));
         }
 
-        if let Some(name) = self.cache_type_name.get(&oid) {
-            return Ok(PgTypeInfo::new(TypeId(oid), name));
+        if let Some(type_info) = self.cache_type_info.get(&oid) {
+            return Ok(type_info.clone());
         }
 
-        let name = if fetch_type_info {
+        if fetch_type_info {
+            self.fetch_type_info(oid).await
+        } else {
+            // NOTE: The name isn't too important for the decode lifecycle of TEXT
+            Ok(PgTypeInfo::new(TypeId(oid), ""))
+        }
+    }
+
+    // Look up the type with the given OID in the catalog
+    //
+    // A domain has the same format as its base type, and an array of a domain the format of
+    // the array of the base type, so they are resolved to those types to be decoded as them
+    fn fetch_type_info(&mut self, oid: u32) -> BoxFuture<'_, crate::Result<PgTypeInfo>> {
+        Box::pin(async move {
             // language=SQL
-            let (name,): (String,) = query_as(
+            let (name, base_oid, element_base_oid): (String, Option<u32>, Option<u32>) = query_as(
                 "
-    SELECT UPPER(typname) FROM pg_catalog.pg_type WHERE oid = $1
-                    ",
+SELECT UPPER(pg_type.typname),
+       CASE WHEN pg_type.typtype = 'd' THEN pg_type.typbasetype END,
+       element.typbasetype
+FROM pg_catalog.pg_type
+LEFT JOIN pg_catalog.pg_type element
+    ON pg_type.typcategory = 'A' AND element.oid = pg_type.typelem AND element.typtype = 'd'
+WHERE pg_type.oid = $1
+                ",
             )
             .bind(oid)
             .fetch_one(&mut *self)
             .await?;
 
-            // Emplace the new type name <-> OID association in the cache
-            let shared = SharedStr::from(name);
+            let type_info = if let Some(base_oid) = base_oid {
+                self.get_type_info_by_oid(base_oid, true).await?
+            } else if let Some(element_base_oid) = element_base_oid {
+                // the base type of the element may be a domain too
+                let element = self.get_type_info_by_oid(element_base_oid, true).await?;
 
-            self.cache_type_oid.insert(shared.clone(), oid);
-            self.cache_type_name.insert(oid, shared.clone());
+                // language=SQL
+                let (array_oid,): (u32,) = query_as(
+                    "
+SELECT typarray FROM pg_catalog.pg_type WHERE oid = $1
+                    ",
+                )
+                .bind(element.id.map_or(element_base_oid, |id| id.0))
+                .fetch_one(&mut *self)
+                .await?;
 
-            shared
-        } else {
-            // NOTE: The name isn't too important for the decode lifecycle of TEXT
-            SharedStr::Static("")
-        };
+                self.get_type_info_by_oid(array_oid, true).await?
+            } else {
+                let shared = SharedStr::from(name);
+
+                // Emplace the new type name <-> OID association in the cache
+                self.cache_type_oid.insert(shared.clone(), oid);
+
+                PgTypeInfo::new(TypeId(oid), shared)
+            };
+
+            self.cache_type_info.insert(oid, type_info.clone());
 
-        Ok(PgTypeInfo::new(TypeId(oid), name))
+            Ok(type_info)
+        })
     }
 
     async fn map_result_columns(
@@ -438,17 +605,23 @@ SELECT oid FROM pg_catalog.pg_type WHERE typname ILIKE $1
 
     // Poll messages from Postgres, counting the rows affected, until we finish the query
     // This must be called directly after a call to [PgConnection::execute]
-    async fn affected_rows(&mut self) -> crate::Result<u64> {
+    async fn affected_rows(&mut self) -> crate::Result<QueryResult> {
         let mut rows = 0;
 
         loop {
             match self.stream.receive().await? {
                 Message::ParseComplete
                 | Message::BindComplete
+                | Message::CloseComplete
                 | Message::NoData
                 | Message::EmptyQueryResponse
                 | Message::RowDescription => {}
 
+                Message::ParameterStatus => {
+                    // reported when a `SET` changes a run-time parameter
+                    // we do not track these values
+                }
+
                 Message::DataRow => {
                     // TODO: should we log a warning? this is almost
                     //       definitely a programmer error
@@ -474,7 +647,10 @@ SELECT oid FROM pg_catalog.pg_type WHERE typname ILIKE $1
             }
         }
 
-        Ok(rows)
+        Ok(QueryResult {
+            rows_affected: rows,
+            last_insert_id: None,
+        })
     }
 }
 
@@ -484,18 +660,77 @@ impl Executor for super::PgConnection {
     fn execute<'e, 'q: 'e, 'c: 'e, E: 'e>(
         &'c mut self,
         query: E,
-    ) -> BoxFuture<'e, crate::Result<u64>>
+    ) -> BoxFuture<'e, crate::Result<QueryResult>>
     where
         E: Execute<'q, Self::Database>,
     {
         Box::pin(async move {
             let (query, arguments) = query.into_parts();
+            let mut logger = QueryLogger::new(query, arguments.as_ref(), &self.log_settings);
+
+            let result = async {
+                self.run(query, arguments).await?;
+                self.affected_rows().await
+            }
+            .await;
 
-            self.run(query, arguments).await?;
-            self.affected_rows().await
+            match &result {
+                Ok(result) => logger.add_rows(result.rows_affected),
+                Err(error) => logger.record_error(error),
+            }
+
+            result
         })
     }
 
+    fn execute_many<'e, 'q: 'e, 'c: 'e>(
+        &'c mut self,
+        query: &'q str,
+    ) -> BoxStream<'e, crate::Result<QueryResult>> {
+        let mut logger = QueryLogger::new::<PgArguments>(query, None, &self.log_settings);
+        let span = logger.span();
+
+        let stream = try_stream! {
+            self.run(query, None).await?;
+
+            // The simple query protocol returns a [CommandComplete] for each statement
+            loop {
+                match self.stream.receive().await? {
+                    Message::CommandComplete => {
+                        let rows_affected = CommandComplete::read(self.stream.buffer())?.affected_rows;
+                        logger.add_rows(rows_affected);
+
+                        yield QueryResult {
+                            rows_affected,
+                            last_insert_id: None,
+                        };
+                    }
+
+                    Message::RowDescription
+                    | Message::DataRow
+                    | Message::EmptyQueryResponse
+                    | Message::ParameterStatus => {}
+
+                    Message::ReadyForQuery => {
+                        let _ready = ReadyForQuery::read(self.stream.buffer())?;
+
+                        self.is_ready = true;
+                        break;
+                    }
+
+                    message => {
+                        let error: crate::Error =
+                            protocol_err!("execute_many: unexpected message: {:?}", message).into();
+
+                        Err(error)?;
+                    }
+                }
+            }
+        };
+
+        Box::pin(stream.inspect_err(move |error| span.record_error(error)))
+    }
+
     fn fetch<'q, E>(&mut self, query: E) -> PgCursor<'_, 'q>
     where
         E: Execute<'q, Self::Database>,
@@ -503,7 +738,6 @@ impl Executor for super::PgConnection {
         PgCursor::from_connection(self, query)
     }
 
-    #[doc(hidden)]
     fn describe<'e, 'q, E: 'e>(
         &'e mut self,
         query: E,
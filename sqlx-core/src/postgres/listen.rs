@@ -4,10 +4,10 @@ use std::io;
 use async_stream::try_stream;
 use futures_channel::mpsc;
 use futures_core::future::BoxFuture;
-use futures_core::stream::Stream;
+use futures_core::stream::{BoxStream, Stream};
 
 use crate::describe::Describe;
-use crate::executor::{Execute, Executor, RefExecutor};
+use crate::executor::{Execute, Executor, QueryResult, RefExecutor};
 use crate::pool::{Pool, PoolConnection};
 use crate::postgres::protocol::{Message, NotificationResponse};
 use crate::postgres::{PgConnection, PgCursor, Postgres};
@@ -17,7 +17,10 @@ use crate::postgres::{PgConnection, PgCursor, Postgres};
 /// This listener will auto-reconnect. If the active
 /// connection being used ever dies, this listener will detect that event, create a
 /// new connection, will re-subscribe to all of the originally specified channels, and will resume
-/// operations as normal.
+/// operations as normal. Use [`try_recv`] to be told when this happens, as any
+/// notifications sent while the listener was disconnected are lost.
+///
+/// [`try_recv`]: #method.try_recv
 pub struct PgListener {
     pool: Pool<PgConnection>,
     connection: Option<PoolConnection<PgConnection>>,
@@ -30,6 +33,7 @@ pub struct PgListener {
 pub struct PgNotification<'c>(NotificationResponse<'c>);
 
 impl PgListener {
+    /// Connect a new listener to the database at the given URL.
     pub async fn new(url: &str) -> crate::Result<Self> {
         // Create a pool of 1 without timeouts (as they don't apply here)
         // We only use the pool to handle re-connections
@@ -43,6 +47,9 @@ impl PgListener {
         Self::from_pool(&pool).await
     }
 
+    /// Create a listener that takes its connections from an existing pool.
+    ///
+    /// The listener holds on to one connection of the pool for as long as it is alive.
     pub async fn from_pool(pool: &Pool<PgConnection>) -> crate::Result<Self> {
         // Pull out an initial connection
         let mut connection = pool.acquire().await?;
@@ -62,8 +69,10 @@ impl PgListener {
 
     /// Starts listening for notifications on a channel.
     pub async fn listen(&mut self, channel: &str) -> crate::Result<()> {
+        self.connect_if_needed().await?;
+
         self.connection()
-            .execute(&*format!("LISTEN {}", ident(channel)))
+            .execute(&*format!(r#"LISTEN "{}""#, ident(channel)))
             .await?;
 
         self.channels.push(channel.to_owned());
@@ -76,6 +85,8 @@ impl PgListener {
         &mut self,
         channels: impl IntoIterator<Item = &str>,
     ) -> crate::Result<()> {
+        self.connect_if_needed().await?;
+
         let beg = self.channels.len();
         self.channels.extend(channels.into_iter().map(|s| s.into()));
 
@@ -90,8 +101,10 @@ impl PgListener {
 
     /// Stops listening for notifications on a channel.
     pub async fn unlisten(&mut self, channel: &str) -> crate::Result<()> {
+        self.connect_if_needed().await?;
+
         self.connection()
-            .execute(&*format!("UNLISTEN {}", ident(channel)))
+            .execute(&*format!(r#"UNLISTEN "{}""#, ident(channel)))
             .await?;
 
         if let Some(pos) = self.channels.iter().position(|s| s == channel) {
@@ -103,6 +116,8 @@ impl PgListener {
 
     /// Stops listening for notifications on all channels.
     pub async fn unlisten_all(&mut self) -> crate::Result<()> {
+        self.connect_if_needed().await?;
+
         self.connection().execute("UNLISTEN *").await?;
 
         self.channels.clear();
@@ -132,24 +147,46 @@ impl PgListener {
     }
 
     /// Receives the next notification available from any of the subscribed channels.
+    ///
+    /// If the connection to Postgres is lost, this reconnects and keeps waiting for
+    /// notifications.
     pub async fn recv(&mut self) -> crate::Result<PgNotification<'_>> {
-        // Flush the buffer first, if anything
-        // This would only fill up if this listener is used as a connection
-        if let Ok(Some(notification)) = self.buffer_rx.try_next() {
+        if let Ok(notification) = self.buffer_rx.try_recv() {
             return Ok(PgNotification(notification));
         }
 
-        loop {
-            // Ensure we have an active connection to work with.
-            self.connect_if_needed().await?;
+        while !self.wait_for_notification().await? {}
+
+        self.read_notification()
+    }
+
+    /// Receives the next notification available from any of the subscribed channels.
+    ///
+    /// Returns `Ok(None)` if the connection to Postgres was lost. The next call reconnects
+    /// and listens on all of the channels again; any notifications sent in between are lost.
+    pub async fn try_recv(&mut self) -> crate::Result<Option<PgNotification<'_>>> {
+        if let Ok(notification) = self.buffer_rx.try_recv() {
+            return Ok(Some(PgNotification(notification)));
+        }
+
+        if !self.wait_for_notification().await? {
+            return Ok(None);
+        }
 
+        self.read_notification().map(Some)
+    }
+
+    // Reads messages until a notification is received, returning `false` if the
+    // connection was lost instead
+    async fn wait_for_notification(&mut self) -> crate::Result<bool> {
+        // Ensure we have an active connection to work with.
+        self.connect_if_needed().await?;
+
+        loop {
             match self.connection().stream.read().await {
-                // We've received an async notification, return it.
+                // We've received an async notification, it's read by the caller.
                 Ok(Message::NotificationResponse) => {
-                    let notification =
-                        NotificationResponse::read(self.connection().stream.buffer())?;
-
-                    return Ok(PgNotification(notification));
+                    return Ok(true);
                 }
 
                 // Mark the connection as ready for another query
@@ -160,11 +197,16 @@ impl PgListener {
                 // Ignore unexpected messages
                 Ok(_) => {}
 
-                // The connection is dead, ensure that it is dropped,
-                // update self state, and loop to try again.
-                Err(crate::Error::Io(err)) if err.kind() == io::ErrorKind::ConnectionAborted => {
+                // The connection is dead, ensure that it is dropped
+                // and update self state so we reconnect on the next call.
+                Err(crate::Error::Io(err))
+                    if err.kind() == io::ErrorKind::ConnectionAborted
+                        || err.kind() == io::ErrorKind::ConnectionReset =>
+                {
                     self.buffer_tx = self.connection().stream.notifications.take();
                     self.connection = None;
+
+                    return Ok(false);
                 }
 
                 // Forward other errors
@@ -175,6 +217,12 @@ impl PgListener {
         }
     }
 
+    fn read_notification(&mut self) -> crate::Result<PgNotification<'_>> {
+        let notification = NotificationResponse::read(self.connection().stream.buffer())?;
+
+        Ok(PgNotification(notification))
+    }
+
     /// Consume this listener, returning a `Stream` of notifications.
     pub fn into_stream(
         mut self,
@@ -194,13 +242,20 @@ impl Executor for PgListener {
     fn execute<'e, 'q: 'e, 'c: 'e, E: 'e>(
         &'c mut self,
         query: E,
-    ) -> BoxFuture<'e, crate::Result<u64>>
+    ) -> BoxFuture<'e, crate::Result<QueryResult>>
     where
         E: Execute<'q, Self::Database>,
     {
         self.connection().execute(query)
     }
 
+    fn execute_many<'e, 'q: 'e, 'c: 'e>(
+        &'c mut self,
+        query: &'q str,
+    ) -> BoxStream<'e, crate::Result<QueryResult>> {
+        self.connection().execute_many(query)
+    }
+
     fn fetch<'q, E>(&mut self, query: E) -> PgCursor<'_, 'q>
     where
         E: Execute<'q, Self::Database>,
@@ -208,7 +263,6 @@ impl Executor for PgListener {
         self.connection().fetch(query)
     }
 
-    #[doc(hidden)]
     fn describe<'e, 'q, E: 'e>(
         &'e mut self,
         query: E,
@@ -1,27 +1,40 @@
 //! **Postgres** database and connection types.
 
+pub use advisory_lock::{PgAdvisoryLock, PgAdvisoryLockGuard};
 pub use arguments::PgArguments;
 pub use buffer::PgRawBuffer;
 pub use connection::PgConnection;
+pub use copy::{PgBinaryCopyIn, PgCopyIn, PgCopyRow};
 pub use cursor::PgCursor;
 pub use database::Postgres;
 pub use error::PgError;
 pub use listen::{PgListener, PgNotification};
+pub use notice::PgNotice;
+pub use options::PgConnectOptions;
+pub use pipeline::PgPipeline;
+pub use protocol::Severity as PgSeverity;
 pub use row::PgRow;
 pub use type_info::PgTypeInfo;
 pub use value::{PgData, PgValue};
 
+mod advisory_lock;
 mod arguments;
 mod buffer;
+mod chunked;
 mod connection;
+mod copy;
 mod cursor;
 mod database;
 mod error;
 mod executor;
 mod listen;
+mod notice;
+mod options;
+mod pipeline;
 mod protocol;
 mod row;
 mod sasl;
+mod statement_cache;
 mod stream;
 mod tls;
 mod type_info;
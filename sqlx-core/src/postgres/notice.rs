@@ -0,0 +1,43 @@
+use std::fmt::{self, Display};
+
+use crate::postgres::protocol::{Response, Severity};
+
+/// A notice or warning sent by Postgres while running a query, received by the handler given to
+/// [`PgConnection::set_notice_handler`].
+///
+/// [`PgConnection::set_notice_handler`]: crate::postgres::PgConnection::set_notice_handler
+#[derive(Debug)]
+pub struct PgNotice(pub(super) Response);
+
+impl PgNotice {
+    /// The severity of the notice, e.g. `Notice` or `Warning`.
+    pub fn severity(&self) -> Severity {
+        self.0.severity
+    }
+
+    /// The SQLSTATE code of the notice.
+    pub fn code(&self) -> &str {
+        &self.0.code
+    }
+
+    /// The primary human-readable message of the notice.
+    pub fn message(&self) -> &str {
+        &self.0.message
+    }
+
+    /// An optional secondary message with more detail about the notice.
+    pub fn detail(&self) -> Option<&str> {
+        self.0.detail.as_deref()
+    }
+
+    /// An optional suggestion of what to do about the notice.
+    pub fn hint(&self) -> Option<&str> {
+        self.0.hint.as_deref()
+    }
+}
+
+impl Display for PgNotice {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(self.message())
+    }
+}
@@ -0,0 +1,135 @@
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::io::TcpOptions;
+
+/// Options for a new connection, set with [`PgConnection::connect_with`].
+///
+/// These are the options that can not be given in the URL, or that are more convenient to set
+/// here; an option that is set overrides the parameter of the URL for the same thing.
+///
+/// ```rust,ignore
+/// let options = PgConnectOptions::new()
+///     .ssl_client_cert("/path/to/client.crt")
+///     .ssl_client_key_from_pem(env::var("CLIENT_KEY")?);
+///
+/// let url = "postgres://user@localhost/db?sslmode=verify-full";
+/// let mut conn = PgConnection::connect_with(url, &options).await?;
+/// ```
+///
+/// [`PgConnection::connect_with`]: crate::postgres::PgConnection::connect_with
+#[derive(Debug, Clone, Default)]
+pub struct PgConnectOptions {
+    pub(crate) ssl_client_cert: Option<CertificateInput>,
+    pub(crate) ssl_client_key: Option<CertificateInput>,
+    pub(crate) tcp: TcpOptions,
+    pub(crate) search_path: Option<String>,
+}
+
+// A PEM file, by its path or its contents
+#[derive(Debug, Clone)]
+#[cfg_attr(not(any(feature = "tls", feature = "tls-rustls")), allow(dead_code))]
+pub(crate) enum CertificateInput {
+    File(PathBuf),
+    Pem(Vec<u8>),
+}
+
+impl PgConnectOptions {
+    /// The default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the path of the PEM file of the certificate the client presents to the server
+    /// during the TLS handshake, as for the `cert` authentication method.
+    ///
+    /// The same as the `sslcert` parameter of the URL. It needs a key, set with
+    /// [`ssl_client_key`](Self::ssl_client_key) or the `sslkey` parameter.
+    pub fn ssl_client_cert(mut self, path: impl AsRef<Path>) -> Self {
+        self.ssl_client_cert = Some(CertificateInput::File(path.as_ref().to_owned()));
+        self
+    }
+
+    /// Set the certificate the client presents to the server, as the contents of a PEM file.
+    pub fn ssl_client_cert_from_pem(mut self, pem: impl AsRef<[u8]>) -> Self {
+        self.ssl_client_cert = Some(CertificateInput::Pem(pem.as_ref().to_owned()));
+        self
+    }
+
+    /// Set the path of the PEM file of the private key of the client certificate.
+    ///
+    /// The same as the `sslkey` parameter of the URL. The key is a PKCS #8 key
+    /// (`BEGIN PRIVATE KEY`); with `rustls`, an RSA key (`BEGIN RSA PRIVATE KEY`) also works.
+    pub fn ssl_client_key(mut self, path: impl AsRef<Path>) -> Self {
+        self.ssl_client_key = Some(CertificateInput::File(path.as_ref().to_owned()));
+        self
+    }
+
+    /// Set the private key of the client certificate, as the contents of a PEM file.
+    pub fn ssl_client_key_from_pem(mut self, pem: impl AsRef<[u8]>) -> Self {
+        self.ssl_client_key = Some(CertificateInput::Pem(pem.as_ref().to_owned()));
+        self
+    }
+
+    /// Set the schemas in which the tables, types and functions whose names are not qualified
+    /// by a schema are looked up, in order, as the `search_path` of the connection.
+    ///
+    /// The same as the `search_path` parameter of the URL, e.g. `?search_path=tenant_42,public`,
+    /// which also sets it for the connections of a pool. It is set when the connection is
+    /// started, as the default of the session, so a `RESET search_path` or `DISCARD ALL`, or a
+    /// rolled back `SET search_path`, goes back to it rather than to the default of the server.
+    /// Each name is quoted, so it is case-sensitive; `$user` is the schema with the name of the
+    /// user.
+    ///
+    /// ```rust,ignore
+    /// let options = PgConnectOptions::new().search_path(&["tenant_42", "public"]);
+    /// ```
+    pub fn search_path<I>(mut self, schemas: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let schemas: Vec<_> = schemas
+            .into_iter()
+            .map(|schema| format!("\"{}\"", schema.as_ref().replace('"', "\"\"")))
+            .collect();
+
+        self.search_path = Some(schemas.join(", "));
+        self
+    }
+
+    /// Enable TCP keepalive, sending probes once the connection has been idle for `time`
+    /// (in whole seconds), so that a connection through a NAT or a firewall is not
+    /// dropped while it is idle and a dead peer is detected.
+    ///
+    /// The same as the `tcp_keepalive` parameter of the URL, e.g. `?tcp_keepalive=60`, which
+    /// also sets it for the connections of a pool.
+    ///
+    /// This is not set for a connection over a Unix domain socket. With `async-std` it is only
+    /// supported on Unix.
+    pub fn tcp_keepalive(mut self, time: Duration) -> Self {
+        self.tcp.keepalive = Some(time);
+        self
+    }
+
+    /// Set `TCP_NODELAY` on the socket, which disables Nagle's algorithm when `true`, so that
+    /// small messages are sent at once.
+    ///
+    /// The same as the `tcp_nodelay` parameter of the URL, e.g. `?tcp_nodelay=true`.
+    ///
+    /// This is not set for a connection over a Unix domain socket.
+    pub fn tcp_nodelay(mut self, nodelay: bool) -> Self {
+        self.tcp.nodelay = Some(nodelay);
+        self
+    }
+}
+
+#[cfg(any(feature = "tls", feature = "tls-rustls"))]
+impl CertificateInput {
+    pub(crate) async fn read(&self) -> crate::Result<Vec<u8>> {
+        match self {
+            CertificateInput::File(path) => Ok(crate::runtime::fs::read(path).await?),
+            CertificateInput::Pem(pem) => Ok(pem.clone()),
+        }
+    }
+}
@@ -0,0 +1,200 @@
+use crate::executor::{Execute, QueryResult};
+use crate::logger::QueryLogger;
+use crate::postgres::protocol::{self, CommandComplete, Message, StatementId, TypeFormat};
+use crate::postgres::{PgArguments, PgConnection, Postgres};
+
+impl PgConnection {
+    /// Run a batch of queries, sending all of them to Postgres before reading any of their
+    /// results, in a single round-trip instead of one for each query.
+    ///
+    /// The queries are added to the [`PgPipeline`] given to `build` and run in that order.
+    /// The result of each query, the number of rows it affected or its error, is at the same
+    /// index in the returned `Vec`; the rows returned by a query are discarded. The outer
+    /// `Result` is an error when the pipeline could not be run at all, e.g. when the connection
+    /// was lost.
+    ///
+    /// Each query runs in its own implicit transaction, as if it had been executed by itself,
+    /// so a query that fails does not stop the ones after it. In a [`Transaction`] however, a
+    /// failed query aborts the transaction and the queries after it fail too.
+    ///
+    /// ```rust,ignore
+    /// let results = conn
+    ///     .pipeline(|p| {
+    ///         p.query(sqlx::query("INSERT INTO users (name) VALUES ($1)").bind("Alice"));
+    ///         p.query(sqlx::query("INSERT INTO users (name) VALUES ($1)").bind("Bob"));
+    ///         p.query("DELETE FROM sessions");
+    ///     })
+    ///     .await?;
+    ///
+    /// for result in results {
+    ///     println!("{} rows affected", result?.rows_affected());
+    /// }
+    /// ```
+    ///
+    /// A query with arguments is parsed again each time it is run in a pipeline, as the unnamed
+    /// statement, instead of using the statement cache; a query without arguments is sent with
+    /// the simple query protocol, as for [`Executor::execute`].
+    ///
+    /// [`Transaction`]: crate::Transaction
+    /// [`Executor::execute`]: crate::Executor::execute
+    pub async fn pipeline<'q, F>(
+        &mut self,
+        build: F,
+    ) -> crate::Result<Vec<crate::Result<QueryResult>>>
+    where
+        F: FnOnce(&mut PgPipeline<'q>),
+    {
+        let mut pipeline = PgPipeline {
+            queries: Vec::new(),
+        };
+
+        build(&mut pipeline);
+
+        // Looking up the OID of a type by its name runs a query of its own, so the types are
+        // resolved before any of the pipeline is written
+        let mut queries = Vec::with_capacity(pipeline.queries.len());
+
+        for (query, mut arguments) in pipeline.queries {
+            let types = match &mut arguments {
+                Some(arguments) => {
+                    arguments.buffer.patch_type_holes(self).await?;
+
+                    self.param_type_ids(arguments).await?
+                }
+
+                None => Vec::new(),
+            };
+
+            queries.push((query, arguments, types));
+        }
+
+        self.wait_until_ready().await?;
+
+        let mut loggers = Vec::with_capacity(queries.len());
+
+        for (query, arguments, types) in &queries {
+            loggers.push(QueryLogger::new(
+                query,
+                arguments.as_ref(),
+                &self.log_settings,
+            ));
+
+            if let Some(arguments) = arguments {
+                self.stream.write(protocol::Parse {
+                    statement: StatementId(0),
+                    param_types: types,
+                    query,
+                });
+
+                self.stream.write(protocol::Bind {
+                    portal: "",
+                    statement: StatementId(0),
+                    formats: arguments.formats(),
+                    values_len: arguments.types.len() as i16,
+                    values: &arguments.buffer,
+                    result_formats: &[TypeFormat::Binary],
+                });
+
+                self.write_execute("", 0);
+
+                // A [Sync] after each query ends its implicit transaction, and the skipping
+                // of the messages after an error, at the end of that query
+                self.write_sync();
+            } else {
+                self.write_simple_query(query);
+            }
+        }
+
+        self.pending_queries += queries.len();
+        self.stream.flush().await?;
+
+        let mut results = Vec::with_capacity(queries.len());
+
+        for mut logger in loggers {
+            let result = self.pipeline_result().await?;
+
+            match &result {
+                Ok(result) => logger.add_rows(result.rows_affected),
+                Err(error) => logger.record_error(error),
+            }
+
+            results.push(result);
+        }
+
+        Ok(results)
+    }
+
+    // Poll the messages of the next query of a pipeline, until its [ReadyForQuery]
+    async fn pipeline_result(&mut self) -> crate::Result<crate::Result<QueryResult>> {
+        let mut rows = 0;
+        let mut error = None;
+
+        loop {
+            match self.stream.receive().await {
+                Ok(Message::ParseComplete)
+                | Ok(Message::BindComplete)
+                | Ok(Message::NoData)
+                | Ok(Message::EmptyQueryResponse)
+                | Ok(Message::RowDescription)
+                | Ok(Message::DataRow)
+                | Ok(Message::ParameterStatus) => {}
+
+                Ok(Message::CommandComplete) => {
+                    rows += CommandComplete::read(self.stream.buffer())?.affected_rows;
+                }
+
+                Ok(Message::ReadyForQuery) => {
+                    self.pending_queries -= 1;
+                    break;
+                }
+
+                // the error of this query; postgres skips the rest of it until the [Sync]
+                Err(crate::Error::Database(database)) => {
+                    error = Some(crate::Error::Database(database));
+                }
+
+                Ok(message) => {
+                    return Err(protocol_err!("pipeline: unexpected message: {:?}", message).into());
+                }
+
+                Err(error) => return Err(error),
+            }
+        }
+
+        Ok(match error {
+            Some(error) => Err(error),
+
+            None => Ok(QueryResult {
+                rows_affected: rows,
+                last_insert_id: None,
+            }),
+        })
+    }
+}
+
+/// The queries of a pipeline, which are added by the closure given to
+/// [`PgConnection::pipeline`].
+pub struct PgPipeline<'q> {
+    queries: Vec<(&'q str, Option<PgArguments>)>,
+}
+
+impl<'q> PgPipeline<'q> {
+    /// Add a query to the end of the pipeline.
+    pub fn query<E>(&mut self, query: E) -> &mut Self
+    where
+        E: Execute<'q, Postgres>,
+    {
+        self.queries.push(query.into_parts());
+        self
+    }
+
+    /// The number of queries in the pipeline.
+    pub fn len(&self) -> usize {
+        self.queries.len()
+    }
+
+    /// Whether no query has been added to the pipeline.
+    pub fn is_empty(&self) -> bool {
+        self.queries.is_empty()
+    }
+}
@@ -163,13 +163,38 @@ impl AuthenticationSaslContinue {
     }
 }
 
+#[derive(Debug)]
+pub(crate) struct AuthenticationSaslFinal {
+    // The signature of the server, to check that it knows the password too
+    pub(crate) verifier: Vec<u8>,
+}
+
+impl AuthenticationSaslFinal {
+    pub(crate) fn read(buf: &[u8]) -> crate::Result<Self> {
+        // server-final-message = (server-error / verifier) ["," extensions]
+        let verifier = buf
+            .split(|byte| *byte == b',')
+            .find_map(|s| s.strip_prefix(b"v="))
+            .ok_or(protocol_err!(
+                "SaslFinal response from postgres did not have a verifier"
+            ))?;
+
+        Ok(Self {
+            verifier: base64::decode(verifier).map_err(|_| {
+                protocol_err!("verifier value response from postgres was not base64 encoded")
+            })?,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::Authentication;
-    use crate::postgres::protocol::authentication::AuthenticationMd5;
+    use crate::postgres::protocol::authentication::{AuthenticationMd5, AuthenticationSaslFinal};
 
     const AUTH_OK: &[u8] = b"\0\0\0\0";
     const AUTH_MD5: &[u8] = b"\0\0\0\x05\x93\x189\x98";
+    const AUTH_SASL_FINAL: &[u8] = b"\0\0\0\x0cv=rmF9pqV8S7suAoZWja4dJRkFsKQ=";
 
     #[test]
     fn it_reads_auth_ok() {
@@ -186,4 +211,16 @@ mod tests {
         assert!(matches!(m, Authentication::Md5Password));
         assert_eq!(data.salt, [147, 24, 57, 152]);
     }
+
+    #[test]
+    fn it_reads_auth_sasl_final() {
+        let m = Authentication::read(AUTH_SASL_FINAL).unwrap();
+        let data = AuthenticationSaslFinal::read(&AUTH_SASL_FINAL[4..]).unwrap();
+
+        assert!(matches!(m, Authentication::SaslFinal));
+        assert_eq!(
+            data.verifier,
+            base64::decode("rmF9pqV8S7suAoZWja4dJRkFsKQ=").unwrap()
+        );
+    }
 }
@@ -0,0 +1,33 @@
+use crate::io::BufMut;
+use crate::postgres::protocol::Write;
+use byteorder::NetworkEndian;
+
+pub struct CopyData<'a>(pub &'a [u8]);
+
+impl Write for CopyData<'_> {
+    fn write(&self, buf: &mut Vec<u8>) {
+        buf.push(b'd');
+
+        // len + data
+        buf.put_i32::<NetworkEndian>((4 + self.0.len()) as i32);
+
+        buf.put_bytes(self.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CopyData, Write};
+
+    const COPY_DATA_ROW: &[u8] = b"d\0\0\0\x081\tA\n";
+
+    #[test]
+    fn it_writes_copy_data() {
+        let mut buf = Vec::new();
+        let m = CopyData(b"1\tA\n");
+
+        m.write(&mut buf);
+
+        assert_eq!(buf, COPY_DATA_ROW);
+    }
+}
@@ -0,0 +1,16 @@
+use crate::io::BufMut;
+use crate::postgres::protocol::Write;
+use byteorder::NetworkEndian;
+
+pub struct CopyFail<'a>(pub &'a str);
+
+impl Write for CopyFail<'_> {
+    fn write(&self, buf: &mut Vec<u8>) {
+        buf.push(b'f');
+
+        // len + message + nul
+        buf.put_i32::<NetworkEndian>((4 + self.0.len() + 1) as i32);
+
+        buf.put_str_nul(self.0);
+    }
+}
@@ -9,6 +9,10 @@ pub enum Message {
     BindComplete,
     CloseComplete,
     CommandComplete,
+    CopyData,
+    CopyDone,
+    CopyInResponse,
+    CopyOutResponse,
     DataRow,
     EmptyQueryResponse,
     ErrorResponse,
@@ -46,6 +50,10 @@ impl TryFrom<u8> for Message {
             b't' => Message::ParameterDescription,
             b'T' => Message::RowDescription,
             b'I' => Message::EmptyQueryResponse,
+            b'd' => Message::CopyData,
+            b'c' => Message::CopyDone,
+            b'G' => Message::CopyInResponse,
+            b'H' => Message::CopyOutResponse,
 
             id => {
                 return Err(protocol_err!("unknown message: {:?}", id as char).into());
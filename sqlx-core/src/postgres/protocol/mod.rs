@@ -10,13 +10,21 @@ pub use type_id::TypeId;
 
 // REQUESTS
 mod bind;
+mod close;
+mod copy_data;
+mod copy_done;
+mod copy_fail;
 mod describe;
 mod execute;
+mod flush;
 mod parse;
 mod password_message;
 mod query;
 mod sasl;
-#[cfg_attr(not(feature = "tls"), allow(unused_imports, dead_code))]
+#[cfg_attr(
+    not(any(feature = "tls", feature = "tls-rustls")),
+    allow(unused_imports, dead_code)
+)]
 mod ssl_request;
 mod startup_message;
 mod statement;
@@ -24,13 +32,21 @@ mod sync;
 mod terminate;
 
 pub(crate) use bind::Bind;
+pub(crate) use close::Close;
+pub(crate) use copy_data::CopyData;
+pub(crate) use copy_done::CopyDone;
+pub(crate) use copy_fail::CopyFail;
 pub(crate) use describe::Describe;
 pub(crate) use execute::Execute;
+pub(crate) use flush::Flush;
 pub(crate) use parse::Parse;
 pub(crate) use password_message::PasswordMessage;
 pub(crate) use query::Query;
 pub(crate) use sasl::{hi, SaslInitialResponse, SaslResponse};
-#[cfg_attr(not(feature = "tls"), allow(unused_imports, dead_code))]
+#[cfg_attr(
+    not(any(feature = "tls", feature = "tls-rustls")),
+    allow(unused_imports, dead_code)
+)]
 pub(crate) use ssl_request::SslRequest;
 pub(crate) use startup_message::StartupMessage;
 pub(crate) use statement::StatementId;
@@ -44,6 +60,7 @@ mod command_complete;
 mod data_row;
 mod notification_response;
 mod parameter_description;
+mod parameter_status;
 mod ready_for_query;
 mod response;
 mod row_description;
@@ -52,6 +69,7 @@ mod message;
 
 pub(crate) use authentication::{
     Authentication, AuthenticationMd5, AuthenticationSasl, AuthenticationSaslContinue,
+    AuthenticationSaslFinal,
 };
 pub(crate) use backend_key_data::BackendKeyData;
 pub(crate) use command_complete::CommandComplete;
@@ -59,8 +77,10 @@ pub(crate) use data_row::DataRow;
 pub(crate) use message::Message;
 pub(crate) use notification_response::NotificationResponse;
 pub(crate) use parameter_description::ParameterDescription;
+pub(crate) use parameter_status::ParameterStatus;
 pub(crate) use ready_for_query::ReadyForQuery;
-pub(crate) use response::{Response, Severity};
+pub(crate) use response::Response;
+pub use response::Severity;
 pub(crate) use row_description::{Field, RowDescription};
 
 pub(crate) trait Write {
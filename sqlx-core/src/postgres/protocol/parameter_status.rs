@@ -0,0 +1,32 @@
+use crate::io::Buf;
+
+/// A run-time parameter of the server, reported at startup and whenever it changes.
+#[derive(Debug)]
+pub struct ParameterStatus<'a> {
+    pub name: &'a str,
+    pub value: &'a str,
+}
+
+impl<'a> ParameterStatus<'a> {
+    pub(crate) fn read(mut buf: &'a [u8]) -> crate::Result<Self> {
+        let name = buf.get_str_nul()?;
+        let value = buf.get_str_nul()?;
+
+        Ok(Self { name, value })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ParameterStatus;
+
+    const PARAMETER_STATUS: &[u8] = b"server_version\x0015.4 (Debian 15.4-1.pgdg120+1)\x00";
+
+    #[test]
+    fn it_decodes_parameter_status() {
+        let message = ParameterStatus::read(PARAMETER_STATUS).unwrap();
+
+        assert_eq!(message.name, "server_version");
+        assert_eq!(message.value, "15.4 (Debian 15.4-1.pgdg120+1)");
+    }
+}
@@ -29,6 +29,8 @@ impl TypeId {
 
     pub(crate) const NUMERIC: TypeId = TypeId(1700);
 
+    pub(crate) const MONEY: TypeId = TypeId(790);
+
     pub(crate) const TEXT: TypeId = TypeId(25);
     pub(crate) const VARCHAR: TypeId = TypeId(1043);
     pub(crate) const BPCHAR: TypeId = TypeId(1042);
@@ -39,14 +41,36 @@ impl TypeId {
     pub(crate) const TIME: TypeId = TypeId(1083);
     pub(crate) const TIMESTAMP: TypeId = TypeId(1114);
     pub(crate) const TIMESTAMPTZ: TypeId = TypeId(1184);
+    pub(crate) const INTERVAL: TypeId = TypeId(1186);
 
     pub(crate) const BYTEA: TypeId = TypeId(17);
 
+    pub(crate) const BIT: TypeId = TypeId(1560);
+    pub(crate) const VARBIT: TypeId = TypeId(1562);
+
     pub(crate) const UUID: TypeId = TypeId(2950);
 
     pub(crate) const CIDR: TypeId = TypeId(650);
     pub(crate) const INET: TypeId = TypeId(869);
 
+    pub(crate) const MACADDR: TypeId = TypeId(829);
+    pub(crate) const MACADDR8: TypeId = TypeId(774);
+
+    pub(crate) const POINT: TypeId = TypeId(600);
+    pub(crate) const PATH: TypeId = TypeId(602);
+    pub(crate) const BOX: TypeId = TypeId(603);
+    pub(crate) const POLYGON: TypeId = TypeId(604);
+
+    pub(crate) const INT4RANGE: TypeId = TypeId(3904);
+    pub(crate) const INT8RANGE: TypeId = TypeId(3926);
+    pub(crate) const NUMRANGE: TypeId = TypeId(3906);
+    pub(crate) const TSRANGE: TypeId = TypeId(3908);
+    pub(crate) const TSTZRANGE: TypeId = TypeId(3910);
+    pub(crate) const DATERANGE: TypeId = TypeId(3912);
+
+    pub(crate) const TSVECTOR: TypeId = TypeId(3614);
+    pub(crate) const TSQUERY: TypeId = TypeId(3615);
+
     // Arrays
 
     pub(crate) const ARRAY_BOOL: TypeId = TypeId(1000);
@@ -69,22 +93,39 @@ impl TypeId {
 
     pub(crate) const ARRAY_NUMERIC: TypeId = TypeId(1231);
 
+    pub(crate) const ARRAY_MONEY: TypeId = TypeId(791);
+
     pub(crate) const ARRAY_DATE: TypeId = TypeId(1182);
     pub(crate) const ARRAY_TIME: TypeId = TypeId(1183);
     pub(crate) const ARRAY_TIMESTAMP: TypeId = TypeId(1115);
     pub(crate) const ARRAY_TIMESTAMPTZ: TypeId = TypeId(1185);
+    pub(crate) const ARRAY_INTERVAL: TypeId = TypeId(1187);
 
     pub(crate) const ARRAY_BYTEA: TypeId = TypeId(1001);
 
+    pub(crate) const ARRAY_BIT: TypeId = TypeId(1561);
+    pub(crate) const ARRAY_VARBIT: TypeId = TypeId(1563);
+
     pub(crate) const ARRAY_UUID: TypeId = TypeId(2951);
 
     pub(crate) const ARRAY_CIDR: TypeId = TypeId(651);
     pub(crate) const ARRAY_INET: TypeId = TypeId(1041);
 
+    pub(crate) const ARRAY_MACADDR: TypeId = TypeId(1040);
+    pub(crate) const ARRAY_MACADDR8: TypeId = TypeId(775);
+
+    pub(crate) const ARRAY_POINT: TypeId = TypeId(1017);
+
+    pub(crate) const ARRAY_TSVECTOR: TypeId = TypeId(3643);
+    pub(crate) const ARRAY_TSQUERY: TypeId = TypeId(3645);
+
     // JSON
 
     pub(crate) const JSON: TypeId = TypeId(114);
     pub(crate) const JSONB: TypeId = TypeId(3802);
+    pub(crate) const JSONPATH: TypeId = TypeId(4072);
+
+    pub(crate) const ARRAY_JSONPATH: TypeId = TypeId(4073);
 
     // Records
 
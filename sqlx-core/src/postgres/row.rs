@@ -5,7 +5,7 @@ use crate::postgres::protocol::{DataRow, TypeFormat};
 use crate::postgres::type_info::SharedStr;
 use crate::postgres::value::PgValue;
 use crate::postgres::{PgTypeInfo, Postgres};
-use crate::row::{ColumnIndex, Row};
+use crate::row::{self, ColumnIndex, Row};
 
 // A statement has 0 or more columns being returned from the database
 // For Postgres, each column has an OID and a format (binary or text)
@@ -52,7 +52,6 @@ impl<'c> Row<'c> for PgRow<'c> {
         self.data.len()
     }
 
-    #[doc(hidden)]
     fn try_get_raw<I>(&self, index: I) -> crate::Result<PgValue<'c>>
     where
         I: ColumnIndex<'c, Self>,
@@ -68,6 +67,20 @@ impl<'c> Row<'c> for PgRow<'c> {
 
         Ok(value)
     }
+
+    fn try_column<I>(&self, index: I) -> crate::Result<row::Column<'_, Postgres>>
+    where
+        I: ColumnIndex<'c, Self>,
+    {
+        let ordinal = index.index(self)?;
+        let column = &self.statement.columns[ordinal];
+
+        Ok(row::Column {
+            name: column.name.as_deref(),
+            ordinal,
+            type_info: Some(column.type_info.clone()),
+        })
+    }
 }
 
 impl<'c> ColumnIndex<'c, PgRow<'c>> for usize {
@@ -87,7 +100,15 @@ impl<'c> ColumnIndex<'c, PgRow<'c>> for str {
         row.statement
             .names
             .get(self)
-            .ok_or_else(|| crate::Error::ColumnNotFound((*self).into()))
+            .ok_or_else(|| {
+                crate::Error::column_not_found(
+                    self,
+                    row.statement
+                        .columns
+                        .iter()
+                        .filter_map(|column| column.name.as_deref()),
+                )
+            })
             .map(|&index| index as usize)
     }
 }
@@ -1,13 +1,13 @@
 use hmac::{Hmac, Mac};
 use rand::Rng;
-use sha2::{Digest, Sha256};
+use sha2::{Digest, Sha256, Sha384, Sha512};
 
 use crate::postgres::protocol::{
-    hi, Authentication, AuthenticationSaslContinue, Message, SaslInitialResponse, SaslResponse,
+    hi, Authentication, AuthenticationSaslContinue, AuthenticationSaslFinal, Message,
+    SaslInitialResponse, SaslResponse,
 };
 use crate::postgres::stream::PgStream;
 
-static GS2_HEADER: &'static str = "n,,";
 static CHANNEL_ATTR: &'static str = "c";
 static USERNAME_ATTR: &'static str = "n";
 static CLIENT_PROOF_ATTR: &'static str = "p";
@@ -40,15 +40,99 @@ fn nonce() -> String {
     format!("{}={}", NONCE_ATTR, nonce)
 }
 
+// The `tls-server-end-point` channel binding data of RFC 5929: the hash of the DER encoded
+// certificate of the server, with the hash function of its signature algorithm, or SHA-256
+// if that is MD5 or SHA-1
+fn tls_server_end_point(cert: &[u8]) -> Vec<u8> {
+    // sha384WithRSAEncryption, ecdsa-with-SHA384
+    const SHA_384: &[&[u8]] = &[
+        b"\x2a\x86\x48\x86\xf7\x0d\x01\x01\x0c",
+        b"\x2a\x86\x48\xce\x3d\x04\x03\x03",
+    ];
+
+    // sha512WithRSAEncryption, ecdsa-with-SHA512
+    const SHA_512: &[&[u8]] = &[
+        b"\x2a\x86\x48\x86\xf7\x0d\x01\x01\x0d",
+        b"\x2a\x86\x48\xce\x3d\x04\x03\x04",
+    ];
+
+    match signature_algorithm(cert) {
+        Some(oid) if SHA_384.contains(&oid) => Sha384::digest(cert).to_vec(),
+        Some(oid) if SHA_512.contains(&oid) => Sha512::digest(cert).to_vec(),
+        _ => Sha256::digest(cert).to_vec(),
+    }
+}
+
+// The object identifier of the signature algorithm of a DER encoded certificate
+// Certificate ::= SEQUENCE { tbsCertificate, signatureAlgorithm, signatureValue }
+fn signature_algorithm(cert: &[u8]) -> Option<&[u8]> {
+    let (cert, _) = der_element(cert)?;
+
+    // skip tbsCertificate
+    let (_, rest) = der_element(cert)?;
+
+    // AlgorithmIdentifier ::= SEQUENCE { algorithm OBJECT IDENTIFIER, parameters ANY }
+    let (algorithm, _) = der_element(rest)?;
+    let (oid, _) = der_element(algorithm)?;
+
+    Some(oid)
+}
+
+// The contents of the DER encoded element at the start of the buffer and the rest of it
+fn der_element(buf: &[u8]) -> Option<(&[u8], &[u8])> {
+    let (&len, mut buf) = buf.get(1..)?.split_first()?;
+
+    let len = if len & 0x80 == 0 {
+        len as usize
+    } else {
+        // long form: the length is in the next `len & 0x7f` bytes
+        let n = (len & 0x7f) as usize;
+        if n == 0 || n > 4 || buf.len() < n {
+            return None;
+        }
+
+        let (bytes, rest) = buf.split_at(n);
+        buf = rest;
+
+        bytes.iter().fold(0, |len, &b| (len << 8) | b as usize)
+    };
+
+    if buf.len() < len {
+        return None;
+    }
+
+    Some(buf.split_at(len))
+}
+
 // Performs authenticiton using Simple Authentication Security Layer (SASL) which is what
 // Postgres uses
 pub(super) async fn authenticate<T: AsRef<str>>(
     stream: &mut PgStream,
     username: T,
     password: T,
+    plus: bool,
 ) -> crate::Result<()> {
-    // channel-binding = "c=" base64
-    let channel_binding = format!("{}={}", CHANNEL_ATTR, base64::encode(GS2_HEADER));
+    // With `SCRAM-SHA-256-PLUS`, which the server offers over TLS, the authentication is bound
+    // to the TLS connection by the hash of the certificate of the server
+    let end_point = match stream.stream.peer_certificate()? {
+        Some(cert) if plus => Some(tls_server_end_point(&cert)),
+        _ => None,
+    };
+
+    // gs2-header = gs2-cbind-flag "," [ authzid ] ","
+    let gs2_header = match end_point {
+        Some(_) => "p=tls-server-end-point,,",
+
+        // the client supports channel binding but the server does not
+        None if stream.stream.is_tls() => "y,,",
+
+        None => "n,,",
+    };
+
+    // channel-binding = "c=" base64(gs2-header [cbind-data])
+    let mut cbind_input = gs2_header.as_bytes().to_vec();
+    cbind_input.extend_from_slice(end_point.as_deref().unwrap_or_default());
+    let channel_binding = format!("{}={}", CHANNEL_ATTR, base64::encode(&cbind_input));
     // "n=" saslname ;; Usernames are prepared using SASLprep.
     let username = format!("{}={}", USERNAME_ATTR, username.as_ref());
     // nonce = "r=" c-nonce [s-nonce] ;; Second part provided by server.
@@ -58,11 +142,14 @@ pub(super) async fn authenticate<T: AsRef<str>>(
     // client-first-message-bare = [reserved-mext ","] username "," nonce ["," extensions]
     let client_first_message = format!(
         "{gs2_header}{client_first_message_bare}",
-        gs2_header = GS2_HEADER,
+        gs2_header = gs2_header,
         client_first_message_bare = client_first_message_bare
     );
 
-    stream.write(SaslInitialResponse(&client_first_message));
+    stream.write(SaslInitialResponse {
+        response: &client_first_message,
+        plus: end_point.is_some(),
+    });
     stream.flush().await?;
 
     let server_first_message = stream.receive().await?;
@@ -127,7 +214,7 @@ pub(super) async fn authenticate<T: AsRef<str>>(
             let mut mac =
                 Hmac::<Sha256>::new_varkey(&server_key).expect("HMAC can take key of any size");
             mac.input(&auth_message.as_bytes());
-            let _server_signature = mac.result().code();
+            let server_signature = mac.result().code();
 
             // client-final-message = client-final-message-without-proof "," proof
             let client_final_message = format!(
@@ -140,10 +227,36 @@ pub(super) async fn authenticate<T: AsRef<str>>(
             stream.write(SaslResponse(&client_final_message));
             stream.flush().await?;
 
-            let _server_final_response = stream.receive().await?;
-            // todo: assert that this was SaslFinal?
+            // The server proves that it knows the password too with its signature; an error
+            // of the server, for a wrong password, is returned by `receive`
+            let server_final_message = stream.receive().await?;
+
+            match server_final_message {
+                Message::Authentication => match Authentication::read(stream.buffer())? {
+                    Authentication::SaslFinal => {
+                        let sasl = AuthenticationSaslFinal::read(&stream.buffer()[4..])?;
+
+                        if sasl.verifier[..] != server_signature[..] {
+                            return Err(protocol_err!(
+                                "the signature of the server in SaslFinal is not valid"
+                            )
+                            .into());
+                        }
+
+                        Ok(())
+                    }
+
+                    auth => Err(protocol_err!(
+                        "Expected Authentication::SaslFinal, but received {:?}",
+                        auth
+                    ))?,
+                },
 
-            Ok(())
+                message => Err(protocol_err!(
+                    "Expected Message::Authentication, but received {:?}",
+                    message
+                ))?,
+            }
         } else {
             Err(protocol_err!(
                 "Expected Authentication::SaslContinue, but received {:?}",
@@ -157,3 +270,30 @@ pub(super) async fn authenticate<T: AsRef<str>>(
         ))?
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::tls_server_end_point;
+
+    // The DER encoded certificate of a PEM file
+    fn der(pem: &str) -> Vec<u8> {
+        let base64: String = pem
+            .lines()
+            .filter(|line| !line.starts_with("-----"))
+            .collect();
+
+        base64::decode(&base64).unwrap()
+    }
+
+    #[test]
+    fn it_hashes_the_certificate_for_tls_server_end_point() {
+        // `sha256WithRSAEncryption`, so hashed with SHA-256
+        let cert = der(include_str!("../../../tests/certs/server.crt"));
+
+        assert_eq!(
+            tls_server_end_point(&cert),
+            &b"\x63\x90\x12\x7e\x0a\x14\x48\xbd\x67\x0f\x93\x30\x34\x4c\x94\x48\
+               \x12\xa2\xdf\x7c\x84\x75\x70\xa7\xb5\x9c\x37\xab\x31\x40\xfd\xd7"[..]
+        );
+    }
+}
@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+
+use crate::postgres::protocol::StatementId;
+
+// A cache of query -> prepared statement ID
+//
+// If the cache has a capacity, the least recently used statement is removed when a new
+// statement would go over it; the caller is responsible for closing that statement
+// on the server.
+pub(crate) struct StatementCache {
+    capacity: Option<usize>,
+    statements: HashMap<Box<str>, (StatementId, u64)>,
+
+    // incremented on every use of a statement to track which was used least recently
+    tick: u64,
+}
+
+impl StatementCache {
+    pub(crate) fn new(capacity: Option<usize>) -> Self {
+        Self {
+            capacity,
+            statements: HashMap::with_capacity(capacity.unwrap_or(10).min(10)),
+            tick: 0,
+        }
+    }
+
+    // A capacity of 0 disables the cache
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.capacity != Some(0)
+    }
+
+    pub(crate) fn get(&mut self, query: &str) -> Option<StatementId> {
+        self.tick += 1;
+
+        let tick = self.tick;
+
+        self.statements.get_mut(query).map(|(id, used)| {
+            *used = tick;
+            *id
+        })
+    }
+
+    pub(crate) fn insert(&mut self, query: &str, id: StatementId) {
+        self.tick += 1;
+        self.statements.insert(query.into(), (id, self.tick));
+    }
+
+    // Removes the least recently used statement if there is no room for another one
+    pub(crate) fn remove_lru_if_full(&mut self) -> Option<StatementId> {
+        let capacity = self.capacity?;
+
+        if capacity == 0 || self.statements.len() < capacity {
+            return None;
+        }
+
+        let query = self
+            .statements
+            .iter()
+            .min_by_key(|(_, (_, used))| *used)
+            .map(|(query, _)| query.clone())?;
+
+        self.statements.remove(&query).map(|(id, _)| id)
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.statements.len()
+    }
+}
+
+#[test]
+fn test_statement_cache_unbounded() {
+    let mut cache = StatementCache::new(None);
+
+    for i in 0..100 {
+        cache.insert(&format!("SELECT {}", i), StatementId(i));
+
+        assert!(cache.remove_lru_if_full().is_none());
+    }
+
+    assert_eq!(cache.len(), 100);
+    assert!(cache.get("SELECT 50") == Some(StatementId(50)));
+}
+
+#[test]
+fn test_statement_cache_removes_lru() {
+    let mut cache = StatementCache::new(Some(2));
+
+    cache.insert("SELECT 1", StatementId(1));
+
+    assert!(cache.remove_lru_if_full().is_none());
+
+    cache.insert("SELECT 2", StatementId(2));
+
+    // using the first statement makes the second the least recently used
+    assert!(cache.get("SELECT 1") == Some(StatementId(1)));
+    assert!(cache.remove_lru_if_full() == Some(StatementId(2)));
+
+    cache.insert("SELECT 3", StatementId(3));
+
+    assert_eq!(cache.len(), 2);
+    assert!(cache.get("SELECT 2").is_none());
+    assert!(cache.remove_lru_if_full() == Some(StatementId(1)));
+}
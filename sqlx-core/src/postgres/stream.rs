@@ -1,12 +1,13 @@
 use std::convert::TryInto;
 use std::net::Shutdown;
+use std::path::PathBuf;
 
 use byteorder::NetworkEndian;
 use futures_channel::mpsc::UnboundedSender;
 
-use crate::io::{Buf, BufStream, MaybeTlsStream};
+use crate::io::{Buf, BufStream, MaybeTlsStream, TcpOptions};
 use crate::postgres::protocol::{Message, NotificationResponse, Response, Write};
-use crate::postgres::PgError;
+use crate::postgres::{PgError, PgNotice};
 
 use crate::url::Url;
 use futures_util::SinkExt;
@@ -14,6 +15,7 @@ use futures_util::SinkExt;
 pub struct PgStream {
     pub(super) stream: BufStream<MaybeTlsStream>,
     pub(super) notifications: Option<UnboundedSender<NotificationResponse<'static>>>,
+    pub(super) notice_handler: Option<Box<dyn Fn(PgNotice) + Send + Sync>>,
 
     // Most recently received message
     // Is referenced by our buffered stream
@@ -22,11 +24,24 @@ pub struct PgStream {
 }
 
 impl PgStream {
-    pub(super) async fn new(url: &Url) -> crate::Result<Self> {
-        let stream = MaybeTlsStream::connect(&url, 5432).await?;
+    pub(super) async fn new(url: &Url, tcp: &TcpOptions) -> crate::Result<Self> {
+        let stream = match socket_path(url)? {
+            #[cfg(unix)]
+            Some(path) => MaybeTlsStream::connect_unix(&path).await?,
+
+            #[cfg(not(unix))]
+            Some(_) => {
+                return Err(
+                    protocol_err!("Unix domain sockets are not supported on this platform").into(),
+                )
+            }
+
+            None => MaybeTlsStream::connect(&url, 5432, tcp).await?,
+        };
 
         Ok(Self {
             notifications: None,
+            notice_handler: None,
             stream: BufStream::new(stream),
             message: (Message::ReadyForQuery, 0),
         })
@@ -60,6 +75,10 @@ impl PgStream {
             // If there is any data in our read buffer we need to make sure we flush that
             // so reading will return the *next* message
             self.stream.consume(self.message.1 as usize);
+
+            // a read that is dropped while waiting for the next message must not consume this
+            // one again
+            self.message.1 = 0;
         }
 
         let mut header = self.stream.peek(4 + 1).await?;
@@ -90,8 +109,10 @@ impl PgStream {
                         return Err(crate::Error::Database(Box::new(PgError(response))));
                     }
 
-                    // TODO: Provide some way of receiving these non-critical
-                    //       notices from postgres
+                    if let Some(handler) = &self.notice_handler {
+                        handler(PgNotice(response));
+                    }
+
                     continue;
                 }
 
@@ -120,3 +141,27 @@ impl PgStream {
         &self.stream.buffer()[..(self.message.1 as usize)]
     }
 }
+
+// The path of the Unix domain socket of the server, if the host is a directory rather than
+// a host name; as for libpq, the socket in the directory is named after the port,
+// e.g. `/var/run/postgresql/.s.PGSQL.5432`
+pub(super) fn socket_path(url: &Url) -> crate::Result<Option<PathBuf>> {
+    let dir = match url.param("host") {
+        Some(host) if host.starts_with('/') => PathBuf::from(&*host),
+
+        Some(host) => {
+            return Err(protocol_err!(
+                "the `host` parameter must be the directory of a Unix domain socket, not {:?}",
+                host
+            )
+            .into())
+        }
+
+        None => match url.host_path() {
+            Some(dir) => dir,
+            None => return Ok(None),
+        },
+    };
+
+    Ok(Some(dir.join(format!(".s.PGSQL.{}", url.port(5432)))))
+}
@@ -1,74 +1,123 @@
 use crate::postgres::stream::PgStream;
+use crate::postgres::PgConnectOptions;
 use crate::url::Url;
 
-#[cfg_attr(not(feature = "tls"), allow(unused_variables))]
-pub(crate) async fn request_if_needed(stream: &mut PgStream, url: &Url) -> crate::Result<()> {
-    // https://www.postgresql.org/docs/12/libpq-ssl.html#LIBPQ-SSL-SSLMODE-STATEMENTS
-    match url.param("sslmode").as_deref() {
-        Some("disable") | Some("allow") => {
-            // Do nothing
-        }
+/// The `sslmode` of a connection, which decides if and how it is upgraded to TLS.
+///
+/// With TLS compiled in, a connection is `prefer` by default and `disable` otherwise.
+// https://www.postgresql.org/docs/12/libpq-ssl.html#LIBPQ-SSL-SSLMODE-STATEMENTS
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SslMode {
+    // Only without TLS
+    Disable,
+    // Without TLS first, and then with it (unverified) if the server refused the connection
+    Allow,
+    // With TLS (unverified) first, and then without it if it could not be negotiated
+    Prefer,
+    // Only with TLS, without verifying the certificate
+    Require,
+    // Only with TLS, with a certificate signed by a trusted authority
+    VerifyCa,
+    // Only with TLS, with a certificate signed by a trusted authority for the host
+    VerifyFull,
+}
 
-        #[cfg(feature = "tls")]
-        Some("prefer") | None => {
-            // We default to [prefer] if TLS is compiled in
-            if !try_upgrade(stream, url, true, true).await? {
-                // TLS upgrade failed; fall back to a normal connection
+impl SslMode {
+    pub(crate) fn from_url(url: &Url) -> crate::Result<Self> {
+        let mode = match url.param("sslmode").as_deref() {
+            Some("disable") => SslMode::Disable,
+            Some("allow") => SslMode::Allow,
+            Some("prefer") => SslMode::Prefer,
+            Some("require") => SslMode::Require,
+            Some("verify-ca") => SslMode::VerifyCa,
+            Some("verify-full") => SslMode::VerifyFull,
+
+            None if cfg!(any(feature = "tls", feature = "tls-rustls")) => SslMode::Prefer,
+            None => SslMode::Disable,
+
+            Some(mode) => {
+                return Err(tls_err!("unknown `sslmode` value: {:?}", mode).into());
             }
-        }
+        };
 
-        #[cfg(not(feature = "tls"))]
-        None => {
-            // The user neither explicitly enabled TLS in the connection string
-            // nor did they turn the `tls` feature on
+        match mode {
+            SslMode::Prefer | SslMode::Require | SslMode::VerifyCa | SslMode::VerifyFull
+                if !cfg!(any(feature = "tls", feature = "tls-rustls")) =>
+            {
+                Err(tls_err!(
+                    "sslmode {:?} unsupported; SQLx was compiled without TLS support",
+                    url.param("sslmode").unwrap_or_default()
+                )
+                .into())
+            }
+
+            mode => Ok(mode),
+        }
+    }
+}
 
+// Upgrades the connection to TLS if the mode asks for it first; the fallbacks of `allow` and
+// `prefer` connect again, so they are up to the caller
+#[cfg(any(feature = "tls", feature = "tls-rustls"))]
+pub(crate) async fn request_if_needed(
+    stream: &mut PgStream,
+    url: &Url,
+    mode: SslMode,
+    options: &PgConnectOptions,
+) -> crate::Result<()> {
+    match mode {
+        SslMode::Disable | SslMode::Allow => {
             // Do nothing
         }
 
-        #[cfg(feature = "tls")]
-        Some(mode @ "require") | Some(mode @ "verify-ca") | Some(mode @ "verify-full") => {
+        SslMode::Prefer => {
+            if !try_upgrade(stream, url, options, true, true).await? {
+                // The server does not support TLS; keep the normal connection
+            }
+        }
+
+        SslMode::Require | SslMode::VerifyCa | SslMode::VerifyFull => {
             if !try_upgrade(
                 stream,
                 url,
+                options,
                 // false for both verify-ca and verify-full
-                mode == "require",
+                mode == SslMode::Require,
                 // false for only verify-full
-                mode != "verify-full",
+                mode != SslMode::VerifyFull,
             )
             .await?
             {
                 return Err(tls_err!("server does not support TLS").into());
             }
         }
-
-        #[cfg(not(feature = "tls"))]
-        Some(mode @ "prefer")
-        | Some(mode @ "require")
-        | Some(mode @ "verify-ca")
-        | Some(mode @ "verify-full") => {
-            return Err(tls_err!(
-                "sslmode {:?} unsupported; SQLx was compiled without `tls` feature",
-                mode
-            )
-            .into());
-        }
-
-        Some(mode) => {
-            return Err(tls_err!("unknown `sslmode` value: {:?}", mode).into());
-        }
     }
 
     Ok(())
 }
 
-#[cfg(feature = "tls")]
+// Without TLS compiled in, only `disable` and `allow` get past `SslMode::from_url`
+#[cfg(not(any(feature = "tls", feature = "tls-rustls")))]
+pub(crate) async fn request_if_needed(
+    _stream: &mut PgStream,
+    _url: &Url,
+    _mode: SslMode,
+    _options: &PgConnectOptions,
+) -> crate::Result<()> {
+    Ok(())
+}
+
+#[cfg(any(feature = "tls", feature = "tls-rustls"))]
 async fn try_upgrade(
     stream: &mut PgStream,
     url: &Url,
+    options: &PgConnectOptions,
     accept_invalid_certs: bool,
     accept_invalid_host_names: bool,
 ) -> crate::Result<bool> {
-    use async_native_tls::TlsConnector;
+    use crate::io::TlsConfig;
+
+    let (client_cert, client_key) = read_client_certificate(url, options).await?;
 
     stream.write(crate::postgres::protocol::SslRequest);
     stream.flush().await?;
@@ -93,25 +142,29 @@ async fn try_upgrade(
         }
     }
 
-    let mut connector = TlsConnector::new()
-        .danger_accept_invalid_certs(accept_invalid_certs)
-        .danger_accept_invalid_hostnames(accept_invalid_host_names);
+    // Try to read in the root certificate for postgres using several
+    // standard methods (used by psql and libpq)
+    let root_cert = if !accept_invalid_certs {
+        read_root_certificate(url).await?
+    } else {
+        None
+    };
 
-    if !accept_invalid_certs {
-        // Try to read in the root certificate for postgres using several
-        // standard methods (used by psql and libpq)
-        if let Some(cert) = read_root_certificate(&url).await? {
-            connector = connector.add_root_certificate(cert);
-        }
-    }
+    let config = TlsConfig {
+        accept_invalid_certs,
+        accept_invalid_hostnames: accept_invalid_host_names,
+        root_cert: root_cert.as_deref(),
+        client_cert: client_cert.as_deref(),
+        client_key: client_key.as_deref(),
+    };
 
-    stream.stream.upgrade(url, connector).await?;
+    stream.stream.upgrade(url, config).await?;
 
     Ok(true)
 }
 
-#[cfg(feature = "tls")]
-async fn read_root_certificate(url: &Url) -> crate::Result<Option<async_native_tls::Certificate>> {
+#[cfg(any(feature = "tls", feature = "tls-rustls"))]
+async fn read_root_certificate(url: &Url) -> crate::Result<Option<Vec<u8>>> {
     use crate::runtime::fs;
     use std::env;
 
@@ -136,7 +189,37 @@ async fn read_root_certificate(url: &Url) -> crate::Result<Option<async_native_t
         }
     }
 
-    data.map(|data| async_native_tls::Certificate::from_pem(&data))
-        .transpose()
-        .map_err(Into::into)
+    Ok(data)
+}
+
+// The certificate and key of the client, from the options or the `sslcert` and `sslkey`
+// parameters; unlike `libpq`, there is no default location for them
+#[cfg(any(feature = "tls", feature = "tls-rustls"))]
+async fn read_client_certificate(
+    url: &Url,
+    options: &PgConnectOptions,
+) -> crate::Result<(Option<Vec<u8>>, Option<Vec<u8>>)> {
+    use crate::postgres::options::CertificateInput;
+
+    let from_url = |param| {
+        url.param(param)
+            .map(|path| CertificateInput::File((*path).into()))
+    };
+
+    let cert = options
+        .ssl_client_cert
+        .clone()
+        .or_else(|| from_url("sslcert"));
+    let key = options
+        .ssl_client_key
+        .clone()
+        .or_else(|| from_url("sslkey"));
+
+    match (cert, key) {
+        (Some(cert), Some(key)) => Ok((Some(cert.read().await?), Some(key.read().await?))),
+        (None, None) => Ok((None, None)),
+
+        (Some(_), None) => Err(tls_err!("a client certificate needs a key (`sslkey`)").into()),
+        (None, Some(_)) => Err(tls_err!("a client key needs a certificate (`sslcert`)").into()),
+    }
 }
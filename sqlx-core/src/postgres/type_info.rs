@@ -71,6 +71,11 @@ impl TypeInfo for PgTypeInfo {
                 | (TypeId::ARRAY_CIDR, TypeId::ARRAY_INET)
                 | (TypeId::ARRAY_INET, TypeId::ARRAY_CIDR) => true,
 
+                (TypeId::BIT, TypeId::VARBIT)
+                | (TypeId::VARBIT, TypeId::BIT)
+                | (TypeId::ARRAY_BIT, TypeId::ARRAY_VARBIT)
+                | (TypeId::ARRAY_VARBIT, TypeId::ARRAY_BIT) => true,
+
                 // the following text-like types are compatible
                 (TypeId::VARCHAR, other)
                 | (TypeId::TEXT, other)
@@ -119,6 +124,14 @@ impl TypeInfo for PgTypeInfo {
             };
         }
 
+        // Values of custom types inside of records and arrays only know the OID of their type
+        // and custom types in Rust only know their name; there is nothing to compare
+        if (self.id.is_some() && self.name.is_empty() && other.id.is_none())
+            || (other.id.is_some() && other.name.is_empty() && self.id.is_none())
+        {
+            return true;
+        }
+
         // If the type names match, the types are equivalent (and compatible)
         // If the type names are the empty string, they are invalid type names
 
@@ -3,8 +3,9 @@
 use crate::database::Database;
 use crate::decode::Decode;
 use crate::encode::Encode;
-use crate::postgres::types::raw::{PgArrayDecoder, PgArrayEncoder};
-use crate::postgres::{PgRawBuffer, PgValue, Postgres};
+use crate::postgres::protocol::TypeId;
+use crate::postgres::types::raw::{is_array_type, PgArrayDecoder, PgArrayEncoder};
+use crate::postgres::{PgRawBuffer, PgTypeInfo, PgValue, Postgres};
 use crate::types::Type;
 
 impl<T> Encode<Postgres> for [T]
@@ -66,3 +67,30 @@ where
         <[T] as Type<DB>>::type_info()
     }
 }
+
+// Postgres has no separate type for an array of arrays; a `Vec<Vec<i32>>` is an `INT4[]`
+// with two dimensions
+impl<T> Type<Postgres> for [Vec<T>]
+where
+    [T]: Type<Postgres>,
+{
+    fn type_info() -> PgTypeInfo {
+        let ty = <[T] as Type<Postgres>>::type_info();
+
+        if is_array_type(&ty) {
+            ty
+        } else {
+            // `[u8]` is the only slice that is not an array (it is a BYTEA)
+            PgTypeInfo::new(TypeId::ARRAY_BYTEA, "BYTEA[]")
+        }
+    }
+}
+
+impl<T> Type<Postgres> for Vec<Vec<T>>
+where
+    [T]: Type<Postgres>,
+{
+    fn type_info() -> PgTypeInfo {
+        <[Vec<T>] as Type<Postgres>>::type_info()
+    }
+}
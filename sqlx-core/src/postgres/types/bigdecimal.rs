@@ -44,7 +44,7 @@ impl TryFrom<BigDecimal> for PgNumeric {
         // this routine is specifically optimized for base-10
         let (sign, base_10) = bigint.to_radix_be(10);
 
-        // weight is positive power of 10000
+        // weight_10 is the number of base-10 digits before the decimal point
         // exp is the negative power of 10
         let weight_10 = base_10.len() as i64 - exp;
 
@@ -53,40 +53,19 @@ impl TryFrom<BigDecimal> for PgNumeric {
         // exactly what our scale should be
         let scale: i16 = cmp::max(0, exp).try_into()?;
 
-        // there's an implicit +1 offset in the interpretation
-        let weight: i16 = if weight_10 <= 0 {
-            weight_10 / 4 - 1
-        } else {
-            weight_10 / 4
-        }
-        .try_into()?;
-
-        let digits_len = if base_10.len() % 4 != 0 {
-            base_10.len() / 4 + 1
-        } else {
-            base_10.len() / 4
-        };
+        // the power of 10 of the first digit decides the power of 10000 it falls in; the
+        // digits are padded with zeros so they are grouped by 4 from that power down
+        let first = weight_10 - 1;
+        let weight: i16 = first.div_euclid(4).try_into()?;
+        let padding = 3 - first.rem_euclid(4) as usize;
 
-        let offset = if weight_10 < 0 {
-            4 - (-weight_10) % 4
-        } else {
-            weight_10 % 4
-        } as usize;
-
-        let mut digits = Vec::with_capacity(digits_len);
-
-        if let Some(first) = base_10.get(..offset) {
-            if offset != 0 {
-                digits.push(base_10_to_10000(first));
-            }
-        }
+        let mut padded = vec![0; padding];
+        padded.extend_from_slice(&base_10);
 
-        if let Some(rest) = base_10.get(offset..) {
-            digits.extend(
-                rest.chunks(4)
-                    .map(|chunk| base_10_to_10000(chunk) * 10i16.pow(4 - chunk.len() as u32)),
-            );
-        }
+        let mut digits: Vec<i16> = padded
+            .chunks(4)
+            .map(|chunk| base_10_to_10000(chunk) * 10i16.pow(4 - chunk.len() as u32))
+            .collect();
 
         while let Some(&0) = digits.last() {
             digits.pop();
@@ -108,13 +87,13 @@ impl TryFrom<PgNumeric> for BigDecimal {
     type Error = crate::Error;
 
     fn try_from(numeric: PgNumeric) -> crate::Result<Self> {
-        let (digits, sign, weight) = match numeric {
+        let (digits, sign, weight, scale) = match numeric {
             PgNumeric::Number {
                 digits,
                 sign,
                 weight,
-                ..
-            } => (digits, sign, weight),
+                scale,
+            } => (digits, sign, weight, scale),
             PgNumeric::NotANumber => {
                 return Err(crate::Error::Decode(
                     "BigDecimal does not support NaN values".into(),
@@ -129,7 +108,7 @@ impl TryFrom<PgNumeric> for BigDecimal {
         };
 
         // weight is 0 if the decimal point falls after the first base-10000 digit
-        let scale = (digits.len() as i64 - weight as i64 - 1) * 4;
+        let digits_scale = (digits.len() as i64 - weight as i64 - 1) * 4;
 
         // no optimized algorithm for base-10 so use base-100 for faster processing
         let mut cents = Vec::with_capacity(digits.len() * 2);
@@ -138,11 +117,17 @@ impl TryFrom<PgNumeric> for BigDecimal {
             cents.push((digit % 100) as u8);
         }
 
-        let bigint = BigInt::from_radix_be(sign, &cents, 100).ok_or_else(|| {
-            crate::Error::Decode("PgNumeric contained an out-of-range digit".into())
-        })?;
+        let bigint = if cents.is_empty() {
+            BigInt::default()
+        } else {
+            BigInt::from_radix_be(sign, &cents, 100).ok_or_else(|| {
+                crate::Error::Decode("PgNumeric contained an out-of-range digit".into())
+            })?
+        };
 
-        Ok(BigDecimal::new(bigint, scale))
+        // the digits are in groups of 4 so there may be more trailing zeros than the scale of
+        // the value; keep its scale so e.g. `1.50` is not decoded as `1.5000`
+        Ok(BigDecimal::new(bigint, digits_scale).with_scale(scale.into()))
     }
 }
 
@@ -169,9 +154,7 @@ impl Decode<'_, Postgres> for BigDecimal {
     fn decode(value: PgValue) -> crate::Result<Self> {
         match value.try_get()? {
             PgData::Binary(binary) => PgNumeric::from_bytes(binary)?.try_into(),
-            PgData::Text(text) => text
-                .parse::<BigDecimal>()
-                .map_err(|e| crate::Error::Decode(e.into())),
+            PgData::Text(text) => PgNumeric::from_str(text)?.try_into(),
         }
     }
 }
@@ -289,6 +272,28 @@ fn test_bigdecimal_to_pgnumeric() {
         }
     );
 
+    let four_digits: BigDecimal = "1234".parse().unwrap();
+    assert_eq!(
+        PgNumeric::try_from(four_digits).unwrap(),
+        PgNumeric::Number {
+            sign: PgNumericSign::Positive,
+            scale: 0,
+            weight: 0,
+            digits: vec![1234]
+        }
+    );
+
+    let small: BigDecimal = "-0.00000000015".parse().unwrap();
+    assert_eq!(
+        PgNumeric::try_from(small).unwrap(),
+        PgNumeric::Number {
+            sign: PgNumericSign::Negative,
+            scale: 11,
+            weight: -3,
+            digits: vec![150]
+        }
+    );
+
     let one_digit_decimal: BigDecimal = "0.00001234".parse().unwrap();
     assert_eq!(
         PgNumeric::try_from(one_digit_decimal).unwrap(),
@@ -300,3 +305,46 @@ fn test_bigdecimal_to_pgnumeric() {
         }
     );
 }
+
+#[test]
+fn test_pgnumeric_to_bigdecimal() {
+    let zero = PgNumeric::Number {
+        sign: PgNumericSign::Positive,
+        scale: 2,
+        weight: 0,
+        digits: vec![],
+    };
+    assert_eq!(BigDecimal::try_from(zero).unwrap().to_string(), "0.00");
+
+    let trailing_zeros = PgNumeric::Number {
+        sign: PgNumericSign::Positive,
+        scale: 2,
+        weight: 0,
+        digits: vec![1, 5000],
+    };
+    assert_eq!(
+        BigDecimal::try_from(trailing_zeros).unwrap().to_string(),
+        "1.50"
+    );
+
+    let small = PgNumeric::Number {
+        sign: PgNumericSign::Negative,
+        scale: 5,
+        weight: -2,
+        digits: vec![1000],
+    };
+    assert_eq!(BigDecimal::try_from(small).unwrap().to_string(), "-0.00001");
+
+    let decimal = PgNumeric::Number {
+        sign: PgNumericSign::Negative,
+        scale: 9,
+        weight: 2,
+        digits: vec![1, 2345, 6789, 0, 0, 1000],
+    };
+    assert_eq!(
+        BigDecimal::try_from(decimal).unwrap().to_string(),
+        "-123456789.000000001"
+    );
+
+    assert!(BigDecimal::try_from(PgNumeric::NotANumber).is_err());
+}
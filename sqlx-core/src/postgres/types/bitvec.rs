@@ -0,0 +1,158 @@
+use bitvec::order::Msb0;
+use bitvec::vec::BitVec;
+use byteorder::{BigEndian, ByteOrder};
+
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::postgres::protocol::TypeId;
+use crate::postgres::{PgData, PgRawBuffer, PgTypeInfo, PgValue, Postgres};
+use crate::types::Type;
+
+// `BIT(N)` and `VARBIT` share a wire format so either may be decoded; `VARBIT` is sent
+// as it is accepted for both
+impl Type<Postgres> for BitVec<Msb0, u8> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::VARBIT, "VARBIT")
+    }
+}
+
+impl Type<Postgres> for [BitVec<Msb0, u8>] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::ARRAY_VARBIT, "VARBIT[]")
+    }
+}
+
+impl Type<Postgres> for Vec<BitVec<Msb0, u8>> {
+    fn type_info() -> PgTypeInfo {
+        <[BitVec<Msb0, u8>] as Type<Postgres>>::type_info()
+    }
+}
+
+// the number of bits followed by the bits packed from the most significant bit of the
+// first byte; the unused bits of the last byte are always zero
+impl Encode<Postgres> for BitVec<Msb0, u8> {
+    fn encode(&self, buf: &mut PgRawBuffer) {
+        buf.extend_from_slice(&(self.len() as i32).to_be_bytes());
+
+        let start = buf.len();
+        buf.extend_from_slice(self.as_slice());
+
+        let unused = self.as_slice().len() * 8 - self.len();
+
+        if unused > 0 {
+            if let Some(last) = buf[start..].last_mut() {
+                *last &= 0xff << unused;
+            }
+        }
+    }
+
+    fn size_hint(&self) -> usize {
+        4 + self.as_slice().len()
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for BitVec<Msb0, u8> {
+    fn decode(value: PgValue<'de>) -> crate::Result<Self> {
+        match value.try_get()? {
+            PgData::Binary(buf) => {
+                if buf.len() < 4 {
+                    return Err(decode_err!(
+                        "expected at least 4 bytes for VARBIT but received {}",
+                        buf.len()
+                    ));
+                }
+
+                let len = BigEndian::read_i32(buf);
+                let bytes = &buf[4..];
+
+                // the bits must fill every byte but the last one
+                let padding = (bytes.len() * 8) as i64 - len as i64;
+
+                if len < 0 || !(0..8).contains(&padding) {
+                    return Err(decode_err!(
+                        "expected {} bits for VARBIT but received {} bytes",
+                        len,
+                        bytes.len()
+                    ));
+                }
+
+                let mut bits = BitVec::from_vec(bytes.to_vec());
+                bits.truncate(len as usize);
+
+                Ok(bits)
+            }
+
+            PgData::Text(s) => parse_bits(s).ok_or_else(|| decode_err!("invalid VARBIT: {:?}", s)),
+        }
+    }
+}
+
+fn parse_bits(s: &str) -> Option<BitVec<Msb0, u8>> {
+    let mut bits = BitVec::with_capacity(s.len());
+
+    for ch in s.chars() {
+        match ch {
+            '0' => bits.push(false),
+            '1' => bits.push(true),
+            _ => return None,
+        }
+    }
+
+    Some(bits)
+}
+
+#[cfg(test)]
+fn bits(s: &str) -> BitVec<Msb0, u8> {
+    parse_bits(s).unwrap()
+}
+
+#[test]
+fn test_encode_bitvec() {
+    let mut buf = PgRawBuffer::default();
+    Encode::<Postgres>::encode(&bits("1011001110"), &mut buf);
+
+    assert_eq!(&**buf, [0, 0, 0, 10, 0b1011_0011, 0b1000_0000]);
+
+    let mut buf = PgRawBuffer::default();
+    Encode::<Postgres>::encode(&BitVec::<Msb0, u8>::new(), &mut buf);
+
+    assert_eq!(&**buf, [0, 0, 0, 0]);
+}
+
+#[test]
+fn test_encode_bitvec_padding() {
+    // bits past the length of the vector must not leak into the padding
+    let mut value = BitVec::<Msb0, u8>::from_vec(vec![0xff, 0xff]);
+    value.truncate(10);
+
+    let mut buf = PgRawBuffer::default();
+    Encode::<Postgres>::encode(&value, &mut buf);
+
+    assert_eq!(&**buf, [0, 0, 0, 10, 0xff, 0b1100_0000]);
+}
+
+#[test]
+fn test_decode_bitvec() {
+    let buf = [0, 0, 0, 10, 0b1011_0011, 0b1000_0000];
+    let value: BitVec<Msb0, u8> = Decode::<Postgres>::decode(PgValue::from_bytes(&buf)).unwrap();
+
+    assert_eq!(value, bits("1011001110"));
+    assert_eq!(value.len(), 10);
+
+    let value: BitVec<Msb0, u8> =
+        Decode::<Postgres>::decode(PgValue::from_bytes(&[0, 0, 0, 0])).unwrap();
+
+    assert!(value.is_empty());
+
+    let res: crate::Result<BitVec<Msb0, u8>> =
+        Decode::<Postgres>::decode(PgValue::from_bytes(&[0, 0, 0, 10, 0xff]));
+
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_decode_bitvec_text() {
+    assert_eq!(parse_bits("").map(|bits| bits.len()), Some(0));
+    assert_eq!(parse_bits("101").map(|bits| bits.len()), Some(3));
+    assert!(parse_bits("10a").is_none());
+}
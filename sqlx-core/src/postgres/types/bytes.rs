@@ -44,14 +44,45 @@ impl<'de> Decode<'de, Postgres> for Vec<u8> {
     fn decode(value: PgValue<'de>) -> crate::Result<Self> {
         match value.try_get()? {
             PgData::Binary(buf) => Ok(buf.to_vec()),
-            PgData::Text(s) => {
-                // BYTEA is formatted as \x followed by hex characters
-                hex::decode(&s[2..]).map_err(crate::Error::decode)
-            }
+            PgData::Text(s) => decode_text(s),
         }
     }
 }
 
+// <https://www.postgresql.org/docs/current/datatype-binary.html>
+fn decode_text(s: &str) -> crate::Result<Vec<u8>> {
+    // the hex format is \x followed by two hex characters for each byte
+    if let Some(hex) = s.strip_prefix("\\x") {
+        return hex::decode(hex).map_err(crate::Error::decode);
+    }
+
+    // the escape format (`bytea_output = 'escape'`) writes a backslash as \\ and other bytes
+    // that are not printable as \ followed by three octal digits
+    let mut bytes = Vec::with_capacity(s.len());
+    let mut rest = s.as_bytes();
+
+    while let Some((&byte, tail)) = rest.split_first() {
+        if byte != b'\\' {
+            bytes.push(byte);
+            rest = tail;
+        } else if let Some(tail) = tail.strip_prefix(b"\\") {
+            bytes.push(b'\\');
+            rest = tail;
+        } else {
+            let octal = tail
+                .get(..3)
+                .and_then(|octal| std::str::from_utf8(octal).ok())
+                .and_then(|octal| u8::from_str_radix(octal, 8).ok())
+                .ok_or_else(|| decode_err!("invalid escape in BYTEA {:?}", s))?;
+
+            bytes.push(octal);
+            rest = &tail[3..];
+        }
+    }
+
+    Ok(bytes)
+}
+
 impl<'de> Decode<'de, Postgres> for &'de [u8] {
     fn decode(value: PgValue<'de>) -> crate::Result<Self> {
         match value.try_get()? {
@@ -64,3 +95,19 @@ impl<'de> Decode<'de, Postgres> for &'de [u8] {
         }
     }
 }
+
+#[test]
+fn test_decode_text() {
+    assert_eq!(decode_text("\\x").unwrap(), b"");
+    assert_eq!(decode_text("\\xdeadbeef").unwrap(), b"\xDE\xAD\xBE\xEF");
+    assert_eq!(decode_text("\\x610062").unwrap(), b"a\0b");
+
+    assert_eq!(decode_text("").unwrap(), b"");
+    assert_eq!(decode_text("abc").unwrap(), b"abc");
+    assert_eq!(decode_text("a\\000b").unwrap(), b"a\0b");
+    assert_eq!(decode_text("\\\\\\336\\255").unwrap(), b"\\\xDE\xAD");
+
+    assert!(decode_text("\\xabc").is_err());
+    assert!(decode_text("\\12").is_err());
+    assert!(decode_text("\\999").is_err());
+}
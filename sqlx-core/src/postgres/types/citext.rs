@@ -0,0 +1,87 @@
+//! The [`citext`](https://www.postgresql.org/docs/current/citext.html) extension type.
+
+use std::fmt::{self, Display};
+use std::ops::Deref;
+
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::postgres::{PgData, PgRawBuffer, PgTypeInfo, PgValue, Postgres};
+use crate::types::Type;
+
+/// A Postgres `CITEXT`, a string that is compared case-insensitively by Postgres.
+///
+/// Binding a `PgCiText` instead of a `&str` or `String` makes Postgres compare it to a `CITEXT`
+/// column as a `CITEXT`; a `TEXT` parameter would make the comparison case-sensitive. The
+/// comparison of two `PgCiText` in Rust is case-sensitive, like that of their strings.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct PgCiText(pub String);
+
+impl Display for PgCiText {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Deref for PgCiText {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for PgCiText {
+    fn from(s: String) -> Self {
+        PgCiText(s)
+    }
+}
+
+impl From<&'_ str> for PgCiText {
+    fn from(s: &str) -> Self {
+        PgCiText(s.to_owned())
+    }
+}
+
+// `citext` is defined by an extension so its OID differs between databases
+impl Type<Postgres> for PgCiText {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("citext")
+    }
+}
+
+// the binary format is the same as the text format
+impl Encode<Postgres> for PgCiText {
+    fn encode(&self, buf: &mut PgRawBuffer) {
+        buf.extend_from_slice(self.0.as_bytes());
+    }
+
+    fn size_hint(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for PgCiText {
+    fn decode(value: PgValue<'de>) -> crate::Result<Self> {
+        let s = match value.try_get()? {
+            PgData::Binary(buf) => std::str::from_utf8(buf).map_err(crate::Error::decode)?,
+            PgData::Text(s) => s,
+        };
+
+        Ok(PgCiText(s.to_owned()))
+    }
+}
+
+#[test]
+fn test_decode_citext() {
+    let s: PgCiText = Decode::<Postgres>::decode(PgValue::from_bytes(b"Hello")).unwrap();
+
+    assert_eq!(s, PgCiText::from("Hello"));
+
+    let s: PgCiText = Decode::<Postgres>::decode(PgValue::from_str("Hello")).unwrap();
+
+    assert_eq!(&*s, "Hello");
+
+    let res: crate::Result<PgCiText> = Decode::<Postgres>::decode(PgValue::from_bytes(b"\xFF"));
+
+    assert!(res.is_err());
+}
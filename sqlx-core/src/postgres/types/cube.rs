@@ -0,0 +1,228 @@
+//! The [`cube`](https://www.postgresql.org/docs/current/cube.html) extension type.
+
+use std::str::FromStr;
+
+use byteorder::{NetworkEndian, ReadBytesExt};
+
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::error::Error;
+use crate::postgres::{PgData, PgRawBuffer, PgTypeInfo, PgValue, Postgres};
+use crate::types::Type;
+
+// the header of the binary format is the number of dimensions with the top bit set for a point
+const POINT_BIT: u32 = 0x8000_0000;
+const DIM_MASK: u32 = 0x7fff_ffff;
+
+/// A Postgres `CUBE`, an n-dimensional box given by its lower left and upper right corners.
+///
+/// A point is a cube where both corners are the same.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PgCube {
+    lower_left: Vec<f64>,
+    upper_right: Vec<f64>,
+}
+
+impl PgCube {
+    /// Create a cube from two opposite corners.
+    ///
+    /// Returns `None` if the corners do not have the same number of dimensions.
+    pub fn new(lower_left: Vec<f64>, upper_right: Vec<f64>) -> Option<Self> {
+        if lower_left.len() != upper_right.len() {
+            return None;
+        }
+
+        Some(PgCube {
+            lower_left,
+            upper_right,
+        })
+    }
+
+    /// Create a cube that is a single point.
+    pub fn point(coordinates: Vec<f64>) -> Self {
+        PgCube {
+            upper_right: coordinates.clone(),
+            lower_left: coordinates,
+        }
+    }
+
+    /// The coordinates of the lower left corner.
+    pub fn lower_left(&self) -> &[f64] {
+        &self.lower_left
+    }
+
+    /// The coordinates of the upper right corner.
+    pub fn upper_right(&self) -> &[f64] {
+        &self.upper_right
+    }
+
+    /// The number of dimensions of the cube.
+    pub fn dimensions(&self) -> usize {
+        self.lower_left.len()
+    }
+
+    /// Returns `true` if both corners of the cube are the same.
+    pub fn is_point(&self) -> bool {
+        self.lower_left == self.upper_right
+    }
+}
+
+// `cube` is defined by an extension so its OID differs between databases
+impl Type<Postgres> for PgCube {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("cube")
+    }
+}
+
+impl Encode<Postgres> for PgCube {
+    fn encode(&self, buf: &mut PgRawBuffer) {
+        let mut header = self.dimensions() as u32 & DIM_MASK;
+
+        if self.is_point() {
+            header |= POINT_BIT;
+        }
+
+        buf.extend_from_slice(&header.to_be_bytes());
+
+        for coordinate in &self.lower_left {
+            buf.extend_from_slice(&coordinate.to_be_bytes());
+        }
+
+        if !self.is_point() {
+            for coordinate in &self.upper_right {
+                buf.extend_from_slice(&coordinate.to_be_bytes());
+            }
+        }
+    }
+
+    fn size_hint(&self) -> usize {
+        4 + self.dimensions() * 16
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for PgCube {
+    fn decode(value: PgValue<'de>) -> crate::Result<Self> {
+        match value.try_get()? {
+            PgData::Binary(mut buf) => {
+                let header = buf.read_u32::<NetworkEndian>().map_err(Error::decode)?;
+                let dimensions = (header & DIM_MASK) as usize;
+                let corners = if header & POINT_BIT != 0 { 1 } else { 2 };
+
+                if buf.len() != dimensions * corners * 8 {
+                    return Err(decode_err!(
+                        "expected {} coordinates for CUBE but received {} bytes",
+                        dimensions * corners,
+                        buf.len()
+                    ));
+                }
+
+                let lower_left = read_coordinates(&mut buf, dimensions)?;
+
+                if corners == 1 {
+                    return Ok(PgCube::point(lower_left));
+                }
+
+                let upper_right = read_coordinates(&mut buf, dimensions)?;
+
+                Ok(PgCube {
+                    lower_left,
+                    upper_right,
+                })
+            }
+
+            PgData::Text(s) => parse_cube(s).ok_or_else(|| decode_err!("invalid CUBE: {:?}", s)),
+        }
+    }
+}
+
+fn read_coordinates(buf: &mut &[u8], dimensions: usize) -> crate::Result<Vec<f64>> {
+    (0..dimensions)
+        .map(|_| buf.read_f64::<NetworkEndian>().map_err(Error::decode))
+        .collect()
+}
+
+// (x, y, ...) for a point or (x, y, ...),(x, y, ...) for a box
+fn parse_cube(s: &str) -> Option<PgCube> {
+    let s = s.trim();
+
+    match s.find("),") {
+        Some(end) => PgCube::new(
+            parse_coordinates(&s[..=end])?,
+            parse_coordinates(&s[end + 2..])?,
+        ),
+
+        None => parse_coordinates(s).map(PgCube::point),
+    }
+}
+
+fn parse_coordinates(s: &str) -> Option<Vec<f64>> {
+    let s = s.trim().strip_prefix('(')?.strip_suffix(')')?;
+
+    s.split(',')
+        .map(|coordinate| f64::from_str(coordinate.trim()).ok())
+        .collect()
+}
+
+#[test]
+fn test_encode_cube() {
+    let mut buf = PgRawBuffer::default();
+    Encode::<Postgres>::encode(&PgCube::point(vec![1.0, 2.0]), &mut buf);
+
+    assert_eq!(&buf[..4], [0x80, 0, 0, 2]);
+    assert_eq!(&buf[4..12], 1.0_f64.to_be_bytes());
+    assert_eq!(&buf[12..], 2.0_f64.to_be_bytes());
+
+    let mut buf = PgRawBuffer::default();
+    Encode::<Postgres>::encode(&PgCube::new(vec![1.0], vec![2.0]).unwrap(), &mut buf);
+
+    assert_eq!(&buf[..4], [0, 0, 0, 1]);
+    assert_eq!(&buf[4..12], 1.0_f64.to_be_bytes());
+    assert_eq!(&buf[12..], 2.0_f64.to_be_bytes());
+}
+
+#[test]
+fn test_decode_cube() {
+    let mut buf = vec![0, 0, 0, 1];
+    buf.extend_from_slice(&1.0_f64.to_be_bytes());
+    buf.extend_from_slice(&2.0_f64.to_be_bytes());
+
+    let cube: PgCube = Decode::<Postgres>::decode(PgValue::from_bytes(&buf)).unwrap();
+
+    assert_eq!(cube.lower_left(), [1.0]);
+    assert_eq!(cube.upper_right(), [2.0]);
+
+    // a point only has a single corner
+    buf[0] = 0x80;
+
+    let res: crate::Result<PgCube> = Decode::<Postgres>::decode(PgValue::from_bytes(&buf));
+
+    assert!(res.is_err());
+
+    let cube: PgCube = Decode::<Postgres>::decode(PgValue::from_bytes(&buf[..12])).unwrap();
+
+    assert_eq!(cube, PgCube::point(vec![1.0]));
+}
+
+#[test]
+fn test_decode_cube_text() {
+    assert_eq!(
+        parse_cube("(1, 2.5, -3)"),
+        Some(PgCube::point(vec![1.0, 2.5, -3.0]))
+    );
+
+    assert_eq!(
+        parse_cube("(1, 2),(3, 4)"),
+        PgCube::new(vec![1.0, 2.0], vec![3.0, 4.0])
+    );
+
+    assert_eq!(parse_cube("(1, 2),(3)"), None);
+    assert_eq!(parse_cube("(1, a)"), None);
+    assert_eq!(parse_cube("1, 2"), None);
+}
+
+#[test]
+fn test_cube_dimensions() {
+    assert!(PgCube::new(vec![1.0], vec![1.0, 2.0]).is_none());
+    assert!(PgCube::point(vec![1.0, 2.0]).is_point());
+    assert_eq!(PgCube::point(vec![1.0, 2.0]).dimensions(), 2);
+}
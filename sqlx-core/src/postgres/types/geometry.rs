@@ -0,0 +1,484 @@
+//! Postgres [geometric types](https://www.postgresql.org/docs/current/datatype-geometric.html).
+
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+use byteorder::{NetworkEndian, ReadBytesExt};
+
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::error::Error;
+use crate::postgres::protocol::TypeId;
+use crate::postgres::{PgData, PgRawBuffer, PgTypeInfo, PgValue, Postgres};
+use crate::types::Type;
+
+/// A Postgres `POINT`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PgPoint {
+    pub x: f64,
+    pub y: f64,
+}
+
+/// A Postgres `BOX`, a rectangle given by two opposite corners.
+///
+/// Postgres reorders the corners so that `high` is the upper right and `low` the lower left.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PgBox {
+    pub high: PgPoint,
+    pub low: PgPoint,
+}
+
+/// A Postgres `PATH`, a list of connected points.
+///
+/// An open path has distinct first and last points; in a closed path the last point
+/// connects back to the first.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PgPath {
+    pub closed: bool,
+    pub points: Vec<PgPoint>,
+}
+
+/// A Postgres `POLYGON`, a closed list of points.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PgPolygon {
+    pub points: Vec<PgPoint>,
+}
+
+impl Type<Postgres> for PgPoint {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::POINT, "POINT")
+    }
+}
+
+impl Type<Postgres> for [PgPoint] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::ARRAY_POINT, "POINT[]")
+    }
+}
+
+impl Type<Postgres> for Vec<PgPoint> {
+    fn type_info() -> PgTypeInfo {
+        <[PgPoint] as Type<Postgres>>::type_info()
+    }
+}
+
+impl Type<Postgres> for PgBox {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::BOX, "BOX")
+    }
+}
+
+impl Type<Postgres> for PgPath {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::PATH, "PATH")
+    }
+}
+
+impl Type<Postgres> for PgPolygon {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::POLYGON, "POLYGON")
+    }
+}
+
+impl Encode<Postgres> for PgPoint {
+    fn encode(&self, buf: &mut PgRawBuffer) {
+        write_point(buf, self);
+    }
+
+    fn size_hint(&self) -> usize {
+        16
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for PgPoint {
+    fn decode(value: PgValue<'de>) -> crate::Result<Self> {
+        match value.try_get()? {
+            // the binary format is just the two coordinates as FLOAT8
+            PgData::Binary(mut buf) => {
+                let point = read_point(&mut buf)?;
+                expect_end(buf, "POINT")?;
+
+                Ok(point)
+            }
+
+            PgData::Text(s) => parse_point(s).ok_or_else(|| decode_err!("invalid POINT: {:?}", s)),
+        }
+    }
+}
+
+impl Encode<Postgres> for PgBox {
+    fn encode(&self, buf: &mut PgRawBuffer) {
+        write_point(buf, &self.high);
+        write_point(buf, &self.low);
+    }
+
+    fn size_hint(&self) -> usize {
+        32
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for PgBox {
+    fn decode(value: PgValue<'de>) -> crate::Result<Self> {
+        match value.try_get()? {
+            PgData::Binary(mut buf) => {
+                let high = read_point(&mut buf)?;
+                let low = read_point(&mut buf)?;
+                expect_end(buf, "BOX")?;
+
+                Ok(PgBox { high, low })
+            }
+
+            PgData::Text(s) => match parse_points(s).as_deref() {
+                Some([high, low]) => Ok(PgBox {
+                    high: *high,
+                    low: *low,
+                }),
+
+                _ => Err(decode_err!("invalid BOX: {:?}", s)),
+            },
+        }
+    }
+}
+
+impl Encode<Postgres> for PgPath {
+    fn encode(&self, buf: &mut PgRawBuffer) {
+        buf.push(self.closed as u8);
+        write_points(buf, &self.points);
+    }
+
+    fn size_hint(&self) -> usize {
+        1 + 4 + self.points.len() * 16
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for PgPath {
+    fn decode(value: PgValue<'de>) -> crate::Result<Self> {
+        match value.try_get()? {
+            PgData::Binary(mut buf) => {
+                let closed = buf.read_u8().map_err(Error::decode)? != 0;
+                let points = read_points(&mut buf)?;
+                expect_end(buf, "PATH")?;
+
+                Ok(PgPath { closed, points })
+            }
+
+            // [(x,y),...] is open and ((x,y),...) is closed
+            PgData::Text(s) => {
+                let s = s.trim();
+
+                let (closed, points) = if let Some(points) = strip_delimiters(s, '[', ']') {
+                    (false, points)
+                } else if let Some(points) = strip_delimiters(s, '(', ')') {
+                    (true, points)
+                } else {
+                    return Err(decode_err!("invalid PATH: {:?}", s));
+                };
+
+                let points =
+                    parse_points(points).ok_or_else(|| decode_err!("invalid PATH: {:?}", s))?;
+
+                Ok(PgPath { closed, points })
+            }
+        }
+    }
+}
+
+impl Encode<Postgres> for PgPolygon {
+    fn encode(&self, buf: &mut PgRawBuffer) {
+        // the bounding box is calculated by the server
+        write_points(buf, &self.points);
+    }
+
+    fn size_hint(&self) -> usize {
+        4 + self.points.len() * 16
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for PgPolygon {
+    fn decode(value: PgValue<'de>) -> crate::Result<Self> {
+        match value.try_get()? {
+            PgData::Binary(mut buf) => {
+                let points = read_points(&mut buf)?;
+                expect_end(buf, "POLYGON")?;
+
+                Ok(PgPolygon { points })
+            }
+
+            // ((x,y),...)
+            PgData::Text(s) => strip_delimiters(s.trim(), '(', ')')
+                .and_then(parse_points)
+                .map(|points| PgPolygon { points })
+                .ok_or_else(|| decode_err!("invalid POLYGON: {:?}", s)),
+        }
+    }
+}
+
+fn write_point(buf: &mut PgRawBuffer, point: &PgPoint) {
+    buf.extend_from_slice(&point.x.to_be_bytes());
+    buf.extend_from_slice(&point.y.to_be_bytes());
+}
+
+fn write_points(buf: &mut PgRawBuffer, points: &[PgPoint]) {
+    // Postgres refuses paths and polygons of more points than this anyway
+    let len = i32::try_from(points.len()).unwrap_or(i32::MAX);
+
+    buf.extend_from_slice(&len.to_be_bytes());
+
+    for point in points {
+        write_point(buf, point);
+    }
+}
+
+fn read_point(buf: &mut &[u8]) -> crate::Result<PgPoint> {
+    let x = buf.read_f64::<NetworkEndian>().map_err(Error::decode)?;
+    let y = buf.read_f64::<NetworkEndian>().map_err(Error::decode)?;
+
+    Ok(PgPoint { x, y })
+}
+
+fn read_points(buf: &mut &[u8]) -> crate::Result<Vec<PgPoint>> {
+    let len = buf.read_i32::<NetworkEndian>().map_err(Error::decode)?;
+
+    if len < 0 || len as usize > buf.len() / 16 {
+        return Err(decode_err!("invalid number of points: {}", len));
+    }
+
+    (0..len).map(|_| read_point(buf)).collect()
+}
+
+fn expect_end(buf: &[u8], name: &str) -> crate::Result<()> {
+    if buf.is_empty() {
+        Ok(())
+    } else {
+        Err(decode_err!(
+            "{} bytes left over decoding {}",
+            buf.len(),
+            name
+        ))
+    }
+}
+
+fn strip_delimiters(s: &str, start: char, end: char) -> Option<&str> {
+    s.strip_prefix(start)?.strip_suffix(end)
+}
+
+// (x,y)
+fn parse_point(s: &str) -> Option<PgPoint> {
+    let s = strip_delimiters(s.trim(), '(', ')')?;
+    let mut coordinates = s.splitn(2, ',');
+
+    let x = f64::from_str(coordinates.next()?.trim()).ok()?;
+    let y = f64::from_str(coordinates.next()?.trim()).ok()?;
+
+    Some(PgPoint { x, y })
+}
+
+// (x,y),(x,y),...
+fn parse_points(mut s: &str) -> Option<Vec<PgPoint>> {
+    let mut points = Vec::new();
+
+    loop {
+        let end = s.find(')')?;
+
+        points.push(parse_point(&s[..=end])?);
+        s = s[end + 1..].trim_start();
+
+        if s.is_empty() {
+            break;
+        }
+
+        s = s.strip_prefix(',')?.trim_start();
+    }
+
+    Some(points)
+}
+
+#[cfg(test)]
+fn point(x: f64, y: f64) -> PgPoint {
+    PgPoint { x, y }
+}
+
+#[test]
+fn test_encode_point() {
+    let mut buf = PgRawBuffer::default();
+
+    Encode::<Postgres>::encode(&point(1.5, -2.0), &mut buf);
+    assert_eq!(
+        &**buf,
+        [1.5_f64.to_be_bytes(), (-2.0_f64).to_be_bytes()]
+            .concat()
+            .as_slice()
+    );
+
+    let decoded: PgPoint = Decode::<Postgres>::decode(PgValue::from_bytes(&buf)).unwrap();
+    assert_eq!(decoded, point(1.5, -2.0));
+}
+
+#[test]
+fn test_decode_point() {
+    let mut buf = Vec::new();
+    buf.extend_from_slice(&1.5_f64.to_be_bytes());
+    buf.extend_from_slice(&(-2.0_f64).to_be_bytes());
+
+    let value: PgPoint = Decode::<Postgres>::decode(PgValue::from_bytes(&buf)).unwrap();
+    assert_eq!(value, point(1.5, -2.0));
+
+    let value: PgPoint = Decode::<Postgres>::decode(PgValue::from_str("(1.5,-2)")).unwrap();
+    assert_eq!(value, point(1.5, -2.0));
+
+    // too short and too long
+    assert!(Decode::<Postgres>::decode(PgValue::from_bytes(&buf[..15]))
+        .map(|_: PgPoint| ())
+        .is_err());
+
+    buf.push(0);
+    assert!(Decode::<Postgres>::decode(PgValue::from_bytes(&buf))
+        .map(|_: PgPoint| ())
+        .is_err());
+
+    assert!(Decode::<Postgres>::decode(PgValue::from_str("(1.5)"))
+        .map(|_: PgPoint| ())
+        .is_err());
+}
+
+#[test]
+fn test_encode_box() {
+    let mut buf = PgRawBuffer::default();
+
+    Encode::<Postgres>::encode(
+        &PgBox {
+            high: point(1.0, 2.0),
+            low: point(0.0, -1.0),
+        },
+        &mut buf,
+    );
+
+    assert_eq!(
+        &**buf,
+        [
+            1.0_f64.to_be_bytes(),
+            2.0_f64.to_be_bytes(),
+            0.0_f64.to_be_bytes(),
+            (-1.0_f64).to_be_bytes()
+        ]
+        .concat()
+        .as_slice()
+    );
+}
+
+#[test]
+fn test_decode_box() {
+    let value = PgBox {
+        high: point(1.0, 2.0),
+        low: point(0.0, -1.0),
+    };
+
+    let mut buf = PgRawBuffer::default();
+    Encode::<Postgres>::encode(&value, &mut buf);
+
+    let decoded: PgBox = Decode::<Postgres>::decode(PgValue::from_bytes(&buf)).unwrap();
+    assert_eq!(decoded, value);
+
+    let decoded: PgBox = Decode::<Postgres>::decode(PgValue::from_str("(1,2),(0,-1)")).unwrap();
+    assert_eq!(decoded, value);
+
+    assert!(Decode::<Postgres>::decode(PgValue::from_str("(1,2)"))
+        .map(|_: PgBox| ())
+        .is_err());
+}
+
+#[test]
+fn test_encode_path() {
+    let mut buf = PgRawBuffer::default();
+
+    Encode::<Postgres>::encode(
+        &PgPath {
+            closed: true,
+            points: vec![point(1.0, 2.0)],
+        },
+        &mut buf,
+    );
+
+    assert_eq!(
+        &**buf,
+        [
+            &[1_u8, 0, 0, 0, 1][..],
+            &1.0_f64.to_be_bytes(),
+            &2.0_f64.to_be_bytes(),
+        ]
+        .concat()
+        .as_slice()
+    );
+}
+
+#[test]
+fn test_decode_path() {
+    for &closed in &[false, true] {
+        let value = PgPath {
+            closed,
+            points: vec![point(0.0, 0.0), point(1.0, 1.0), point(2.5, -3.0)],
+        };
+
+        let mut buf = PgRawBuffer::default();
+        Encode::<Postgres>::encode(&value, &mut buf);
+
+        let decoded: PgPath = Decode::<Postgres>::decode(PgValue::from_bytes(&buf)).unwrap();
+        assert_eq!(decoded, value);
+    }
+
+    let decoded: PgPath = Decode::<Postgres>::decode(PgValue::from_str("[(0,0),(1,1)]")).unwrap();
+    assert_eq!(
+        decoded,
+        PgPath {
+            closed: false,
+            points: vec![point(0.0, 0.0), point(1.0, 1.0)],
+        }
+    );
+
+    let decoded: PgPath = Decode::<Postgres>::decode(PgValue::from_str("((0,0),(1,1))")).unwrap();
+    assert_eq!(
+        decoded,
+        PgPath {
+            closed: true,
+            points: vec![point(0.0, 0.0), point(1.0, 1.0)],
+        }
+    );
+
+    // the point count is larger than the payload
+    let buf = [0_u8, 0, 0, 0, 2, 0, 0, 0];
+    assert!(Decode::<Postgres>::decode(PgValue::from_bytes(&buf))
+        .map(|_: PgPath| ())
+        .is_err());
+}
+
+#[test]
+fn test_encode_polygon() {
+    let mut buf = PgRawBuffer::default();
+
+    Encode::<Postgres>::encode(&PgPolygon { points: Vec::new() }, &mut buf);
+
+    assert_eq!(&**buf, [0, 0, 0, 0]);
+}
+
+#[test]
+fn test_decode_polygon() {
+    let value = PgPolygon {
+        points: vec![point(0.0, 0.0), point(1.0, 1.0), point(1.0, 0.0)],
+    };
+
+    let mut buf = PgRawBuffer::default();
+    Encode::<Postgres>::encode(&value, &mut buf);
+
+    let decoded: PgPolygon = Decode::<Postgres>::decode(PgValue::from_bytes(&buf)).unwrap();
+    assert_eq!(decoded, value);
+
+    let decoded: PgPolygon =
+        Decode::<Postgres>::decode(PgValue::from_str("((0,0),(1,1),(1,0))")).unwrap();
+    assert_eq!(decoded, value);
+
+    assert!(
+        Decode::<Postgres>::decode(PgValue::from_str("[(0,0),(1,1)]"))
+            .map(|_: PgPolygon| ())
+            .is_err()
+    );
+}
@@ -0,0 +1,207 @@
+//! The [`hstore`](https://www.postgresql.org/docs/current/hstore.html) extension type.
+
+use std::collections::HashMap;
+
+use byteorder::BigEndian;
+
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::io::Buf;
+use crate::postgres::{PgData, PgRawBuffer, PgTypeInfo, PgValue, Postgres};
+use crate::types::Type;
+
+// `hstore` is defined by an extension so its OID differs between databases
+impl Type<Postgres> for HashMap<String, Option<String>> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("hstore")
+    }
+}
+
+impl Encode<Postgres> for HashMap<String, Option<String>> {
+    fn encode(&self, buf: &mut PgRawBuffer) {
+        buf.extend_from_slice(&(self.len() as i32).to_be_bytes());
+
+        for (key, value) in self {
+            buf.extend_from_slice(&(key.len() as i32).to_be_bytes());
+            buf.extend_from_slice(key.as_bytes());
+
+            match value {
+                Some(value) => {
+                    buf.extend_from_slice(&(value.len() as i32).to_be_bytes());
+                    buf.extend_from_slice(value.as_bytes());
+                }
+
+                None => buf.extend_from_slice(&(-1_i32).to_be_bytes()),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> usize {
+        4 + self
+            .iter()
+            .map(|(key, value)| 8 + key.len() + value.as_ref().map_or(0, String::len))
+            .sum::<usize>()
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for HashMap<String, Option<String>> {
+    fn decode(value: PgValue<'de>) -> crate::Result<Self> {
+        match value.try_get()? {
+            PgData::Binary(mut buf) => {
+                let count = buf.get_i32::<BigEndian>()?;
+
+                if count < 0 {
+                    return Err(decode_err!("invalid number of pairs in HSTORE: {}", count));
+                }
+
+                let mut map = HashMap::with_capacity(count as usize);
+
+                for _ in 0..count {
+                    let key = read_string(&mut buf)?.ok_or_else(|| {
+                        crate::Error::Decode("unexpected NULL key in HSTORE".into())
+                    })?;
+
+                    let value = read_string(&mut buf)?;
+
+                    map.insert(key, value);
+                }
+
+                if !buf.is_empty() {
+                    return Err(decode_err!("{} unread bytes after HSTORE", buf.len()));
+                }
+
+                Ok(map)
+            }
+
+            PgData::Text(s) => {
+                parse_hstore(s).ok_or_else(|| decode_err!("invalid HSTORE: {:?}", s))
+            }
+        }
+    }
+}
+
+fn read_string(buf: &mut &[u8]) -> crate::Result<Option<String>> {
+    let len = buf.get_i32::<BigEndian>()?;
+
+    if len < 0 {
+        return Ok(None);
+    }
+
+    if len as usize > buf.len() {
+        return Err(decode_err!("invalid length {} of HSTORE string", len));
+    }
+
+    let (s, rest) = buf.split_at(len as usize);
+    *buf = rest;
+
+    Ok(Some(
+        String::from_utf8(s.to_vec()).map_err(crate::Error::decode)?,
+    ))
+}
+
+// the text format is a comma-separated list of `"key"=>"value"` where the value may be `NULL`;
+// `"` and `\` inside of quotes are escaped with `\`
+fn parse_hstore(s: &str) -> Option<HashMap<String, Option<String>>> {
+    let mut map = HashMap::new();
+    let mut s = s.trim_start();
+
+    while !s.is_empty() {
+        let (key, rest) = parse_quoted(s)?;
+        let rest = rest.trim_start().strip_prefix("=>")?.trim_start();
+
+        let (value, rest) = match rest.strip_prefix("NULL") {
+            Some(rest) => (None, rest),
+            None => {
+                let (value, rest) = parse_quoted(rest)?;
+                (Some(value), rest)
+            }
+        };
+
+        map.insert(key, value);
+
+        let rest = rest.trim_start();
+
+        s = match rest.strip_prefix(',') {
+            Some(rest) => rest.trim_start(),
+            None if rest.is_empty() => rest,
+            None => return None,
+        };
+    }
+
+    Some(map)
+}
+
+fn parse_quoted(s: &str) -> Option<(String, &str)> {
+    let s = s.strip_prefix('"')?;
+    let mut value = String::new();
+    let mut chars = s.char_indices();
+
+    while let Some((index, ch)) = chars.next() {
+        match ch {
+            '\\' => value.push(chars.next()?.1),
+            '"' => return Some((value, &s[index + 1..])),
+            _ => value.push(ch),
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+fn pairs(pairs: &[(&str, Option<&str>)]) -> HashMap<String, Option<String>> {
+    pairs
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.map(str::to_owned)))
+        .collect()
+}
+
+#[test]
+fn test_encode_hstore() {
+    let mut buf = PgRawBuffer::default();
+    Encode::<Postgres>::encode(&pairs(&[("a", None)]), &mut buf);
+
+    assert_eq!(&**buf, [0, 0, 0, 1, 0, 0, 0, 1, b'a', 255, 255, 255, 255]);
+
+    let mut buf = PgRawBuffer::default();
+    Encode::<Postgres>::encode(&pairs(&[("a", Some("bc"))]), &mut buf);
+
+    assert_eq!(
+        &**buf,
+        [0, 0, 0, 1, 0, 0, 0, 1, b'a', 0, 0, 0, 2, b'b', b'c']
+    );
+}
+
+#[test]
+fn test_decode_hstore() {
+    let buf = [0, 0, 0, 0];
+    let map: HashMap<String, Option<String>> =
+        Decode::<Postgres>::decode(PgValue::from_bytes(&buf)).unwrap();
+
+    assert!(map.is_empty());
+
+    let buf = [
+        0, 0, 0, 2, 0, 0, 0, 1, b'a', 255, 255, 255, 255, 0, 0, 0, 1, b'b', 0, 0, 0, 0,
+    ];
+    let map: HashMap<String, Option<String>> =
+        Decode::<Postgres>::decode(PgValue::from_bytes(&buf)).unwrap();
+
+    assert_eq!(map, pairs(&[("a", None), ("b", Some(""))]));
+}
+
+#[test]
+fn test_decode_hstore_text() {
+    assert_eq!(parse_hstore(""), Some(HashMap::new()));
+
+    assert_eq!(
+        parse_hstore(r#""a"=>"1", "b"=>NULL"#),
+        Some(pairs(&[("a", Some("1")), ("b", None)]))
+    );
+
+    assert_eq!(
+        parse_hstore(r#""b,\"c"=>"NULL", "d\\e"=>"=>""#),
+        Some(pairs(&[(r#"b,"c"#, Some("NULL")), (r#"d\e"#, Some("=>"))]))
+    );
+
+    assert_eq!(parse_hstore(r#""a"=>"1" "b"=>"2""#), None);
+    assert_eq!(parse_hstore(r#""a"=>"1"#), None);
+}
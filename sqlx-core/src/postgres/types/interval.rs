@@ -0,0 +1,843 @@
+use std::convert::TryFrom;
+use std::ops::{Add, Sub};
+
+use byteorder::BigEndian;
+
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::io::Buf;
+use crate::postgres::protocol::TypeId;
+use crate::postgres::{PgData, PgRawBuffer, PgTypeInfo, PgValue, Postgres};
+use crate::types::Type;
+
+const MICROSECONDS_PER_SECOND: i64 = 1_000_000;
+const MICROSECONDS_PER_MINUTE: i64 = 60 * MICROSECONDS_PER_SECOND;
+const MICROSECONDS_PER_HOUR: i64 = 60 * MICROSECONDS_PER_MINUTE;
+const MICROSECONDS_PER_DAY: i64 = 24 * MICROSECONDS_PER_HOUR;
+
+// used by Postgres when justifying intervals
+const DAYS_PER_MONTH: i64 = 30;
+
+/// A Postgres `INTERVAL`.
+///
+/// Postgres stores intervals as three independent fields; months and days are not
+/// converted to a fixed number of microseconds as their length varies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PgInterval {
+    pub months: i32,
+    pub days: i32,
+    pub microseconds: i64,
+}
+
+impl PgInterval {
+    /// An interval of the given number of months.
+    pub fn months(months: i32) -> Self {
+        PgInterval {
+            months,
+            ..Default::default()
+        }
+    }
+
+    /// An interval of the given number of days.
+    pub fn days(days: i32) -> Self {
+        PgInterval {
+            days,
+            ..Default::default()
+        }
+    }
+
+    /// An interval of the given number of hours.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of microseconds would overflow an `i64`.
+    pub fn hours(hours: i64) -> Self {
+        Self::builder()
+            .hours(hours)
+            .build()
+            .expect("PgInterval::hours out of bounds")
+    }
+
+    /// An interval of the given number of minutes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of microseconds would overflow an `i64`.
+    pub fn minutes(minutes: i64) -> Self {
+        Self::builder()
+            .minutes(minutes)
+            .build()
+            .expect("PgInterval::minutes out of bounds")
+    }
+
+    /// An interval of the given number of seconds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of microseconds would overflow an `i64`.
+    pub fn seconds(seconds: i64) -> Self {
+        Self::builder()
+            .seconds(seconds)
+            .build()
+            .expect("PgInterval::seconds out of bounds")
+    }
+
+    /// An interval of the given number of microseconds.
+    pub fn microseconds(microseconds: i64) -> Self {
+        PgInterval {
+            microseconds,
+            ..Default::default()
+        }
+    }
+
+    /// Combine several components into one interval.
+    ///
+    /// ```rust,ignore
+    /// // 1 mon 15 days 01:30:00
+    /// let interval = PgInterval::builder().months(1).days(15).minutes(90).build();
+    /// ```
+    pub fn builder() -> PgIntervalBuilder {
+        PgIntervalBuilder::default()
+    }
+
+    /// Add two intervals field by field, returning `None` if any field overflows.
+    ///
+    /// Like Postgres, this never converts between months, days and microseconds.
+    pub fn checked_add(&self, other: &PgInterval) -> Option<PgInterval> {
+        Some(PgInterval {
+            months: self.months.checked_add(other.months)?,
+            days: self.days.checked_add(other.days)?,
+            microseconds: self.microseconds.checked_add(other.microseconds)?,
+        })
+    }
+
+    /// Subtract two intervals field by field, returning `None` if any field overflows.
+    pub fn checked_sub(&self, other: &PgInterval) -> Option<PgInterval> {
+        Some(PgInterval {
+            months: self.months.checked_sub(other.months)?,
+            days: self.days.checked_sub(other.days)?,
+            microseconds: self.microseconds.checked_sub(other.microseconds)?,
+        })
+    }
+
+    /// Roll whole 24 hour periods into days, like `justify_hours()` in Postgres.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of days overflows an `i32`.
+    pub fn justify_hours(&self) -> PgInterval {
+        let mut interval = Justify::from(self);
+
+        interval.days_from_time();
+        interval.align_time();
+
+        interval.finish("justify_hours")
+    }
+
+    /// Roll whole 30 day periods into months, like `justify_days()` in Postgres.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of months overflows an `i32`.
+    pub fn justify_days(&self) -> PgInterval {
+        let mut interval = Justify::from(self);
+
+        interval.months_from_days();
+        interval.align_days(false);
+
+        interval.finish("justify_days")
+    }
+
+    /// Roll 24 hours into a day and 30 days into a month so that all fields share the same
+    /// sign, like `justify_interval()` in Postgres.
+    ///
+    /// This is useful when comparing intervals that represent the same duration but were
+    /// produced by different computations.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of days or months overflows an `i32`.
+    pub fn justify_interval(&self) -> PgInterval {
+        let mut interval = Justify::from(self);
+
+        interval.days_from_time();
+        interval.months_from_days();
+        interval.align_days(true);
+        interval.align_time();
+
+        interval.finish("justify_interval")
+    }
+}
+
+// Mirrors `interval_justify_*` in src/backend/utils/adt/timestamp.c
+struct Justify {
+    months: i64,
+    days: i64,
+    microseconds: i64,
+}
+
+impl From<&'_ PgInterval> for Justify {
+    fn from(interval: &PgInterval) -> Self {
+        Justify {
+            months: i64::from(interval.months),
+            days: i64::from(interval.days),
+            microseconds: interval.microseconds,
+        }
+    }
+}
+
+impl Justify {
+    fn days_from_time(&mut self) {
+        self.days += self.microseconds / MICROSECONDS_PER_DAY;
+        self.microseconds %= MICROSECONDS_PER_DAY;
+    }
+
+    fn months_from_days(&mut self) {
+        self.months += self.days / DAYS_PER_MONTH;
+        self.days %= DAYS_PER_MONTH;
+    }
+
+    // a remainder of days (or of time, if there are no days) should not have a different sign
+    // than the months
+    fn align_days(&mut self, with_time: bool) {
+        let time = if with_time { self.microseconds } else { 0 };
+
+        if self.months > 0 && (self.days < 0 || (self.days == 0 && time < 0)) {
+            self.days += DAYS_PER_MONTH;
+            self.months -= 1;
+        } else if self.months < 0 && (self.days > 0 || (self.days == 0 && time > 0)) {
+            self.days -= DAYS_PER_MONTH;
+            self.months += 1;
+        }
+    }
+
+    // a remainder of time should not have a different sign than the days
+    fn align_time(&mut self) {
+        if self.days > 0 && self.microseconds < 0 {
+            self.microseconds += MICROSECONDS_PER_DAY;
+            self.days -= 1;
+        } else if self.days < 0 && self.microseconds > 0 {
+            self.microseconds -= MICROSECONDS_PER_DAY;
+            self.days += 1;
+        }
+    }
+
+    fn finish(self, function: &str) -> PgInterval {
+        match (i32::try_from(self.months), i32::try_from(self.days)) {
+            (Ok(months), Ok(days)) => PgInterval {
+                months,
+                days,
+                microseconds: self.microseconds,
+            },
+
+            _ => panic!("PgInterval::{} out of bounds", function),
+        }
+    }
+}
+
+impl Add for PgInterval {
+    type Output = PgInterval;
+
+    fn add(self, other: PgInterval) -> PgInterval {
+        self.checked_add(&other)
+            .expect("overflow when adding intervals")
+    }
+}
+
+impl Sub for PgInterval {
+    type Output = PgInterval;
+
+    fn sub(self, other: PgInterval) -> PgInterval {
+        self.checked_sub(&other)
+            .expect("overflow when subtracting intervals")
+    }
+}
+
+/// Builder for [PgInterval].
+///
+/// Components are summed; [`build`] returns `None` if the result does not fit in an interval.
+///
+/// [`build`]: #method.build
+#[derive(Debug, Clone, Default)]
+pub struct PgIntervalBuilder {
+    months: i64,
+    days: i64,
+    microseconds: i128,
+}
+
+impl PgIntervalBuilder {
+    /// Add the given number of years.
+    pub fn years(mut self, years: i32) -> Self {
+        self.months += i64::from(years) * 12;
+        self
+    }
+
+    /// Add the given number of months.
+    pub fn months(mut self, months: i32) -> Self {
+        self.months += i64::from(months);
+        self
+    }
+
+    /// Add the given number of weeks.
+    pub fn weeks(mut self, weeks: i32) -> Self {
+        self.days += i64::from(weeks) * 7;
+        self
+    }
+
+    /// Add the given number of days.
+    pub fn days(mut self, days: i32) -> Self {
+        self.days += i64::from(days);
+        self
+    }
+
+    /// Add the given number of hours.
+    pub fn hours(mut self, hours: i64) -> Self {
+        self.microseconds += i128::from(hours) * i128::from(MICROSECONDS_PER_HOUR);
+        self
+    }
+
+    /// Add the given number of minutes.
+    pub fn minutes(mut self, minutes: i64) -> Self {
+        self.microseconds += i128::from(minutes) * i128::from(MICROSECONDS_PER_MINUTE);
+        self
+    }
+
+    /// Add the given number of seconds.
+    pub fn seconds(mut self, seconds: i64) -> Self {
+        self.microseconds += i128::from(seconds) * i128::from(MICROSECONDS_PER_SECOND);
+        self
+    }
+
+    /// Add the given number of microseconds.
+    pub fn microseconds(mut self, microseconds: i64) -> Self {
+        self.microseconds += i128::from(microseconds);
+        self
+    }
+
+    pub fn build(self) -> Option<PgInterval> {
+        Some(PgInterval {
+            months: i32::try_from(self.months).ok()?,
+            days: i32::try_from(self.days).ok()?,
+            microseconds: i64::try_from(self.microseconds).ok()?,
+        })
+    }
+}
+
+impl Type<Postgres> for PgInterval {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::INTERVAL, "INTERVAL")
+    }
+}
+
+impl Type<Postgres> for [PgInterval] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::ARRAY_INTERVAL, "INTERVAL[]")
+    }
+}
+
+impl Type<Postgres> for Vec<PgInterval> {
+    fn type_info() -> PgTypeInfo {
+        <[PgInterval] as Type<Postgres>>::type_info()
+    }
+}
+
+impl Encode<Postgres> for PgInterval {
+    fn encode(&self, buf: &mut PgRawBuffer) {
+        buf.extend_from_slice(&self.microseconds.to_be_bytes());
+        buf.extend_from_slice(&self.days.to_be_bytes());
+        buf.extend_from_slice(&self.months.to_be_bytes());
+    }
+
+    fn size_hint(&self) -> usize {
+        16
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for PgInterval {
+    fn decode(value: PgValue<'de>) -> crate::Result<Self> {
+        match value.try_get()? {
+            PgData::Binary(mut buf) => {
+                let microseconds = buf.get_i64::<BigEndian>()?;
+                let days = buf.get_i32::<BigEndian>()?;
+                let months = buf.get_i32::<BigEndian>()?;
+
+                Ok(PgInterval {
+                    months,
+                    days,
+                    microseconds,
+                })
+            }
+
+            PgData::Text(s) => {
+                parse(s).ok_or_else(|| decode_err!("invalid or out of range interval: {:?}", s))
+            }
+        }
+    }
+}
+
+// Parses the `postgres`, `postgres_verbose` and `iso_8601` values of `IntervalStyle`
+fn parse(s: &str) -> Option<PgInterval> {
+    let s = s.trim();
+
+    if s.is_empty() {
+        None
+    } else if let Some(s) = s.strip_prefix('P') {
+        parse_iso_8601(s)
+    } else {
+        parse_postgres(s)
+    }
+}
+
+// 1 year 2 mons -3 days 04:05:06.789
+// @ 1 year 2 mons 3 days 4 hours 5 mins 6.789 secs ago
+fn parse_postgres(s: &str) -> Option<PgInterval> {
+    let mut interval = Interval::default();
+    let mut tokens = s.split_whitespace().peekable();
+    let mut ago = false;
+
+    if tokens.peek() == Some(&"@") {
+        tokens.next();
+    }
+
+    while let Some(token) = tokens.next() {
+        if token == "ago" {
+            // `ago` may only appear once, at the very end
+            if tokens.next().is_some() {
+                return None;
+            }
+
+            ago = true;
+        } else if token.contains(':') {
+            interval.add_microseconds(parse_time(token)?)?;
+        } else {
+            let unit = tokens.next()?;
+
+            interval.add(token, unit.trim_end_matches('s'))?;
+        }
+    }
+
+    let interval = interval.finish()?;
+
+    if ago {
+        negate(interval)
+    } else {
+        Some(interval)
+    }
+}
+
+// P1Y2M3DT4H5M6.789S
+fn parse_iso_8601(s: &str) -> Option<PgInterval> {
+    let mut interval = Interval::default();
+
+    let (date, time) = match s.find('T') {
+        Some(index) => (&s[..index], Some(&s[index + 1..])),
+        None => (s, None),
+    };
+
+    for (value, designator) in iso_8601_components(date)? {
+        let unit = match designator {
+            'Y' => "year",
+            'M' => "mon",
+            'W' => "week",
+            'D' => "day",
+            _ => return None,
+        };
+
+        interval.add(value, unit)?;
+    }
+
+    if let Some(time) = time {
+        for (value, designator) in iso_8601_components(time)? {
+            let unit = match designator {
+                'H' => "hour",
+                'M' => "min",
+                'S' => "sec",
+                _ => return None,
+            };
+
+            interval.add(value, unit)?;
+        }
+    }
+
+    interval.finish()
+}
+
+// Splits `1Y-2M3D` into `[("1", 'Y'), ("-2", 'M'), ("3", 'D')]`
+fn iso_8601_components(mut s: &str) -> Option<Vec<(&str, char)>> {
+    let mut components = Vec::new();
+
+    while !s.is_empty() {
+        let index = s.find(|c: char| c.is_ascii_alphabetic())?;
+        let designator = s[index..].chars().next()?;
+
+        components.push((&s[..index], designator));
+        s = &s[index + 1..];
+    }
+
+    Some(components)
+}
+
+// [-]HH:MM[:SS[.ffffff]]; hours are not limited to 24
+fn parse_time(s: &str) -> Option<i64> {
+    let (negative, s) = split_sign(s);
+    let mut parts = s.split(':');
+
+    let hours: i64 = parts.next()?.parse().ok()?;
+    let minutes: i64 = parts.next()?.parse().ok()?;
+    let microseconds = match parts.next() {
+        Some(seconds) => parse_seconds(seconds)?,
+        None => 0,
+    };
+
+    if parts.next().is_some() {
+        return None;
+    }
+
+    let total = hours
+        .checked_mul(MICROSECONDS_PER_HOUR)?
+        .checked_add(minutes.checked_mul(MICROSECONDS_PER_MINUTE)?)?
+        .checked_add(microseconds)?;
+
+    Some(if negative { -total } else { total })
+}
+
+// Parses unsigned, possibly fractional, seconds into microseconds
+fn parse_seconds(s: &str) -> Option<i64> {
+    let (whole, fraction) = match s.find('.') {
+        Some(index) => (&s[..index], &s[index + 1..]),
+        None => (s, ""),
+    };
+
+    if whole.is_empty() || !whole.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    if !fraction.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let mut microseconds = whole
+        .parse::<i64>()
+        .ok()?
+        .checked_mul(MICROSECONDS_PER_SECOND)?;
+
+    // anything past microsecond precision is truncated
+    let mut scale = MICROSECONDS_PER_SECOND / 10;
+
+    for digit in fraction.bytes().take(6) {
+        microseconds += i64::from(digit - b'0') * scale;
+        scale /= 10;
+    }
+
+    Some(microseconds)
+}
+
+fn split_sign(s: &str) -> (bool, &str) {
+    if let Some(s) = s.strip_prefix('-') {
+        (true, s)
+    } else if let Some(s) = s.strip_prefix('+') {
+        (false, s)
+    } else {
+        (false, s)
+    }
+}
+
+fn negate(interval: PgInterval) -> Option<PgInterval> {
+    Some(PgInterval {
+        months: interval.months.checked_neg()?,
+        days: interval.days.checked_neg()?,
+        microseconds: interval.microseconds.checked_neg()?,
+    })
+}
+
+// Accumulates components in wider integers so out-of-range values are caught at the end
+#[derive(Default)]
+struct Interval {
+    months: i64,
+    days: i64,
+    microseconds: i64,
+}
+
+impl Interval {
+    fn add(&mut self, value: &str, unit: &str) -> Option<()> {
+        if unit == "sec" || unit == "second" {
+            let (negative, value) = split_sign(value);
+            let microseconds = parse_seconds(value)?;
+
+            return self.add_microseconds(if negative {
+                -microseconds
+            } else {
+                microseconds
+            });
+        }
+
+        let value: i64 = value.parse().ok()?;
+
+        match unit {
+            "year" => self.months = self.months.checked_add(value.checked_mul(12)?)?,
+            "mon" | "month" => self.months = self.months.checked_add(value)?,
+            "week" => self.days = self.days.checked_add(value.checked_mul(7)?)?,
+            "day" => self.days = self.days.checked_add(value)?,
+
+            "hour" => self.add_microseconds(value.checked_mul(MICROSECONDS_PER_HOUR)?)?,
+            "min" | "minute" => {
+                self.add_microseconds(value.checked_mul(MICROSECONDS_PER_MINUTE)?)?
+            }
+
+            _ => return None,
+        }
+
+        Some(())
+    }
+
+    fn add_microseconds(&mut self, value: i64) -> Option<()> {
+        self.microseconds = self.microseconds.checked_add(value)?;
+
+        Some(())
+    }
+
+    fn finish(self) -> Option<PgInterval> {
+        Some(PgInterval {
+            months: i32::try_from(self.months).ok()?,
+            days: i32::try_from(self.days).ok()?,
+            microseconds: self.microseconds,
+        })
+    }
+}
+
+#[cfg(test)]
+fn interval(months: i32, days: i32, microseconds: i64) -> PgInterval {
+    PgInterval {
+        months,
+        days,
+        microseconds,
+    }
+}
+
+#[test]
+fn test_encode_interval() {
+    let mut buf = PgRawBuffer::default();
+
+    Encode::<Postgres>::encode(&interval(0, 0, 0), &mut buf);
+    assert_eq!(&**buf, [0; 16]);
+    buf.clear();
+
+    Encode::<Postgres>::encode(&interval(1, 2, 3), &mut buf);
+    assert_eq!(&**buf, [0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 2, 0, 0, 0, 1]);
+    buf.clear();
+
+    Encode::<Postgres>::encode(&interval(0, -1, 0), &mut buf);
+    assert_eq!(
+        &**buf,
+        [0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 255, 255, 0, 0, 0, 0]
+    );
+}
+
+#[test]
+fn test_decode_interval() {
+    let buf = [0u8; 16];
+    let value: PgInterval = Decode::<Postgres>::decode(PgValue::from_bytes(&buf)).unwrap();
+    assert_eq!(value, interval(0, 0, 0));
+
+    let buf = [0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 2, 0, 0, 0, 1];
+    let value: PgInterval = Decode::<Postgres>::decode(PgValue::from_bytes(&buf)).unwrap();
+    assert_eq!(value, interval(1, 2, 3));
+}
+
+#[test]
+fn test_decode_interval_text() {
+    fn decode(s: &str) -> PgInterval {
+        Decode::<Postgres>::decode(PgValue::from_str(s)).unwrap()
+    }
+
+    assert_eq!(decode("00:00:00"), interval(0, 0, 0));
+    assert_eq!(decode("-3 days"), interval(0, -3, 0));
+    assert_eq!(
+        decode("1 day -02:00:00"),
+        interval(0, 1, -2 * MICROSECONDS_PER_HOUR)
+    );
+    assert_eq!(
+        decode("100:00:00"),
+        interval(0, 0, 100 * MICROSECONDS_PER_HOUR)
+    );
+    assert_eq!(decode("-1 years -2 mons"), interval(-14, 0, 0));
+    assert_eq!(
+        decode("1 year 2 mons 3 days 04:05:06.789"),
+        interval(14, 3, 14_706_789_000)
+    );
+    assert_eq!(decode("00:00:00.000001"), interval(0, 0, 1));
+
+    assert_eq!(
+        decode("@ 1 year 2 mons 3 days 4 hours 5 mins 6.789 secs ago"),
+        interval(-14, -3, -14_706_789_000)
+    );
+    assert_eq!(decode("@ 1 min -1 sec"), interval(0, 0, 59_000_000));
+
+    assert_eq!(decode("PT0S"), interval(0, 0, 0));
+    assert_eq!(
+        decode("P1Y2M3DT4H5M6.789S"),
+        interval(14, 3, 14_706_789_000)
+    );
+    assert_eq!(decode("P-3D"), interval(0, -3, 0));
+    assert_eq!(decode("P1MT-1H"), interval(1, 0, -MICROSECONDS_PER_HOUR));
+    assert_eq!(decode("P2W"), interval(0, 14, 0));
+}
+
+#[test]
+fn test_interval_constructors() {
+    assert_eq!(PgInterval::months(3), interval(3, 0, 0));
+    assert_eq!(PgInterval::days(-2), interval(0, -2, 0));
+    assert_eq!(PgInterval::hours(1), interval(0, 0, MICROSECONDS_PER_HOUR));
+    assert_eq!(
+        PgInterval::minutes(90),
+        interval(0, 0, 90 * MICROSECONDS_PER_MINUTE)
+    );
+    assert_eq!(
+        PgInterval::seconds(-1),
+        interval(0, 0, -MICROSECONDS_PER_SECOND)
+    );
+    assert_eq!(PgInterval::microseconds(7), interval(0, 0, 7));
+
+    assert_eq!(
+        PgInterval::builder()
+            .years(1)
+            .months(2)
+            .weeks(1)
+            .days(3)
+            .hours(4)
+            .minutes(5)
+            .seconds(6)
+            .microseconds(789_000)
+            .build(),
+        Some(interval(14, 10, 14_706_789_000))
+    );
+
+    assert_eq!(PgInterval::builder().build(), Some(interval(0, 0, 0)));
+    assert_eq!(PgInterval::builder().hours(i64::MAX).build(), None);
+    assert_eq!(
+        PgInterval::builder().months(i32::MAX).months(1).build(),
+        None
+    );
+
+    // components that overflow on their own may cancel out
+    assert_eq!(
+        PgInterval::builder()
+            .hours(i64::MAX)
+            .hours(-i64::MAX)
+            .build(),
+        Some(interval(0, 0, 0))
+    );
+}
+
+#[test]
+#[should_panic(expected = "PgInterval::hours out of bounds")]
+fn test_interval_constructor_overflow() {
+    PgInterval::hours(i64::MAX);
+}
+
+#[test]
+fn test_interval_arithmetic() {
+    // months are never normalized into days
+    assert_eq!(
+        PgInterval::months(1) + PgInterval::days(15),
+        interval(1, 15, 0)
+    );
+    assert_eq!(
+        PgInterval::days(1) - PgInterval::hours(24),
+        interval(0, 1, -24 * MICROSECONDS_PER_HOUR)
+    );
+    assert_eq!(
+        interval(1, 2, 3).checked_add(&interval(4, 5, 6)),
+        Some(interval(5, 7, 9))
+    );
+    assert_eq!(
+        interval(1, 2, 3).checked_sub(&interval(4, 5, 6)),
+        Some(interval(-3, -3, -3))
+    );
+
+    assert_eq!(
+        PgInterval::months(i32::MAX).checked_add(&PgInterval::months(1)),
+        None
+    );
+    assert_eq!(
+        PgInterval::days(i32::MIN).checked_sub(&PgInterval::days(1)),
+        None
+    );
+    assert_eq!(
+        PgInterval::microseconds(i64::MAX).checked_add(&PgInterval::microseconds(1)),
+        None
+    );
+}
+
+#[test]
+#[should_panic(expected = "overflow when adding intervals")]
+fn test_interval_add_overflow() {
+    let _ = PgInterval::months(i32::MAX) + PgInterval::months(1);
+}
+
+#[test]
+fn test_interval_justify() {
+    // SELECT justify_hours('27 hours'), justify_hours('-1 day 25 hours')
+    assert_eq!(
+        PgInterval::hours(27).justify_hours(),
+        interval(0, 1, 3 * MICROSECONDS_PER_HOUR)
+    );
+    assert_eq!(
+        interval(0, -1, 25 * MICROSECONDS_PER_HOUR).justify_hours(),
+        interval(0, 0, MICROSECONDS_PER_HOUR)
+    );
+    assert_eq!(
+        interval(0, 2, -MICROSECONDS_PER_HOUR).justify_hours(),
+        interval(0, 1, 23 * MICROSECONDS_PER_HOUR)
+    );
+
+    // SELECT justify_days('35 days'), justify_days('1 mon -1 hour'), justify_days('1 mon -2 days')
+    assert_eq!(PgInterval::days(35).justify_days(), interval(1, 5, 0));
+    assert_eq!(
+        interval(1, 0, -MICROSECONDS_PER_HOUR).justify_days(),
+        interval(1, 0, -MICROSECONDS_PER_HOUR)
+    );
+    assert_eq!(interval(1, -2, 0).justify_days(), interval(0, 28, 0));
+    assert_eq!(PgInterval::days(-65).justify_days(), interval(-2, -5, 0));
+
+    // SELECT justify_interval('1 mon -1 hour')
+    assert_eq!(
+        interval(1, 0, -MICROSECONDS_PER_HOUR).justify_interval(),
+        interval(0, 29, 23 * MICROSECONDS_PER_HOUR)
+    );
+    // SELECT justify_interval('-1 mon 1 hour')
+    assert_eq!(
+        interval(-1, 0, MICROSECONDS_PER_HOUR).justify_interval(),
+        interval(0, -29, -23 * MICROSECONDS_PER_HOUR)
+    );
+    // SELECT justify_interval('29 days 25 hours')
+    assert_eq!(
+        interval(0, 29, 25 * MICROSECONDS_PER_HOUR).justify_interval(),
+        interval(1, 0, MICROSECONDS_PER_HOUR)
+    );
+    assert_eq!(
+        PgInterval::default().justify_interval(),
+        PgInterval::default()
+    );
+}
+
+#[test]
+#[should_panic(expected = "PgInterval::justify_hours out of bounds")]
+fn test_interval_justify_overflow() {
+    interval(0, i32::MAX, MICROSECONDS_PER_DAY).justify_hours();
+}
+
+#[test]
+fn test_decode_interval_text_invalid() {
+    fn decode(s: &str) -> crate::Result<PgInterval> {
+        Decode::<Postgres>::decode(PgValue::from_str(s))
+    }
+
+    assert!(decode("").is_err());
+    assert!(decode("1").is_err());
+    assert!(decode("1 fortnight").is_err());
+    assert!(decode("1 day ago 1 hour").is_err());
+    assert!(decode("P1X").is_err());
+    assert!(decode("PT1.5H").is_err());
+    assert!(decode("3000000000 days").is_err());
+}
@@ -2,7 +2,9 @@ use crate::decode::Decode;
 use crate::encode::Encode;
 use crate::io::{Buf, BufMut};
 use crate::postgres::protocol::TypeId;
-use crate::postgres::{PgData, PgRawBuffer, PgTypeInfo, PgValue, Postgres};
+use crate::postgres::{PgData, PgRawBuffer, PgRow, PgTypeInfo, PgValue, Postgres};
+use crate::row::Row;
+use crate::types::json::JsonRow;
 use crate::types::{Json, Type};
 use crate::value::RawValue;
 use serde::{Deserialize, Serialize};
@@ -23,7 +25,7 @@ impl Type<Postgres> for JsonValue {
 
 impl Encode<Postgres> for JsonValue {
     fn encode(&self, buf: &mut PgRawBuffer) {
-        Json(self).encode(buf)
+        Encode::<Postgres>::encode(&Json(self), buf)
     }
 }
 
@@ -41,7 +43,7 @@ impl Type<Postgres> for &'_ JsonRawValue {
 
 impl Encode<Postgres> for &'_ JsonRawValue {
     fn encode(&self, buf: &mut PgRawBuffer) {
-        Json(self).encode(buf)
+        Encode::<Postgres>::encode(&Json(self), buf)
     }
 }
 
@@ -96,3 +98,59 @@ where
         .map_err(crate::Error::decode)
     }
 }
+
+impl<'c> JsonRow<'c> for PgRow<'c> {
+    fn column_to_json(&self, index: usize) -> crate::Result<JsonValue> {
+        let value = self.try_get_raw(index)?;
+
+        let data = match value.get() {
+            Some(data) => data,
+            None => return Ok(JsonValue::Null),
+        };
+
+        // the type of a value is only known from its column for the text format
+        let type_info = &self.statement.columns[index].type_info;
+
+        Ok(match type_info.id {
+            Some(TypeId::BOOL) => JsonValue::Bool(Decode::<Postgres>::decode(value)?),
+
+            Some(TypeId::INT2) => <i16 as Decode<Postgres>>::decode(value)?.into(),
+            Some(TypeId::INT4) => <i32 as Decode<Postgres>>::decode(value)?.into(),
+            Some(TypeId::INT8) => <i64 as Decode<Postgres>>::decode(value)?.into(),
+            Some(TypeId::OID) => <u32 as Decode<Postgres>>::decode(value)?.into(),
+
+            Some(TypeId::FLOAT4) => <f32 as Decode<Postgres>>::decode(value)?.into(),
+            Some(TypeId::FLOAT8) => <f64 as Decode<Postgres>>::decode(value)?.into(),
+
+            #[cfg(feature = "bigdecimal")]
+            Some(TypeId::NUMERIC) => JsonValue::String(
+                <bigdecimal::BigDecimal as Decode<Postgres>>::decode(value)?.to_string(),
+            ),
+
+            Some(TypeId::TEXT)
+            | Some(TypeId::VARCHAR)
+            | Some(TypeId::BPCHAR)
+            | Some(TypeId::NAME)
+            | Some(TypeId::UNKNOWN) => JsonValue::String(Decode::<Postgres>::decode(value)?),
+
+            Some(TypeId::BYTEA) => {
+                let bytes: Vec<u8> = Decode::<Postgres>::decode(value)?;
+
+                JsonValue::String(base64::encode(&bytes))
+            }
+
+            Some(TypeId::JSON) | Some(TypeId::JSONB) => Decode::<Postgres>::decode(value)?,
+
+            _ => match data {
+                PgData::Text(s) => JsonValue::String(s.to_owned()),
+
+                PgData::Binary(_) => {
+                    return Err(decode_err!(
+                        "unsupported conversion of {} in the binary format to JSON",
+                        type_info
+                    ));
+                }
+            },
+        })
+    }
+}
@@ -0,0 +1,113 @@
+use std::fmt::{self, Display};
+
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::postgres::protocol::TypeId;
+use crate::postgres::{PgData, PgRawBuffer, PgTypeInfo, PgValue, Postgres};
+use crate::types::Type;
+
+// the binary format is a version byte followed by the text format
+const VERSION: u8 = 1;
+
+/// A Postgres `JSONPATH`, a query for `jsonb_path_query` and related functions such as
+/// `$.a[*] ? (@ > 2)`.
+///
+/// The path is parsed by Postgres, which normalizes it when it is returned; the path above is
+/// decoded as `$."a"[*]?(@ > 2)`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct PgJsonPath(pub String);
+
+impl Display for PgJsonPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<String> for PgJsonPath {
+    fn from(path: String) -> Self {
+        PgJsonPath(path)
+    }
+}
+
+impl From<&'_ str> for PgJsonPath {
+    fn from(path: &str) -> Self {
+        PgJsonPath(path.to_owned())
+    }
+}
+
+impl Type<Postgres> for PgJsonPath {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::JSONPATH, "JSONPATH")
+    }
+}
+
+impl Type<Postgres> for [PgJsonPath] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::ARRAY_JSONPATH, "JSONPATH[]")
+    }
+}
+
+impl Type<Postgres> for Vec<PgJsonPath> {
+    fn type_info() -> PgTypeInfo {
+        <[PgJsonPath] as Type<Postgres>>::type_info()
+    }
+}
+
+impl Encode<Postgres> for PgJsonPath {
+    fn encode(&self, buf: &mut PgRawBuffer) {
+        buf.push(VERSION);
+        buf.extend_from_slice(self.0.as_bytes());
+    }
+
+    fn size_hint(&self) -> usize {
+        1 + self.0.len()
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for PgJsonPath {
+    fn decode(value: PgValue<'de>) -> crate::Result<Self> {
+        let s = match value.try_get()? {
+            PgData::Binary(buf) => match buf.split_first() {
+                Some((&VERSION, s)) => std::str::from_utf8(s).map_err(crate::Error::decode)?,
+                Some((version, _)) => {
+                    return Err(decode_err!(
+                        "unsupported JSONPATH format version {}",
+                        version
+                    ));
+                }
+                None => return Err(crate::Error::Decode("missing JSONPATH version".into())),
+            },
+
+            PgData::Text(s) => s,
+        };
+
+        Ok(PgJsonPath(s.to_owned()))
+    }
+}
+
+#[test]
+fn test_encode_json_path() {
+    let mut buf = PgRawBuffer::default();
+    Encode::<Postgres>::encode(&PgJsonPath::from("$.a"), &mut buf);
+
+    assert_eq!(&**buf, b"\x01$.a");
+}
+
+#[test]
+fn test_decode_json_path() {
+    let path: PgJsonPath = Decode::<Postgres>::decode(PgValue::from_bytes(b"\x01$.\"a\"")).unwrap();
+
+    assert_eq!(path, PgJsonPath::from("$.\"a\""));
+
+    let path: PgJsonPath = Decode::<Postgres>::decode(PgValue::from_str("$.\"a\"")).unwrap();
+
+    assert_eq!(path, PgJsonPath::from("$.\"a\""));
+
+    let res: crate::Result<PgJsonPath> = Decode::<Postgres>::decode(PgValue::from_bytes(b"\x02$"));
+
+    assert!(res.is_err());
+
+    let res: crate::Result<PgJsonPath> = Decode::<Postgres>::decode(PgValue::from_bytes(b""));
+
+    assert!(res.is_err());
+}
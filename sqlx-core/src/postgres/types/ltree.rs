@@ -0,0 +1,271 @@
+//! The [`ltree`](https://www.postgresql.org/docs/current/ltree.html) extension types.
+
+use std::fmt::{self, Display};
+use std::ops::Deref;
+use std::str::FromStr;
+
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::postgres::{PgData, PgRawBuffer, PgTypeInfo, PgValue, Postgres};
+use crate::types::Type;
+
+// the binary format of both types is a version byte followed by the text format
+const VERSION: u8 = 1;
+
+/// A Postgres `LTREE`, a path of labels such as `Top.Science.Astronomy`.
+///
+/// Labels may only contain the characters `A-Za-z0-9_` and must not be empty. This is checked
+/// whenever a label is added so that a `PgLTree` can always be sent to Postgres.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct PgLTree {
+    labels: Vec<String>,
+}
+
+/// A Postgres `LQUERY`, a pattern for matching a [`PgLTree`] such as `Top.*.Astronomy`.
+///
+/// The pattern is validated by Postgres.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct PgLQuery(pub String);
+
+/// The error returned when a label of a [`PgLTree`] is invalid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PgLTreeParseError {
+    /// A label was empty, e.g. in `Top..Astronomy`.
+    EmptyLabel,
+
+    /// A label contained a character outside of `A-Za-z0-9_`.
+    InvalidLabel(String),
+}
+
+impl PgLTree {
+    /// Create an empty path.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a path from a list of labels.
+    pub fn from_labels<I, S>(labels: I) -> Result<Self, PgLTreeParseError>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut ltree = Self::new();
+
+        for label in labels {
+            ltree.push(label)?;
+        }
+
+        Ok(ltree)
+    }
+
+    /// Append a label to the end of the path.
+    pub fn push(&mut self, label: impl Into<String>) -> Result<(), PgLTreeParseError> {
+        let label = label.into();
+
+        if label.is_empty() {
+            return Err(PgLTreeParseError::EmptyLabel);
+        }
+
+        if !label
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || ch == '_')
+        {
+            return Err(PgLTreeParseError::InvalidLabel(label));
+        }
+
+        self.labels.push(label);
+
+        Ok(())
+    }
+
+    /// Remove the last label of the path, if any.
+    pub fn pop(&mut self) -> Option<String> {
+        self.labels.pop()
+    }
+
+    /// Unwrap the labels of the path.
+    pub fn into_labels(self) -> Vec<String> {
+        self.labels
+    }
+}
+
+impl Deref for PgLTree {
+    type Target = [String];
+
+    fn deref(&self) -> &[String] {
+        &self.labels
+    }
+}
+
+impl IntoIterator for PgLTree {
+    type Item = String;
+    type IntoIter = std::vec::IntoIter<String>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.labels.into_iter()
+    }
+}
+
+impl FromStr for PgLTree {
+    type Err = PgLTreeParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Ok(Self::new());
+        }
+
+        Self::from_labels(s.split('.'))
+    }
+}
+
+impl Display for PgLTree {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, label) in self.labels.iter().enumerate() {
+            if i > 0 {
+                f.write_str(".")?;
+            }
+
+            f.write_str(label)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for PgLQuery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Display for PgLTreeParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PgLTreeParseError::EmptyLabel => f.write_str("empty label in LTREE"),
+            PgLTreeParseError::InvalidLabel(label) => {
+                write!(f, "invalid label {:?} in LTREE", label)
+            }
+        }
+    }
+}
+
+impl std::error::Error for PgLTreeParseError {}
+
+// `ltree` is defined by an extension so its OID differs between databases
+impl Type<Postgres> for PgLTree {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("ltree")
+    }
+}
+
+impl Type<Postgres> for PgLQuery {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("lquery")
+    }
+}
+
+impl Encode<Postgres> for PgLTree {
+    fn encode(&self, buf: &mut PgRawBuffer) {
+        buf.push(VERSION);
+        buf.extend_from_slice(self.to_string().as_bytes());
+    }
+
+    fn size_hint(&self) -> usize {
+        1 + self
+            .labels
+            .iter()
+            .map(|label| label.len() + 1)
+            .sum::<usize>()
+    }
+}
+
+impl Encode<Postgres> for PgLQuery {
+    fn encode(&self, buf: &mut PgRawBuffer) {
+        buf.push(VERSION);
+        buf.extend_from_slice(self.0.as_bytes());
+    }
+
+    fn size_hint(&self) -> usize {
+        1 + self.0.len()
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for PgLTree {
+    fn decode(value: PgValue<'de>) -> crate::Result<Self> {
+        let s = decode_versioned_str(value)?;
+
+        // the labels are not validated again as newer versions of Postgres may allow more
+        // characters than are accepted here
+        let labels = if s.is_empty() {
+            Vec::new()
+        } else {
+            s.split('.').map(str::to_owned).collect()
+        };
+
+        Ok(PgLTree { labels })
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for PgLQuery {
+    fn decode(value: PgValue<'de>) -> crate::Result<Self> {
+        decode_versioned_str(value).map(|s| PgLQuery(s.to_owned()))
+    }
+}
+
+fn decode_versioned_str(value: PgValue<'_>) -> crate::Result<&str> {
+    match value.try_get()? {
+        PgData::Binary(buf) => match buf.split_first() {
+            Some((&VERSION, s)) => std::str::from_utf8(s).map_err(crate::Error::decode),
+            Some((version, _)) => Err(decode_err!("unsupported version {}", version)),
+            None => Err(crate::Error::Decode("missing version".into())),
+        },
+
+        PgData::Text(s) => Ok(s),
+    }
+}
+
+#[test]
+fn test_ltree_labels() {
+    let ltree = PgLTree::from_labels(vec!["Top", "Science", "Astronomy"]).unwrap();
+
+    assert_eq!(&*ltree, ["Top", "Science", "Astronomy"]);
+    assert_eq!(ltree.to_string(), "Top.Science.Astronomy");
+    assert_eq!("Top.Science.Astronomy".parse(), Ok(ltree));
+    assert_eq!("".parse(), Ok(PgLTree::new()));
+
+    assert_eq!(
+        "Top..Astronomy".parse::<PgLTree>(),
+        Err(PgLTreeParseError::EmptyLabel)
+    );
+
+    assert_eq!(
+        PgLTree::from_labels(vec!["Top", "Sci ence"]),
+        Err(PgLTreeParseError::InvalidLabel("Sci ence".into()))
+    );
+}
+
+#[test]
+fn test_encode_ltree() {
+    let ltree = PgLTree::from_labels(vec!["a", "b_1"]).unwrap();
+
+    let mut buf = PgRawBuffer::default();
+    Encode::<Postgres>::encode(&ltree, &mut buf);
+
+    assert_eq!(&**buf, b"\x01a.b_1");
+    assert!(Encode::<Postgres>::size_hint(&ltree) >= buf.len());
+}
+
+#[test]
+fn test_decode_ltree() {
+    let ltree: PgLTree = Decode::<Postgres>::decode(PgValue::from_bytes(b"\x01a.b_1")).unwrap();
+
+    assert_eq!(&*ltree, ["a", "b_1"]);
+
+    let res: crate::Result<PgLTree> = Decode::<Postgres>::decode(PgValue::from_bytes(b"\x02a"));
+
+    assert!(res.is_err());
+
+    let lquery: PgLQuery = Decode::<Postgres>::decode(PgValue::from_bytes(b"\x01a.*")).unwrap();
+
+    assert_eq!(lquery, PgLQuery("a.*".into()));
+}
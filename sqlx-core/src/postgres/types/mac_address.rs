@@ -0,0 +1,171 @@
+//! Postgres [MAC address types](https://www.postgresql.org/docs/current/datatype-net-types.html#DATATYPE-MACADDR).
+
+use std::fmt::{self, Display};
+
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::postgres::protocol::TypeId;
+use crate::postgres::{PgData, PgRawBuffer, PgTypeInfo, PgValue, Postgres};
+use crate::types::Type;
+
+/// A Postgres `MACADDR`, a 6 byte (EUI-48) MAC address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct PgMacAddress(pub [u8; 6]);
+
+/// A Postgres `MACADDR8`, an 8 byte (EUI-64) MAC address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct PgMacAddress8(pub [u8; 8]);
+
+macro_rules! impl_mac_address {
+    ($ty:ident, $len:literal, $id:ident, $array_id:ident, $name:literal) => {
+        impl Type<Postgres> for $ty {
+            fn type_info() -> PgTypeInfo {
+                PgTypeInfo::new(TypeId::$id, $name)
+            }
+        }
+
+        impl Type<Postgres> for [$ty] {
+            fn type_info() -> PgTypeInfo {
+                PgTypeInfo::new(TypeId::$array_id, concat!($name, "[]"))
+            }
+        }
+
+        impl Type<Postgres> for Vec<$ty> {
+            fn type_info() -> PgTypeInfo {
+                <[$ty] as Type<Postgres>>::type_info()
+            }
+        }
+
+        impl Encode<Postgres> for $ty {
+            fn encode(&self, buf: &mut PgRawBuffer) {
+                buf.extend_from_slice(&self.0);
+            }
+
+            fn size_hint(&self) -> usize {
+                $len
+            }
+        }
+
+        impl<'de> Decode<'de, Postgres> for $ty {
+            fn decode(value: PgValue<'de>) -> crate::Result<Self> {
+                let mut octets = [0; $len];
+
+                match value.try_get()? {
+                    PgData::Binary(buf) => {
+                        // the other width has a different length so it can never be misread
+                        if buf.len() != $len {
+                            return Err(decode_err!(
+                                "expected {} bytes for {} but received {}",
+                                $len,
+                                $name,
+                                buf.len()
+                            ));
+                        }
+
+                        octets.copy_from_slice(buf);
+                    }
+
+                    PgData::Text(s) => {
+                        if !parse_mac_address(s, &mut octets) {
+                            return Err(decode_err!("invalid {}: {:?}", $name, s));
+                        }
+                    }
+                }
+
+                Ok($ty(octets))
+            }
+        }
+
+        impl Display for $ty {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                for (i, octet) in self.0.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(":")?;
+                    }
+
+                    write!(f, "{:02x}", octet)?;
+                }
+
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_mac_address!(PgMacAddress, 6, MACADDR, ARRAY_MACADDR, "MACADDR");
+impl_mac_address!(PgMacAddress8, 8, MACADDR8, ARRAY_MACADDR8, "MACADDR8");
+
+// Postgres always outputs `xx:xx:xx:xx:xx:xx` with lowercase hex digits
+fn parse_mac_address(s: &str, octets: &mut [u8]) -> bool {
+    let mut parts = s.split(':');
+
+    for octet in octets.iter_mut() {
+        match parts.next() {
+            Some(part) if part.len() == 2 => match u8::from_str_radix(part, 16) {
+                Ok(value) => *octet = value,
+                Err(_) => return false,
+            },
+
+            _ => return false,
+        }
+    }
+
+    parts.next().is_none()
+}
+
+#[test]
+fn test_encode_mac_address() {
+    let mut buf = PgRawBuffer::default();
+    Encode::<Postgres>::encode(&PgMacAddress([8, 0, 0x2b, 1, 2, 3]), &mut buf);
+
+    assert_eq!(&**buf, [8, 0, 0x2b, 1, 2, 3]);
+
+    let mut buf = PgRawBuffer::default();
+    Encode::<Postgres>::encode(&PgMacAddress8([8, 0, 0x2b, 1, 2, 3, 4, 5]), &mut buf);
+
+    assert_eq!(&**buf, [8, 0, 0x2b, 1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn test_decode_mac_address() {
+    let addr: PgMacAddress =
+        Decode::<Postgres>::decode(PgValue::from_bytes(&[8, 0, 0x2b, 1, 2, 3])).unwrap();
+
+    assert_eq!(addr, PgMacAddress([8, 0, 0x2b, 1, 2, 3]));
+
+    let res: crate::Result<PgMacAddress> =
+        Decode::<Postgres>::decode(PgValue::from_bytes(&[8, 0, 0x2b, 1, 2, 3, 4, 5]));
+
+    assert!(res.is_err());
+
+    let res: crate::Result<PgMacAddress8> =
+        Decode::<Postgres>::decode(PgValue::from_bytes(&[8, 0, 0x2b, 1, 2, 3]));
+
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_decode_mac_address_text() {
+    let mut octets = [0; 6];
+
+    assert!(parse_mac_address("08:00:2b:01:02:0f", &mut octets));
+    assert_eq!(octets, [8, 0, 0x2b, 1, 2, 0xf]);
+
+    assert!(!parse_mac_address("08:00:2b:01:02", &mut octets));
+    assert!(!parse_mac_address("08:00:2b:01:02:03:04:05", &mut octets));
+    assert!(!parse_mac_address("08:00:2b:01:02:3", &mut octets));
+    assert!(!parse_mac_address("08:00:2b:01:02:zz", &mut octets));
+}
+
+#[test]
+fn test_display_mac_address() {
+    assert_eq!(
+        PgMacAddress([8, 0, 0x2b, 1, 2, 0xf]).to_string(),
+        "08:00:2b:01:02:0f"
+    );
+
+    assert_eq!(
+        PgMacAddress8([8, 0, 0x2b, 1, 2, 3, 4, 5]).to_string(),
+        "08:00:2b:01:02:03:04:05"
+    );
+}
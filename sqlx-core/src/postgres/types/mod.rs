@@ -12,6 +12,43 @@
 //! | `f64`                                 | DOUBLE PRECISION, FLOAT8                             |
 //! | `&str`, `String`                      | VARCHAR, CHAR(N), TEXT, NAME                         |
 //! | `&[u8]`, `Vec<u8>`                    | BYTEA                                                |
+//! | [`PgInterval`]                        | INTERVAL                                             |
+//! | [`PgPoint`]                           | POINT                                                |
+//! | [`PgBox`]                             | BOX                                                  |
+//! | [`PgPath`]                            | PATH                                                 |
+//! | [`PgPolygon`]                         | POLYGON                                              |
+//! | [`PgRange<i32>`]                      | INT4RANGE                                            |
+//! | [`PgRange<i64>`]                      | INT8RANGE                                            |
+//! | `IpAddr`, `Ipv4Addr`, `Ipv6Addr`      | INET, CIDR                                           |
+//! | `std::time::SystemTime`               | TIMESTAMPTZ                                          |
+//! | [`PgMacAddress`]                      | MACADDR                                              |
+//! | [`PgMacAddress8`]                     | MACADDR8                                             |
+//! | [`PgMoney`]                           | MONEY                                                |
+//! | [`PgLTree`]                           | LTREE (requires the `ltree` extension)               |
+//! | [`PgLQuery`]                          | LQUERY (requires the `ltree` extension)              |
+//! | [`PgJsonPath`]                        | JSONPATH                                             |
+//! | [`PgCube`]                            | CUBE (requires the `cube` extension)                 |
+//! | [`PgCiText`]                          | CITEXT (requires the `citext` extension)             |
+//! | [`PgTsVector`]                        | TSVECTOR                                             |
+//! | [`PgTsQuery`]                         | TSQUERY                                              |
+//!
+//! [`PgInterval`]: crate::postgres::types::PgInterval
+//! [`PgPoint`]: crate::postgres::types::PgPoint
+//! [`PgBox`]: crate::postgres::types::PgBox
+//! [`PgPath`]: crate::postgres::types::PgPath
+//! [`PgPolygon`]: crate::postgres::types::PgPolygon
+//! [`PgRange<i32>`]: crate::postgres::types::PgRange
+//! [`PgRange<i64>`]: crate::postgres::types::PgRange
+//! [`PgMacAddress`]: crate::postgres::types::PgMacAddress
+//! [`PgMacAddress8`]: crate::postgres::types::PgMacAddress8
+//! [`PgMoney`]: crate::postgres::types::PgMoney
+//! [`PgLTree`]: crate::postgres::types::PgLTree
+//! [`PgLQuery`]: crate::postgres::types::PgLQuery
+//! [`PgJsonPath`]: crate::postgres::types::PgJsonPath
+//! [`PgCube`]: crate::postgres::types::PgCube
+//! [`PgCiText`]: crate::postgres::types::PgCiText
+//! [`PgTsVector`]: crate::postgres::types::PgTsVector
+//! [`PgTsQuery`]: crate::postgres::types::PgTsQuery
 //!
 //! ### [`chrono`](https://crates.io/crates/chrono)
 //!
@@ -24,6 +61,9 @@
 //! | `chrono::NaiveDateTime`               | TIMESTAMP                                            |
 //! | `chrono::NaiveDate`                   | DATE                                                 |
 //! | `chrono::NaiveTime`                   | TIME                                                 |
+//! | `PgRange<chrono::DateTime<Utc>>`      | TSTZRANGE                                            |
+//! | `PgRange<chrono::NaiveDateTime>`      | TSRANGE                                              |
+//! | `PgRange<chrono::NaiveDate>`          | DATERANGE                                            |
 //!
 //! ### [`time`](https://crates.io/crates/time)
 //!
@@ -35,6 +75,9 @@
 //! | `time::OffsetDateTime`                | TIMESTAMPTZ                                          |
 //! | `time::Date`                          | DATE                                                 |
 //! | `time::Time`                          | TIME                                                 |
+//! | `PgRange<time::OffsetDateTime>`       | TSTZRANGE                                            |
+//! | `PgRange<time::PrimitiveDateTime>`    | TSRANGE                                              |
+//! | `PgRange<time::Date>`                 | DATERANGE                                            |
 //!
 //! ### [`uuid`](https://crates.io/crates/uuid)
 //!
@@ -52,6 +95,22 @@
 //! |---------------------------------------|------------------------------------------------------|
 //! | `ipnetwork::IpNetwork`                | INET, CIDR                                           |
 //!
+//! ### [`hstore`](https://www.postgresql.org/docs/current/hstore.html)
+//!
+//! Requires the `hstore` Cargo feature flag and the `hstore` extension in the database.
+//!
+//! | Rust type                             | Postgres type(s)                                     |
+//! |---------------------------------------|------------------------------------------------------|
+//! | `HashMap<String, Option<String>>`     | HSTORE                                               |
+//!
+//! ### [`bitvec`](https://crates.io/crates/bitvec)
+//!
+//! Requires the `bitvec` Cargo feature flag.
+//!
+//! | Rust type                             | Postgres type(s)                                     |
+//! |---------------------------------------|------------------------------------------------------|
+//! | `bitvec::vec::BitVec<Msb0, u8>`       | BIT, VARBIT                                          |
+//!
 //! ### [`json`](https://crates.io/crates/serde_json)
 //!
 //! Requires the `json` Cargo feature flag.
@@ -91,6 +150,9 @@
 //! }
 //! ```
 //!
+//! Fields are read and written in the order they are declared. A field may be given the name
+//! of its attribute with `#[sqlx(rename = "..")]`, which is used in decode errors.
+//!
 //! Anonymous composite types are represented as tuples. Note that anonymous composites may only
 //! be returned and not sent to Postgres (this is a limitation of postgres).
 //!
@@ -98,6 +160,9 @@
 //!
 //! One-dimensional arrays are supported as `Vec<T>` or `&[T]` where `T` implements `Type`.
 //!
+//! Multi-dimensional arrays are supported as nested vectors, e.g. `Vec<Vec<i32>>` for a
+//! two-dimensional `INT4[]`. Every row must have the same length.
+//!
 //! # [Enumerations](https://www.postgresql.org/docs/current/datatype-enum.html)
 //!
 //! User-defined enumerations are supported through a derive for `Type`.
@@ -135,10 +200,33 @@ use crate::postgres::{PgValue, Postgres};
 mod array;
 mod bool;
 mod bytes;
+mod citext;
+mod cube;
 mod float;
+mod geometry;
 mod int;
+mod interval;
+mod json_path;
+mod ltree;
+mod mac_address;
+mod money;
+mod net;
+mod range;
 mod record;
 mod str;
+mod system_time;
+mod text_search;
+
+pub use citext::PgCiText;
+pub use cube::PgCube;
+pub use geometry::{PgBox, PgPath, PgPoint, PgPolygon};
+pub use interval::{PgInterval, PgIntervalBuilder};
+pub use json_path::PgJsonPath;
+pub use ltree::{PgLQuery, PgLTree, PgLTreeParseError};
+pub use mac_address::{PgMacAddress, PgMacAddress8};
+pub use money::PgMoney;
+pub use range::PgRange;
+pub use text_search::{PgTsLexeme, PgTsQuery, PgTsVector, PgTsWeight};
 
 // internal types used by other types to encode or decode related formats
 #[doc(hidden)]
@@ -162,6 +250,12 @@ mod json;
 #[cfg(feature = "ipnetwork")]
 mod ipnetwork;
 
+#[cfg(feature = "hstore")]
+mod hstore;
+
+#[cfg(feature = "bitvec")]
+mod bitvec;
+
 // Implement `Decode` for all postgres types
 // The concept of a nullable `RawValue` is db-specific
 // `Type` is implemented generically at src/types.rs
@@ -170,7 +264,7 @@ where
     T: Decode<'de, Postgres>,
 {
     fn decode(value: PgValue<'de>) -> crate::Result<Self> {
-        Ok(if value.get().is_some() {
+        Ok(if !value.is_null() {
             Some(<T as Decode<Postgres>>::decode(value)?)
         } else {
             None
@@ -196,6 +290,8 @@ pub(crate) fn try_resolve_type_name(oid: u32) -> Option<&'static str> {
 
         TypeId::NUMERIC => "NUMERIC",
 
+        TypeId::MONEY => "MONEY",
+
         TypeId::TEXT => "TEXT",
         TypeId::VARCHAR => "VARCHAR",
         TypeId::BPCHAR => "BPCHAR",
@@ -206,14 +302,36 @@ pub(crate) fn try_resolve_type_name(oid: u32) -> Option<&'static str> {
         TypeId::TIME => "TIME",
         TypeId::TIMESTAMP => "TIMESTAMP",
         TypeId::TIMESTAMPTZ => "TIMESTAMPTZ",
+        TypeId::INTERVAL => "INTERVAL",
 
         TypeId::BYTEA => "BYTEA",
 
+        TypeId::BIT => "BIT",
+        TypeId::VARBIT => "VARBIT",
+
         TypeId::UUID => "UUID",
 
         TypeId::CIDR => "CIDR",
         TypeId::INET => "INET",
 
+        TypeId::MACADDR => "MACADDR",
+        TypeId::MACADDR8 => "MACADDR8",
+
+        TypeId::POINT => "POINT",
+        TypeId::PATH => "PATH",
+        TypeId::BOX => "BOX",
+        TypeId::POLYGON => "POLYGON",
+
+        TypeId::INT4RANGE => "INT4RANGE",
+        TypeId::INT8RANGE => "INT8RANGE",
+        TypeId::NUMRANGE => "NUMRANGE",
+        TypeId::TSRANGE => "TSRANGE",
+        TypeId::TSTZRANGE => "TSTZRANGE",
+        TypeId::DATERANGE => "DATERANGE",
+
+        TypeId::TSVECTOR => "TSVECTOR",
+        TypeId::TSQUERY => "TSQUERY",
+
         TypeId::ARRAY_BOOL => "BOOL[]",
 
         TypeId::ARRAY_CHAR => "\"CHAR\"[]",
@@ -234,20 +352,37 @@ pub(crate) fn try_resolve_type_name(oid: u32) -> Option<&'static str> {
 
         TypeId::ARRAY_NUMERIC => "NUMERIC[]",
 
+        TypeId::ARRAY_MONEY => "MONEY[]",
+
         TypeId::ARRAY_DATE => "DATE[]",
         TypeId::ARRAY_TIME => "TIME[]",
         TypeId::ARRAY_TIMESTAMP => "TIMESTAMP[]",
         TypeId::ARRAY_TIMESTAMPTZ => "TIMESTAMPTZ[]",
+        TypeId::ARRAY_INTERVAL => "INTERVAL[]",
 
         TypeId::ARRAY_BYTEA => "BYTEA[]",
 
+        TypeId::ARRAY_BIT => "BIT[]",
+        TypeId::ARRAY_VARBIT => "VARBIT[]",
+
         TypeId::ARRAY_UUID => "UUID[]",
 
         TypeId::ARRAY_CIDR => "CIDR[]",
         TypeId::ARRAY_INET => "INET[]",
 
+        TypeId::ARRAY_MACADDR => "MACADDR[]",
+        TypeId::ARRAY_MACADDR8 => "MACADDR8[]",
+
+        TypeId::ARRAY_POINT => "POINT[]",
+
+        TypeId::ARRAY_TSVECTOR => "TSVECTOR[]",
+        TypeId::ARRAY_TSQUERY => "TSQUERY[]",
+
         TypeId::JSON => "JSON",
         TypeId::JSONB => "JSONB",
+        TypeId::JSONPATH => "JSONPATH",
+
+        TypeId::ARRAY_JSONPATH => "JSONPATH[]",
 
         TypeId::RECORD => "RECORD",
         TypeId::ARRAY_RECORD => "RECORD[]",
@@ -0,0 +1,160 @@
+use byteorder::{BigEndian, ByteOrder};
+
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::postgres::protocol::TypeId;
+use crate::postgres::{PgData, PgRawBuffer, PgTypeInfo, PgValue, Postgres};
+use crate::types::Type;
+
+/// A Postgres `MONEY`, the raw amount in the smallest unit of the currency.
+///
+/// The number of fraction digits is determined by the `lc_monetary` setting of the server
+/// and is not sent over the wire, so `PgMoney` holds the amount exactly as it is stored. With
+/// the common scale of `2`, `$123.45` is `PgMoney(12345)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct PgMoney(pub i64);
+
+#[cfg(feature = "bigdecimal")]
+impl PgMoney {
+    /// Convert the amount to a decimal with `scale` fraction digits.
+    pub fn to_decimal(self, scale: i64) -> bigdecimal::BigDecimal {
+        bigdecimal::BigDecimal::new(self.0.into(), scale)
+    }
+
+    /// Convert a decimal to an amount with `scale` fraction digits.
+    ///
+    /// Any further fraction digits are truncated. Returns `None` if the amount does not fit
+    /// in an `i64`.
+    pub fn from_decimal(decimal: &bigdecimal::BigDecimal, scale: i64) -> Option<Self> {
+        use bigdecimal::ToPrimitive;
+
+        let (digits, _) = decimal.with_scale(scale).into_bigint_and_exponent();
+
+        digits.to_i64().map(PgMoney)
+    }
+}
+
+impl Type<Postgres> for PgMoney {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::MONEY, "MONEY")
+    }
+}
+
+impl Type<Postgres> for [PgMoney] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::ARRAY_MONEY, "MONEY[]")
+    }
+}
+
+impl Type<Postgres> for Vec<PgMoney> {
+    fn type_info() -> PgTypeInfo {
+        <[PgMoney] as Type<Postgres>>::type_info()
+    }
+}
+
+impl Encode<Postgres> for PgMoney {
+    fn encode(&self, buf: &mut PgRawBuffer) {
+        <i64 as Encode<Postgres>>::encode(&self.0, buf);
+    }
+
+    fn size_hint(&self) -> usize {
+        8
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for PgMoney {
+    fn decode(value: PgValue<'de>) -> crate::Result<Self> {
+        match value.try_get()? {
+            PgData::Binary(buf) => {
+                if buf.len() != 8 {
+                    return Err(decode_err!(
+                        "expected 8 bytes for MONEY but received {}",
+                        buf.len()
+                    ));
+                }
+
+                Ok(PgMoney(BigEndian::read_i64(buf)))
+            }
+
+            PgData::Text(s) => parse_money(s).ok_or_else(|| decode_err!("invalid MONEY: {:?}", s)),
+        }
+    }
+}
+
+// the text format depends on the locale (e.g. `-$1,234.50`) but always includes every
+// fraction digit, so the raw amount is the digits with everything else stripped
+fn parse_money(s: &str) -> Option<PgMoney> {
+    let negative = s.contains('-') || s.starts_with('(');
+    let mut amount: i64 = 0;
+    let mut any_digits = false;
+
+    for ch in s.chars() {
+        if let Some(digit) = ch.to_digit(10) {
+            any_digits = true;
+
+            amount = amount.checked_mul(10)?.checked_sub(digit as i64)?;
+        }
+    }
+
+    if !any_digits {
+        return None;
+    }
+
+    // the amount is accumulated as a negative number so that `i64::MIN` can be represented
+    if negative {
+        Some(PgMoney(amount))
+    } else {
+        amount.checked_neg().map(PgMoney)
+    }
+}
+
+#[test]
+fn test_encode_money() {
+    let mut buf = PgRawBuffer::default();
+    Encode::<Postgres>::encode(&PgMoney(12345), &mut buf);
+
+    assert_eq!(&**buf, [0, 0, 0, 0, 0, 0, 0x30, 0x39]);
+}
+
+#[test]
+fn test_decode_money() {
+    let money: PgMoney =
+        Decode::<Postgres>::decode(PgValue::from_bytes(&[0, 0, 0, 0, 0, 0, 0x30, 0x39])).unwrap();
+
+    assert_eq!(money, PgMoney(12345));
+
+    let res: crate::Result<PgMoney> = Decode::<Postgres>::decode(PgValue::from_bytes(&[0, 0]));
+
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_decode_money_text() {
+    assert_eq!(parse_money("$123.45"), Some(PgMoney(12345)));
+    assert_eq!(parse_money("-$1,234.50"), Some(PgMoney(-123450)));
+    assert_eq!(parse_money("($0.01)"), Some(PgMoney(-1)));
+    assert_eq!(
+        parse_money("-$92,233,720,368,547,758.08"),
+        Some(PgMoney(i64::MIN))
+    );
+
+    assert_eq!(parse_money("$92,233,720,368,547,758.08"), None);
+    assert_eq!(parse_money("$"), None);
+}
+
+#[cfg(feature = "bigdecimal")]
+#[test]
+fn test_money_decimal() {
+    use std::str::FromStr;
+
+    let decimal = bigdecimal::BigDecimal::from_str("123.45").unwrap();
+
+    assert_eq!(PgMoney(12345).to_decimal(2), decimal);
+    assert_eq!(PgMoney::from_decimal(&decimal, 2), Some(PgMoney(12345)));
+    assert_eq!(PgMoney::from_decimal(&decimal, 1), Some(PgMoney(1234)));
+    assert_eq!(PgMoney::from_decimal(&decimal, 0), Some(PgMoney(123)));
+
+    let decimal = bigdecimal::BigDecimal::from_str("-92233720368547758.09").unwrap();
+
+    assert_eq!(PgMoney::from_decimal(&decimal, 2), None);
+}
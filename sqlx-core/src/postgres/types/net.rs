@@ -0,0 +1,268 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::postgres::protocol::TypeId;
+use crate::postgres::value::PgValue;
+use crate::postgres::{PgData, PgRawBuffer, PgTypeInfo, Postgres};
+use crate::types::Type;
+use crate::Error;
+
+#[cfg(windows)]
+const AF_INET: u8 = 2;
+// Maybe not used, but defining to follow Rust's libstd/net/sys
+#[cfg(redox)]
+const AF_INET: u8 = 1;
+#[cfg(not(any(windows, redox)))]
+const AF_INET: u8 = libc::AF_INET as u8;
+
+const PGSQL_AF_INET: u8 = AF_INET;
+const PGSQL_AF_INET6: u8 = AF_INET + 1;
+
+const INET_TYPE: u8 = 0;
+const CIDR_TYPE: u8 = 1;
+
+impl Type<Postgres> for IpAddr {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::INET, "INET")
+    }
+}
+
+impl Type<Postgres> for Ipv4Addr {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::INET, "INET")
+    }
+}
+
+impl Type<Postgres> for Ipv6Addr {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::INET, "INET")
+    }
+}
+
+impl Type<Postgres> for [IpAddr] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::ARRAY_INET, "INET[]")
+    }
+}
+
+impl Type<Postgres> for Vec<IpAddr> {
+    fn type_info() -> PgTypeInfo {
+        <[IpAddr] as Type<Postgres>>::type_info()
+    }
+}
+
+impl Encode<Postgres> for IpAddr {
+    fn encode(&self, buf: &mut PgRawBuffer) {
+        match self {
+            IpAddr::V4(addr) => addr.encode(buf),
+            IpAddr::V6(addr) => addr.encode(buf),
+        }
+    }
+
+    fn size_hint(&self) -> usize {
+        match self {
+            IpAddr::V4(addr) => addr.size_hint(),
+            IpAddr::V6(addr) => addr.size_hint(),
+        }
+    }
+}
+
+impl Encode<Postgres> for Ipv4Addr {
+    fn encode(&self, buf: &mut PgRawBuffer) {
+        encode_inet(buf, IpAddr::V4(*self), 32, false);
+    }
+
+    fn size_hint(&self) -> usize {
+        8
+    }
+}
+
+impl Encode<Postgres> for Ipv6Addr {
+    fn encode(&self, buf: &mut PgRawBuffer) {
+        encode_inet(buf, IpAddr::V6(*self), 128, false);
+    }
+
+    fn size_hint(&self) -> usize {
+        20
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for IpAddr {
+    fn decode(value: PgValue<'de>) -> crate::Result<Self> {
+        let (addr, prefix) = match value.try_get()? {
+            PgData::Binary(buf) => decode_inet(buf)?,
+            PgData::Text(s) => parse_inet(s)?,
+        };
+
+        // a network can not be represented by a single address
+        if prefix != max_prefix(&addr) {
+            return Err(decode_err!(
+                "expected a host address but received the network {}/{}",
+                addr,
+                prefix
+            ));
+        }
+
+        Ok(addr)
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for Ipv4Addr {
+    fn decode(value: PgValue<'de>) -> crate::Result<Self> {
+        match IpAddr::decode(value)? {
+            IpAddr::V4(addr) => Ok(addr),
+            IpAddr::V6(addr) => Err(decode_err!(
+                "expected an IPv4 address but received {}",
+                addr
+            )),
+        }
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for Ipv6Addr {
+    fn decode(value: PgValue<'de>) -> crate::Result<Self> {
+        match IpAddr::decode(value)? {
+            IpAddr::V6(addr) => Ok(addr),
+            IpAddr::V4(addr) => Err(decode_err!(
+                "expected an IPv6 address but received {}",
+                addr
+            )),
+        }
+    }
+}
+
+fn max_prefix(addr: &IpAddr) -> u8 {
+    match addr {
+        IpAddr::V4(_) => 32,
+        IpAddr::V6(_) => 128,
+    }
+}
+
+// address family, netmask bits, is-cidr flag, address length and then the address itself
+pub(super) fn encode_inet(buf: &mut PgRawBuffer, addr: IpAddr, prefix: u8, is_cidr: bool) {
+    let net_type = if is_cidr { CIDR_TYPE } else { INET_TYPE };
+
+    match addr {
+        IpAddr::V4(addr) => {
+            buf.push(PGSQL_AF_INET);
+            buf.push(prefix);
+            buf.push(net_type);
+            buf.push(4);
+            buf.extend_from_slice(&addr.octets());
+        }
+
+        IpAddr::V6(addr) => {
+            buf.push(PGSQL_AF_INET6);
+            buf.push(prefix);
+            buf.push(net_type);
+            buf.push(16);
+            buf.extend_from_slice(&addr.octets());
+        }
+    }
+}
+
+pub(super) fn decode_inet(bytes: &[u8]) -> crate::Result<(IpAddr, u8)> {
+    if bytes.len() < 8 {
+        return Err(Error::Decode("Input too short".into()));
+    }
+
+    let af = bytes[0];
+    let prefix = bytes[1];
+    let net_type = bytes[2];
+    let len = bytes[3];
+
+    if net_type == INET_TYPE || net_type == CIDR_TYPE {
+        if af == PGSQL_AF_INET && bytes.len() == 8 && len == 4 {
+            let addr = Ipv4Addr::new(bytes[4], bytes[5], bytes[6], bytes[7]);
+
+            return Ok((IpAddr::V4(addr), prefix));
+        }
+
+        if af == PGSQL_AF_INET6 && bytes.len() == 20 && len == 16 {
+            let mut octets = [0; 16];
+            octets.copy_from_slice(&bytes[4..]);
+
+            return Ok((IpAddr::V6(Ipv6Addr::from(octets)), prefix));
+        }
+    }
+
+    Err(Error::Decode("Invalid input".into()))
+}
+
+// the text format is the address optionally followed by `/<prefix>`; Postgres omits
+// the prefix for an INET host address but always includes it for CIDR
+fn parse_inet(s: &str) -> crate::Result<(IpAddr, u8)> {
+    let mut parts = s.splitn(2, '/');
+
+    let addr: IpAddr = parts
+        .next()
+        .unwrap_or_default()
+        .parse()
+        .map_err(Error::decode)?;
+
+    let prefix = match parts.next() {
+        Some(prefix) => prefix.parse().map_err(Error::decode)?,
+        None => max_prefix(&addr),
+    };
+
+    Ok((addr, prefix))
+}
+
+#[test]
+fn test_encode_ipv4() {
+    let mut buf = PgRawBuffer::default();
+    Encode::<Postgres>::encode(&Ipv4Addr::new(192, 168, 0, 1), &mut buf);
+
+    assert_eq!(&**buf, [PGSQL_AF_INET, 32, INET_TYPE, 4, 192, 168, 0, 1]);
+}
+
+#[test]
+fn test_encode_cidr_ipv6() {
+    let addr: Ipv6Addr = "2001:db8::".parse().unwrap();
+
+    let mut buf = PgRawBuffer::default();
+    encode_inet(&mut buf, IpAddr::V6(addr), 64, true);
+
+    assert_eq!(&buf[..4], [PGSQL_AF_INET6, 64, CIDR_TYPE, 16]);
+    assert_eq!(&buf[4..], addr.octets());
+}
+
+#[test]
+fn test_decode_inet() {
+    let buf = [PGSQL_AF_INET, 24, CIDR_TYPE, 4, 10, 0, 0, 0];
+
+    assert_eq!(
+        decode_inet(&buf).unwrap(),
+        (IpAddr::V4(Ipv4Addr::new(10, 0, 0, 0)), 24)
+    );
+
+    assert!(decode_inet(&buf[..7]).is_err());
+    assert!(decode_inet(&[PGSQL_AF_INET6, 24, CIDR_TYPE, 4, 10, 0, 0, 0]).is_err());
+
+    let addr: IpAddr =
+        Decode::<Postgres>::decode(PgValue::from_bytes(&[PGSQL_AF_INET, 32, 0, 4, 1, 2, 3, 4]))
+            .unwrap();
+
+    assert_eq!(addr, IpAddr::V4(Ipv4Addr::new(1, 2, 3, 4)));
+
+    let res: crate::Result<IpAddr> = Decode::<Postgres>::decode(PgValue::from_bytes(&buf));
+
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_decode_inet_text() {
+    assert_eq!(
+        parse_inet("127.0.0.1").unwrap(),
+        (IpAddr::V4(Ipv4Addr::LOCALHOST), 32)
+    );
+
+    assert_eq!(
+        parse_inet("2001:db8::/64").unwrap(),
+        ("2001:db8::".parse().unwrap(), 64)
+    );
+
+    assert!(parse_inet("127.0.0.1/").is_err());
+    assert!(parse_inet("localhost").is_err());
+}
@@ -0,0 +1,544 @@
+//! Postgres [range types](https://www.postgresql.org/docs/current/rangetypes.html).
+
+use std::ops::{Bound, Range, RangeBounds, RangeFrom, RangeInclusive, RangeTo, RangeToInclusive};
+
+use byteorder::BigEndian;
+
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::io::Buf;
+use crate::postgres::protocol::TypeId;
+use crate::postgres::{PgData, PgRawBuffer, PgTypeInfo, PgValue, Postgres};
+use crate::types::Type;
+
+// flags of the binary format
+// https://github.com/postgres/postgres/blob/master/src/include/utils/rangetypes.h
+const RANGE_EMPTY: u8 = 0x01;
+const RANGE_LB_INC: u8 = 0x02;
+const RANGE_UB_INC: u8 = 0x04;
+const RANGE_LB_INF: u8 = 0x08;
+const RANGE_UB_INF: u8 = 0x10;
+
+/// A Postgres range of `T`, such as `INT4RANGE` for `PgRange<i32>`.
+///
+/// An infinite bound is [`Bound::Unbounded`], as is a lower bound of `-infinity` or an upper
+/// bound of `infinity` of a range of dates or timestamps, such as `[2020-01-01,infinity)`,
+/// which is sent back as a missing bound. A range with no values, such as
+/// `Excluded(1)..Excluded(1)`, can be sent but Postgres stores it as `empty`, which cannot be
+/// decoded as a `PgRange` as it has no bounds; check `isempty()` in the query instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PgRange<T> {
+    pub start: Bound<T>,
+    pub end: Bound<T>,
+}
+
+impl<T> From<(Bound<T>, Bound<T>)> for PgRange<T> {
+    fn from((start, end): (Bound<T>, Bound<T>)) -> Self {
+        Self { start, end }
+    }
+}
+
+impl<T> From<Range<T>> for PgRange<T> {
+    fn from(range: Range<T>) -> Self {
+        Self {
+            start: Bound::Included(range.start),
+            end: Bound::Excluded(range.end),
+        }
+    }
+}
+
+impl<T> From<RangeInclusive<T>> for PgRange<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        let (start, end) = range.into_inner();
+
+        Self {
+            start: Bound::Included(start),
+            end: Bound::Included(end),
+        }
+    }
+}
+
+impl<T> From<RangeFrom<T>> for PgRange<T> {
+    fn from(range: RangeFrom<T>) -> Self {
+        Self {
+            start: Bound::Included(range.start),
+            end: Bound::Unbounded,
+        }
+    }
+}
+
+impl<T> From<RangeTo<T>> for PgRange<T> {
+    fn from(range: RangeTo<T>) -> Self {
+        Self {
+            start: Bound::Unbounded,
+            end: Bound::Excluded(range.end),
+        }
+    }
+}
+
+impl<T> From<RangeToInclusive<T>> for PgRange<T> {
+    fn from(range: RangeToInclusive<T>) -> Self {
+        Self {
+            start: Bound::Unbounded,
+            end: Bound::Included(range.end),
+        }
+    }
+}
+
+impl<T> RangeBounds<T> for PgRange<T> {
+    fn start_bound(&self) -> Bound<&T> {
+        as_ref(&self.start)
+    }
+
+    fn end_bound(&self) -> Bound<&T> {
+        as_ref(&self.end)
+    }
+}
+
+macro_rules! impl_range_type {
+    ($($ty:ty => $id:ident, $name:literal;)*) => {
+        $(
+            impl Type<Postgres> for PgRange<$ty> {
+                fn type_info() -> PgTypeInfo {
+                    PgTypeInfo::new(TypeId::$id, $name)
+                }
+            }
+        )*
+    };
+}
+
+impl_range_type! {
+    i32 => INT4RANGE, "INT4RANGE";
+    i64 => INT8RANGE, "INT8RANGE";
+}
+
+#[cfg(feature = "bigdecimal")]
+impl_range_type! {
+    bigdecimal::BigDecimal => NUMRANGE, "NUMRANGE";
+}
+
+#[cfg(feature = "chrono")]
+impl_range_type! {
+    chrono::NaiveDate => DATERANGE, "DATERANGE";
+    chrono::NaiveDateTime => TSRANGE, "TSRANGE";
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz> Type<Postgres> for PgRange<chrono::DateTime<Tz>>
+where
+    Tz: chrono::TimeZone,
+{
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::TSTZRANGE, "TSTZRANGE")
+    }
+}
+
+#[cfg(feature = "time")]
+impl_range_type! {
+    time::Date => DATERANGE, "DATERANGE";
+    time::PrimitiveDateTime => TSRANGE, "TSRANGE";
+    time::OffsetDateTime => TSTZRANGE, "TSTZRANGE";
+}
+
+impl<T> Encode<Postgres> for PgRange<T>
+where
+    T: Encode<Postgres>,
+{
+    fn encode(&self, buf: &mut PgRawBuffer) {
+        let mut flags = 0;
+
+        flags |= match self.start {
+            Bound::Included(_) => RANGE_LB_INC,
+            Bound::Excluded(_) => 0,
+            Bound::Unbounded => RANGE_LB_INF,
+        };
+
+        flags |= match self.end {
+            Bound::Included(_) => RANGE_UB_INC,
+            Bound::Excluded(_) => 0,
+            Bound::Unbounded => RANGE_UB_INF,
+        };
+
+        buf.push(flags);
+
+        // an empty range is detected by Postgres from the bounds
+        encode_bound(buf, &self.start);
+        encode_bound(buf, &self.end);
+    }
+
+    fn size_hint(&self) -> usize {
+        let bound_size = |bound: &Bound<T>| match bound {
+            Bound::Included(value) | Bound::Excluded(value) => 4 + value.size_hint(),
+            Bound::Unbounded => 0,
+        };
+
+        1 + bound_size(&self.start) + bound_size(&self.end)
+    }
+}
+
+impl<'de, T> Decode<'de, Postgres> for PgRange<T>
+where
+    T: Type<Postgres>,
+    T: for<'r> Decode<'r, Postgres>,
+{
+    fn decode(value: PgValue<'de>) -> crate::Result<Self> {
+        match value.try_get()? {
+            PgData::Binary(mut buf) => {
+                let flags = buf.get_u8()?;
+
+                if flags & RANGE_EMPTY != 0 {
+                    return Err(empty_range_err());
+                }
+
+                let start = decode_bound(&mut buf, flags, RANGE_LB_INF, RANGE_LB_INC)?;
+                let end = decode_bound(&mut buf, flags, RANGE_UB_INF, RANGE_UB_INC)?;
+
+                if !buf.is_empty() {
+                    return Err(decode_err!("{} unread bytes after range", buf.len()));
+                }
+
+                Ok(Self { start, end })
+            }
+
+            PgData::Text(s) => parse_range(s),
+        }
+    }
+}
+
+fn as_ref<T>(bound: &Bound<T>) -> Bound<&T> {
+    match bound {
+        Bound::Included(value) => Bound::Included(value),
+        Bound::Excluded(value) => Bound::Excluded(value),
+        Bound::Unbounded => Bound::Unbounded,
+    }
+}
+
+fn empty_range_err() -> crate::Error {
+    crate::Error::Decode("an empty range has no bounds and cannot be decoded as PgRange".into())
+}
+
+fn encode_bound<T>(buf: &mut PgRawBuffer, bound: &Bound<T>)
+where
+    T: Encode<Postgres>,
+{
+    if let Bound::Included(value) | Bound::Excluded(value) = bound {
+        // write zeros for length
+        buf.extend(&[0; 4]);
+
+        let start = buf.len();
+        value.encode(buf);
+        let size = buf.len() - start;
+
+        // replaces zeros with actual length
+        buf[start - 4..start].copy_from_slice(&(size as u32).to_be_bytes());
+    }
+}
+
+// Whether the value of a bound is the `infinity` or `-infinity` of a date or timestamp, for the
+// end of the range it is a bound of; it is decoded as an unbounded bound, as no Rust type of a
+// date or timestamp has it
+fn is_infinite<T>(value: &PgData<'_>, upper: bool) -> bool
+where
+    T: Type<Postgres>,
+{
+    let id = match T::type_info().id {
+        Some(id) => id,
+        None => return false,
+    };
+
+    match value {
+        PgData::Binary(buf) if id == TypeId::TIMESTAMP || id == TypeId::TIMESTAMPTZ => {
+            *buf == if upper { i64::MAX } else { i64::MIN }.to_be_bytes()
+        }
+
+        PgData::Binary(buf) if id == TypeId::DATE => {
+            *buf == if upper { i32::MAX } else { i32::MIN }.to_be_bytes()
+        }
+
+        PgData::Text(s)
+            if id == TypeId::TIMESTAMP || id == TypeId::TIMESTAMPTZ || id == TypeId::DATE =>
+        {
+            *s == if upper { "infinity" } else { "-infinity" }
+        }
+
+        _ => false,
+    }
+}
+
+fn decode_bound<T>(buf: &mut &[u8], flags: u8, inf: u8, inc: u8) -> crate::Result<Bound<T>>
+where
+    T: Type<Postgres>,
+    T: for<'r> Decode<'r, Postgres>,
+{
+    if flags & inf != 0 {
+        return Ok(Bound::Unbounded);
+    }
+
+    let len = buf.get_i32::<BigEndian>()?;
+
+    if len < 0 || len as usize > buf.len() {
+        return Err(decode_err!("invalid length {} of range bound", len));
+    }
+
+    let (value, rest) = buf.split_at(len as usize);
+    *buf = rest;
+
+    if is_infinite::<T>(&PgData::Binary(value), inf == RANGE_UB_INF) {
+        return Ok(Bound::Unbounded);
+    }
+
+    let value = T::decode(PgValue::bytes(T::type_info(), value))?;
+
+    Ok(if flags & inc != 0 {
+        Bound::Included(value)
+    } else {
+        Bound::Excluded(value)
+    })
+}
+
+// the text format is `empty` or the bounds between `[` or `(` and `]` or `)`,
+// where a missing bound is infinite
+fn parse_range<T>(s: &str) -> crate::Result<PgRange<T>>
+where
+    T: Type<Postgres>,
+    T: for<'r> Decode<'r, Postgres>,
+{
+    if s == "empty" {
+        return Err(empty_range_err());
+    }
+
+    let invalid = || decode_err!("invalid range: {:?}", s);
+
+    let lower_inc = match s.chars().next() {
+        Some('[') => true,
+        Some('(') => false,
+        _ => return Err(invalid()),
+    };
+
+    let upper_inc = match s.chars().last() {
+        Some(']') => true,
+        Some(')') => false,
+        _ => return Err(invalid()),
+    };
+
+    if s.len() < 2 {
+        return Err(invalid());
+    }
+
+    let (lower, rest) = split_bound(&s[1..s.len() - 1]).ok_or_else(invalid)?;
+
+    let rest = rest.strip_prefix(',').ok_or_else(invalid)?;
+    let (upper, rest) = split_bound(rest).ok_or_else(invalid)?;
+
+    if !rest.is_empty() {
+        return Err(invalid());
+    }
+
+    let parse_bound = |bound: Option<String>, inc: bool, upper: bool| -> crate::Result<Bound<T>> {
+        Ok(match bound {
+            None => Bound::Unbounded,
+            Some(bound) if is_infinite::<T>(&PgData::Text(&bound), upper) => Bound::Unbounded,
+            Some(bound) => {
+                let value = T::decode(PgValue::from_str(&bound))?;
+
+                if inc {
+                    Bound::Included(value)
+                } else {
+                    Bound::Excluded(value)
+                }
+            }
+        })
+    };
+
+    Ok(PgRange {
+        start: parse_bound(lower, lower_inc, false)?,
+        end: parse_bound(upper, upper_inc, true)?,
+    })
+}
+
+// split off a bound, which may be quoted with `""` and `\` escapes inside quotes;
+// returns `None` for the bound if it is missing
+fn split_bound(s: &str) -> Option<(Option<String>, &str)> {
+    let mut value = String::new();
+    let mut in_quotes = false;
+    let mut chars = s.char_indices().peekable();
+
+    while let Some((index, ch)) = chars.next() {
+        match ch {
+            '"' if in_quotes && chars.peek().map(|&(_, ch)| ch) == Some('"') => {
+                chars.next();
+                value.push('"');
+            }
+
+            '"' => in_quotes = !in_quotes,
+
+            '\\' => value.push(chars.next()?.1),
+
+            ',' if !in_quotes => {
+                return Some((Some(value).filter(|_| index > 0), &s[index..]));
+            }
+
+            _ => value.push(ch),
+        }
+    }
+
+    if in_quotes {
+        return None;
+    }
+
+    Some((Some(value).filter(|_| !s.is_empty()), ""))
+}
+
+#[test]
+fn test_encode_range() {
+    let mut buf = PgRawBuffer::default();
+    PgRange::from(1_i32..10).encode(&mut buf);
+
+    assert_eq!(
+        &**buf,
+        [
+            RANGE_LB_INC,
+            0,
+            0,
+            0,
+            4,
+            0,
+            0,
+            0,
+            1,
+            0,
+            0,
+            0,
+            4,
+            0,
+            0,
+            0,
+            10
+        ]
+    );
+
+    let mut buf = PgRawBuffer::default();
+    PgRange::<i32>::from(..=7).encode(&mut buf);
+
+    assert_eq!(
+        &**buf,
+        [RANGE_LB_INF | RANGE_UB_INC, 0, 0, 0, 4, 0, 0, 0, 7]
+    );
+
+    let mut buf = PgRawBuffer::default();
+    PgRange::<i64>::from((Bound::Unbounded, Bound::Unbounded)).encode(&mut buf);
+
+    assert_eq!(&**buf, [RANGE_LB_INF | RANGE_UB_INF]);
+}
+
+#[test]
+fn test_decode_range() {
+    let buf = [
+        RANGE_LB_INC,
+        0,
+        0,
+        0,
+        4,
+        0,
+        0,
+        0,
+        1,
+        0,
+        0,
+        0,
+        4,
+        0,
+        0,
+        0,
+        10,
+    ];
+    let range: PgRange<i32> = Decode::<Postgres>::decode(PgValue::from_bytes(&buf)).unwrap();
+
+    assert_eq!(range, PgRange::from(1..10));
+
+    let buf = [RANGE_UB_INF, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 5];
+    let range: PgRange<i64> = Decode::<Postgres>::decode(PgValue::from_bytes(&buf)).unwrap();
+
+    assert_eq!(range.start, Bound::Excluded(5));
+    assert_eq!(range.end, Bound::Unbounded);
+
+    let buf = [RANGE_EMPTY];
+    let res: crate::Result<PgRange<i32>> = Decode::<Postgres>::decode(PgValue::from_bytes(&buf));
+
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_decode_range_text() {
+    let range: PgRange<i32> = Decode::<Postgres>::decode(PgValue::from_str("[1,10)")).unwrap();
+    assert_eq!(range, PgRange::from(1..10));
+
+    let range: PgRange<i32> = Decode::<Postgres>::decode(PgValue::from_str("(,5]")).unwrap();
+    assert_eq!(range, PgRange::from(..=5));
+
+    let range: PgRange<i64> = Decode::<Postgres>::decode(PgValue::from_str("[3,)")).unwrap();
+    assert_eq!(range, PgRange::from(3..));
+
+    let range: PgRange<i64> = Decode::<Postgres>::decode(PgValue::from_str("(,)")).unwrap();
+    assert_eq!(range, PgRange::from((Bound::Unbounded, Bound::Unbounded)));
+
+    let res: crate::Result<PgRange<i32>> = Decode::<Postgres>::decode(PgValue::from_str("empty"));
+    assert!(res.is_err());
+
+    let res: crate::Result<PgRange<i32>> = Decode::<Postgres>::decode(PgValue::from_str("[1,2"));
+    assert!(res.is_err());
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_decode_tstzrange_with_infinity() {
+    use chrono::{DateTime, NaiveDate, Utc};
+
+    let start = DateTime::<Utc>::from_utc(NaiveDate::from_ymd(2020, 1, 1).and_hms(0, 0, 0), Utc);
+
+    // [2020-01-01,infinity), 2020-01-01 being 631152000000000 microseconds after 2000-01-01
+    let mut buf = vec![RANGE_LB_INC, 0, 0, 0, 8];
+    buf.extend_from_slice(&631_152_000_000_000_i64.to_be_bytes());
+    buf.extend_from_slice(&[0, 0, 0, 8]);
+    buf.extend_from_slice(&i64::MAX.to_be_bytes());
+
+    let range: PgRange<DateTime<Utc>> =
+        Decode::<Postgres>::decode(PgValue::bytes(PgRange::<DateTime<Utc>>::type_info(), &buf))
+            .unwrap();
+
+    assert_eq!(range, PgRange::from(start..));
+
+    let range: PgRange<DateTime<Utc>> =
+        Decode::<Postgres>::decode(PgValue::from_str(r#"(-infinity,"2020-01-01 00:00:00+00"]"#))
+            .unwrap();
+
+    assert_eq!(range, PgRange::from(..=start));
+
+    // `infinity` is not the start of a range
+    let res: crate::Result<PgRange<DateTime<Utc>>> =
+        Decode::<Postgres>::decode(PgValue::from_str("[infinity,)"));
+
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_split_bound() {
+    assert_eq!(split_bound("1,2"), Some((Some("1".into()), ",2")));
+    assert_eq!(split_bound(",2"), Some((None, ",2")));
+    assert_eq!(split_bound(""), Some((None, "")));
+
+    assert_eq!(
+        split_bound(r#""2020-01-01 00:00:00","2020-02-01 00:00:00""#),
+        Some((
+            Some("2020-01-01 00:00:00".into()),
+            r#","2020-02-01 00:00:00""#
+        ))
+    );
+
+    assert_eq!(
+        split_bound(r#""a "" \\ b""#),
+        Some((Some(r#"a " \ b"#.into()), ""))
+    );
+
+    assert_eq!(split_bound(r#""a,b"#), None);
+}
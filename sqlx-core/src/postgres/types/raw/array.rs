@@ -1,19 +1,81 @@
 use crate::decode::Decode;
 use crate::encode::{Encode, IsNull};
 use crate::io::{Buf, BufMut};
+use crate::postgres::protocol::TypeId;
 use crate::postgres::types::raw::sequence::PgSequenceDecoder;
-use crate::postgres::{PgData, PgRawBuffer, PgValue, Postgres};
+use crate::postgres::{PgData, PgRawBuffer, PgTypeInfo, PgValue, Postgres};
 use crate::types::Type;
-use byteorder::BE;
+use byteorder::{ByteOrder, BE};
 use std::marker::PhantomData;
 
 // https://git.postgresql.org/gitweb/?p=postgresql.git;a=blob;f=src/include/utils/array.h;h=7f7e744cb12bc872f628f90dad99dfdf074eb314;hb=master#l6
 // https://git.postgresql.org/gitweb/?p=postgresql.git;a=blob;f=src/backend/utils/adt/arrayfuncs.c;h=7a4a5aaa86dc1c8cffa2d899c89511dc317d485b;hb=master#l1547
 
+// Postgres has no separate type for multi-dimensional arrays; an `INT4[]` may have any number
+// of dimensions. When the element type is itself an array, each element is one row of a
+// multi-dimensional array.
+pub(crate) fn is_array_type(ty: &PgTypeInfo) -> bool {
+    ty.name.ends_with("[]")
+}
+
+// The type of the innermost elements of an array type
+fn element_type_info(ty: &PgTypeInfo) -> PgTypeInfo {
+    let id = ty.id.and_then(|id| {
+        Some(match id {
+            TypeId::ARRAY_BOOL => TypeId::BOOL,
+            TypeId::ARRAY_CHAR => TypeId::CHAR,
+            TypeId::ARRAY_INT2 => TypeId::INT2,
+            TypeId::ARRAY_INT4 => TypeId::INT4,
+            TypeId::ARRAY_INT8 => TypeId::INT8,
+            TypeId::ARRAY_OID => TypeId::OID,
+            TypeId::ARRAY_FLOAT4 => TypeId::FLOAT4,
+            TypeId::ARRAY_FLOAT8 => TypeId::FLOAT8,
+            TypeId::ARRAY_TEXT => TypeId::TEXT,
+            TypeId::ARRAY_VARCHAR => TypeId::VARCHAR,
+            TypeId::ARRAY_BPCHAR => TypeId::BPCHAR,
+            TypeId::ARRAY_NAME => TypeId::NAME,
+            TypeId::ARRAY_NUMERIC => TypeId::NUMERIC,
+            TypeId::ARRAY_MONEY => TypeId::MONEY,
+            TypeId::ARRAY_DATE => TypeId::DATE,
+            TypeId::ARRAY_TIME => TypeId::TIME,
+            TypeId::ARRAY_TIMESTAMP => TypeId::TIMESTAMP,
+            TypeId::ARRAY_TIMESTAMPTZ => TypeId::TIMESTAMPTZ,
+            TypeId::ARRAY_INTERVAL => TypeId::INTERVAL,
+            TypeId::ARRAY_BYTEA => TypeId::BYTEA,
+            TypeId::ARRAY_UUID => TypeId::UUID,
+            TypeId::ARRAY_CIDR => TypeId::CIDR,
+            TypeId::ARRAY_INET => TypeId::INET,
+            TypeId::ARRAY_MACADDR => TypeId::MACADDR,
+            TypeId::ARRAY_MACADDR8 => TypeId::MACADDR8,
+            TypeId::ARRAY_POINT => TypeId::POINT,
+            TypeId::ARRAY_RECORD => TypeId::RECORD,
+
+            _ => return None,
+        })
+    });
+
+    let name = ty.name.trim_end_matches("[]").to_owned();
+
+    match id {
+        Some(id) => PgTypeInfo::new(id, name),
+
+        // the OID is looked up by name when the value is bound
+        None => PgTypeInfo {
+            id: None,
+            name: name.into(),
+        },
+    }
+}
+
 pub(crate) struct PgArrayEncoder<'enc, T> {
     count: usize,
+    ndim_index: usize,
     len_start_index: usize,
     buf: &'enc mut PgRawBuffer,
+    // the dimensions of the first element if `T` is itself an array
+    nested: bool,
+    inner_dims: Option<Vec<i32>>,
+    ragged: bool,
     phantom: PhantomData<T>,
 }
 
@@ -22,7 +84,14 @@ where
     T: Encode<Postgres> + Type<Postgres>,
 {
     pub(crate) fn new(buf: &'enc mut PgRawBuffer) -> Self {
-        let ty = <T as Type<Postgres>>::type_info();
+        let mut ty = <T as Type<Postgres>>::type_info();
+        let nested = is_array_type(&ty);
+
+        if nested {
+            ty = element_type_info(&ty);
+        }
+
+        let ndim_index = buf.len();
 
         // ndim
         buf.put_i32::<BE>(1);
@@ -49,14 +118,23 @@ where
 
         Self {
             count: 0,
+            ndim_index,
             len_start_index,
             buf,
+            nested,
+            inner_dims: None,
+            ragged: false,
 
             phantom: PhantomData,
         }
     }
 
     pub(crate) fn encode(&mut self, item: T) {
+        if self.nested {
+            self.encode_nested(item);
+            return;
+        }
+
         // Allocate space for the length of the encoded elemement up front
         let el_len_index = self.buf.len();
         self.buf.put_i32::<BE>(0);
@@ -79,18 +157,147 @@ where
         self.count += 1;
     }
 
+    // The element is encoded as an array of its own; its dimensions are added to the header
+    // and its elements are appended to ours
+    fn encode_nested(&mut self, item: T) {
+        let mut inner = PgRawBuffer::default();
+
+        if let IsNull::Yes = Encode::<Postgres>::encode_nullable(&item, &mut inner) {
+            // a row of a multi-dimensional array can not be NULL
+            self.ragged = true;
+            self.count += 1;
+
+            return;
+        }
+
+        let ndim = BE::read_i32(&inner[0..]);
+
+        let (dims, header_len) = if ndim == 0 {
+            // an empty array has no dimension header
+            (vec![0], 12)
+        } else {
+            let dims = (0..ndim as usize)
+                .map(|i| BE::read_i32(&inner[12 + i * 8..]))
+                .collect();
+
+            (dims, 12 + ndim as usize * 8)
+        };
+
+        match self.inner_dims {
+            None => {
+                for dim in &dims {
+                    self.buf.put_i32::<BE>(*dim);
+                    self.buf.put_i32::<BE>(1);
+                }
+
+                self.inner_dims = Some(dims);
+            }
+
+            Some(ref inner_dims) if *inner_dims != dims => {
+                self.ragged = true;
+            }
+
+            Some(_) => {}
+        }
+
+        self.buf.extend_from_buffer(&inner, header_len);
+        self.count += 1;
+    }
+
     pub(crate) fn finish(&mut self) {
         const I32_SIZE: usize = std::mem::size_of::<i32>();
 
+        if self.count == 0 {
+            // an empty array has no dimensions (and so no dimension lengths or bounds)
+            self.buf[self.ndim_index..self.ndim_index + I32_SIZE]
+                .copy_from_slice(&0_i32.to_be_bytes());
+
+            self.buf.truncate(self.len_start_index);
+
+            return;
+        }
+
         let size_bytes = (self.count as i32).to_be_bytes();
 
         self.buf[self.len_start_index..self.len_start_index + I32_SIZE]
             .copy_from_slice(&size_bytes);
+
+        if let Some(inner_dims) = &self.inner_dims {
+            let ndim = if self.ragged {
+                // `Encode` can not fail so we send an invalid number of dimensions instead
+                // to have Postgres reject the value
+                -1
+            } else {
+                1 + inner_dims.len() as i32
+            };
+
+            self.buf[self.ndim_index..self.ndim_index + I32_SIZE]
+                .copy_from_slice(&ndim.to_be_bytes());
+        } else if self.ragged {
+            // every row was NULL
+            self.buf[self.ndim_index..self.ndim_index + I32_SIZE]
+                .copy_from_slice(&(-1_i32).to_be_bytes());
+        }
+    }
+}
+
+// The rows of a multi-dimensional array in the binary format
+struct PgNestedArray<'de> {
+    buf: &'de [u8],
+    // the header of each row; the dimensions after the first
+    header: Vec<u8>,
+    // the number of elements in each row
+    row_len: usize,
+    rows: usize,
+}
+
+impl<'de> PgNestedArray<'de> {
+    fn next_row(&mut self) -> crate::Result<Option<Vec<u8>>> {
+        if self.rows == 0 {
+            if !self.buf.is_empty() {
+                return Err(decode_err!(
+                    "{} unexpected bytes after the last element of the array",
+                    self.buf.len()
+                ));
+            }
+
+            return Ok(None);
+        }
+
+        // find the end of the elements of this row
+        let mut end = 0;
+
+        for _ in 0..self.row_len {
+            if self.buf.len() < end + 4 {
+                return Err(crate::Error::Decode(
+                    "array has fewer elements than given by its dimensions".into(),
+                ));
+            }
+
+            let len = BE::read_i32(&self.buf[end..]);
+
+            end += 4 + len.max(0) as usize;
+        }
+
+        if end > self.buf.len() {
+            return Err(crate::Error::Decode(
+                "array has fewer elements than given by its dimensions".into(),
+            ));
+        }
+
+        let mut row = self.header.clone();
+        row.extend_from_slice(&self.buf[..end]);
+
+        self.buf = &self.buf[end..];
+        self.rows -= 1;
+
+        Ok(Some(row))
     }
 }
 
 pub(crate) struct PgArrayDecoder<'de, T> {
     inner: PgSequenceDecoder<'de>,
+    nested: Option<PgNestedArray<'de>>,
     phantom: PhantomData<T>,
 }
 
@@ -101,6 +308,7 @@ where
 {
     pub(crate) fn new(value: PgValue<'de>) -> crate::Result<Self> {
         let mut data = value.try_get()?;
+        let nested = is_array_type(&T::type_info());
 
         let element_oid = match data {
             PgData::Binary(ref mut buf) => {
@@ -111,13 +319,21 @@ where
                     // ndim of 0 is an empty array
                     return Ok(Self {
                         inner: PgSequenceDecoder::new(PgData::Binary(&[]), None),
+                        nested: None,
                         phantom: PhantomData,
                     });
                 }
 
-                if ndim != 1 {
+                if !nested && ndim != 1 {
+                    return Err(decode_err!(
+                        "unsupported dimensionality: encountered an array of {} dimensions; only one-dimensional arrays are supported",
+                        ndim
+                    ));
+                }
+
+                if nested && ndim < 2 {
                     return Err(decode_err!(
-                        "encountered an array of {} dimensions; only one-dimensional arrays are supported",
+                        "unsupported dimensionality: encountered an array of {} dimensions; expected a multi-dimensional array",
                         ndim
                     ));
                 }
@@ -129,32 +345,92 @@ where
                 // element type OID
                 let element_oid = buf.get_u32::<BE>()?;
 
-                // length of each array axis
-                let _dimensions = buf.get_i32::<BE>()?;
+                let mut dims = Vec::with_capacity(ndim as usize);
 
-                // lower boundary of each dimension
-                let lower_bnds = buf.get_i32::<BE>()?;
+                for _ in 0..ndim {
+                    // length of each array axis
+                    let dim = buf.get_i32::<BE>()?;
 
-                if lower_bnds != 1 {
-                    return Err(decode_err!(
-                        "encountered an array with a lower bound of {} in the first dimension; only arrays starting at one are supported",
-                        lower_bnds
-                    ));
+                    // lower boundary of each dimension
+                    let lower_bnds = buf.get_i32::<BE>()?;
+
+                    if lower_bnds != 1 {
+                        return Err(decode_err!(
+                            "encountered an array with a lower bound of {} in the first dimension; only arrays starting at one are supported",
+                            lower_bnds
+                        ));
+                    }
+
+                    dims.push(dim);
+                }
+
+                if nested {
+                    let mut header = Vec::new();
+
+                    header.put_i32::<BE>(ndim - 1);
+                    header.put_i32::<BE>(0);
+                    header.put_u32::<BE>(element_oid);
+
+                    for dim in &dims[1..] {
+                        header.put_i32::<BE>(*dim);
+                        header.put_i32::<BE>(1);
+                    }
+
+                    return Ok(Self {
+                        inner: PgSequenceDecoder::new(PgData::Binary(&[]), None),
+                        nested: Some(PgNestedArray {
+                            buf,
+                            header,
+                            row_len: dims[1..].iter().map(|dim| *dim as usize).product(),
+                            rows: dims[0] as usize,
+                        }),
+                        phantom: PhantomData,
+                    });
                 }
 
                 Some(element_oid)
             }
 
-            PgData::Text(_) => None,
+            PgData::Text(s) => {
+                // arrays that do not start at one are prefixed with their bounds, e.g. `[0:1]={1,2}`
+                if s.starts_with('[') {
+                    return Err(decode_err!(
+                        "encountered an array with explicit bounds {:?}; only arrays starting at one are supported",
+                        s.split('=').next().unwrap_or_default()
+                    ));
+                }
+
+                if !nested && s.starts_with("{{") {
+                    return Err(crate::Error::Decode(
+                        "unsupported dimensionality: encountered a multi-dimensional array; only one-dimensional arrays are supported".into(),
+                    ));
+                }
+
+                if nested && s != "{}" && !s.starts_with("{{") {
+                    return Err(crate::Error::Decode(
+                        "unsupported dimensionality: encountered a one-dimensional array; expected a multi-dimensional array".into(),
+                    ));
+                }
+
+                None
+            }
         };
 
         Ok(Self {
             inner: PgSequenceDecoder::new(data, element_oid),
+            nested: None,
             phantom: PhantomData,
         })
     }
 
     fn decode(&mut self) -> crate::Result<Option<T>> {
+        if let Some(nested) = &mut self.nested {
+            return match nested.next_row()? {
+                Some(row) => T::decode(PgValue::bytes(T::type_info(), &row)).map(Some),
+                None => Ok(None),
+            };
+        }
+
         self.inner.decode()
     }
 }
@@ -194,6 +470,96 @@ mod tests {
         assert_eq!(&**buf, BUF_BINARY_I32);
     }
 
+    #[test]
+    fn it_encodes_empty() {
+        let mut buf = PgRawBuffer::default();
+        PgArrayEncoder::<i32>::new(&mut buf).finish();
+
+        assert_eq!(&**buf, b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x17");
+    }
+
+    #[test]
+    fn it_encodes_nulls() {
+        let mut buf = PgRawBuffer::default();
+        let mut encoder = PgArrayEncoder::new(&mut buf);
+
+        encoder.encode(Some(1_i32));
+        encoder.encode(None);
+        encoder.finish();
+
+        assert_eq!(
+            &**buf,
+            &b"\x00\x00\x00\x01\x00\x00\x00\x00\x00\x00\x00\x17\x00\x00\x00\x02\x00\x00\x00\x01\x00\x00\x00\x04\x00\x00\x00\x01\xff\xff\xff\xff"[..]
+        );
+    }
+
+    // select '{{1,2,3},{4,5,6}}'::int4[]
+    const BUF_BINARY_I32_2X3: &[u8] = b"\x00\x00\x00\x02\x00\x00\x00\x00\x00\x00\x00\x17\x00\x00\x00\x02\x00\x00\x00\x01\x00\x00\x00\x03\x00\x00\x00\x01\x00\x00\x00\x04\x00\x00\x00\x01\x00\x00\x00\x04\x00\x00\x00\x02\x00\x00\x00\x04\x00\x00\x00\x03\x00\x00\x00\x04\x00\x00\x00\x04\x00\x00\x00\x04\x00\x00\x00\x05\x00\x00\x00\x04\x00\x00\x00\x06";
+
+    #[test]
+    fn it_encodes_nested() {
+        let mut buf = PgRawBuffer::default();
+        let mut encoder = PgArrayEncoder::new(&mut buf);
+
+        encoder.encode(vec![1_i32, 2, 3]);
+        encoder.encode(vec![4_i32, 5, 6]);
+        encoder.finish();
+
+        assert_eq!(&**buf, BUF_BINARY_I32_2X3);
+    }
+
+    #[test]
+    fn it_encodes_ragged_as_invalid() {
+        let mut buf = PgRawBuffer::default();
+        let mut encoder = PgArrayEncoder::new(&mut buf);
+
+        encoder.encode(vec![1_i32, 2, 3]);
+        encoder.encode(vec![4_i32]);
+        encoder.finish();
+
+        assert_eq!(&buf[..4], b"\xff\xff\xff\xff");
+    }
+
+    #[test]
+    fn it_decodes_binary_nested() -> crate::Result<()> {
+        let mut decoder = PgArrayDecoder::<Vec<i32>>::new(PgValue::from_bytes(BUF_BINARY_I32_2X3))?;
+
+        assert_eq!(decoder.decode()?, Some(vec![1, 2, 3]));
+        assert_eq!(decoder.decode()?, Some(vec![4, 5, 6]));
+        assert_eq!(decoder.decode()?, None);
+
+        // the last element is missing
+        let buf = &BUF_BINARY_I32_2X3[..BUF_BINARY_I32_2X3.len() - 8];
+        let mut decoder = PgArrayDecoder::<Vec<i32>>::new(PgValue::from_bytes(buf))?;
+
+        assert_eq!(decoder.decode()?, Some(vec![1, 2, 3]));
+        assert!(decoder.decode().is_err());
+
+        // a one-dimensional array has no rows
+        assert!(PgArrayDecoder::<Vec<i32>>::new(PgValue::from_bytes(BUF_BINARY_I32)).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_decodes_text_nested() -> crate::Result<()> {
+        let s = "{{\"a\",\"}\"},{NULL,\"\\\"{\"}}";
+        let mut decoder = PgArrayDecoder::<Vec<Option<String>>>::new(PgValue::from_str(s))?;
+
+        assert_eq!(
+            decoder.decode()?,
+            Some(vec![Some("a".to_string()), Some("}".to_string())])
+        );
+
+        assert_eq!(decoder.decode()?, Some(vec![None, Some("\"{".to_string())]));
+
+        assert_eq!(decoder.decode()?, None);
+
+        assert!(PgArrayDecoder::<Vec<i32>>::new(PgValue::from_str("{1,2}")).is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn it_decodes_text_i32() -> crate::Result<()> {
         let s = "{1,152,-12412}";
@@ -219,6 +585,38 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn it_decodes_text_nulls() -> crate::Result<()> {
+        let s = "{NULL,\"NULL\",a}";
+        let mut decoder = PgArrayDecoder::<Option<String>>::new(PgValue::from_str(s))?;
+
+        assert_eq!(decoder.decode()?, Some(None));
+        assert_eq!(decoder.decode()?, Some(Some("NULL".to_string())));
+        assert_eq!(decoder.decode()?, Some(Some("a".to_string())));
+        assert_eq!(decoder.decode()?, None);
+
+        let mut decoder = PgArrayDecoder::<i32>::new(PgValue::from_str("{1,NULL}"))?;
+
+        assert_eq!(decoder.decode()?, Some(1));
+        assert!(decoder.decode().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_rejects_multi_dimensional_arrays() {
+        assert!(PgArrayDecoder::<i32>::new(PgValue::from_str("{{1,2},{3,4}}")).is_err());
+        assert!(PgArrayDecoder::<i32>::new(PgValue::from_str("[0:1]={1,2}")).is_err());
+
+        // select '{{1},{2}}'::int4[]
+        let buf = b"\x00\x00\x00\x02\x00\x00\x00\x00\x00\x00\x00\x17\x00\x00\x00\x02\x00\x00\x00\x01\x00\x00\x00\x01\x00\x00\x00\x01\x00\x00\x00\x04\x00\x00\x00\x01\x00\x00\x00\x04\x00\x00\x00\x02";
+        let err = PgArrayDecoder::<i32>::new(PgValue::from_bytes(buf))
+            .err()
+            .unwrap();
+
+        assert!(err.to_string().starts_with("unsupported dimensionality"));
+    }
+
     #[test]
     fn it_decodes_binary_nulls() -> crate::Result<()> {
         let mut decoder = PgArrayDecoder::<Option<bool>>::new(PgValue::from_bytes(
@@ -230,6 +628,29 @@ mod tests {
         assert_eq!(decoder.decode()?, Some(None));
         assert_eq!(decoder.decode()?, Some(Some(false)));
 
+        // a NULL element can only be decoded into an `Option`
+        let mut buf = PgRawBuffer::default();
+        let mut encoder = PgArrayEncoder::new(&mut buf);
+
+        encoder.encode(Some(1_i32));
+        encoder.encode(None);
+        encoder.encode(Some(3_i32));
+        encoder.finish();
+
+        let mut decoder = PgArrayDecoder::<i32>::new(PgValue::from_bytes(&buf))?;
+
+        assert_eq!(decoder.decode()?, Some(1));
+
+        let err = decoder.decode().unwrap_err();
+        assert!(err.to_string().starts_with("unexpected null"), "{}", err);
+
+        let mut decoder = PgArrayDecoder::<Option<i32>>::new(PgValue::from_bytes(&buf))?;
+
+        assert_eq!(decoder.decode()?, Some(Some(1)));
+        assert_eq!(decoder.decode()?, Some(None));
+        assert_eq!(decoder.decode()?, Some(Some(3)));
+        assert_eq!(decoder.decode()?, None);
+
         Ok(())
     }
 
@@ -103,19 +103,104 @@ impl PgNumeric {
             })
         }
     }
+
+    // Parses the text format of a `NUMERIC`, e.g. `-12.3400`, `1.5e-10` or `NaN`, into the
+    // same digits, weight and scale as the binary format
+    pub(crate) fn from_str(s: &str) -> crate::Result<Self> {
+        let invalid = || Error::Decode(format!("invalid NUMERIC {:?}", s).into());
+
+        if s.eq_ignore_ascii_case("NaN") {
+            return Ok(PgNumeric::NotANumber);
+        }
+
+        let (sign, unsigned) = match s.as_bytes().first() {
+            Some(b'-') => (PgNumericSign::Negative, &s[1..]),
+            Some(b'+') => (PgNumericSign::Positive, &s[1..]),
+            _ => (PgNumericSign::Positive, s),
+        };
+
+        let (mantissa, exponent) = match unsigned.find(&['e', 'E'][..]) {
+            Some(i) => (
+                &unsigned[..i],
+                unsigned[i + 1..].parse::<i64>().map_err(|_| invalid())?,
+            ),
+            None => (unsigned, 0),
+        };
+
+        let (integer, fraction) = match mantissa.find('.') {
+            Some(i) => (&mantissa[..i], &mantissa[i + 1..]),
+            None => (mantissa, ""),
+        };
+
+        if integer.is_empty() && fraction.is_empty()
+            || !integer
+                .bytes()
+                .chain(fraction.bytes())
+                .all(|b| b.is_ascii_digit())
+        {
+            return Err(invalid());
+        }
+
+        // the value is `base_10 * 10 ^ exp_10`
+        let mut exp_10 = exponent - fraction.len() as i64;
+        let scale: i16 = (-exp_10).max(0).try_into().map_err(|_| invalid())?;
+
+        let mut base_10: Vec<i64> = integer
+            .bytes()
+            .chain(fraction.bytes())
+            .skip_while(|&b| b == b'0')
+            .map(|b| (b - b'0') as i64)
+            .collect();
+
+        while let Some(&0) = base_10.last() {
+            base_10.pop();
+            exp_10 += 1;
+        }
+
+        if base_10.is_empty() {
+            return Ok(PgNumeric::Number {
+                sign: PgNumericSign::Positive,
+                scale,
+                weight: 0,
+                digits: Vec::new(),
+            });
+        }
+
+        // the power of 10 of the first and the last digit; each base-10000 digit holds the
+        // base-10 digits of the powers `4 * weight .. 4 * weight + 3`
+        let first = exp_10 + base_10.len() as i64 - 1;
+        let weight = first.div_euclid(4);
+        let last = exp_10.div_euclid(4);
+
+        let digits = (last..=weight)
+            .rev()
+            .map(|group| {
+                (0..4).rev().fold(0, |digit, power| {
+                    let index = first - (group * 4 + power);
+
+                    digit * 10
+                        + base_10
+                            .get(index as usize)
+                            .filter(|_| index >= 0)
+                            .unwrap_or(&0)
+                }) as i16
+            })
+            .collect();
+
+        Ok(PgNumeric::Number {
+            sign,
+            scale,
+            weight: weight.try_into().map_err(|_| invalid())?,
+            digits,
+        })
+    }
 }
-/// ### Note
-///
-/// Receiving `PgNumeric` is currently only supported for the Postgres
-/// binary (prepared statements) protocol.
+
 impl Decode<'_, Postgres> for PgNumeric {
     fn decode(value: PgValue) -> crate::Result<Self> {
-        if let PgData::Binary(bytes) = value.try_get()? {
-            Self::from_bytes(bytes)
-        } else {
-            Err(Error::Decode(
-                format!("`PgNumeric` can only be decoded from the binary protocol").into(),
-            ))
+        match value.try_get()? {
+            PgData::Binary(bytes) => Self::from_bytes(bytes),
+            PgData::Text(text) => Self::from_str(text),
         }
     }
 }
@@ -164,3 +249,61 @@ impl Encode<Postgres> for PgNumeric {
         }
     }
 }
+
+#[test]
+fn test_pgnumeric_from_str() {
+    let number = |sign, weight, scale, digits: &[i16]| PgNumeric::Number {
+        sign,
+        weight,
+        scale,
+        digits: digits.to_vec(),
+    };
+
+    use PgNumericSign::{Negative, Positive};
+
+    assert_eq!(
+        PgNumeric::from_str("0").unwrap(),
+        number(Positive, 0, 0, &[])
+    );
+    assert_eq!(
+        PgNumeric::from_str("-0.00").unwrap(),
+        number(Positive, 0, 2, &[])
+    );
+    assert_eq!(
+        PgNumeric::from_str("1234").unwrap(),
+        number(Positive, 0, 0, &[1234])
+    );
+    assert_eq!(
+        PgNumeric::from_str("10000").unwrap(),
+        number(Positive, 1, 0, &[1])
+    );
+    assert_eq!(
+        PgNumeric::from_str("0.1").unwrap(),
+        number(Positive, -1, 1, &[1000])
+    );
+    assert_eq!(
+        PgNumeric::from_str("0.01234").unwrap(),
+        number(Positive, -1, 5, &[123, 4000])
+    );
+    assert_eq!(
+        PgNumeric::from_str("-12.3400").unwrap(),
+        number(Negative, 0, 4, &[12, 3400])
+    );
+    assert_eq!(
+        PgNumeric::from_str("+123456789.000000001").unwrap(),
+        number(Positive, 2, 9, &[1, 2345, 6789, 0, 0, 1000])
+    );
+    assert_eq!(
+        PgNumeric::from_str("1.5e-10").unwrap(),
+        number(Positive, -3, 11, &[150])
+    );
+    assert_eq!(
+        PgNumeric::from_str("12E4").unwrap(),
+        number(Positive, 1, 0, &[12])
+    );
+    assert_eq!(PgNumeric::from_str("NaN").unwrap(), PgNumeric::NotANumber);
+
+    for text in &["", "-", ".", "1.2.3", "1e", "12a", "Infinity"] {
+        assert!(PgNumeric::from_str(text).is_err(), "{:?}", text);
+    }
+}
@@ -10,6 +10,8 @@ pub(crate) struct PgSequenceDecoder<'de> {
     len: usize,
     is_text_record: bool,
     element_oid: Option<u32>,
+    // a text sequence that ends in a `,` has one more (NULL) element
+    trailing_null: bool,
 }
 
 impl<'de> PgSequenceDecoder<'de> {
@@ -34,6 +36,7 @@ impl<'de> PgSequenceDecoder<'de> {
             element_oid,
             data,
             len: 0,
+            trailing_null: false,
         }
     }
 
@@ -88,14 +91,31 @@ impl<'de> PgSequenceDecoder<'de> {
             }
 
             PgData::Text(ref mut s) => {
-                if s.is_empty() {
+                if s.is_empty() && !self.trailing_null {
                     return Ok(None);
                 }
 
+                self.trailing_null = false;
+
+                if !self.is_text_record && s.starts_with('{') {
+                    // a row of a multi-dimensional array is decoded as an array of its own
+                    let end = find_closing_brace(s)
+                        .ok_or_else(|| decode_err!("unterminated array: {:?}", s))?;
+
+                    let value = T::decode(PgValue::from_str(&s[..=end]))?;
+
+                    *s = s[end + 1..].strip_prefix(',').unwrap_or(&s[end + 1..]);
+
+                    self.len += 1;
+
+                    return Ok(Some(value));
+                }
+
                 let mut value = String::new();
                 let mut in_quotes = false;
                 let mut in_escape = false;
                 let mut in_maybe_quote_escape = false;
+                let mut is_quoted = false;
 
                 let end: Option<usize> = 'outer: loop {
                     let mut iter = s.char_indices().peekable();
@@ -137,6 +157,7 @@ impl<'de> PgSequenceDecoder<'de> {
 
                             '"' => {
                                 in_quotes = !in_quotes;
+                                is_quoted = true;
                             }
 
                             _ => value.push(ch),
@@ -150,16 +171,19 @@ impl<'de> PgSequenceDecoder<'de> {
                 // NOTE: We pass `0` as the type ID because we don't have a reasonable value
                 //       we could use. In TEXT mode, sequences aren't typed.
 
-                let value = T::decode(if end == Some(0) {
+                let value = T::decode(if end == Some(0) || s.is_empty() {
                     PgValue::null()
-                } else if !self.is_text_record && value == "NULL" {
+                } else if !self.is_text_record && !is_quoted && value == "NULL" {
                     // Yes, in arrays the text encoding of a NULL is just NULL
+                    // (the string "NULL" is quoted)
                     PgValue::null()
                 } else {
                     PgValue::from_str(&*value)
                 })?;
 
                 *s = if let Some(end) = end {
+                    self.trailing_null = end + 1 == s.len();
+
                     &s[end + 1..]
                 } else {
                     ""
@@ -173,6 +197,33 @@ impl<'de> PgSequenceDecoder<'de> {
     }
 }
 
+// Find the `}` that closes the array at the start of `s`
+fn find_closing_brace(s: &str) -> Option<usize> {
+    let mut depth = 0;
+    let mut in_quotes = false;
+    let mut in_escape = false;
+
+    for (index, ch) in s.char_indices() {
+        match ch {
+            _ if in_escape => in_escape = false,
+            '\\' => in_escape = true,
+            '"' => in_quotes = !in_quotes,
+            '{' if !in_quotes => depth += 1,
+            '}' if !in_quotes => {
+                depth -= 1;
+
+                if depth == 0 {
+                    return Some(index);
+                }
+            }
+
+            _ => {}
+        }
+    }
+
+    None
+}
+
 #[cfg(test)]
 impl<'de> From<&'de str> for PgSequenceDecoder<'de> {
     fn from(s: &'de str) -> Self {
@@ -198,6 +249,20 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn it_decodes_text_nulls() -> crate::Result<()> {
+        // select (NULL,'',NULL);
+        let data = r#"(,"",)"#;
+        let mut decoder = PgSequenceDecoder::from(data);
+
+        assert_eq!(decoder.decode::<Option<String>>()?, Some(None));
+        assert_eq!(decoder.decode::<Option<String>>()?, Some(Some("".into())));
+        assert_eq!(decoder.decode::<Option<String>>()?, Some(None));
+        assert_eq!(decoder.decode::<Option<String>>()?, None);
+
+        Ok(())
+    }
+
     #[test]
     fn it_decodes_text_nested_sequence() -> crate::Result<()> {
         // select ((1,array[false,true]),array[(1,4),(5,2)]);
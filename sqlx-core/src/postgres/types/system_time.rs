@@ -0,0 +1,293 @@
+use std::convert::TryFrom;
+use std::mem;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use byteorder::BigEndian;
+
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::io::Buf;
+use crate::postgres::protocol::TypeId;
+use crate::postgres::{PgData, PgRawBuffer, PgTypeInfo, PgValue, Postgres};
+use crate::types::Type;
+
+const MICROSECONDS_PER_SECOND: i64 = 1_000_000;
+const MICROSECONDS_PER_DAY: i64 = 24 * 60 * 60 * MICROSECONDS_PER_SECOND;
+
+// microseconds from the Unix epoch, 1970-01-01, to the Postgres epoch, 2000-01-01
+const POSTGRES_EPOCH_MICROSECONDS: i64 = 10_957 * MICROSECONDS_PER_DAY;
+
+// `infinity` and `-infinity`, which no `SystemTime` is
+const INFINITY: i64 = i64::MAX;
+const NEG_INFINITY: i64 = i64::MIN;
+
+impl Type<Postgres> for SystemTime {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::TIMESTAMPTZ, "TIMESTAMPTZ")
+    }
+}
+
+impl Type<Postgres> for [SystemTime] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::ARRAY_TIMESTAMPTZ, "TIMESTAMPTZ[]")
+    }
+}
+
+impl Type<Postgres> for Vec<SystemTime> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::ARRAY_TIMESTAMPTZ, "TIMESTAMPTZ[]")
+    }
+}
+
+// The microseconds since the Unix epoch of a time, rounded down
+fn to_unix_microseconds(time: SystemTime) -> Option<i64> {
+    match time.duration_since(UNIX_EPOCH) {
+        Ok(since) => i64::try_from(since.as_micros()).ok(),
+
+        Err(e) => {
+            let before = e.duration();
+            let micros = i64::try_from(before.as_micros()).ok()?;
+
+            if before.subsec_nanos() % 1_000 == 0 {
+                Some(-micros)
+            } else {
+                (-micros).checked_sub(1)
+            }
+        }
+    }
+}
+
+fn from_unix_microseconds(micros: i64) -> Option<SystemTime> {
+    if micros >= 0 {
+        UNIX_EPOCH.checked_add(Duration::from_micros(micros as u64))
+    } else {
+        UNIX_EPOCH.checked_sub(Duration::from_micros(-(i128::from(micros)) as u64))
+    }
+}
+
+// The days since the Unix epoch of a date of the proleptic Gregorian calendar, where the year
+// 1 BC is the year 0
+//
+// http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = if year >= 0 { year } else { year - 399 } / 400;
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
+
+fn parse_number(s: &str) -> Option<i64> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    s.parse().ok()
+}
+
+// Parse a `TIMESTAMPTZ` in the ISO format of Postgres, e.g. `2000-01-01 12:30:00.25+02` or
+// `0044-03-15 12:00:00+00 BC`, into microseconds since the Unix epoch
+fn parse(s: &str) -> Option<i64> {
+    let (s, bc) = match s.strip_suffix(" BC") {
+        Some(s) => (s, true),
+        None => (s, false),
+    };
+
+    let (date, time) = s.split_once(' ')?;
+
+    let mut date = date.splitn(3, '-').map(parse_number);
+    let year = date.next()??;
+    let month = date.next()??;
+    let day = date.next()??;
+
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+
+    let year = if bc { 1 - year } else { year };
+
+    let offset_at = time.rfind(&['+', '-'][..])?;
+    let (time, offset) = time.split_at(offset_at);
+
+    let (time, fraction) = match time.find('.') {
+        Some(dot) => (&time[..dot], &time[dot + 1..]),
+        None => (time, ""),
+    };
+
+    let mut time = time.splitn(3, ':').map(parse_number);
+    let hours = time.next()??;
+    let minutes = time.next()??;
+    let seconds = time.next()??;
+
+    let micros = match fraction.len() {
+        0 => 0,
+        len if len <= 6 => parse_number(fraction)? * 10_i64.pow(6 - len as u32),
+        _ => return None,
+    };
+
+    // the offset is `+HH`, `+HH:MM` or `+HH:MM:SS`
+    let sign = if offset.starts_with('-') { -1 } else { 1 };
+    let mut offset_seconds = 0;
+
+    for (part, unit) in offset[1..].split(':').zip(&[60 * 60, 60, 1]) {
+        offset_seconds += parse_number(part)? * unit;
+    }
+
+    let seconds = hours * 60 * 60 + minutes * 60 + seconds - sign * offset_seconds;
+
+    days_from_civil(year, month, day)
+        .checked_mul(MICROSECONDS_PER_DAY)?
+        .checked_add(seconds * MICROSECONDS_PER_SECOND + micros)
+}
+
+impl<'de> Decode<'de, Postgres> for SystemTime {
+    fn decode(value: PgValue<'de>) -> crate::Result<Self> {
+        let micros = match value.try_get()? {
+            PgData::Binary(mut buf) => {
+                let micros = buf.get_i64::<BigEndian>()?;
+
+                if micros == INFINITY || micros == NEG_INFINITY {
+                    return Err(crate::Error::Decode(
+                        "infinite TIMESTAMPTZ can not be a SystemTime".into(),
+                    ));
+                }
+
+                micros.checked_add(POSTGRES_EPOCH_MICROSECONDS)
+            }
+
+            PgData::Text(s) if s == "infinity" || s == "-infinity" => {
+                return Err(crate::Error::Decode(
+                    "infinite TIMESTAMPTZ can not be a SystemTime".into(),
+                ));
+            }
+
+            PgData::Text(s) => {
+                Some(parse(s).ok_or_else(|| decode_err!("invalid TIMESTAMPTZ: {:?}", s))?)
+            }
+        };
+
+        micros
+            .and_then(from_unix_microseconds)
+            .ok_or_else(|| crate::Error::Decode("TIMESTAMPTZ out of range for SystemTime".into()))
+    }
+}
+
+impl Encode<Postgres> for SystemTime {
+    fn encode(&self, buf: &mut PgRawBuffer) {
+        let micros = to_unix_microseconds(*self)
+            .and_then(|micros| micros.checked_sub(POSTGRES_EPOCH_MICROSECONDS))
+            .filter(|&micros| micros != INFINITY && micros != NEG_INFINITY)
+            .unwrap_or_else(|| panic!("SystemTime out of range for Postgres: {:?}", self));
+
+        Encode::<Postgres>::encode(&micros, buf);
+    }
+
+    fn size_hint(&self) -> usize {
+        mem::size_of::<i64>()
+    }
+}
+
+#[cfg(test)]
+fn postgres_epoch() -> SystemTime {
+    UNIX_EPOCH + Duration::from_secs(946_684_800)
+}
+
+#[test]
+fn test_encode_system_time() {
+    let mut buf = PgRawBuffer::default();
+
+    Encode::<Postgres>::encode(&postgres_epoch(), &mut buf);
+    assert_eq!(&**buf, 0_i64.to_be_bytes());
+    buf.clear();
+
+    // a microsecond on either side of the Postgres epoch
+    Encode::<Postgres>::encode(&(postgres_epoch() + Duration::from_micros(1)), &mut buf);
+    assert_eq!(&**buf, 1_i64.to_be_bytes());
+    buf.clear();
+
+    Encode::<Postgres>::encode(&(postgres_epoch() - Duration::from_micros(1)), &mut buf);
+    assert_eq!(&**buf, (-1_i64).to_be_bytes());
+    buf.clear();
+
+    // the Unix epoch
+    Encode::<Postgres>::encode(&UNIX_EPOCH, &mut buf);
+    assert_eq!(&**buf, (-946_684_800_000_000_i64).to_be_bytes());
+    buf.clear();
+
+    // nanoseconds are rounded down, also before the Unix epoch
+    Encode::<Postgres>::encode(&(postgres_epoch() + Duration::from_nanos(1_999)), &mut buf);
+    assert_eq!(&**buf, 1_i64.to_be_bytes());
+    buf.clear();
+
+    Encode::<Postgres>::encode(&(UNIX_EPOCH - Duration::from_nanos(1)), &mut buf);
+    assert_eq!(&**buf, (-946_684_800_000_001_i64).to_be_bytes());
+    buf.clear();
+}
+
+#[test]
+#[should_panic(expected = "SystemTime out of range for Postgres")]
+fn test_encode_system_time_out_of_range() {
+    let time = UNIX_EPOCH + Duration::from_micros(i64::MAX as u64) + Duration::from_secs(1);
+
+    Encode::<Postgres>::encode(&time, &mut PgRawBuffer::default());
+}
+
+#[test]
+fn test_decode_system_time() {
+    let decode = |micros: i64| -> crate::Result<SystemTime> {
+        Decode::<Postgres>::decode(PgValue::from_bytes(&micros.to_be_bytes()))
+    };
+
+    assert_eq!(decode(0).unwrap(), postgres_epoch());
+    assert_eq!(
+        decode(1).unwrap(),
+        postgres_epoch() + Duration::from_micros(1)
+    );
+    assert_eq!(
+        decode(-1).unwrap(),
+        postgres_epoch() - Duration::from_micros(1)
+    );
+    assert_eq!(decode(-946_684_800_000_000).unwrap(), UNIX_EPOCH);
+    assert_eq!(
+        decode(-946_684_800_000_001).unwrap(),
+        UNIX_EPOCH - Duration::from_micros(1)
+    );
+
+    // infinity, and a time that overflows the microseconds since the Unix epoch
+    assert!(decode(i64::MAX).is_err());
+    assert!(decode(i64::MIN).is_err());
+    assert!(decode(i64::MAX - 1).is_err());
+}
+
+#[test]
+fn test_decode_system_time_text() {
+    let decode =
+        |s: &str| -> crate::Result<SystemTime> { Decode::<Postgres>::decode(PgValue::from_str(s)) };
+
+    assert_eq!(decode("2000-01-01 00:00:00+00").unwrap(), postgres_epoch());
+    assert_eq!(
+        decode("1999-12-31 23:59:59.999999+00").unwrap(),
+        postgres_epoch() - Duration::from_micros(1)
+    );
+    assert_eq!(
+        decode("2000-01-01 02:00:00.5+02").unwrap(),
+        postgres_epoch() + Duration::from_millis(500)
+    );
+    assert_eq!(
+        decode("1999-12-31 18:29:00-05:30:30").unwrap(),
+        postgres_epoch() - Duration::from_secs(30)
+    );
+    assert_eq!(decode("1970-01-01 00:00:00+00").unwrap(), UNIX_EPOCH);
+
+    // the year 1 BC is the year before 1 AD, which is 719162 days before the Unix epoch
+    assert_eq!(
+        decode("0001-12-31 00:00:00+00 BC").unwrap(),
+        UNIX_EPOCH - Duration::from_secs(719_163 * 24 * 60 * 60)
+    );
+
+    assert!(decode("infinity").is_err());
+    assert!(decode("2000-13-01 00:00:00+00").is_err());
+    assert!(decode("2000-01-01").is_err());
+}
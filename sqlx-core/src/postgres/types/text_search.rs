@@ -0,0 +1,725 @@
+//! Postgres [text search types](https://www.postgresql.org/docs/current/datatype-textsearch.html).
+
+use byteorder::BigEndian;
+
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::io::Buf;
+use crate::postgres::protocol::TypeId;
+use crate::postgres::{PgData, PgRawBuffer, PgTypeInfo, PgValue, Postgres};
+use crate::types::Type;
+
+// positions are stored in the low 14 bits with the weight in the top 2 bits
+const MAX_POSITION: u16 = 0x3fff;
+
+const QI_VAL: u8 = 1;
+const QI_OPR: u8 = 2;
+
+const OP_NOT: u8 = 1;
+const OP_AND: u8 = 2;
+const OP_OR: u8 = 3;
+const OP_PHRASE: u8 = 4;
+
+/// The weight of a lexeme, `A` being the highest and `D` the default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum PgTsWeight {
+    A,
+    B,
+    C,
+    #[default]
+    D,
+}
+
+/// A lexeme of a [`PgTsVector`] with the positions it occurs at.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct PgTsLexeme {
+    pub text: String,
+    pub positions: Vec<(u16, PgTsWeight)>,
+}
+
+/// A Postgres `TSVECTOR`, a sorted list of distinct lexemes.
+///
+/// Postgres sorts the lexemes and merges duplicates itself, so they may be sent in any order.
+/// Positions are limited to `16383`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct PgTsVector {
+    pub lexemes: Vec<PgTsLexeme>,
+}
+
+/// A Postgres `TSQUERY`, a tree of lexemes combined with boolean and phrase operators.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub enum PgTsQuery {
+    /// A query without any lexemes, e.g. when every word is a stop word.
+    ///
+    /// This may only be used as the whole query.
+    #[default]
+    Empty,
+
+    /// A lexeme, optionally restricted to the given weights and matched as a prefix with `:*`.
+    Lexeme {
+        text: String,
+        weights: Vec<PgTsWeight>,
+        prefix: bool,
+    },
+
+    /// `!query`
+    Not(Box<PgTsQuery>),
+
+    /// `left & right`
+    And(Box<PgTsQuery>, Box<PgTsQuery>),
+
+    /// `left | right`
+    Or(Box<PgTsQuery>, Box<PgTsQuery>),
+
+    /// `left <distance> right`, where `<->` is a distance of `1`.
+    Phrase(Box<PgTsQuery>, Box<PgTsQuery>, u16),
+}
+
+impl PgTsWeight {
+    const ALL: [PgTsWeight; 4] = [PgTsWeight::A, PgTsWeight::B, PgTsWeight::C, PgTsWeight::D];
+
+    // the weight of a position in a `TSVECTOR`
+    fn to_bits(self) -> u16 {
+        match self {
+            PgTsWeight::A => 3,
+            PgTsWeight::B => 2,
+            PgTsWeight::C => 1,
+            PgTsWeight::D => 0,
+        }
+    }
+
+    fn from_bits(bits: u16) -> Self {
+        match bits & 3 {
+            3 => PgTsWeight::A,
+            2 => PgTsWeight::B,
+            1 => PgTsWeight::C,
+            _ => PgTsWeight::D,
+        }
+    }
+
+    // the weights of a lexeme in a `TSQUERY` are a bit set
+    fn to_mask(self) -> u8 {
+        1 << self.to_bits()
+    }
+
+    fn from_char(ch: char) -> Option<Self> {
+        match ch.to_ascii_uppercase() {
+            'A' => Some(PgTsWeight::A),
+            'B' => Some(PgTsWeight::B),
+            'C' => Some(PgTsWeight::C),
+            'D' => Some(PgTsWeight::D),
+            _ => None,
+        }
+    }
+}
+
+impl PgTsQuery {
+    /// Create a query matching a single lexeme.
+    pub fn lexeme(text: impl Into<String>) -> Self {
+        PgTsQuery::Lexeme {
+            text: text.into(),
+            weights: Vec::new(),
+            prefix: false,
+        }
+    }
+
+    // the number of items of the binary format
+    fn len(&self) -> usize {
+        match self {
+            PgTsQuery::Empty => 0,
+            PgTsQuery::Lexeme { .. } => 1,
+            PgTsQuery::Not(query) => 1 + query.len(),
+            PgTsQuery::And(left, right)
+            | PgTsQuery::Or(left, right)
+            | PgTsQuery::Phrase(left, right, _) => 1 + left.len() + right.len(),
+        }
+    }
+}
+
+impl Type<Postgres> for PgTsVector {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::TSVECTOR, "TSVECTOR")
+    }
+}
+
+impl Type<Postgres> for [PgTsVector] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::ARRAY_TSVECTOR, "TSVECTOR[]")
+    }
+}
+
+impl Type<Postgres> for Vec<PgTsVector> {
+    fn type_info() -> PgTypeInfo {
+        <[PgTsVector] as Type<Postgres>>::type_info()
+    }
+}
+
+impl Type<Postgres> for PgTsQuery {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::TSQUERY, "TSQUERY")
+    }
+}
+
+impl Type<Postgres> for [PgTsQuery] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::ARRAY_TSQUERY, "TSQUERY[]")
+    }
+}
+
+impl Type<Postgres> for Vec<PgTsQuery> {
+    fn type_info() -> PgTypeInfo {
+        <[PgTsQuery] as Type<Postgres>>::type_info()
+    }
+}
+
+// the number of lexemes followed by each lexeme as a NUL-terminated string, the number of
+// positions and the positions
+impl Encode<Postgres> for PgTsVector {
+    fn encode(&self, buf: &mut PgRawBuffer) {
+        buf.extend_from_slice(&(self.lexemes.len() as i32).to_be_bytes());
+
+        for lexeme in &self.lexemes {
+            buf.extend_from_slice(lexeme.text.as_bytes());
+            buf.push(0);
+
+            buf.extend_from_slice(&(lexeme.positions.len() as u16).to_be_bytes());
+
+            for (position, weight) in &lexeme.positions {
+                let entry = (weight.to_bits() << 14) | (*position).min(MAX_POSITION);
+
+                buf.extend_from_slice(&entry.to_be_bytes());
+            }
+        }
+    }
+
+    fn size_hint(&self) -> usize {
+        4 + self
+            .lexemes
+            .iter()
+            .map(|lexeme| lexeme.text.len() + 3 + lexeme.positions.len() * 2)
+            .sum::<usize>()
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for PgTsVector {
+    fn decode(value: PgValue<'de>) -> crate::Result<Self> {
+        match value.try_get()? {
+            PgData::Binary(mut buf) => {
+                let count = buf.get_i32::<BigEndian>()?;
+
+                if count < 0 {
+                    return Err(decode_err!(
+                        "invalid number of lexemes in TSVECTOR: {}",
+                        count
+                    ));
+                }
+
+                let mut lexemes = Vec::with_capacity((count as usize).min(buf.len()));
+
+                for _ in 0..count {
+                    let text = buf.get_str_nul()?.to_owned();
+                    let len = buf.get_u16::<BigEndian>()?;
+                    let mut positions = Vec::with_capacity(len as usize);
+
+                    for _ in 0..len {
+                        let entry = buf.get_u16::<BigEndian>()?;
+
+                        positions.push((entry & MAX_POSITION, PgTsWeight::from_bits(entry >> 14)));
+                    }
+
+                    lexemes.push(PgTsLexeme { text, positions });
+                }
+
+                if !buf.is_empty() {
+                    return Err(decode_err!("{} unread bytes after TSVECTOR", buf.len()));
+                }
+
+                Ok(PgTsVector { lexemes })
+            }
+
+            PgData::Text(s) => {
+                parse_ts_vector(s).ok_or_else(|| decode_err!("invalid TSVECTOR: {:?}", s))
+            }
+        }
+    }
+}
+
+// the number of items followed by the items in prefix order; the right operand of a binary
+// operator comes before the left operand
+impl Encode<Postgres> for PgTsQuery {
+    fn encode(&self, buf: &mut PgRawBuffer) {
+        buf.extend_from_slice(&(self.len() as i32).to_be_bytes());
+
+        if *self != PgTsQuery::Empty {
+            encode_query_item(buf, self);
+        }
+    }
+
+    fn size_hint(&self) -> usize {
+        4 + self.len() * 4
+    }
+}
+
+fn encode_query_item(buf: &mut PgRawBuffer, query: &PgTsQuery) {
+    match query {
+        // only valid on its own, where it has no items
+        PgTsQuery::Empty => {}
+
+        PgTsQuery::Lexeme {
+            text,
+            weights,
+            prefix,
+        } => {
+            buf.push(QI_VAL);
+            buf.push(
+                weights
+                    .iter()
+                    .fold(0, |mask, weight| mask | weight.to_mask()),
+            );
+            buf.push(*prefix as u8);
+            buf.extend_from_slice(text.as_bytes());
+            buf.push(0);
+        }
+
+        PgTsQuery::Not(query) => {
+            buf.push(QI_OPR);
+            buf.push(OP_NOT);
+
+            encode_query_item(buf, query);
+        }
+
+        PgTsQuery::And(left, right) | PgTsQuery::Or(left, right) => {
+            buf.push(QI_OPR);
+            buf.push(match query {
+                PgTsQuery::And(..) => OP_AND,
+                _ => OP_OR,
+            });
+
+            encode_query_item(buf, right);
+            encode_query_item(buf, left);
+        }
+
+        PgTsQuery::Phrase(left, right, distance) => {
+            buf.push(QI_OPR);
+            buf.push(OP_PHRASE);
+            buf.extend_from_slice(&distance.to_be_bytes());
+
+            encode_query_item(buf, right);
+            encode_query_item(buf, left);
+        }
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for PgTsQuery {
+    fn decode(value: PgValue<'de>) -> crate::Result<Self> {
+        match value.try_get()? {
+            PgData::Binary(mut buf) => {
+                let count = buf.get_i32::<BigEndian>()?;
+
+                if count < 0 {
+                    return Err(decode_err!("invalid number of items in TSQUERY: {}", count));
+                }
+
+                let query = if count == 0 {
+                    PgTsQuery::Empty
+                } else {
+                    decode_query_item(&mut buf)?
+                };
+
+                if query.len() != count as usize || !buf.is_empty() {
+                    return Err(decode_err!(
+                        "expected {} items in TSQUERY but received {}",
+                        count,
+                        query.len()
+                    ));
+                }
+
+                Ok(query)
+            }
+
+            PgData::Text(s) => TsQueryParser { s }
+                .parse()
+                .ok_or_else(|| decode_err!("invalid TSQUERY: {:?}", s)),
+        }
+    }
+}
+
+fn decode_query_item(buf: &mut &[u8]) -> crate::Result<PgTsQuery> {
+    match buf.get_u8()? {
+        QI_VAL => {
+            let mask = buf.get_u8()?;
+            let prefix = buf.get_u8()? != 0;
+            let text = buf.get_str_nul()?.to_owned();
+
+            let weights = PgTsWeight::ALL
+                .iter()
+                .copied()
+                .filter(|weight| mask & weight.to_mask() != 0)
+                .collect();
+
+            Ok(PgTsQuery::Lexeme {
+                text,
+                weights,
+                prefix,
+            })
+        }
+
+        QI_OPR => match buf.get_u8()? {
+            OP_NOT => Ok(PgTsQuery::Not(Box::new(decode_query_item(buf)?))),
+
+            OP_AND => {
+                let right = decode_query_item(buf)?;
+                let left = decode_query_item(buf)?;
+
+                Ok(PgTsQuery::And(Box::new(left), Box::new(right)))
+            }
+
+            OP_OR => {
+                let right = decode_query_item(buf)?;
+                let left = decode_query_item(buf)?;
+
+                Ok(PgTsQuery::Or(Box::new(left), Box::new(right)))
+            }
+
+            OP_PHRASE => {
+                let distance = buf.get_u16::<BigEndian>()?;
+                let right = decode_query_item(buf)?;
+                let left = decode_query_item(buf)?;
+
+                Ok(PgTsQuery::Phrase(Box::new(left), Box::new(right), distance))
+            }
+
+            operator => Err(decode_err!("unknown TSQUERY operator {}", operator)),
+        },
+
+        ty => Err(decode_err!("unknown TSQUERY item type {}", ty)),
+    }
+}
+
+// 'lexeme':1A,2 'other' ...
+fn parse_ts_vector(s: &str) -> Option<PgTsVector> {
+    let mut lexemes = Vec::new();
+    let mut s = s.trim_start();
+
+    while !s.is_empty() {
+        let (text, rest) = parse_quoted(s)?;
+        let mut positions = Vec::new();
+
+        s = rest;
+
+        if let Some(rest) = s.strip_prefix(':') {
+            let end = rest.find(' ').unwrap_or(rest.len());
+
+            for entry in rest[..end].split(',') {
+                let (position, weight) = match entry.chars().last().and_then(PgTsWeight::from_char)
+                {
+                    Some(weight) => (&entry[..entry.len() - 1], weight),
+                    None => (entry, PgTsWeight::D),
+                };
+
+                positions.push((position.parse().ok()?, weight));
+            }
+
+            s = &rest[end..];
+        }
+
+        lexemes.push(PgTsLexeme { text, positions });
+
+        s = s.trim_start();
+    }
+
+    Some(PgTsVector { lexemes })
+}
+
+// Postgres always quotes a lexeme and escapes `'` and `\` by doubling them
+fn parse_quoted(s: &str) -> Option<(String, &str)> {
+    let s = s.strip_prefix('\'')?;
+    let mut text = String::new();
+    let mut chars = s.char_indices().peekable();
+
+    while let Some((index, ch)) = chars.next() {
+        match ch {
+            '\\' => text.push(chars.next()?.1),
+
+            '\'' => match chars.peek() {
+                Some((_, '\'')) => {
+                    chars.next();
+                    text.push('\'');
+                }
+
+                _ => return Some((text, &s[index + 1..])),
+            },
+
+            _ => text.push(ch),
+        }
+    }
+
+    None
+}
+
+// a recursive descent parser for the text format; from the lowest to the highest precedence
+// the operators are `|`, `&`, `<->` and `!` where binary operators are left-associative
+struct TsQueryParser<'a> {
+    s: &'a str,
+}
+
+impl TsQueryParser<'_> {
+    fn parse(mut self) -> Option<PgTsQuery> {
+        if self.s.trim().is_empty() {
+            return Some(PgTsQuery::Empty);
+        }
+
+        let query = self.parse_or()?;
+
+        if self.s.trim().is_empty() {
+            Some(query)
+        } else {
+            None
+        }
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        match self.s.trim_start().strip_prefix(token) {
+            Some(rest) => {
+                self.s = rest;
+                true
+            }
+
+            None => false,
+        }
+    }
+
+    fn parse_or(&mut self) -> Option<PgTsQuery> {
+        let mut query = self.parse_and()?;
+
+        while self.eat("|") {
+            query = PgTsQuery::Or(Box::new(query), Box::new(self.parse_and()?));
+        }
+
+        Some(query)
+    }
+
+    fn parse_and(&mut self) -> Option<PgTsQuery> {
+        let mut query = self.parse_phrase()?;
+
+        while self.eat("&") {
+            query = PgTsQuery::And(Box::new(query), Box::new(self.parse_phrase()?));
+        }
+
+        Some(query)
+    }
+
+    fn parse_phrase(&mut self) -> Option<PgTsQuery> {
+        let mut query = self.parse_not()?;
+
+        while self.eat("<") {
+            let distance = if self.eat("->") {
+                1
+            } else {
+                let end = self.s.find('>')?;
+                let distance = self.s[..end].trim().parse().ok()?;
+
+                self.s = &self.s[end + 1..];
+
+                distance
+            };
+
+            query = PgTsQuery::Phrase(Box::new(query), Box::new(self.parse_not()?), distance);
+        }
+
+        Some(query)
+    }
+
+    fn parse_not(&mut self) -> Option<PgTsQuery> {
+        if self.eat("!") {
+            return Some(PgTsQuery::Not(Box::new(self.parse_not()?)));
+        }
+
+        if self.eat("(") {
+            let query = self.parse_or()?;
+
+            return if self.eat(")") { Some(query) } else { None };
+        }
+
+        let (text, rest) = parse_quoted(self.s.trim_start())?;
+        let mut weights = Vec::new();
+        let mut prefix = false;
+
+        self.s = rest;
+
+        if let Some(rest) = self.s.strip_prefix(':') {
+            let end = rest
+                .find(|ch: char| ch != '*' && PgTsWeight::from_char(ch).is_none())
+                .unwrap_or(rest.len());
+
+            for ch in rest[..end].chars() {
+                match PgTsWeight::from_char(ch) {
+                    Some(weight) => weights.push(weight),
+                    None => prefix = true,
+                }
+            }
+
+            self.s = &rest[end..];
+        }
+
+        Some(PgTsQuery::Lexeme {
+            text,
+            weights,
+            prefix,
+        })
+    }
+}
+
+#[cfg(test)]
+fn lexeme(text: &str, positions: &[(u16, PgTsWeight)]) -> PgTsLexeme {
+    PgTsLexeme {
+        text: text.to_owned(),
+        positions: positions.to_vec(),
+    }
+}
+
+#[test]
+fn test_encode_ts_vector() {
+    let vector = PgTsVector {
+        lexemes: vec![
+            lexeme("a", &[(1, PgTsWeight::A), (3, PgTsWeight::D)]),
+            lexeme("b", &[(2, PgTsWeight::D)]),
+        ],
+    };
+
+    let mut buf = PgRawBuffer::default();
+    Encode::<Postgres>::encode(&vector, &mut buf);
+
+    assert_eq!(
+        &**buf,
+        b"\0\0\0\x02a\0\0\x02\xc0\x01\0\x03b\0\0\x01\0\x02" as &[u8]
+    );
+}
+
+#[test]
+fn test_decode_ts_vector() {
+    let vector: PgTsVector = Decode::<Postgres>::decode(PgValue::from_bytes(
+        b"\0\0\0\x02a\0\0\x02\xc0\x01\0\x03b\0\0\x01\0\x02",
+    ))
+    .unwrap();
+
+    assert_eq!(
+        vector.lexemes,
+        [
+            lexeme("a", &[(1, PgTsWeight::A), (3, PgTsWeight::D)]),
+            lexeme("b", &[(2, PgTsWeight::D)]),
+        ]
+    );
+
+    let res: crate::Result<PgTsVector> =
+        Decode::<Postgres>::decode(PgValue::from_bytes(b"\0\0\0\x02a\0\0\0"));
+
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_decode_ts_vector_text() {
+    assert_eq!(parse_ts_vector(""), Some(PgTsVector::default()));
+
+    assert_eq!(
+        parse_ts_vector(r"'a\\b':1 'brown':3B,4 'it''s' 'x':2A,9C")
+            .unwrap()
+            .lexemes,
+        [
+            lexeme(r"a\b", &[(1, PgTsWeight::D)]),
+            lexeme("brown", &[(3, PgTsWeight::B), (4, PgTsWeight::D)]),
+            lexeme("it's", &[]),
+            lexeme("x", &[(2, PgTsWeight::A), (9, PgTsWeight::C)]),
+        ]
+    );
+
+    assert_eq!(parse_ts_vector("'a':x"), None);
+    assert_eq!(parse_ts_vector("'a"), None);
+}
+
+#[test]
+fn test_encode_ts_query() {
+    let query = PgTsQuery::And(
+        Box::new(PgTsQuery::lexeme("a")),
+        Box::new(PgTsQuery::Not(Box::new(PgTsQuery::lexeme("b")))),
+    );
+
+    let mut buf = PgRawBuffer::default();
+    Encode::<Postgres>::encode(&query, &mut buf);
+
+    assert_eq!(
+        &**buf,
+        b"\0\0\0\x04\x02\x02\x02\x01\x01\0\0b\0\x01\0\0a\0" as &[u8]
+    );
+
+    let mut buf = PgRawBuffer::default();
+    Encode::<Postgres>::encode(&PgTsQuery::Empty, &mut buf);
+
+    assert_eq!(&**buf, [0, 0, 0, 0]);
+}
+
+#[test]
+fn test_decode_ts_query() {
+    let query: PgTsQuery = Decode::<Postgres>::decode(PgValue::from_bytes(
+        b"\0\0\0\x03\x02\x04\0\x02\x01\x0c\x01b\0\x01\0\0a\0",
+    ))
+    .unwrap();
+
+    assert_eq!(
+        query,
+        PgTsQuery::Phrase(
+            Box::new(PgTsQuery::lexeme("a")),
+            Box::new(PgTsQuery::Lexeme {
+                text: "b".into(),
+                weights: vec![PgTsWeight::A, PgTsWeight::B],
+                prefix: true,
+            }),
+            2
+        )
+    );
+
+    let res: crate::Result<PgTsQuery> =
+        Decode::<Postgres>::decode(PgValue::from_bytes(b"\0\0\0\x02\x01\0\0a\0"));
+
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_decode_ts_query_text() {
+    let parse = |s| TsQueryParser { s }.parse();
+
+    assert_eq!(parse(""), Some(PgTsQuery::Empty));
+
+    assert_eq!(
+        parse("'a' & 'b' | !'c'"),
+        Some(PgTsQuery::Or(
+            Box::new(PgTsQuery::And(
+                Box::new(PgTsQuery::lexeme("a")),
+                Box::new(PgTsQuery::lexeme("b"))
+            )),
+            Box::new(PgTsQuery::Not(Box::new(PgTsQuery::lexeme("c"))))
+        ))
+    );
+
+    assert_eq!(
+        parse("'a' <-> ( 'b' <3> 'c':*A )"),
+        Some(PgTsQuery::Phrase(
+            Box::new(PgTsQuery::lexeme("a")),
+            Box::new(PgTsQuery::Phrase(
+                Box::new(PgTsQuery::lexeme("b")),
+                Box::new(PgTsQuery::Lexeme {
+                    text: "c".into(),
+                    weights: vec![PgTsWeight::A],
+                    prefix: true,
+                }),
+                3
+            )),
+            1
+        ))
+    );
+
+    assert_eq!(parse("'it''s'"), Some(PgTsQuery::lexeme("it's")));
+    assert_eq!(parse("'a' &"), None);
+    assert_eq!(parse("( 'a'"), None);
+    assert_eq!(parse("'a' 'b'"), None);
+}
@@ -32,6 +32,12 @@ impl<'c> PgValue<'c> {
         self.data
     }
 
+    /// Returns true if the value is `NULL`.
+    #[inline]
+    pub(crate) fn is_null(&self) -> bool {
+        self.data.is_none()
+    }
+
     pub(crate) fn null() -> Self {
         Self {
             type_info: None,
@@ -5,11 +5,11 @@ use futures_core::Stream;
 use futures_util::future::ready;
 use futures_util::TryFutureExt;
 
-use crate::arguments::Arguments;
+use crate::arguments::{Arguments, ToArguments};
 use crate::cursor::{Cursor, HasCursor};
 use crate::database::Database;
 use crate::encode::Encode;
-use crate::executor::{Execute, Executor, RefExecutor};
+use crate::executor::{Execute, Executor, QueryResult, RefExecutor};
 use crate::row::HasRow;
 use crate::types::Type;
 
@@ -74,6 +74,40 @@ where
         self
     }
 
+    /// Bind a `NULL` of the SQL type of `T` for use with this SQL query.
+    ///
+    /// This is the same as binding `None::<T>`. **Postgres** is sent the type of each
+    /// parameter, so e.g. `pg_typeof($1)` or `$1 IS NULL` do not need a cast in the query.
+    ///
+    /// ```rust,ignore
+    /// sqlx::query("UPDATE users SET deleted_at = $1 WHERE id = $2")
+    ///     .bind_null::<DateTime<Utc>>()
+    ///     .bind(id)
+    /// ```
+    pub fn bind_null<T>(self) -> Self
+    where
+        T: Type<DB>,
+        T: Encode<DB>,
+    {
+        self.bind(None::<T>)
+    }
+
+    /// Bind the fields of a record, in order, for use with this SQL query.
+    ///
+    /// See [`ToArguments`] to derive it for a struct.
+    ///
+    /// ```rust,ignore
+    /// sqlx::query("INSERT INTO users (name, email) VALUES ($1, $2)")
+    ///     .bind_struct(&new_user)
+    /// ```
+    pub fn bind_struct<T>(mut self, value: &T) -> Self
+    where
+        T: ToArguments<DB>,
+    {
+        value.add_to(&mut self.arguments);
+        self
+    }
+
     #[doc(hidden)]
     pub fn bind_all(self, arguments: DB::Arguments) -> Query<'q, DB> {
         Query {
@@ -84,6 +118,34 @@ where
     }
 }
 
+#[cfg(feature = "postgres")]
+impl<'q> Query<'q, crate::postgres::Postgres> {
+    /// Bind a value that **Postgres** parses as a value of the SQL type of `T`, for use with
+    /// this SQL query.
+    ///
+    /// The type of `T` is sent as the type of the parameter, as [`bind`](Self::bind) sends the
+    /// type of its value, and the value is sent as text, formatted with its `Display`. This can
+    /// bind a parameter whose type Postgres could not infer from the query, or from the type of
+    /// an argument bound as-is.
+    ///
+    /// ```rust,ignore
+    /// // `$1 + 1` with a `TEXT` parameter is an error
+    /// sqlx::query("SELECT $1 + 1")
+    ///     .bind_as::<i64, _>(&form.count)
+    ///
+    /// sqlx::query("SELECT * FROM events WHERE payload @> $1")
+    ///     .bind_as::<serde_json::Value, _>(r#"{"kind": "login"}"#)
+    /// ```
+    pub fn bind_as<T, V>(mut self, value: V) -> Self
+    where
+        T: Type<crate::postgres::Postgres>,
+        V: std::fmt::Display,
+    {
+        self.arguments.add_as::<T>(&value);
+        self
+    }
+}
+
 impl<'q, DB> Query<'q, DB>
 where
     DB: Database,
@@ -121,7 +183,7 @@ where
     DB: Database,
     Self: Execute<'q, DB>,
 {
-    pub async fn execute<E>(self, mut executor: E) -> crate::Result<u64>
+    pub async fn execute<E>(self, mut executor: E) -> crate::Result<QueryResult>
     where
         E: Executor<Database = DB>,
     {
@@ -188,6 +250,27 @@ where
             .await
     }
 
+    /// Get the only row in the result, returning an error if there are no rows or more than one.
+    pub async fn fetch_exactly_one<'e, E>(self, executor: E) -> crate::Result<F::Output>
+    where
+        E: RefExecutor<'e, Database = DB>,
+        'q: 'e,
+    {
+        let mut cursor = executor.fetch_by_ref(self.query);
+        let mut mapper = self.mapper;
+
+        let row = match cursor.next().await? {
+            Some(row) => mapper.try_map_row(row)?,
+            None => return Err(crate::Error::RowNotFound),
+        };
+
+        if cursor.next().await?.is_some() {
+            return Err(crate::Error::TooManyRows);
+        }
+
+        Ok(row)
+    }
+
     pub async fn fetch_all<'e, E>(mut self, executor: E) -> crate::Result<Vec<F::Output>>
     where
         E: RefExecutor<'e, Database = DB>,
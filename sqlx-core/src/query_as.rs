@@ -1,6 +1,6 @@
 use core::marker::PhantomData;
 
-use crate::arguments::Arguments;
+use crate::arguments::{Arguments, ToArguments};
 use crate::database::Database;
 use crate::encode::Encode;
 use crate::executor::Execute;
@@ -15,7 +15,7 @@ where
     DB: Database,
 {
     query: &'q str,
-    arguments: <DB as Database>::Arguments,
+    pub(crate) arguments: <DB as Database>::Arguments,
     database: PhantomData<DB>,
     output: PhantomData<O>,
 }
@@ -34,6 +34,47 @@ where
         self.arguments.add(value);
         self
     }
+
+    /// Bind a `NULL` of the SQL type of `T` for use with this SQL query.
+    ///
+    /// See [`Query::bind_null`](crate::query::Query::bind_null).
+    #[inline]
+    pub fn bind_null<T>(self) -> Self
+    where
+        T: Type<DB>,
+        T: Encode<DB>,
+    {
+        self.bind(None::<T>)
+    }
+
+    /// Bind the fields of a record, in order, for use with this SQL query.
+    ///
+    /// See [`Query::bind_struct`](crate::query::Query::bind_struct).
+    #[inline]
+    pub fn bind_struct<T>(mut self, value: &T) -> Self
+    where
+        T: ToArguments<DB>,
+    {
+        value.add_to(&mut self.arguments);
+        self
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl<'q, O> QueryAs<'q, crate::postgres::Postgres, O> {
+    /// Bind a value that **Postgres** parses as a value of the SQL type of `T`, for use with
+    /// this SQL query.
+    ///
+    /// See [`Query::bind_as`](crate::query::Query::bind_as).
+    #[inline]
+    pub fn bind_as<T, V>(mut self, value: V) -> Self
+    where
+        T: Type<crate::postgres::Postgres>,
+        V: std::fmt::Display,
+    {
+        self.arguments.add_as::<T>(&value);
+        self
+    }
 }
 
 impl<'q, DB, O: Send> Execute<'q, DB> for QueryAs<'q, DB, O>
@@ -49,6 +90,11 @@ where
 /// Construct a raw SQL query that is mapped to a concrete type
 /// using [`FromRow`](crate::row::FromRow).
 ///
+/// The rows returned by any statement can be mapped, e.g. by an `INSERT .. RETURNING` in
+/// **Postgres**. A statement is prepared the first time it is run on a connection and is then
+/// kept in its statement cache, so running it again sends the arguments and reads the rows in
+/// a single round-trip.
+///
 /// Returns [`QueryAs`].
 pub fn query_as<DB, O>(sql: &str) -> QueryAs<DB, O>
 where
@@ -100,6 +146,15 @@ macro_rules! make_query_as {
                 O: 'e + Send + for<'c> crate::row::FromRow<'c, $row<'c>>,
                 'q: 'e;
 
+            fn fetch_exactly_one<'e, E>(
+                self,
+                executor: E,
+            ) -> futures_core::future::BoxFuture<'e, crate::Result<O>>
+            where
+                E: 'e + Send + crate::executor::RefExecutor<'e, Database = $db>,
+                O: 'e + Send + for<'c> crate::row::FromRow<'c, $row<'c>>,
+                'q: 'e;
+
             fn fetch_optional<'e, E>(
                 self,
                 executor: E,
@@ -169,6 +224,33 @@ macro_rules! make_query_as {
                 }))
             }
 
+            fn fetch_exactly_one<'e, E>(
+                self,
+                executor: E,
+            ) -> futures_core::future::BoxFuture<'e, crate::Result<O>>
+            where
+                E: 'e + Send + crate::executor::RefExecutor<'e, Database = $db>,
+                O: 'e + Send + for<'c> crate::row::FromRow<'c, $row<'c>>,
+                'q: 'e,
+            {
+                use crate::cursor::Cursor;
+
+                Box::pin(async move {
+                    let mut cursor = executor.fetch_by_ref(self);
+
+                    let obj = match cursor.next().await? {
+                        Some(row) => O::from_row(&row)?,
+                        None => return Err(crate::Error::RowNotFound),
+                    };
+
+                    if cursor.next().await?.is_some() {
+                        return Err(crate::Error::TooManyRows);
+                    }
+
+                    Ok(obj)
+                })
+            }
+
             fn fetch_all<'e, E>(
                 self,
                 executor: E,
@@ -0,0 +1,254 @@
+//! Build a SQL query at runtime, e.g. to bind a variable number of values.
+
+use std::fmt::{Display, Write};
+
+use crate::arguments::Arguments;
+use crate::database::Database;
+use crate::encode::Encode;
+use crate::executor::Executor;
+use crate::query::{query, Query};
+use crate::types::Type;
+
+/// A SQL query that is built up piece by piece, with values bound as they are pushed.
+///
+/// Each bound value is written as the placeholder of the database (`$1 .. $N` for Postgres, `?`
+/// for MySQL and SQLite) so the same code builds a valid query for any of them.
+///
+/// ```rust,ignore
+/// let mut builder = QueryBuilder::<Postgres>::new("SELECT name FROM users WHERE id IN (");
+///
+/// builder.push_bind_separated(&[1_i32, 2, 3], ", ").push(")");
+///
+/// // SELECT name FROM users WHERE id IN ($1, $2, $3)
+/// let names = builder.build().fetch_all(&mut conn).await?;
+/// ```
+pub struct QueryBuilder<DB>
+where
+    DB: Database,
+{
+    query: String,
+    arguments: Option<DB::Arguments>,
+}
+
+impl<DB> QueryBuilder<DB>
+where
+    DB: Database,
+{
+    /// Start a new query with the given SQL.
+    pub fn new(init: impl Into<String>) -> Self {
+        Self {
+            query: init.into(),
+            arguments: Some(Default::default()),
+        }
+    }
+
+    /// Append SQL to the query.
+    ///
+    /// The SQL is **not** escaped; values from outside the program should be bound with
+    /// [`push_bind`] instead.
+    ///
+    /// [`push_bind`]: #method.push_bind
+    pub fn push(&mut self, sql: impl Display) -> &mut Self {
+        write!(self.query, "{}", sql).expect("error formatting `sql`");
+
+        self
+    }
+
+    /// Bind a value and append its placeholder to the query.
+    pub fn push_bind<T>(&mut self, value: T) -> &mut Self
+    where
+        T: Type<DB>,
+        T: Encode<DB>,
+    {
+        let arguments = self.arguments.as_mut().expect(ERR_BUILT);
+
+        arguments.add(value);
+        arguments
+            .format_placeholder(&mut self.query)
+            .expect("error in format_placeholder");
+
+        self
+    }
+
+    /// Bind each of the values and append their placeholders to the query, with `separator`
+    /// between them.
+    ///
+    /// Nothing is appended if there are no values; note that `IN ()` is not valid SQL.
+    pub fn push_bind_separated<I, T>(&mut self, values: I, separator: &str) -> &mut Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Type<DB>,
+        T: Encode<DB>,
+    {
+        for (index, value) in values.into_iter().enumerate() {
+            if index > 0 {
+                self.query.push_str(separator);
+            }
+
+            self.push_bind(value);
+        }
+
+        self
+    }
+
+    /// Append `VALUES` followed by a tuple for each of `tuples`, e.g. to insert many rows in
+    /// one statement.
+    ///
+    /// `push_tuple` is called with each value and a [`Separated`] that adds its contents to the
+    /// tuple with `, ` between them. Keep the number of bound values under
+    /// [`Arguments::MAX_BINDS`] or use [`execute_values`] to split the tuples across statements.
+    ///
+    /// ```rust,ignore
+    /// let mut builder = QueryBuilder::<Postgres>::new("INSERT INTO users (id, name) ");
+    ///
+    /// // INSERT INTO users (id, name) VALUES ($1, $2), ($3, $4)
+    /// builder.push_values(users, |mut b, user| {
+    ///     b.push_bind(user.id).push_bind(user.name);
+    /// });
+    /// ```
+    ///
+    /// [`Arguments::MAX_BINDS`]: crate::arguments::Arguments::MAX_BINDS
+    /// [`execute_values`]: #method.execute_values
+    pub fn push_values<I, F>(&mut self, tuples: I, mut push_tuple: F) -> &mut Self
+    where
+        I: IntoIterator,
+        F: FnMut(Separated<'_, DB>, I::Item),
+    {
+        self.query.push_str("VALUES ");
+
+        for (index, tuple) in tuples.into_iter().enumerate() {
+            if index > 0 {
+                self.query.push_str(", ");
+            }
+
+            self.query.push('(');
+
+            push_tuple(
+                Separated {
+                    builder: self,
+                    separator: ", ",
+                    push_separator: false,
+                },
+                tuple,
+            );
+
+            self.query.push(')');
+        }
+
+        self
+    }
+
+    /// Run `init` followed by the tuples of [`push_values`], in as many statements as it takes
+    /// to keep each under [`Arguments::MAX_BINDS`] bound values. Returns the total number of
+    /// rows affected.
+    ///
+    /// `binds_per_tuple` is the number of values bound by `push_tuple` for each tuple.
+    ///
+    /// ```rust,ignore
+    /// let rows = QueryBuilder::execute_values(
+    ///     &mut conn,
+    ///     "INSERT INTO users (id, name) ",
+    ///     users,
+    ///     2,
+    ///     |mut b, user| {
+    ///         b.push_bind(user.id).push_bind(user.name);
+    ///     },
+    /// )
+    /// .await?;
+    /// ```
+    ///
+    /// The statements are not run in a transaction; if one fails the rows of those before it
+    /// remain.
+    ///
+    /// [`push_values`]: #method.push_values
+    /// [`Arguments::MAX_BINDS`]: crate::arguments::Arguments::MAX_BINDS
+    pub async fn execute_values<E, I, F>(
+        mut executor: E,
+        init: &str,
+        tuples: I,
+        binds_per_tuple: usize,
+        mut push_tuple: F,
+    ) -> crate::Result<u64>
+    where
+        E: Executor<Database = DB>,
+        I: IntoIterator,
+        F: FnMut(Separated<'_, DB>, I::Item),
+    {
+        let chunk_size = (DB::Arguments::MAX_BINDS / binds_per_tuple.max(1)).max(1);
+        let mut tuples = tuples.into_iter().peekable();
+        let mut rows = 0;
+
+        while tuples.peek().is_some() {
+            let mut builder = QueryBuilder::new(init);
+
+            builder.push_values(tuples.by_ref().take(chunk_size), &mut push_tuple);
+
+            rows += executor.execute(builder.build()).await?.rows_affected();
+        }
+
+        Ok(rows)
+    }
+
+    /// The SQL of the query built so far.
+    pub fn sql(&self) -> &str {
+        &self.query
+    }
+
+    /// Finish the query, moving the bound values into the returned [`Query`].
+    ///
+    /// # Panics
+    /// If the query was already built; a `QueryBuilder` can only be built once.
+    pub fn build(&mut self) -> Query<'_, DB> {
+        let arguments = self.arguments.take().expect(ERR_BUILT);
+
+        query(&self.query).bind_all(arguments)
+    }
+}
+
+const ERR_BUILT: &str = "QueryBuilder was already built";
+
+/// Adds SQL and bound values to a [`QueryBuilder`] with a separator between each of them.
+///
+/// Given to the closure of [`QueryBuilder::push_values`] for the contents of a tuple.
+pub struct Separated<'b, DB>
+where
+    DB: Database,
+{
+    builder: &'b mut QueryBuilder<DB>,
+    separator: &'static str,
+    push_separator: bool,
+}
+
+impl<'b, DB> Separated<'b, DB>
+where
+    DB: Database,
+{
+    /// Append the separator (if this is not the first item) and then SQL.
+    pub fn push(&mut self, sql: impl Display) -> &mut Self {
+        self.separate();
+        self.builder.push(sql);
+
+        self
+    }
+
+    /// Append the separator (if this is not the first item) and then bind a value and
+    /// append its placeholder.
+    pub fn push_bind<T>(&mut self, value: T) -> &mut Self
+    where
+        T: Type<DB>,
+        T: Encode<DB>,
+    {
+        self.separate();
+        self.builder.push_bind(value);
+
+        self
+    }
+
+    fn separate(&mut self) {
+        if self.push_separator {
+            self.builder.query.push_str(self.separator);
+        }
+
+        self.push_separator = true;
+    }
+}
@@ -1,5 +1,8 @@
 //! Contains the `ColumnIndex`, `Row`, and `FromRow` traits.
 
+use std::borrow::Cow;
+use std::fmt::{self, Debug};
+
 use crate::database::Database;
 use crate::decode::Decode;
 use crate::types::{Type, TypeInfo};
@@ -31,7 +34,7 @@ where
 
 impl<'c, R, I> ColumnIndex<'c, R> for &'_ I
 where
-    R: Row<'c>,
+    R: Row<'c> + ?Sized,
     I: ColumnIndex<'c, R> + ?Sized,
 {
     #[inline]
@@ -145,7 +148,7 @@ where
         I: ColumnIndex<'c, Self>,
         T: Decode<'c, Self::Database>,
     {
-        let value = self.try_get_raw(index)?;
+        let value = self.try_get_raw(&index)?;
 
         if let Some(expected_ty) = value.type_info() {
             // NOTE: If there is no type, the value is NULL. This is fine. If the user tries
@@ -153,13 +156,15 @@ where
             //       UnexpectedNullError.
 
             if !expected_ty.compatible(&T::type_info()) {
-                return Err(crate::Error::mismatched_types::<Self::Database, T>(
-                    expected_ty,
+                return Err(column_decode_error(
+                    self,
+                    &index,
+                    crate::Error::mismatched_types::<Self::Database, T>(expected_ty),
                 ));
             }
         }
 
-        T::decode(value)
+        T::decode(value).map_err(|error| column_decode_error(self, &index, error))
     }
 
     /// Index into the database row and decode a single value.
@@ -175,16 +180,140 @@ where
         I: ColumnIndex<'c, Self>,
         T: Decode<'c, Self::Database>,
     {
-        self.try_get_raw(index).and_then(T::decode)
+        let value = self.try_get_raw(&index)?;
+
+        T::decode(value).map_err(|error| column_decode_error(self, &index, error))
     }
 
-    #[doc(hidden)]
+    /// Index into the database row and return the value without decoding it.
+    ///
+    /// The raw value can be inspected before it is decoded, e.g. to choose the Rust type
+    /// from its type or (for Postgres and MySQL) to check if it was sent in the binary or the
+    /// text format. [`RawValue::type_info`] returns `None` for a `NULL` value.
+    ///
+    /// # Errors
+    ///  * [`ColumnNotFound`] if the column by the given name was not found.
+    ///  * [`ColumnIndexOutOfBounds`] if the `usize` index was greater than the number of columns in the row.
+    ///
+    /// [`RawValue::type_info`]: crate::value::RawValue::type_info
+    /// [`ColumnNotFound`]: crate::Error::ColumnNotFound
+    /// [`ColumnIndexOutOfBounds`]: crate::Error::ColumnIndexOutOfBounds
     fn try_get_raw<I>(
         &self,
         index: I,
     ) -> crate::Result<<Self::Database as HasRawValue<'c>>::RawValue>
     where
         I: ColumnIndex<'c, Self>;
+
+    /// Index into the database row and return the name, position and type of a column.
+    ///
+    /// ```rust,ignore
+    /// for index in 0..row.len() {
+    ///     let column = row.column(index);
+    ///
+    ///     println!("{:?}: {:?}", column.name(), column.type_info());
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the column does not exist.
+    /// See [`try_column`](#method.try_column) for a non-panicking version.
+    #[inline]
+    fn column<I>(&self, index: I) -> Column<'_, Self::Database>
+    where
+        I: ColumnIndex<'c, Self>,
+    {
+        self.try_column(index).unwrap()
+    }
+
+    /// Index into the database row and return the name, position and type of a column.
+    ///
+    /// # Errors
+    ///  * [`ColumnNotFound`] if the column by the given name was not found.
+    ///  * [`ColumnIndexOutOfBounds`] if the `usize` index was greater than the number of columns in the row.
+    ///
+    /// [`ColumnNotFound`]: crate::Error::ColumnNotFound
+    /// [`ColumnIndexOutOfBounds`]: crate::Error::ColumnIndexOutOfBounds
+    fn try_column<I>(&self, index: I) -> crate::Result<Column<'_, Self::Database>>
+    where
+        I: ColumnIndex<'c, Self>;
+}
+
+/// A column of a [`Row`], returned by [`Row::column`].
+///
+/// [`Row`]: trait.Row.html
+/// [`Row::column`]: trait.Row.html#method.column
+pub struct Column<'r, DB>
+where
+    DB: Database,
+{
+    pub(crate) name: Option<&'r str>,
+    pub(crate) ordinal: usize,
+    pub(crate) type_info: Option<DB::TypeInfo>,
+}
+
+impl<'r, DB> Column<'r, DB>
+where
+    DB: Database,
+{
+    /// The name of the column, if it has one.
+    pub fn name(&self) -> Option<&'r str> {
+        self.name
+    }
+
+    /// The position of the column in the row, starting at `0`.
+    pub fn ordinal(&self) -> usize {
+        self.ordinal
+    }
+
+    /// The type of the column.
+    ///
+    /// SQLite does not give columns a type; this is the declared type of the column in its
+    /// table or, for an expression, the type of the value in this row (`None` if it is `NULL`).
+    /// The type is always known for Postgres and MySQL.
+    pub fn type_info(&self) -> Option<&DB::TypeInfo> {
+        self.type_info.as_ref()
+    }
+}
+
+impl<DB> Debug for Column<'_, DB>
+where
+    DB: Database,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Column")
+            .field("name", &self.name)
+            .field("ordinal", &self.ordinal)
+            .field("type_info", &self.type_info)
+            .finish()
+    }
+}
+
+// Adds the column to an error from decoding its value
+#[doc(hidden)]
+pub fn column_decode_error<'c, R, I>(row: &R, index: I, error: crate::Error) -> crate::Error
+where
+    R: Row<'c> + ?Sized,
+    I: ColumnIndex<'c, R>,
+{
+    let source = match error {
+        crate::Error::Decode(source) => source,
+        error => return error,
+    };
+
+    match row.try_column(index) {
+        Ok(column) => crate::Error::ColumnDecode {
+            index: match column.name() {
+                Some(name) => format!("{:?}", name),
+                None => column.ordinal().to_string(),
+            }
+            .into(),
+            type_name: column.type_info().map(|ty| ty.to_string().into()),
+            source,
+        },
+
+        Err(_) => crate::Error::Decode(source),
+    }
 }
 
 // Prevent users from implementing the `Row` trait.
@@ -224,6 +353,38 @@ pub trait HasRow<'c> {
 /// }
 /// ```
 ///
+/// The fields can be changed with attributes:
+///
+///  * `#[sqlx(rename = "column")]` reads the field from the column with the given name.
+///  * `#[sqlx(default)]` uses [`Default`] for the field if the row has no such column.
+///  * `#[sqlx(skip)]` does not read the field and always uses [`Default`] for it.
+///  * `#[sqlx(flatten)]` reads the field, a type that also implements `FromRow`, from the same
+///    row, e.g. to select the columns of two tables in a join. The names of its columns are
+///    given by its own fields and their attributes.
+///  * `#[sqlx(prefix = "author_")]` on a flattened field reads the columns of the flattened
+///    struct with the prefix.
+///
+/// On the struct, `#[sqlx(rename_all = "..")]` changes the case of the names of all its fields:
+/// one of `"lowercase"`, `"UPPERCASE"`, `"snake_case"`, `"SCREAMING_SNAKE_CASE"`,
+/// `"camelCase"` or `"PascalCase"`. A field with `#[sqlx(rename)]` keeps its name as it is given.
+///
+/// The name of a column is the prefix (if any) followed by the renamed field, so the fields of
+/// a flattened struct are renamed by their own struct and the prefix is added to every one of
+/// them, including those with `#[sqlx(rename)]`. The prefixes of nested flattened fields are
+/// joined, outermost first.
+///
+/// ```rust,ignore
+/// #[derive(sqlx::FromRow)]
+/// struct Post {
+///     id: i32,
+///     title: String,
+///
+///     // the columns `author_id` and `author_name`
+///     #[sqlx(flatten, prefix = "author_")]
+///     author: User,
+/// }
+/// ```
+///
 /// [`query_as`]: crate::query_as
 /// [`Row::try_get`]: crate::row::Row::try_get
 pub trait FromRow<'c, R>
@@ -233,6 +394,28 @@ where
 {
     #[allow(missing_docs)]
     fn from_row(row: &R) -> crate::Result<Self>;
+
+    /// Build the record from the columns whose names start with `prefix`; used for a
+    /// `#[sqlx(flatten)]` field with a `#[sqlx(prefix = "..")]`.
+    ///
+    /// Only the derived implementations look up their columns with the prefix; otherwise this
+    /// is the same as [`from_row`](#tymethod.from_row).
+    #[doc(hidden)]
+    fn from_row_with_prefix(row: &R, prefix: &str) -> crate::Result<Self> {
+        let _ = prefix;
+
+        Self::from_row(row)
+    }
+}
+
+// The name of the column `name` with `prefix`, used by the derived implementations of `FromRow`
+#[doc(hidden)]
+pub fn prefixed<'a>(prefix: &str, name: &'a str) -> Cow<'a, str> {
+    if prefix.is_empty() {
+        Cow::Borrowed(name)
+    } else {
+        Cow::Owned(format!("{}{}", prefix, name))
+    }
 }
 
 // Macros to help unify the internal implementations as a good chunk
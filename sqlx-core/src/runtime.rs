@@ -26,3 +26,9 @@ pub(crate) use tokio::{
     time::delay_for as sleep,
     time::timeout,
 };
+
+#[cfg(all(feature = "runtime-async-std", unix))]
+pub(crate) use async_std::os::unix::net::UnixStream;
+
+#[cfg(all(feature = "runtime-tokio", unix))]
+pub(crate) use tokio::net::UnixStream;
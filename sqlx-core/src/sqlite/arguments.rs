@@ -1,15 +1,21 @@
 use core::ffi::c_void;
 use core::mem;
 
+use std::fmt;
 use std::os::raw::{c_char, c_int};
 
 use libsqlite3_sys::{
     sqlite3_bind_blob, sqlite3_bind_double, sqlite3_bind_int, sqlite3_bind_int64,
-    sqlite3_bind_null, sqlite3_bind_text, SQLITE_OK, SQLITE_TRANSIENT,
+    sqlite3_bind_null, sqlite3_bind_text, sqlite3_context, sqlite3_result_blob,
+    sqlite3_result_double, sqlite3_result_int, sqlite3_result_int64, sqlite3_result_null,
+    sqlite3_result_text, SQLITE_OK, SQLITE_TRANSIENT,
 };
 
 use crate::arguments::Arguments;
 use crate::encode::{Encode, IsNull};
+use crate::logger::LogArguments;
+use crate::query::Query;
+use crate::query_as::QueryAs;
 use crate::sqlite::statement::Statement;
 use crate::sqlite::Sqlite;
 use crate::sqlite::SqliteError;
@@ -36,9 +42,29 @@ pub enum SqliteArgumentValue {
 pub struct SqliteArguments {
     index: usize,
     values: Vec<SqliteArgumentValue>,
+    pub(super) named: Vec<(String, SqliteArgumentValue)>,
 }
 
 impl SqliteArguments {
+    /// Add the value for the parameter with the given name, e.g. `:id`.
+    ///
+    /// A name without a prefix is bound to each of the `:name`, `@name` and `$name`
+    /// parameters. The value is bound to every use of the parameter in the query.
+    pub fn add_named<T>(&mut self, name: &str, value: T)
+    where
+        T: Encode<Sqlite> + Type<Sqlite>,
+    {
+        let mut values = Vec::with_capacity(1);
+
+        if let IsNull::Yes = value.encode_nullable(&mut values) {
+            values.push(SqliteArgumentValue::Null);
+        }
+
+        let value = values.pop().unwrap_or(SqliteArgumentValue::Null);
+
+        self.named.push((name.to_owned(), value));
+    }
+
     pub(crate) fn next(&mut self) -> Option<SqliteArgumentValue> {
         if self.index >= self.values.len() {
             return None;
@@ -55,6 +81,9 @@ impl SqliteArguments {
 impl Arguments for SqliteArguments {
     type Database = Sqlite;
 
+    // the default `SQLITE_MAX_VARIABLE_NUMBER` of the bundled SQLite
+    const MAX_BINDS: usize = 999;
+
     fn reserve(&mut self, len: usize, _size_hint: usize) {
         self.values.reserve(len);
     }
@@ -69,6 +98,65 @@ impl Arguments for SqliteArguments {
     }
 }
 
+impl<'q> Query<'q, Sqlite> {
+    /// Bind a value to the parameter with the given name, e.g. `:id`, for use with this SQL
+    /// query.
+    ///
+    /// A name without a prefix is bound to each of the `:name`, `@name` and `$name`
+    /// parameters; the value is bound to every use of the parameter in the query. An error is
+    /// returned when the query is executed if it has no parameter with the name.
+    ///
+    /// ```rust,ignore
+    /// sqlx::query("SELECT * FROM users WHERE id = :id OR parent_id = :id")
+    ///     .bind_named("id", 1)
+    /// ```
+    pub fn bind_named<T>(mut self, name: &str, value: T) -> Self
+    where
+        T: Type<Sqlite>,
+        T: Encode<Sqlite>,
+    {
+        self.arguments.add_named(name, value);
+        self
+    }
+}
+
+impl<'q, O> QueryAs<'q, Sqlite, O> {
+    /// Bind a value to the parameter with the given name, e.g. `:id`, for use with this SQL
+    /// query.
+    ///
+    /// See [`Query::bind_named`](crate::query::Query::bind_named).
+    pub fn bind_named<T>(mut self, name: &str, value: T) -> Self
+    where
+        T: Type<Sqlite>,
+        T: Encode<Sqlite>,
+    {
+        self.arguments.add_named(name, value);
+        self
+    }
+}
+
+impl fmt::Debug for SqliteArguments {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut list = f.debug_list();
+
+        list.entries(&self.values);
+
+        for (name, value) in &self.named {
+            list.entry(&format_args!("{} = {:?}", name, value));
+        }
+
+        list.finish()
+    }
+}
+
+impl LogArguments for SqliteArguments {
+    const DB_SYSTEM: &'static str = "sqlite";
+
+    fn count(&self) -> usize {
+        self.values.len() + self.named.len()
+    }
+}
+
 impl SqliteArgumentValue {
     pub(super) fn bind(&self, statement: &mut Statement, index: usize) -> crate::Result<()> {
         let handle = unsafe {
@@ -127,4 +215,31 @@ impl SqliteArgumentValue {
 
         Ok(())
     }
+    // Sets the value as the result of a call to a user-defined function
+    pub(super) fn result(&self, context: *mut sqlite3_context) {
+        // https://www.sqlite.org/c3ref/result_blob.html
+        match self {
+            SqliteArgumentValue::Blob(value) => {
+                let bytes_ptr = value.as_ptr() as *const c_void;
+                let bytes_len = value.len() as i32;
+
+                unsafe { sqlite3_result_blob(context, bytes_ptr, bytes_len, SQLITE_TRANSIENT()) }
+            }
+
+            SqliteArgumentValue::Text(value) => {
+                let bytes_ptr = value.as_ptr() as *const c_char;
+                let bytes_len = value.len() as i32;
+
+                unsafe { sqlite3_result_text(context, bytes_ptr, bytes_len, SQLITE_TRANSIENT()) }
+            }
+
+            SqliteArgumentValue::Double(value) => unsafe { sqlite3_result_double(context, *value) },
+
+            SqliteArgumentValue::Int(value) => unsafe { sqlite3_result_int(context, *value) },
+
+            SqliteArgumentValue::Int64(value) => unsafe { sqlite3_result_int64(context, *value) },
+
+            SqliteArgumentValue::Null => unsafe { sqlite3_result_null(context) },
+        }
+    }
 }
@@ -0,0 +1,223 @@
+use core::ptr::{null_mut, NonNull};
+
+use std::ffi::CString;
+use std::io;
+use std::os::raw::c_int;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use libsqlite3_sys::{
+    sqlite3_blob, sqlite3_blob_bytes, sqlite3_blob_close, sqlite3_blob_open, sqlite3_blob_read,
+    sqlite3_blob_write, SQLITE_OK,
+};
+
+use crate::runtime::{AsyncRead, AsyncWrite};
+use crate::sqlite::{SqliteConnection, SqliteError};
+
+/// Thin wrapper around [sqlite3_blob] to impl `Send`.
+#[derive(Clone, Copy)]
+struct SqliteBlobHandle(NonNull<sqlite3_blob>);
+
+// A BLOB handle is used under the same rules as the connection it was opened on; see the notes
+// on [SqliteConnectionHandle].
+unsafe impl Send for SqliteBlobHandle {}
+
+impl SqliteConnection {
+    /// Open the BLOB in `column` of the row with the given `rowid` in `table` for incremental
+    /// I/O, so it can be read or written in chunks without holding all of it in memory.
+    ///
+    /// `db` is the name of the database the table is in: `"main"`, `"temp"` or the name given
+    /// to an attached database. Returns an error if there is no such table, column or row.
+    ///
+    /// The size of a BLOB can not be changed through the handle; insert the row with
+    /// `zeroblob(N)` to make room for the data before writing it.
+    ///
+    /// ```rust,ignore
+    /// sqlx::query("INSERT INTO files (id, data) VALUES (?, zeroblob(?))")
+    ///     .bind(1)
+    ///     .bind(contents.len() as i64)
+    ///     .execute(&mut conn)
+    ///     .await?;
+    ///
+    /// let mut blob = conn.blob_open("main", "files", "data", 1, false).await?;
+    ///
+    /// blob.write_all(&contents).await?;
+    /// ```
+    pub async fn blob_open(
+        &mut self,
+        db: &str,
+        table: &str,
+        column: &str,
+        rowid: i64,
+        readonly: bool,
+    ) -> crate::Result<SqliteBlob<'_>> {
+        self.rollback_if_pending().await?;
+
+        let db = CString::new(db).map_err(|_| protocol_err!("database name contains a NUL"))?;
+        let table = CString::new(table).map_err(|_| protocol_err!("table name contains a NUL"))?;
+        let column =
+            CString::new(column).map_err(|_| protocol_err!("column name contains a NUL"))?;
+
+        let conn = self.handle;
+
+        let handle = self
+            .worker
+            .run(move || -> crate::Result<SqliteBlobHandle> {
+                let mut handle = null_mut();
+
+                // <https://www.sqlite.org/c3ref/blob_open.html>
+                let status = unsafe {
+                    sqlite3_blob_open(
+                        conn.0.as_ptr(),
+                        db.as_ptr(),
+                        table.as_ptr(),
+                        column.as_ptr(),
+                        rowid,
+                        if readonly { 0 } else { 1 },
+                        &mut handle,
+                    )
+                };
+
+                if status != SQLITE_OK {
+                    return Err(SqliteError::from_connection(conn.0.as_ptr()).into());
+                }
+
+                Ok(SqliteBlobHandle(NonNull::new(handle).unwrap()))
+            })
+            .await?;
+
+        // <https://www.sqlite.org/c3ref/blob_bytes.html>
+        let size = unsafe { sqlite3_blob_bytes(handle.0.as_ptr()) } as usize;
+
+        Ok(SqliteBlob {
+            conn: self,
+            handle,
+            size,
+            offset: 0,
+        })
+    }
+}
+
+/// A BLOB opened for incremental I/O with [`SqliteConnection::blob_open`].
+///
+/// Reads and writes start at the beginning of the BLOB and advance through it. The BLOB can
+/// not be written past its end.
+///
+/// The handle is closed on drop. If the row is changed or deleted while the handle is open,
+/// further reads and writes return an error.
+pub struct SqliteBlob<'c> {
+    conn: &'c mut SqliteConnection,
+    handle: SqliteBlobHandle,
+    size: usize,
+    offset: usize,
+}
+
+impl SqliteBlob<'_> {
+    /// The size of the BLOB in bytes.
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    /// Returns `true` if the BLOB is empty.
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    fn error(&mut self) -> io::Error {
+        io::Error::other(SqliteError::from_connection(self.conn.handle()))
+    }
+}
+
+// Like reading the columns of a row, reading and writing a BLOB is done on the calling thread
+// and is always ready
+
+impl AsyncRead for SqliteBlob<'_> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        _: &mut Context,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let len = buf.len().min(self.size - self.offset);
+
+        if len == 0 {
+            return Poll::Ready(Ok(0));
+        }
+
+        // <https://www.sqlite.org/c3ref/blob_read.html>
+        let status = unsafe {
+            sqlite3_blob_read(
+                self.handle.0.as_ptr(),
+                buf.as_mut_ptr() as *mut _,
+                len as c_int,
+                self.offset as c_int,
+            )
+        };
+
+        if status != SQLITE_OK {
+            return Poll::Ready(Err(self.error()));
+        }
+
+        self.offset += len;
+
+        Poll::Ready(Ok(len))
+    }
+}
+
+impl AsyncWrite for SqliteBlob<'_> {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        _: &mut Context,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let len = buf.len().min(self.size - self.offset);
+
+        if len == 0 && !buf.is_empty() {
+            return Poll::Ready(Err(io::Error::new(
+                io::ErrorKind::WriteZero,
+                "can not write past the end of a BLOB",
+            )));
+        }
+
+        // <https://www.sqlite.org/c3ref/blob_write.html>
+        let status = unsafe {
+            sqlite3_blob_write(
+                self.handle.0.as_ptr(),
+                buf.as_ptr() as *const _,
+                len as c_int,
+                self.offset as c_int,
+            )
+        };
+
+        if status != SQLITE_OK {
+            return Poll::Ready(Err(self.error()));
+        }
+
+        self.offset += len;
+
+        Poll::Ready(Ok(len))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context) -> Poll<io::Result<()>> {
+        // writes go directly to the database
+        Poll::Ready(Ok(()))
+    }
+
+    #[cfg(feature = "runtime-async-std")]
+    fn poll_close(self: Pin<&mut Self>, _: &mut Context) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    #[cfg(feature = "runtime-tokio")]
+    fn poll_shutdown(self: Pin<&mut Self>, _: &mut Context) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl Drop for SqliteBlob<'_> {
+    fn drop(&mut self) {
+        // https://www.sqlite.org/c3ref/blob_close.html
+        unsafe {
+            let _ = sqlite3_blob_close(self.handle.0.as_ptr());
+        }
+    }
+}
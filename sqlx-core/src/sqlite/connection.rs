@@ -9,12 +9,16 @@ use futures_util::future;
 use libsqlite3_sys::{
     sqlite3, sqlite3_close, sqlite3_extended_result_codes, sqlite3_open_v2, SQLITE_OK,
     SQLITE_OPEN_CREATE, SQLITE_OPEN_NOMUTEX, SQLITE_OPEN_READWRITE, SQLITE_OPEN_SHAREDCACHE,
+    SQLITE_OPEN_URI,
 };
 
-use crate::connection::{Connect, Connection};
+use crate::connection::{Connect, Connection, LogSettings};
 use crate::executor::Executor;
+use crate::query_as::query_as;
 use crate::sqlite::statement::Statement;
 use crate::sqlite::worker::Worker;
+use crate::sqlite::{SqliteConnectOptions, SqliteQueryAs};
+use crate::transaction::{AccessMode, TransactionOptions};
 
 use crate::sqlite::SqliteError;
 use crate::url::Url;
@@ -24,6 +28,18 @@ use crate::url::Url;
 pub(super) struct SqliteConnectionHandle(pub(super) NonNull<sqlite3>);
 
 /// A connection to a [Sqlite](struct.Sqlite.html) database.
+///
+/// The URL is the path of the database file, e.g. `sqlite:///path/to/data.db`, or `:memory:`
+/// for an in-memory database. Each connection to `sqlite::memory:` has its own private
+/// database; add `?cache=shared` to give every connection to it in the process, like those of
+/// a pool, the same database. A shared in-memory database is dropped when its last connection
+/// is closed.
+///
+/// Parameters of the URL are [URI parameters] of SQLite. Use `mode=memory` to open a named
+/// in-memory database, e.g. `sqlite://test?mode=memory&cache=shared`, to keep it apart from
+/// the others.
+///
+/// [URI parameters]: https://www.sqlite.org/uri.html#recognized_query_parameters
 pub struct SqliteConnection {
     pub(super) handle: SqliteConnectionHandle,
     pub(super) worker: Worker,
@@ -32,6 +48,9 @@ pub struct SqliteConnection {
     // Storage of persistent statements
     pub(super) statements: Vec<Statement>,
     pub(super) statement_by_query: HashMap<String, usize>,
+    // Is a dropped transaction waiting to be rolled back before the next statement
+    pub(super) pending_rollback: bool,
+    pub(super) log_settings: LogSettings,
 }
 
 // A SQLite3 handle is safe to send between threads, provided not more than
@@ -54,9 +73,17 @@ async fn establish(url: Result<Url, url::ParseError>) -> crate::Result<SqliteCon
         .trim_start_matches("sqlite:")
         .trim_start_matches("//");
 
-    // By default, we connect to an in-memory database.
+    // If the URL has parameters, such as `cache=shared` or `mode=memory`, the database is
+    // opened with a URI filename to give them to SQLite
+    // <https://www.sqlite.org/uri.html>
+    let (filename, uri_flag) = if url.contains('?') {
+        (format!("file:{}", url), SQLITE_OPEN_URI)
+    } else {
+        (url.to_owned(), 0)
+    };
+
     // TODO: Handle the error when there are internal NULs in the database URL
-    let filename = CString::new(url).unwrap();
+    let filename = CString::new(filename).unwrap();
 
     let handle = worker
         .run(move || -> crate::Result<SqliteConnectionHandle> {
@@ -67,7 +94,8 @@ async fn establish(url: Result<Url, url::ParseError>) -> crate::Result<SqliteCon
             let flags = SQLITE_OPEN_READWRITE
                 | SQLITE_OPEN_CREATE
                 | SQLITE_OPEN_NOMUTEX
-                | SQLITE_OPEN_SHAREDCACHE;
+                | SQLITE_OPEN_SHAREDCACHE
+                | uri_flag;
 
             // <https://www.sqlite.org/c3ref/open.html>
             let status = unsafe { sqlite3_open_v2(filename.as_ptr(), &mut handle, flags, null()) };
@@ -103,10 +131,26 @@ async fn establish(url: Result<Url, url::ParseError>) -> crate::Result<SqliteCon
         statement: None,
         statements: Vec::with_capacity(10),
         statement_by_query: HashMap::with_capacity(10),
+        pending_rollback: false,
+        log_settings: LogSettings::default(),
     })
 }
 
 impl SqliteConnection {
+    /// Establish a new connection with the given options.
+    ///
+    /// See [`SqliteConnectOptions`] for the options that can be set.
+    pub async fn connect_with<T>(url: T, options: &SqliteConnectOptions) -> crate::Result<Self>
+    where
+        T: TryInto<Url, Error = url::ParseError>,
+    {
+        let mut conn = establish(url.try_into()).await?;
+
+        options.apply(&mut conn).await?;
+
+        Ok(conn)
+    }
+
     #[inline]
     pub(super) fn handle(&mut self) -> *mut sqlite3 {
         self.handle.0.as_ptr()
@@ -124,16 +168,7 @@ impl Connect for SqliteConnection {
         Box::pin(async move {
             let mut conn = establish(url).await?;
 
-            // https://www.sqlite.org/wal.html
-
-            // language=SQLite
-            conn.execute(
-                r#"
-PRAGMA journal_mode = WAL;
-PRAGMA synchronous = NORMAL;
-                "#,
-            )
-            .await?;
+            SqliteConnectOptions::default().apply(&mut conn).await?;
 
             Ok(conn)
         })
@@ -150,6 +185,55 @@ impl Connection for SqliteConnection {
         // For SQLite connections, PING does effectively nothing
         Box::pin(future::ok(()))
     }
+
+    fn set_log_settings(&mut self, settings: LogSettings) {
+        self.log_settings = settings;
+    }
+
+    fn reset(&mut self) -> BoxFuture<'_, crate::Result<()>> {
+        Box::pin(async move {
+            // the temporary objects are the only state of a connection that can be removed;
+            // that of a `PRAGMA` is kept as there is no record of its value before
+            let objects: Vec<(String, String)> = query_as(
+                "SELECT type, name FROM temp.sqlite_master WHERE type IN ('view', 'trigger', 'table')",
+            )
+            .fetch_all(&mut *self)
+            .await?;
+
+            // a trigger may have been dropped with its table
+            for (kind, name) in objects {
+                let drop = format!(
+                    "DROP {} IF EXISTS temp.\"{}\"",
+                    kind.to_uppercase(),
+                    name.replace('"', "\"\"")
+                );
+
+                self.execute(&*drop).await?;
+            }
+
+            Ok(())
+        })
+    }
+
+    #[doc(hidden)]
+    fn rollback_on_next_use(&mut self) {
+        self.pending_rollback = true;
+    }
+
+    #[doc(hidden)]
+    fn begin_statement(&self, options: &TransactionOptions) -> crate::Result<String> {
+        // every transaction is serializable, which meets any isolation level that is asked for
+
+        if options.access_mode == Some(AccessMode::ReadOnly) {
+            return Err(protocol_err!("SQLite does not support read only transactions").into());
+        }
+
+        if options.deferrable {
+            return Err(protocol_err!("SQLite does not support deferrable transactions").into());
+        }
+
+        Ok("BEGIN".to_owned())
+    }
 }
 
 impl Drop for SqliteConnection {
@@ -3,6 +3,7 @@ use futures_core::future::BoxFuture;
 use crate::connection::ConnectionSource;
 use crate::cursor::Cursor;
 use crate::executor::Execute;
+use crate::logger::QueryLogger;
 use crate::pool::Pool;
 use crate::sqlite::statement::Step;
 use crate::sqlite::{Sqlite, SqliteArguments, SqliteConnection, SqliteRow};
@@ -12,47 +13,71 @@ pub struct SqliteCursor<'c, 'q> {
     query: &'q str,
     arguments: Option<SqliteArguments>,
     pub(super) statement: Option<Option<usize>>,
+    logger: Option<QueryLogger<'q>>,
 }
 
 impl crate::cursor::private::Sealed for SqliteCursor<'_, '_> {}
 
-impl<'c, 'q> Cursor<'c, 'q> for SqliteCursor<'c, 'q> {
-    type Database = Sqlite;
-
-    #[doc(hidden)]
-    fn from_pool<E>(pool: &Pool<SqliteConnection>, query: E) -> Self
+impl<'c, 'q> SqliteCursor<'c, 'q> {
+    // Also used by the cursor of `Any` for a connection of a pool of `AnyConnection`
+    pub(crate) fn from_source<E>(source: ConnectionSource<'c, SqliteConnection>, query: E) -> Self
     where
-        Self: Sized,
         E: Execute<'q, Sqlite>,
     {
         let (query, arguments) = query.into_parts();
 
         Self {
-            source: ConnectionSource::Pool(pool.clone()),
+            source,
             statement: None,
             query,
             arguments,
+            logger: None,
         }
     }
+}
+
+impl<'c, 'q> Cursor<'c, 'q> for SqliteCursor<'c, 'q> {
+    type Database = Sqlite;
 
     #[doc(hidden)]
-    fn from_connection<E>(conn: &'c mut SqliteConnection, query: E) -> Self
+    fn from_pool<E>(pool: &Pool<SqliteConnection>, query: E) -> Self
     where
         Self: Sized,
         E: Execute<'q, Sqlite>,
     {
-        let (query, arguments) = query.into_parts();
+        Self::from_source(ConnectionSource::Pool(pool.clone()), query)
+    }
 
-        Self {
-            source: ConnectionSource::ConnectionRef(conn),
-            statement: None,
-            query,
-            arguments,
-        }
+    #[doc(hidden)]
+    fn from_connection<E>(conn: &'c mut SqliteConnection, query: E) -> Self
+    where
+        Self: Sized,
+        E: Execute<'q, Sqlite>,
+    {
+        Self::from_source(ConnectionSource::ConnectionRef(conn), query)
     }
 
     fn next(&mut self) -> BoxFuture<crate::Result<Option<SqliteRow<'_>>>> {
-        Box::pin(next(self))
+        Box::pin(async move {
+            if self.statement.is_none() && self.logger.is_none() {
+                let conn = self.source.resolve().await?;
+
+                self.logger = Some(QueryLogger::new(
+                    self.query,
+                    self.arguments.as_ref(),
+                    &conn.log_settings,
+                ));
+            }
+
+            let span = self.logger.as_ref().map(QueryLogger::span);
+            let result = next(self).await;
+
+            if let (Err(error), Some(span)) = (&result, span) {
+                span.record_error(error);
+            }
+
+            result
+        })
     }
 }
 
@@ -61,6 +86,10 @@ async fn next<'a, 'c: 'a, 'q: 'a>(
 ) -> crate::Result<Option<SqliteRow<'a>>> {
     let conn = cursor.source.resolve().await?;
 
+    if cursor.statement.is_none() {
+        conn.rollback_if_pending().await?;
+    }
+
     loop {
         if cursor.statement.is_none() {
             let key = conn.prepare(&mut cursor.query, cursor.arguments.is_some())?;
@@ -79,6 +108,10 @@ async fn next<'a, 'c: 'a, 'q: 'a>(
 
         match step {
             Step::Row => {
+                if let Some(logger) = &mut cursor.logger {
+                    logger.add_rows(1);
+                }
+
                 return Ok(Some(SqliteRow {
                     values: statement.data_count(),
                     statement: key,
@@ -87,6 +120,8 @@ async fn next<'a, 'c: 'a, 'q: 'a>(
             }
 
             Step::Done if cursor.query.is_empty() => {
+                // all of the rows were returned
+                cursor.logger = None;
                 return Ok(None);
             }
 
@@ -1,4 +1,4 @@
-use crate::error::DatabaseError;
+use crate::error::{DatabaseError, DatabaseErrorKind};
 
 use bitflags::_core::str::from_utf8_unchecked;
 use libsqlite3_sys::{sqlite3, sqlite3_errmsg, sqlite3_extended_errcode};
@@ -49,6 +49,25 @@ impl DatabaseError for SqliteError {
         Some(&self.code)
     }
 
+    // https://www.sqlite.org/rescode.html#extrc
+    fn kind(&self) -> DatabaseErrorKind {
+        match self.code.parse::<c_int>() {
+            // SQLITE_CONSTRAINT_UNIQUE, SQLITE_CONSTRAINT_PRIMARYKEY
+            Ok(2067) | Ok(1555) => DatabaseErrorKind::UniqueViolation,
+
+            // SQLITE_CONSTRAINT_FOREIGNKEY
+            Ok(787) => DatabaseErrorKind::ForeignKeyViolation,
+
+            // SQLITE_CONSTRAINT_NOTNULL
+            Ok(1299) => DatabaseErrorKind::NotNullViolation,
+
+            // SQLITE_CONSTRAINT_CHECK
+            Ok(275) => DatabaseErrorKind::CheckViolation,
+
+            _ => DatabaseErrorKind::Other,
+        }
+    }
+
     fn as_ref_err(&self) -> &(dyn StdError + Send + Sync + 'static) {
         self
     }
@@ -1,14 +1,18 @@
+use async_stream::try_stream;
 use futures_core::future::BoxFuture;
+use futures_core::stream::BoxStream;
+use futures_util::TryStreamExt;
 
-use libsqlite3_sys::sqlite3_changes;
+use libsqlite3_sys::{sqlite3_changes, sqlite3_last_insert_rowid, sqlite3_total_changes};
 
 use crate::cursor::Cursor;
 use crate::describe::{Column, Describe};
-use crate::executor::{Execute, Executor, RefExecutor};
+use crate::executor::{Execute, Executor, QueryResult, RefExecutor};
+use crate::logger::QueryLogger;
 use crate::sqlite::cursor::SqliteCursor;
 use crate::sqlite::statement::{Statement, Step};
 use crate::sqlite::type_info::SqliteType;
-use crate::sqlite::{Sqlite, SqliteConnection, SqliteTypeInfo};
+use crate::sqlite::{Sqlite, SqliteArguments, SqliteConnection, SqliteTypeInfo};
 
 impl SqliteConnection {
     pub(super) fn prepare(
@@ -54,6 +58,18 @@ impl SqliteConnection {
         Ok(Some(key))
     }
 
+    // Rolls back a transaction that was dropped since the connection was last used
+    pub(super) async fn rollback_if_pending(&mut self) -> crate::Result<()> {
+        if self.pending_rollback {
+            self.pending_rollback = false;
+
+            let key = self.prepare(&mut "ROLLBACK", false)?;
+            self.statement_mut(key).step().await?;
+        }
+
+        Ok(())
+    }
+
     // This is used for [affected_rows] in the public API.
     fn changes(&mut self) -> u64 {
         // Returns the number of rows modified, inserted or deleted by the most recently
@@ -64,6 +80,23 @@ impl SqliteConnection {
         changes as u64
     }
 
+    fn total_changes(&mut self) -> u64 {
+        // Returns the number of rows modified, inserted or deleted by all INSERT, UPDATE or
+        // DELETE statements completed since the connection was opened.
+
+        // https://www.sqlite.org/c3ref/total_changes.html
+        let changes = unsafe { sqlite3_total_changes(self.handle()) };
+        changes as u64
+    }
+
+    fn last_insert_rowid(&mut self) -> i64 {
+        // Returns the rowid of the most recent successful INSERT into a rowid table
+        // on the connection.
+
+        // https://www.sqlite.org/c3ref/last_insert_rowid.html
+        unsafe { sqlite3_last_insert_rowid(self.handle()) }
+    }
+
     #[inline]
     pub(super) fn statement(&self, key: Option<usize>) -> &Statement {
         match key {
@@ -87,34 +120,87 @@ impl Executor for SqliteConnection {
     fn execute<'e, 'q: 'e, 'c: 'e, E: 'e>(
         &'c mut self,
         query: E,
-    ) -> BoxFuture<'e, crate::Result<u64>>
+    ) -> BoxFuture<'e, crate::Result<QueryResult>>
     where
         E: Execute<'q, Self::Database>,
     {
         let (mut query, mut arguments) = query.into_parts();
 
         Box::pin(async move {
-            loop {
-                let key = self.prepare(&mut query, arguments.is_some())?;
-                let statement = self.statement_mut(key);
+            let mut logger = QueryLogger::new(query, arguments.as_ref(), &self.log_settings);
 
-                if let Some(arguments) = &mut arguments {
-                    statement.bind(arguments)?;
-                }
+            let result = async {
+                self.rollback_if_pending().await?;
 
-                while let Step::Row = statement.step().await? {
-                    // We only care about the rows modified; ignore
-                }
+                loop {
+                    let key = self.prepare(&mut query, arguments.is_some())?;
+                    let statement = self.statement_mut(key);
 
-                if query.is_empty() {
-                    break;
+                    if let Some(arguments) = &mut arguments {
+                        statement.bind(arguments)?;
+                    }
+
+                    while let Step::Row = statement.step().await? {
+                        // We only care about the rows modified; ignore
+                    }
+
+                    if query.is_empty() {
+                        break;
+                    }
                 }
+
+                Ok(QueryResult {
+                    rows_affected: self.changes(),
+                    last_insert_id: Some(self.last_insert_rowid()),
+                })
             }
+            .await;
 
-            Ok(self.changes())
+            match &result {
+                Ok(result) => logger.add_rows(result.rows_affected),
+                Err(error) => logger.record_error(error),
+            }
+
+            result
         })
     }
 
+    fn execute_many<'e, 'q: 'e, 'c: 'e>(
+        &'c mut self,
+        mut query: &'q str,
+    ) -> BoxStream<'e, crate::Result<QueryResult>> {
+        let mut logger = QueryLogger::new::<SqliteArguments>(query, None, &self.log_settings);
+        let span = logger.span();
+
+        let stream = try_stream! {
+            self.rollback_if_pending().await?;
+
+            while !query.is_empty() {
+                let key = self.prepare(&mut query, false)?;
+
+                // e.g. a comment after the last statement
+                if unsafe { self.statement(key).handle() }.is_none() {
+                    continue;
+                }
+
+                // [changes] is only set by INSERT, UPDATE and DELETE statements
+                let total_changes = self.total_changes();
+
+                while let Step::Row = self.statement_mut(key).step().await? {}
+
+                let rows_affected = self.total_changes() - total_changes;
+                logger.add_rows(rows_affected);
+
+                yield QueryResult {
+                    rows_affected,
+                    last_insert_id: Some(self.last_insert_rowid()),
+                };
+            }
+        };
+
+        Box::pin(stream.inspect_err(move |error| span.record_error(error)))
+    }
+
     fn fetch<'q, E>(&mut self, query: E) -> SqliteCursor<'_, 'q>
     where
         E: Execute<'q, Self::Database>,
@@ -122,7 +208,6 @@ impl Executor for SqliteConnection {
         SqliteCursor::from_connection(self, query)
     }
 
-    #[doc(hidden)]
     fn describe<'e, 'q, E: 'e>(
         &'e mut self,
         query: E,
@@ -131,6 +216,8 @@ impl Executor for SqliteConnection {
         E: Execute<'q, Self::Database>,
     {
         Box::pin(async move {
+            self.rollback_if_pending().await?;
+
             let (mut query, _) = query.into_parts();
             let key = self.prepare(&mut query, false)?;
             let statement = self.statement_mut(key);
@@ -147,18 +234,7 @@ impl Executor for SqliteConnection {
                 let name = statement.column_name(i).to_owned();
                 let decl = statement.column_decltype(i);
 
-                let r#type = match decl {
-                    None => None,
-                    Some(decl) => match &*decl.to_ascii_lowercase() {
-                        "bool" | "boolean" => Some(SqliteType::Boolean),
-                        "clob" | "text" => Some(SqliteType::Text),
-                        "blob" => Some(SqliteType::Blob),
-                        "real" | "double" | "double precision" | "float" => Some(SqliteType::Float),
-                        decl @ _ if decl.contains("int") => Some(SqliteType::Integer),
-                        decl @ _ if decl.contains("char") => Some(SqliteType::Text),
-                        _ => None,
-                    },
-                };
+                let r#type = decl.and_then(SqliteType::from_decltype);
 
                 columns.push(Column {
                     name: Some(name.into()),
@@ -0,0 +1,228 @@
+use core::ffi::c_void;
+
+use std::ffi::CString;
+use std::os::raw::{c_char, c_int};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::slice;
+
+use libsqlite3_sys::{
+    sqlite3_context, sqlite3_create_function_v2, sqlite3_result_error, sqlite3_user_data,
+    sqlite3_value, SQLITE_DETERMINISTIC, SQLITE_OK, SQLITE_UTF8,
+};
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::sqlite::value::SqliteValue;
+use crate::sqlite::{Sqlite, SqliteArgumentValue, SqliteConnection, SqliteError};
+use crate::types::{Type, TypeInfo};
+use crate::value::RawValue;
+
+impl SqliteConnection {
+    /// Register a scalar function, written in Rust, that can be called from SQL on this
+    /// connection.
+    ///
+    /// `n_args` is the number of arguments the function takes, or `-1` for any number of
+    /// arguments. The arguments are decoded from the [`SqliteFunctionContext`] given to the
+    /// function and its result is encoded as the result of the call. An error returned from
+    /// the function is an error of the statement that called it.
+    ///
+    /// ```rust,ignore
+    /// conn.create_scalar_function("rust_upper", 1, |ctx| {
+    ///     Ok(ctx.try_get::<Option<&str>>(0)?.map(str::to_uppercase))
+    /// })?;
+    ///
+    /// let (name,): (String,) = sqlx::query_as("SELECT rust_upper(name) FROM users")
+    ///     .fetch_one(&mut conn)
+    ///     .await?;
+    /// ```
+    ///
+    /// A function registered with the same name and number of arguments as an existing one
+    /// replaces it.
+    pub fn create_scalar_function<F, R>(
+        &mut self,
+        name: &str,
+        n_args: i32,
+        function: F,
+    ) -> crate::Result<()>
+    where
+        F: Fn(&SqliteFunctionContext<'_>) -> crate::Result<R> + Send + 'static,
+        R: Encode<Sqlite>,
+    {
+        self.create_function(name, n_args, SQLITE_UTF8, function)
+    }
+
+    /// Register a scalar function, like [`create_scalar_function`], that always returns the
+    /// same result for the same arguments.
+    ///
+    /// SQLite can optimize calls to a deterministic function and they can be used in
+    /// the expressions of indexes and `CHECK` constraints.
+    ///
+    /// [`create_scalar_function`]: #method.create_scalar_function
+    pub fn create_deterministic_function<F, R>(
+        &mut self,
+        name: &str,
+        n_args: i32,
+        function: F,
+    ) -> crate::Result<()>
+    where
+        F: Fn(&SqliteFunctionContext<'_>) -> crate::Result<R> + Send + 'static,
+        R: Encode<Sqlite>,
+    {
+        self.create_function(name, n_args, SQLITE_UTF8 | SQLITE_DETERMINISTIC, function)
+    }
+
+    fn create_function<F, R>(
+        &mut self,
+        name: &str,
+        n_args: i32,
+        flags: c_int,
+        function: F,
+    ) -> crate::Result<()>
+    where
+        F: Fn(&SqliteFunctionContext<'_>) -> crate::Result<R> + Send + 'static,
+        R: Encode<Sqlite>,
+    {
+        let name = CString::new(name).map_err(|_| protocol_err!("function name contains a NUL"))?;
+
+        let function = Box::into_raw(Box::new(function));
+
+        // <https://www.sqlite.org/c3ref/create_function.html>
+        // SQLite owns the function from here; `destroy` is called when the function is replaced
+        // or the connection is closed, and also if the function could not be created
+        let status = unsafe {
+            sqlite3_create_function_v2(
+                self.handle(),
+                name.as_ptr(),
+                n_args,
+                flags,
+                function as *mut c_void,
+                Some(call::<F, R>),
+                None,
+                None,
+                Some(destroy::<F>),
+            )
+        };
+
+        if status != SQLITE_OK {
+            return Err(SqliteError::from_connection(self.handle()).into());
+        }
+
+        Ok(())
+    }
+}
+
+/// The arguments of a call to a function registered with
+/// [`SqliteConnection::create_scalar_function`].
+pub struct SqliteFunctionContext<'a> {
+    args: &'a [*mut sqlite3_value],
+}
+
+impl<'a> SqliteFunctionContext<'a> {
+    /// The number of arguments the function was called with.
+    pub fn len(&self) -> usize {
+        self.args.len()
+    }
+
+    /// Returns `true` if the function was called without arguments.
+    pub fn is_empty(&self) -> bool {
+        self.args.is_empty()
+    }
+
+    /// Decode the argument at `index`.
+    ///
+    /// # Panics
+    /// If the argument could not be decoded; see [`try_get`](#method.try_get).
+    pub fn get<T>(&self, index: usize) -> T
+    where
+        T: Type<Sqlite>,
+        T: Decode<'a, Sqlite>,
+    {
+        self.try_get(index).unwrap()
+    }
+
+    /// Decode the argument at `index`.
+    ///
+    /// Returns an error if there is no argument at `index` or it could not be decoded into
+    /// the requested type.
+    pub fn try_get<T>(&self, index: usize) -> crate::Result<T>
+    where
+        T: Type<Sqlite>,
+        T: Decode<'a, Sqlite>,
+    {
+        let arg = *self.args.get(index).ok_or_else(|| {
+            decode_err!(
+                "argument index {} out of range for a call with {} arguments",
+                index,
+                self.args.len()
+            )
+        })?;
+
+        // the value is only used during the call of the function that is given this context
+        let value = unsafe { SqliteValue::argument(arg) };
+
+        if let Some(expected_ty) = value.type_info() {
+            if !expected_ty.compatible(&T::type_info()) {
+                return Err(crate::Error::mismatched_types::<Sqlite, T>(expected_ty));
+            }
+        }
+
+        T::decode(value)
+    }
+}
+
+unsafe extern "C" fn call<F, R>(
+    context: *mut sqlite3_context,
+    argc: c_int,
+    argv: *mut *mut sqlite3_value,
+) where
+    F: Fn(&SqliteFunctionContext<'_>) -> crate::Result<R>,
+    R: Encode<Sqlite>,
+{
+    // https://www.sqlite.org/c3ref/user_data.html
+    let function = &*(sqlite3_user_data(context) as *const F);
+
+    let args = if argc > 0 {
+        slice::from_raw_parts(argv as *const *mut sqlite3_value, argc as usize)
+    } else {
+        &[]
+    };
+
+    // a panic must not unwind into SQLite
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        function(&SqliteFunctionContext { args })
+    }));
+
+    match result {
+        Ok(Ok(value)) => {
+            let mut values = Vec::with_capacity(1);
+
+            if let IsNull::Yes = value.encode_nullable(&mut values) {
+                values.push(SqliteArgumentValue::Null);
+            }
+
+            match values.first() {
+                Some(value) => value.result(context),
+                None => result_error(context, "function did not return a value"),
+            }
+        }
+
+        Ok(Err(error)) => result_error(context, &error.to_string()),
+
+        Err(_) => result_error(context, "function panicked"),
+    }
+}
+
+unsafe extern "C" fn destroy<F>(function: *mut c_void) {
+    drop(Box::from_raw(function as *mut F));
+}
+
+fn result_error(context: *mut sqlite3_context, message: &str) {
+    // https://www.sqlite.org/c3ref/result_blob.html
+    unsafe {
+        sqlite3_result_error(
+            context,
+            message.as_ptr() as *const c_char,
+            message.len() as c_int,
+        );
+    }
+}
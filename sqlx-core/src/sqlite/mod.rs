@@ -6,11 +6,14 @@
 #![allow(unsafe_code)]
 
 mod arguments;
+mod blob;
 mod connection;
 mod cursor;
 mod database;
 mod error;
 mod executor;
+mod function;
+mod options;
 mod row;
 mod statement;
 mod type_info;
@@ -19,10 +22,13 @@ mod value;
 mod worker;
 
 pub use arguments::{SqliteArgumentValue, SqliteArguments};
+pub use blob::SqliteBlob;
 pub use connection::SqliteConnection;
 pub use cursor::SqliteCursor;
 pub use database::Sqlite;
 pub use error::SqliteError;
+pub use function::SqliteFunctionContext;
+pub use options::{SqliteConnectOptions, SqliteJournalMode, SqliteSynchronous};
 pub use row::SqliteRow;
 pub use type_info::SqliteTypeInfo;
 pub use value::SqliteValue;
@@ -0,0 +1,173 @@
+use std::fmt::Write;
+use std::time::Duration;
+
+use crate::executor::Executor;
+use crate::sqlite::SqliteConnection;
+
+/// Options for a new connection, set with [`SqliteConnection::connect_with`].
+///
+/// Each option is set with a `PRAGMA` before the connection is returned. By default the journal
+/// mode is WAL and `synchronous` is NORMAL, as for a connection opened with
+/// [`Connect::connect`]; the other options keep the defaults of SQLite.
+///
+/// ```rust,ignore
+/// let options = SqliteConnectOptions::new()
+///     .foreign_keys(true)
+///     .busy_timeout(Duration::from_secs(5));
+///
+/// let mut conn = SqliteConnection::connect_with("sqlite://data.db", &options).await?;
+/// ```
+///
+/// A pool can apply the options to each of its connections with [`Builder::after_connect`]:
+///
+/// ```rust,ignore
+/// let pool = SqlitePool::builder()
+///     .after_connect(move |conn| {
+///         let options = options.clone();
+///
+///         Box::pin(async move { options.apply(conn).await })
+///     })
+///     .build("sqlite://data.db")
+///     .await?;
+/// ```
+///
+/// [`Connect::connect`]: crate::connection::Connect::connect
+/// [`Builder::after_connect`]: crate::pool::Builder::after_connect
+#[derive(Debug, Clone)]
+pub struct SqliteConnectOptions {
+    pub(crate) journal_mode: Option<SqliteJournalMode>,
+    pub(crate) synchronous: Option<SqliteSynchronous>,
+    pub(crate) foreign_keys: Option<bool>,
+    pub(crate) busy_timeout: Option<Duration>,
+}
+
+impl Default for SqliteConnectOptions {
+    fn default() -> Self {
+        Self {
+            journal_mode: Some(SqliteJournalMode::Wal),
+            synchronous: Some(SqliteSynchronous::Normal),
+            foreign_keys: None,
+            busy_timeout: None,
+        }
+    }
+}
+
+impl SqliteConnectOptions {
+    /// The default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the journal mode of the database.
+    ///
+    /// An in-memory database always uses the MEMORY or OFF journal mode.
+    pub fn journal_mode(mut self, journal_mode: SqliteJournalMode) -> Self {
+        self.journal_mode = Some(journal_mode);
+        self
+    }
+
+    /// Set how often SQLite waits for data to be written to disk.
+    pub fn synchronous(mut self, synchronous: SqliteSynchronous) -> Self {
+        self.synchronous = Some(synchronous);
+        self
+    }
+
+    /// Set whether foreign key constraints are enforced.
+    ///
+    /// SQLite does **not** enforce foreign keys unless this is turned on for each connection,
+    /// apart from builds like the one bundled with sqlx that turn it on by default.
+    pub fn foreign_keys(mut self, foreign_keys: bool) -> Self {
+        self.foreign_keys = Some(foreign_keys);
+        self
+    }
+
+    /// Set how long to wait for a lock held by another connection before a statement fails
+    /// with `SQLITE_BUSY`.
+    pub fn busy_timeout(mut self, busy_timeout: Duration) -> Self {
+        self.busy_timeout = Some(busy_timeout);
+        self
+    }
+
+    /// Set the options on an open connection.
+    pub async fn apply(&self, conn: &mut SqliteConnection) -> crate::Result<()> {
+        let mut pragmas = String::new();
+
+        if let Some(journal_mode) = self.journal_mode {
+            let _ = writeln!(pragmas, "PRAGMA journal_mode = {};", journal_mode.as_sql());
+        }
+
+        if let Some(synchronous) = self.synchronous {
+            let _ = writeln!(pragmas, "PRAGMA synchronous = {};", synchronous.as_sql());
+        }
+
+        if let Some(foreign_keys) = self.foreign_keys {
+            let _ = writeln!(
+                pragmas,
+                "PRAGMA foreign_keys = {};",
+                if foreign_keys { "ON" } else { "OFF" }
+            );
+        }
+
+        if let Some(busy_timeout) = self.busy_timeout {
+            let _ = writeln!(
+                pragmas,
+                "PRAGMA busy_timeout = {};",
+                busy_timeout.as_millis()
+            );
+        }
+
+        if !pragmas.is_empty() {
+            conn.execute(&*pragmas).await?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The journal mode of a SQLite database.
+///
+/// See <https://www.sqlite.org/pragma.html#pragma_journal_mode>.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SqliteJournalMode {
+    Delete,
+    Truncate,
+    Persist,
+    Memory,
+    Wal,
+    Off,
+}
+
+impl SqliteJournalMode {
+    pub(crate) fn as_sql(self) -> &'static str {
+        match self {
+            SqliteJournalMode::Delete => "DELETE",
+            SqliteJournalMode::Truncate => "TRUNCATE",
+            SqliteJournalMode::Persist => "PERSIST",
+            SqliteJournalMode::Memory => "MEMORY",
+            SqliteJournalMode::Wal => "WAL",
+            SqliteJournalMode::Off => "OFF",
+        }
+    }
+}
+
+/// How often SQLite waits for data to be written to disk.
+///
+/// See <https://www.sqlite.org/pragma.html#pragma_synchronous>.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SqliteSynchronous {
+    Off,
+    Normal,
+    Full,
+    Extra,
+}
+
+impl SqliteSynchronous {
+    pub(crate) fn as_sql(self) -> &'static str {
+        match self {
+            SqliteSynchronous::Off => "OFF",
+            SqliteSynchronous::Normal => "NORMAL",
+            SqliteSynchronous::Full => "FULL",
+            SqliteSynchronous::Extra => "EXTRA",
+        }
+    }
+}
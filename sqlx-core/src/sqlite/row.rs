@@ -1,7 +1,9 @@
-use crate::row::{ColumnIndex, Row};
+use crate::row::{self, ColumnIndex, Row};
 use crate::sqlite::statement::Statement;
+use crate::sqlite::type_info::SqliteType;
 use crate::sqlite::value::SqliteValue;
-use crate::sqlite::{Sqlite, SqliteConnection};
+use crate::sqlite::{Sqlite, SqliteConnection, SqliteTypeInfo};
+use crate::value::RawValue;
 
 pub struct SqliteRow<'c> {
     pub(super) values: usize,
@@ -33,14 +35,40 @@ impl<'c> Row<'c> for SqliteRow<'c> {
         self.values
     }
 
-    #[doc(hidden)]
     fn try_get_raw<I>(&self, index: I) -> crate::Result<SqliteValue<'c>>
     where
         I: ColumnIndex<'c, Self>,
     {
-        Ok(SqliteValue {
-            statement: self.statement(),
-            index: index.index(self)? as i32,
+        Ok(SqliteValue::column(
+            self.statement(),
+            index.index(self)? as i32,
+        ))
+    }
+
+    fn try_column<I>(&self, index: I) -> crate::Result<row::Column<'_, Sqlite>>
+    where
+        I: ColumnIndex<'c, Self>,
+    {
+        let ordinal = index.index(self)?;
+        let statement = self.statement();
+
+        // the declared type of a table column, or the type of the value for an expression
+        let type_info = match statement
+            .column_decltype(ordinal)
+            .and_then(SqliteType::from_decltype)
+        {
+            Some(r#type) => Some(SqliteTypeInfo {
+                r#type,
+                affinity: None,
+            }),
+
+            None => SqliteValue::column(statement, ordinal as i32).type_info(),
+        };
+
+        Ok(row::Column {
+            name: Some(statement.column_name(ordinal)),
+            ordinal,
+            type_info,
         })
     }
 }
@@ -59,10 +87,17 @@ impl<'c> ColumnIndex<'c, SqliteRow<'c>> for usize {
 
 impl<'c> ColumnIndex<'c, SqliteRow<'c>> for str {
     fn index(&self, row: &SqliteRow<'c>) -> crate::Result<usize> {
-        row.statement()
+        let statement = row.statement();
+
+        statement
             .columns
             .get(self)
-            .ok_or_else(|| crate::Error::ColumnNotFound((*self).into()))
+            .ok_or_else(|| {
+                crate::Error::column_not_found(
+                    self,
+                    (0..row.values).map(|index| statement.column_name(index)),
+                )
+            })
             .map(|&index| index as usize)
     }
 }
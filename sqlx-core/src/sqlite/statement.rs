@@ -2,16 +2,17 @@
 
 use core::ptr::{null, null_mut, NonNull};
 use std::collections::HashMap;
-use std::ffi::CStr;
+use std::ffi::{CStr, CString};
 use std::os::raw::{c_char, c_int};
 use std::ptr;
 
 use libsqlite3_sys::{
-    sqlite3_bind_parameter_count, sqlite3_clear_bindings, sqlite3_column_count,
-    sqlite3_column_database_name, sqlite3_column_decltype, sqlite3_column_name,
-    sqlite3_column_origin_name, sqlite3_column_table_name, sqlite3_data_count, sqlite3_finalize,
-    sqlite3_prepare_v3, sqlite3_reset, sqlite3_step, sqlite3_stmt, sqlite3_table_column_metadata,
-    SQLITE_DONE, SQLITE_OK, SQLITE_PREPARE_NO_VTAB, SQLITE_PREPARE_PERSISTENT, SQLITE_ROW,
+    sqlite3_bind_parameter_count, sqlite3_bind_parameter_index, sqlite3_clear_bindings,
+    sqlite3_column_count, sqlite3_column_database_name, sqlite3_column_decltype,
+    sqlite3_column_name, sqlite3_column_origin_name, sqlite3_column_table_name, sqlite3_data_count,
+    sqlite3_finalize, sqlite3_prepare_v3, sqlite3_reset, sqlite3_step, sqlite3_stmt,
+    sqlite3_table_column_metadata, SQLITE_DONE, SQLITE_OK, SQLITE_PREPARE_NO_VTAB,
+    SQLITE_PREPARE_PERSISTENT, SQLITE_ROW,
 };
 
 use crate::sqlite::connection::SqliteConnectionHandle;
@@ -137,7 +138,7 @@ impl Statement {
         }
     }
 
-    pub(super) fn column_name(&mut self, index: usize) -> &str {
+    pub(super) fn column_name(&self, index: usize) -> &str {
         unsafe {
             self.handle()
                 .map(|handle| {
@@ -152,7 +153,7 @@ impl Statement {
         }
     }
 
-    pub(super) fn column_decltype(&mut self, index: usize) -> Option<&str> {
+    pub(super) fn column_decltype(&self, index: usize) -> Option<&str> {
         unsafe {
             self.handle()
                 .and_then(|handle| {
@@ -244,9 +245,55 @@ impl Statement {
             }
         }
 
+        for (name, value) in &arguments.named {
+            let indexes = self.param_indexes(name)?;
+
+            if indexes.is_empty() {
+                return Err(protocol_err!("no parameter named {:?} in the query", name).into());
+            }
+
+            for index in indexes {
+                value.bind(self, index)?;
+            }
+        }
+
         Ok(())
     }
 
+    // Finds the indexes of the parameters with a name, with each of the prefixes if it has none
+    fn param_indexes(&self, name: &str) -> crate::Result<Vec<usize>> {
+        let handle = match unsafe { self.handle() } {
+            Some(handle) => handle,
+            None => return Ok(Vec::new()),
+        };
+
+        let names = if name.starts_with(&[':', '@', '$'][..]) {
+            vec![name.to_owned()]
+        } else {
+            vec![
+                format!(":{}", name),
+                format!("@{}", name),
+                format!("${}", name),
+            ]
+        };
+
+        let mut indexes = Vec::new();
+
+        for name in names {
+            let name =
+                CString::new(name).map_err(|_| protocol_err!("parameter name contains a NUL"))?;
+
+            // https://www.sqlite.org/c3ref/bind_parameter_index.html
+            let index = unsafe { sqlite3_bind_parameter_index(handle, name.as_ptr()) };
+
+            if index > 0 {
+                indexes.push(index as usize);
+            }
+        }
+
+        Ok(indexes)
+    }
+
     pub(super) fn reset(&mut self) {
         let handle = unsafe {
             if let Some(handle) = self.handle() {
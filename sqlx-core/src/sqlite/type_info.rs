@@ -14,6 +14,21 @@ pub(crate) enum SqliteType {
     Boolean,
 }
 
+impl SqliteType {
+    // The type of a column declared with `decl` in a `CREATE TABLE`, if it is one we know
+    pub(crate) fn from_decltype(decl: &str) -> Option<Self> {
+        match &*decl.to_ascii_lowercase() {
+            "bool" | "boolean" => Some(SqliteType::Boolean),
+            "clob" | "text" => Some(SqliteType::Text),
+            "blob" => Some(SqliteType::Blob),
+            "real" | "double" | "double precision" | "float" => Some(SqliteType::Float),
+            decl if decl.contains("int") => Some(SqliteType::Integer),
+            decl if decl.contains("char") => Some(SqliteType::Text),
+            _ => None,
+        }
+    }
+}
+
 // https://www.sqlite.org/datatype3.html#type_affinity
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub(crate) enum SqliteTypeAffinity {
@@ -0,0 +1,218 @@
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, SecondsFormat, Utc};
+
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::error::UnexpectedNullError;
+use crate::sqlite::type_info::{SqliteType, SqliteTypeAffinity};
+use crate::sqlite::{Sqlite, SqliteArgumentValue, SqliteTypeInfo, SqliteValue};
+use crate::types::Type;
+
+// <https://www.sqlite.org/lang_datefunc.html>
+
+// SQLite does not have a storage class for dates; the date and time functions read them as
+// TEXT, as a REAL Julian day number or as an INTEGER with the `unixepoch` modifier. Dates are
+// written as RFC 3339 TEXT which can be given directly to these functions.
+
+// Julian day number of 1970-01-01 00:00:00 UTC
+const JULIAN_DAY_UNIX_EPOCH: f64 = 2_440_587.5;
+
+impl Type<Sqlite> for NaiveDateTime {
+    fn type_info() -> SqliteTypeInfo {
+        SqliteTypeInfo::new(SqliteType::Text, SqliteTypeAffinity::Text)
+    }
+}
+
+impl Encode<Sqlite> for NaiveDateTime {
+    fn encode(&self, values: &mut Vec<SqliteArgumentValue>) {
+        values.push(SqliteArgumentValue::Text(
+            self.format("%Y-%m-%dT%H:%M:%S%.f").to_string(),
+        ));
+    }
+}
+
+impl<'de> Decode<'de, Sqlite> for NaiveDateTime {
+    fn decode(value: SqliteValue<'de>) -> crate::Result<Self> {
+        match value.r#type() {
+            Some(SqliteType::Integer) => from_unix_timestamp(value.int64()),
+            Some(SqliteType::Float) => from_julian_day(value.double()),
+
+            _ => parse_datetime(
+                value
+                    .text()
+                    .ok_or_else(|| crate::Error::decode(UnexpectedNullError))?,
+            ),
+        }
+    }
+}
+
+impl Type<Sqlite> for DateTime<Utc> {
+    fn type_info() -> SqliteTypeInfo {
+        SqliteTypeInfo::new(SqliteType::Text, SqliteTypeAffinity::Text)
+    }
+}
+
+impl Encode<Sqlite> for DateTime<Utc> {
+    fn encode(&self, values: &mut Vec<SqliteArgumentValue>) {
+        values.push(SqliteArgumentValue::Text(
+            self.to_rfc3339_opts(SecondsFormat::AutoSi, true),
+        ));
+    }
+}
+
+impl<'de> Decode<'de, Sqlite> for DateTime<Utc> {
+    fn decode(value: SqliteValue<'de>) -> crate::Result<Self> {
+        let naive: NaiveDateTime = Decode::<Sqlite>::decode(value)?;
+
+        Ok(DateTime::from_utc(naive, Utc))
+    }
+}
+
+impl Type<Sqlite> for NaiveDate {
+    fn type_info() -> SqliteTypeInfo {
+        SqliteTypeInfo::new(SqliteType::Text, SqliteTypeAffinity::Text)
+    }
+}
+
+impl Encode<Sqlite> for NaiveDate {
+    fn encode(&self, values: &mut Vec<SqliteArgumentValue>) {
+        values.push(SqliteArgumentValue::Text(
+            self.format("%Y-%m-%d").to_string(),
+        ));
+    }
+}
+
+impl<'de> Decode<'de, Sqlite> for NaiveDate {
+    fn decode(value: SqliteValue<'de>) -> crate::Result<Self> {
+        if let Some(SqliteType::Text) = value.r#type() {
+            if let Some(text) = value.text() {
+                if let Ok(date) = NaiveDate::parse_from_str(text, "%Y-%m-%d") {
+                    return Ok(date);
+                }
+            }
+        }
+
+        let naive: NaiveDateTime = Decode::<Sqlite>::decode(value)?;
+
+        Ok(naive.date())
+    }
+}
+
+// The formats of the time values read by the SQLite date and time functions, apart from
+// `HH:MM` and `HH:MM:SS` without a date; `%.f` accepts a missing fraction
+const DATETIME_FORMATS: &[&str] = &[
+    "%Y-%m-%d %H:%M:%S%.f",
+    "%Y-%m-%dT%H:%M:%S%.f",
+    "%Y-%m-%d %H:%M",
+    "%Y-%m-%dT%H:%M",
+];
+
+// Parses a date and time, which is in UTC unless it is followed by an offset. As with the SQLite
+// date and time functions, a time with an offset is converted to UTC.
+fn parse_datetime(text: &str) -> crate::Result<NaiveDateTime> {
+    let utc = text.strip_suffix('Z').unwrap_or(text);
+
+    for format in DATETIME_FORMATS {
+        if let Ok(datetime) = NaiveDateTime::parse_from_str(utc, format) {
+            return Ok(datetime);
+        }
+
+        if let Ok(datetime) =
+            DateTime::<FixedOffset>::parse_from_str(text, &format!("{}%:z", format))
+        {
+            return Ok(datetime.naive_utc());
+        }
+    }
+
+    if let Ok(date) = NaiveDate::parse_from_str(text, "%Y-%m-%d") {
+        return Ok(date.and_hms(0, 0, 0));
+    }
+
+    Err(decode_err!("invalid date and time {:?}", text))
+}
+
+fn from_unix_timestamp(seconds: i64) -> crate::Result<NaiveDateTime> {
+    NaiveDateTime::from_timestamp_opt(seconds, 0)
+        .ok_or_else(|| decode_err!("unix timestamp {} is out of range", seconds))
+}
+
+fn from_julian_day(day: f64) -> crate::Result<NaiveDateTime> {
+    // SQLite keeps times to the millisecond
+    let millis = ((day - JULIAN_DAY_UNIX_EPOCH) * 86_400_000.0).round();
+
+    if !millis.is_finite() || millis.abs() > i64::MAX as f64 {
+        return Err(decode_err!("Julian day {} is out of range", day));
+    }
+
+    let millis = millis as i64;
+
+    NaiveDateTime::from_timestamp_opt(
+        millis.div_euclid(1000),
+        (millis.rem_euclid(1000) * 1_000_000) as u32,
+    )
+    .ok_or_else(|| decode_err!("Julian day {} is out of range", day))
+}
+
+#[test]
+fn test_parse_datetime() {
+    let expected = NaiveDate::from_ymd(2019, 1, 2).and_hms(5, 10, 20);
+
+    assert_eq!(parse_datetime("2019-01-02 05:10:20").unwrap(), expected);
+    assert_eq!(parse_datetime("2019-01-02T05:10:20").unwrap(), expected);
+    assert_eq!(parse_datetime("2019-01-02T05:10:20Z").unwrap(), expected);
+    assert_eq!(
+        parse_datetime("2019-01-02 07:40:20+02:30").unwrap(),
+        expected
+    );
+    assert_eq!(
+        parse_datetime("2019-01-02T01:10:20-04:00").unwrap(),
+        expected
+    );
+
+    assert_eq!(
+        parse_datetime("2019-01-02 05:10:20.115").unwrap(),
+        NaiveDate::from_ymd(2019, 1, 2).and_hms_milli(5, 10, 20, 115)
+    );
+
+    assert_eq!(
+        parse_datetime("2019-01-02T05:10:20.115100Z").unwrap(),
+        NaiveDate::from_ymd(2019, 1, 2).and_hms_micro(5, 10, 20, 115_100)
+    );
+
+    assert_eq!(
+        parse_datetime("2019-01-02 05:10").unwrap(),
+        NaiveDate::from_ymd(2019, 1, 2).and_hms(5, 10, 0)
+    );
+
+    assert_eq!(
+        parse_datetime("2019-01-02").unwrap(),
+        NaiveDate::from_ymd(2019, 1, 2).and_hms(0, 0, 0)
+    );
+}
+
+#[test]
+fn test_parse_datetime_errors_with_text() {
+    for text in &[
+        "",
+        "01/02/2019",
+        "2019-01-02 05:10:20 PST",
+        "2019-13-02 05:10:20",
+    ] {
+        let err = parse_datetime(text).unwrap_err();
+
+        assert_eq!(err.to_string(), format!("invalid date and time {:?}", text));
+    }
+}
+
+#[test]
+fn test_from_julian_day() {
+    // SELECT julianday('2019-01-02 05:10:20.115')
+    assert_eq!(
+        from_julian_day(2458485.7155105905).unwrap(),
+        NaiveDate::from_ymd(2019, 1, 2).and_hms_milli(5, 10, 20, 115)
+    );
+
+    assert_eq!(
+        from_julian_day(JULIAN_DAY_UNIX_EPOCH).unwrap(),
+        NaiveDate::from_ymd(1970, 1, 1).and_hms(0, 0, 0)
+    );
+}
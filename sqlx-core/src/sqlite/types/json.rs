@@ -0,0 +1,37 @@
+use serde_json::Value as JsonValue;
+
+use crate::row::Row;
+use crate::sqlite::type_info::SqliteType;
+use crate::sqlite::SqliteRow;
+use crate::types::json::JsonRow;
+
+// SQLite does not have a JSON type; the JSON functions read and return JSON as TEXT and a row is
+// converted to JSON from the storage class of each value. An INTEGER in a column declared as
+// BOOLEAN is a boolean.
+
+impl<'c> JsonRow<'c> for SqliteRow<'c> {
+    fn column_to_json(&self, index: usize) -> crate::Result<JsonValue> {
+        let value = self.try_get_raw(index)?;
+
+        Ok(match value.r#type() {
+            None => JsonValue::Null,
+
+            Some(SqliteType::Integer) => {
+                let declared = self.column(index).type_info().map(|info| info.r#type);
+
+                if declared == Some(SqliteType::Boolean) {
+                    JsonValue::Bool(value.int64() != 0)
+                } else {
+                    value.int64().into()
+                }
+            }
+
+            Some(SqliteType::Float) => value.double().into(),
+            Some(SqliteType::Text) => value.text().unwrap_or_default().into(),
+            Some(SqliteType::Blob) => JsonValue::String(base64::encode(value.blob())),
+
+            // values have one of the storage classes above
+            Some(SqliteType::Boolean) => unreachable!(),
+        })
+    }
+}
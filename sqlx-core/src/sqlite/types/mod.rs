@@ -13,6 +13,20 @@
 //! | `&str`, `String`                      | TEXT                                                 |
 //! | `&[u8]`, `Vec<u8>`                    | BLOB                                                 |
 //!
+//! ### [`chrono`](https://crates.io/crates/chrono)
+//!
+//! Requires the `chrono` Cargo feature flag.
+//!
+//! | Rust type                             | SQLite type(s)                                       |
+//! |---------------------------------------|------------------------------------------------------|
+//! | `chrono::NaiveDateTime`               | DATETIME                                             |
+//! | `chrono::DateTime<Utc>`               | DATETIME                                             |
+//! | `chrono::NaiveDate`                   | DATE                                                 |
+//!
+//! These are written as RFC 3339 TEXT. They are read from TEXT in any of the formats accepted
+//! by the SQLite date and time functions, a REAL Julian day number or an INTEGER Unix timestamp;
+//! a time with an offset is converted to UTC.
+//!
 //! # Nullable
 //!
 //! In addition, `Option<T>` is supported where `T` implements `Type`. An `Option<T>` represents
@@ -29,6 +43,12 @@ mod float;
 mod int;
 mod str;
 
+#[cfg(feature = "chrono")]
+mod chrono;
+
+#[cfg(feature = "json")]
+mod json;
+
 impl<'de, T> Decode<'de, Sqlite> for Option<T>
 where
     T: Decode<'de, Sqlite>,
@@ -1,11 +1,16 @@
+use core::marker::PhantomData;
+use core::ptr;
 use core::slice;
 
 use std::ffi::CStr;
+use std::os::raw::c_int;
 use std::str::from_utf8_unchecked;
 
 use libsqlite3_sys::{
     sqlite3_column_blob, sqlite3_column_bytes, sqlite3_column_double, sqlite3_column_int,
-    sqlite3_column_int64, sqlite3_column_text, sqlite3_column_type, SQLITE_BLOB, SQLITE_FLOAT,
+    sqlite3_column_int64, sqlite3_column_text, sqlite3_column_type, sqlite3_value,
+    sqlite3_value_blob, sqlite3_value_bytes, sqlite3_value_double, sqlite3_value_int,
+    sqlite3_value_int64, sqlite3_value_text, sqlite3_value_type, SQLITE_BLOB, SQLITE_FLOAT,
     SQLITE_INTEGER, SQLITE_NULL, SQLITE_TEXT,
 };
 
@@ -15,8 +20,18 @@ use crate::sqlite::{Sqlite, SqliteTypeInfo};
 use crate::value::RawValue;
 
 pub struct SqliteValue<'c> {
-    pub(super) index: i32,
-    pub(super) statement: &'c Statement,
+    source: Source<'c>,
+}
+
+enum Source<'c> {
+    // A column of the current result row of a query
+    Column {
+        index: i32,
+        statement: &'c Statement,
+    },
+
+    // An argument given to a user-defined function; only valid during the call
+    Argument(*mut sqlite3_value, PhantomData<&'c ()>),
 }
 
 // https://www.sqlite.org/c3ref/column_blob.html
@@ -24,21 +39,49 @@ pub struct SqliteValue<'c> {
 
 // These routines return information about a single column of the current result row of a query.
 
+// https://www.sqlite.org/c3ref/value_blob.html
+
+// These routines return information about an argument of a user-defined function in the same
+// way.
+
+// Reads the value with `column` when it is a column of a statement and with `value` when it is
+// an argument; evaluates to `$default` for a null statement
+macro_rules! read {
+    ($self:ident, $column:ident, $value:ident, $default:expr) => {
+        unsafe {
+            match $self.source {
+                Source::Column { index, statement } => statement
+                    .handle()
+                    .map_or($default, |handle| $column(handle, index)),
+
+                Source::Argument(value, _) => $value(value),
+            }
+        }
+    };
+}
+
 impl<'c> SqliteValue<'c> {
+    pub(super) fn column(statement: &'c Statement, index: i32) -> Self {
+        Self {
+            source: Source::Column { index, statement },
+        }
+    }
+
+    // The caller must not use the value after the function it was given to returns
+    pub(super) unsafe fn argument(value: *mut sqlite3_value) -> Self {
+        Self {
+            source: Source::Argument(value, PhantomData),
+        }
+    }
+
     /// Returns true if the value should be intrepreted as NULL.
-    pub(super) fn is_null(&self) -> bool {
+    pub(crate) fn is_null(&self) -> bool {
         self.r#type().is_none()
     }
 
-    fn r#type(&self) -> Option<SqliteType> {
-        let type_code = unsafe {
-            if let Some(handle) = self.statement.handle() {
-                sqlite3_column_type(handle, self.index)
-            } else {
-                // unreachable: null statements do not have any values to type
-                return None;
-            }
-        };
+    pub(super) fn r#type(&self) -> Option<SqliteType> {
+        // null statements do not have any values to type
+        let type_code: c_int = read!(self, sqlite3_column_type, sqlite3_value_type, SQLITE_NULL);
 
         // SQLITE_INTEGER, SQLITE_FLOAT, SQLITE_TEXT, SQLITE_BLOB, or SQLITE_NULL
         match type_code {
@@ -54,65 +97,39 @@ impl<'c> SqliteValue<'c> {
 
     /// Returns the 32-bit INTEGER result.
     pub(super) fn int(&self) -> i32 {
-        unsafe {
-            self.statement
-                .handle()
-                .map_or(0, |handle| sqlite3_column_int(handle, self.index))
-        }
+        read!(self, sqlite3_column_int, sqlite3_value_int, 0)
     }
 
     /// Returns the 64-bit INTEGER result.
     pub(super) fn int64(&self) -> i64 {
-        unsafe {
-            self.statement
-                .handle()
-                .map_or(0, |handle| sqlite3_column_int64(handle, self.index))
-        }
+        read!(self, sqlite3_column_int64, sqlite3_value_int64, 0)
     }
 
     /// Returns the 64-bit, REAL result.
     pub(super) fn double(&self) -> f64 {
-        unsafe {
-            self.statement
-                .handle()
-                .map_or(0.0, |handle| sqlite3_column_double(handle, self.index))
-        }
+        read!(self, sqlite3_column_double, sqlite3_value_double, 0.0)
     }
 
     /// Returns the UTF-8 TEXT result.
     pub(super) fn text(&self) -> Option<&'c str> {
-        unsafe {
-            self.statement.handle().and_then(|handle| {
-                let ptr = sqlite3_column_text(handle, self.index);
-
-                if ptr.is_null() {
-                    None
-                } else {
-                    Some(from_utf8_unchecked(CStr::from_ptr(ptr as _).to_bytes()))
-                }
-            })
+        let ptr = read!(self, sqlite3_column_text, sqlite3_value_text, ptr::null());
+
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { from_utf8_unchecked(CStr::from_ptr(ptr as _).to_bytes()) })
         }
     }
 
     fn bytes(&self) -> usize {
         // Returns the size of the result in bytes.
-        unsafe {
-            self.statement
-                .handle()
-                .map_or(0, |handle| sqlite3_column_bytes(handle, self.index)) as usize
-        }
+        read!(self, sqlite3_column_bytes, sqlite3_value_bytes, 0) as usize
     }
 
     /// Returns the BLOB result.
     pub(super) fn blob(&self) -> &'c [u8] {
-        let ptr = unsafe {
-            if let Some(handle) = self.statement.handle() {
-                sqlite3_column_blob(handle, self.index)
-            } else {
-                // Null statements do not exist
-                return &[];
-            }
-        };
+        // Null statements do not exist
+        let ptr = read!(self, sqlite3_column_blob, sqlite3_value_blob, ptr::null());
 
         if ptr.is_null() {
             // Empty BLOBs are received as null pointers
@@ -1,13 +1,14 @@
 use std::ops::{Deref, DerefMut};
 
 use futures_core::future::BoxFuture;
+use futures_core::stream::BoxStream;
+use futures_util::future;
 
-use crate::connection::Connection;
+use crate::connection::{Connection, LogSettings};
 use crate::cursor::HasCursor;
 use crate::database::Database;
 use crate::describe::Describe;
-use crate::executor::{Execute, Executor, RefExecutor};
-use crate::runtime::spawn;
+use crate::executor::{Execute, Executor, QueryResult, RefExecutor};
 
 /// Represents an in-progress database transaction.
 ///
@@ -29,6 +30,29 @@ use crate::runtime::spawn;
 ///     .await?;
 /// ```
 ///
+/// As `drop` can't wait for the database, the `ROLLBACK` of a dropped transaction is sent
+/// before anything else the next time its connection is used, such as by the next task to
+/// acquire it from a [`Pool`]. Its changes are never visible to the next user of the connection,
+/// but until then the transaction and any locks it holds remain open on the server.
+///
+/// Calling [`begin`] on a transaction nests it in a save point; [`commit`] and [`rollback`] on the
+/// nested transaction release or roll back to that save point while the outer transaction
+/// continues. Only the outermost transaction issues `BEGIN` and `COMMIT` or `ROLLBACK`.
+///
+/// ```rust,ignore
+/// let mut tx = pool.begin().await?;
+/// sqlx::query("INSERT INTO articles (slug) VALUES ('kept')").execute(&mut tx).await?;
+///
+/// let mut savepoint = tx.begin().await?;
+/// sqlx::query("INSERT INTO articles (slug) VALUES ('discarded')").execute(&mut savepoint).await?;
+///
+/// // only the second INSERT is undone
+/// let tx = savepoint.rollback().await?;
+/// tx.commit().await?;
+/// ```
+///
+/// [`Pool`]: crate::pool::Pool
+/// [`begin`]: #method.begin
 /// [`commit`]: #method.commit
 /// [`rollback`]: #method.rollback
 // Transaction<PoolConnection<PgConnection>>
@@ -61,6 +85,17 @@ where
         })
     }
 
+    pub(crate) async fn new_with(mut inner: C, options: TransactionOptions) -> crate::Result<Self> {
+        let stmt = inner.begin_statement(&options)?;
+
+        inner.execute(&*stmt).await?;
+
+        Ok(Self {
+            inner: Some(inner),
+            depth: 1,
+        })
+    }
+
     /// Creates a new save point in the current transaction and returns
     /// a new `Transaction` object to manage its scope.
     pub async fn begin(self) -> crate::Result<Transaction<Transaction<C>>> {
@@ -104,6 +139,90 @@ where
 
 const ERR_FINALIZED: &str = "(bug) transaction already finalized";
 
+/// Options for starting a transaction with [`Connection::begin_with`].
+///
+/// Not every database supports every option; starting a transaction with an option that is not
+/// supported returns an error.
+///
+/// ```rust,ignore
+/// let options = TransactionOptions::new()
+///     .isolation_level(IsolationLevel::Serializable)
+///     .access_mode(AccessMode::ReadOnly)
+///     .deferrable(true);
+///
+/// let mut tx = conn.begin_with(options).await?;
+/// ```
+///
+/// [`Connection::begin_with`]: crate::connection::Connection::begin_with
+#[derive(Debug, Clone, Default)]
+pub struct TransactionOptions {
+    pub(crate) isolation_level: Option<IsolationLevel>,
+    pub(crate) access_mode: Option<AccessMode>,
+    pub(crate) deferrable: bool,
+}
+
+impl TransactionOptions {
+    /// Options that start a transaction with the defaults of the database.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the isolation level of the transaction.
+    pub fn isolation_level(mut self, isolation_level: IsolationLevel) -> Self {
+        self.isolation_level = Some(isolation_level);
+        self
+    }
+
+    /// Set whether the transaction may write to the database.
+    pub fn access_mode(mut self, access_mode: AccessMode) -> Self {
+        self.access_mode = Some(access_mode);
+        self
+    }
+
+    /// Set whether the transaction is deferrable.
+    ///
+    /// A deferrable transaction that is also serializable and read only waits until it can run
+    /// without the chance of a serialization failure. Only supported by Postgres.
+    pub fn deferrable(mut self, deferrable: bool) -> Self {
+        self.deferrable = deferrable;
+        self
+    }
+}
+
+/// The isolation level of a transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IsolationLevel {
+    ReadCommitted,
+    RepeatableRead,
+    Serializable,
+}
+
+impl IsolationLevel {
+    pub(crate) fn as_sql(self) -> &'static str {
+        match self {
+            IsolationLevel::ReadCommitted => "READ COMMITTED",
+            IsolationLevel::RepeatableRead => "REPEATABLE READ",
+            IsolationLevel::Serializable => "SERIALIZABLE",
+        }
+    }
+}
+
+/// The access mode of a transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccessMode {
+    ReadOnly,
+    ReadWrite,
+}
+
+impl AccessMode {
+    pub(crate) fn as_sql(self) -> &'static str {
+        match self {
+            AccessMode::ReadOnly => "READ ONLY",
+            AccessMode::ReadWrite => "READ WRITE",
+        }
+    }
+}
+
 impl<C> Deref for Transaction<C>
 where
     C: Connection,
@@ -156,6 +275,27 @@ where
     fn ping(&mut self) -> BoxFuture<'_, crate::Result<()>> {
         self.deref_mut().ping()
     }
+
+    #[inline]
+    fn set_log_settings(&mut self, settings: LogSettings) {
+        self.deref_mut().set_log_settings(settings)
+    }
+
+    fn reset(&mut self) -> BoxFuture<'_, crate::Result<()>> {
+        Box::pin(future::err(
+            protocol_err!("a connection can not be reset in a transaction").into(),
+        ))
+    }
+
+    #[doc(hidden)]
+    fn rollback_on_next_use(&mut self) {
+        self.deref_mut().rollback_on_next_use()
+    }
+
+    #[doc(hidden)]
+    fn begin_statement(&self, _options: &TransactionOptions) -> crate::Result<String> {
+        Err(protocol_err!("options can only be given to the outermost transaction").into())
+    }
 }
 
 impl<DB, C> Executor for Transaction<C>
@@ -168,13 +308,20 @@ where
     fn execute<'e, 'q: 'e, 'c: 'e, E: 'e>(
         &'c mut self,
         query: E,
-    ) -> BoxFuture<'e, crate::Result<u64>>
+    ) -> BoxFuture<'e, crate::Result<QueryResult>>
     where
         E: Execute<'q, Self::Database>,
     {
         (**self).execute(query)
     }
 
+    fn execute_many<'e, 'q: 'e, 'c: 'e>(
+        &'c mut self,
+        query: &'q str,
+    ) -> BoxStream<'e, crate::Result<QueryResult>> {
+        (**self).execute_many(query)
+    }
+
     fn fetch<'e, 'q, E>(&'e mut self, query: E) -> <Self::Database as HasCursor<'e, 'q>>::Cursor
     where
         E: Execute<'q, Self::Database>,
@@ -182,7 +329,6 @@ where
         (**self).fetch(query)
     }
 
-    #[doc(hidden)]
     fn describe<'e, 'q, E: 'e>(
         &'e mut self,
         query: E,
@@ -214,11 +360,11 @@ where
     C: Connection,
 {
     fn drop(&mut self) {
-        if self.depth > 0 {
-            if let Some(inner) = self.inner.take() {
-                spawn(async move {
-                    let _ = inner.close().await;
-                });
+        // A save point is dropped along with the transaction it is nested in so only the root
+        // transaction needs to be rolled back
+        if self.depth == 1 {
+            if let Some(inner) = &mut self.inner {
+                inner.rollback_on_next_use();
             }
         }
     }
@@ -41,10 +41,20 @@ pub mod ipnetwork {
     pub use ipnetwork::{IpNetwork, Ipv4Network, Ipv6Network};
 }
 
+#[cfg(feature = "bitvec")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bitvec")))]
+pub mod bitvec {
+    pub use bitvec::{order::Msb0, vec::BitVec};
+}
+
 #[cfg(feature = "json")]
 pub mod json {
     use std::ops::Deref;
 
+    use serde_json::{Map, Value as JsonValue};
+
+    use crate::row::{ColumnIndex, Row};
+
     #[cfg_attr(docsrs, doc(cfg(feature = "json")))]
     #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
     pub struct Json<T>(pub T);
@@ -62,9 +72,65 @@ pub mod json {
             &self.0
         }
     }
+
+    /// A [`Row`] that can be converted to JSON with [`row_to_json`].
+    ///
+    /// This trait is implemented for the row type of each database.
+    ///
+    /// [`Row`]: crate::row::Row
+    pub trait JsonRow<'c>: Row<'c> {
+        #[doc(hidden)]
+        fn column_to_json(&self, index: usize) -> crate::Result<JsonValue>;
+    }
+
+    /// Convert a row to a JSON object with a field for each column, e.g. to return the rows of
+    /// a query that is not known until runtime from a generic API.
+    ///
+    /// Each value is converted according to the type of its column:
+    ///
+    ///  * integers and floating-point numbers are numbers; a `NaN` or infinite float is `null`,
+    ///  * booleans are booleans,
+    ///  * text is a string,
+    ///  * binary (`BYTEA`, `BLOB`, ..) is a base64 string,
+    ///  * JSON is embedded as it is,
+    ///  * and `NULL` is `null`.
+    ///
+    /// Decimals are strings to keep their precision; a Postgres `NUMERIC` in the binary format
+    /// needs the `bigdecimal` feature. Other types are strings when they are received as text;
+    /// a value of another type in the binary format of a prepared query is an error, and can be
+    /// cast to text in the query.
+    ///
+    /// A column without a name is named by its position. When more than one column has the same
+    /// name, the field has the value of the last of them.
+    ///
+    /// ```rust,ignore
+    /// let mut cursor = sqlx::query("SELECT id, name FROM users").fetch(&mut conn);
+    /// let row = cursor.next().await?.unwrap();
+    ///
+    /// // {"id":1,"name":"Herp Derpinson"}
+    /// let user = row_to_json(&row)?;
+    /// ```
+    pub fn row_to_json<'c, R>(row: &R) -> crate::Result<JsonValue>
+    where
+        R: JsonRow<'c>,
+        usize: ColumnIndex<'c, R>,
+    {
+        let mut object = Map::with_capacity(row.len());
+
+        for index in 0..row.len() {
+            let name = match row.column(index).name() {
+                Some(name) => name.to_owned(),
+                None => index.to_string(),
+            };
+
+            object.insert(name, row.column_to_json(index)?);
+        }
+
+        Ok(JsonValue::Object(object))
+    }
 }
 #[cfg(feature = "json")]
-pub use self::json::Json;
+pub use self::json::{row_to_json, Json};
 
 pub trait TypeInfo: PartialEq<Self> + Debug + Display + Clone {
     /// Compares type information to determine if `other` is compatible at the Rust level
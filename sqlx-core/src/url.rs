@@ -1,5 +1,6 @@
 use std::borrow::Cow;
 use std::convert::{TryFrom, TryInto};
+use std::path::PathBuf;
 
 #[derive(Debug)]
 pub struct Url(url::Url);
@@ -34,6 +35,11 @@ impl Url {
         self.0.as_str()
     }
 
+    #[allow(dead_code)]
+    pub(crate) fn scheme(&self) -> &str {
+        self.0.scheme()
+    }
+
     pub fn host(&self) -> &str {
         let host = self.0.host_str();
 
@@ -44,6 +50,21 @@ impl Url {
         }
     }
 
+    /// The host as a path on the local machine, for a host that is a percent-encoded path
+    /// (starting with `%2F`), e.g. of a Unix domain socket.
+    #[allow(dead_code)]
+    pub(crate) fn host_path(&self) -> Option<PathBuf> {
+        let host = percent_encoding::percent_decode_str(self.0.host_str()?)
+            .decode_utf8()
+            .ok()?;
+
+        if host.starts_with('/') {
+            Some(PathBuf::from(&*host))
+        } else {
+            None
+        }
+    }
+
     pub fn port(&self, default: u16) -> u16 {
         self.0.port().unwrap_or(default)
     }
@@ -93,6 +114,10 @@ impl Url {
             .query_pairs()
             .find_map(|(key_, val)| if key == key_ { Some(val) } else { None })
     }
+
+    pub fn params(&self) -> impl Iterator<Item = (Cow<'_, str>, Cow<'_, str>)> + '_ {
+        self.0.query_pairs()
+    }
 }
 
 #[cfg(test)]
@@ -124,4 +149,16 @@ mod tests {
             Some(String::from("username@servername"))
         );
     }
+
+    #[test]
+    fn percent_encoded_host_path() {
+        let url = Url::try_from("postgres://user@%2Fvar%2Frun%2Fpostgresql/db")
+            .expect("Failed to parse URL");
+
+        assert_eq!(url.host_path(), Some(PathBuf::from("/var/run/postgresql")));
+
+        let url = Url::try_from("postgres://user@localhost/db").expect("Failed to parse URL");
+
+        assert_eq!(url.host_path(), None);
+    }
 }
@@ -12,6 +12,31 @@ impl_database_ext! {
 
         Vec<u8> | &[u8],
 
+        sqlx::postgres::types::PgInterval,
+
+        sqlx::postgres::types::PgPoint,
+        sqlx::postgres::types::PgBox,
+        sqlx::postgres::types::PgPath,
+        sqlx::postgres::types::PgPolygon,
+
+        sqlx::postgres::types::PgRange<i32>,
+        sqlx::postgres::types::PgRange<i64>,
+
+        sqlx::postgres::types::PgMacAddress,
+        sqlx::postgres::types::PgMacAddress8,
+
+        sqlx::postgres::types::PgMoney,
+
+        sqlx::postgres::types::PgLTree,
+        sqlx::postgres::types::PgLQuery,
+        sqlx::postgres::types::PgJsonPath,
+
+        sqlx::postgres::types::PgCube,
+        sqlx::postgres::types::PgCiText,
+
+        sqlx::postgres::types::PgTsVector,
+        sqlx::postgres::types::PgTsQuery,
+
         #[cfg(feature = "uuid")]
         sqlx::types::Uuid,
 
@@ -27,6 +52,16 @@ impl_database_ext! {
         #[cfg(feature = "chrono")]
         sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc> | sqlx::types::chrono::DateTime<_>,
 
+        #[cfg(feature = "chrono")]
+        sqlx::postgres::types::PgRange<sqlx::types::chrono::NaiveDate>,
+
+        #[cfg(feature = "chrono")]
+        sqlx::postgres::types::PgRange<sqlx::types::chrono::NaiveDateTime>,
+
+        #[cfg(feature = "chrono")]
+        sqlx::postgres::types::PgRange<sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>> |
+            sqlx::postgres::types::PgRange<sqlx::types::chrono::DateTime<_>>,
+
         #[cfg(feature = "time")]
         sqlx::types::time::Time,
 
@@ -39,15 +74,33 @@ impl_database_ext! {
         #[cfg(feature = "time")]
         sqlx::types::time::OffsetDateTime,
 
+        #[cfg(feature = "time")]
+        sqlx::postgres::types::PgRange<sqlx::types::time::Date>,
+
+        #[cfg(feature = "time")]
+        sqlx::postgres::types::PgRange<sqlx::types::time::PrimitiveDateTime>,
+
+        #[cfg(feature = "time")]
+        sqlx::postgres::types::PgRange<sqlx::types::time::OffsetDateTime>,
+
         #[cfg(feature = "bigdecimal")]
         sqlx::types::BigDecimal,
 
+        #[cfg(feature = "bigdecimal")]
+        sqlx::postgres::types::PgRange<sqlx::types::BigDecimal>,
+
         #[cfg(feature = "ipnetwork")]
         sqlx::types::ipnetwork::IpNetwork,
 
+        #[cfg(feature = "bitvec")]
+        sqlx::types::bitvec::BitVec<sqlx::types::bitvec::Msb0, u8>,
+
         #[cfg(feature = "json")]
         serde_json::Value,
 
+        #[cfg(feature = "hstore")]
+        std::collections::HashMap<String, Option<String>>,
+
         // Arrays
         Vec<bool> | &[bool],
         Vec<String> | &[String],
@@ -58,6 +111,8 @@ impl_database_ext! {
         Vec<i64> | &[i64],
         Vec<f32> | &[f32],
         Vec<f64> | &[f64],
+        Vec<sqlx::postgres::types::PgInterval> | &[sqlx::postgres::types::PgInterval],
+        Vec<sqlx::postgres::types::PgPoint> | &[sqlx::postgres::types::PgPoint],
 
 
         #[cfg(feature = "uuid")]
@@ -97,5 +152,6 @@ impl_database_ext! {
     },
     ParamChecking::Strong,
     feature-types: info => info.type_feature_gate(),
-    row = sqlx::postgres::PgRow
+    row = sqlx::postgres::PgRow,
+    name = "postgres"
 }
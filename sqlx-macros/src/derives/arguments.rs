@@ -0,0 +1,96 @@
+use quote::quote;
+use syn::{
+    parse_quote, punctuated::Punctuated, token::Comma, Data, DataStruct, DeriveInput, Field,
+    Fields, FieldsNamed, Stmt,
+};
+
+use super::attributes::{check_to_arguments_attributes, parse_child_attributes};
+
+pub fn expand_derive_to_arguments(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    match &input.data {
+        Data::Struct(DataStruct {
+            fields: Fields::Named(FieldsNamed { named, .. }),
+            ..
+        }) => expand_derive_to_arguments_struct(input, named),
+
+        Data::Struct(DataStruct {
+            fields: Fields::Unnamed(_),
+            ..
+        }) => Err(syn::Error::new_spanned(
+            input,
+            "tuple structs are not supported",
+        )),
+
+        Data::Struct(DataStruct {
+            fields: Fields::Unit,
+            ..
+        }) => Err(syn::Error::new_spanned(
+            input,
+            "unit structs are not supported",
+        )),
+
+        Data::Enum(_) => Err(syn::Error::new_spanned(input, "enums are not supported")),
+
+        Data::Union(_) => Err(syn::Error::new_spanned(input, "unions are not supported")),
+    }
+}
+
+fn expand_derive_to_arguments_struct(
+    input: &DeriveInput,
+    fields: &Punctuated<Field, Comma>,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let ident = &input.ident;
+
+    check_to_arguments_attributes(input)?;
+
+    let generics = &input.generics;
+
+    let (_, ty_generics, _) = generics.split_for_impl();
+
+    let mut generics = generics.clone();
+    generics.params.insert(0, parse_quote!(DB: sqlx::Database));
+
+    let predicates = &mut generics.make_where_clause().predicates;
+
+    let mut adds: Vec<Stmt> = Vec::with_capacity(fields.len());
+
+    for field in fields {
+        let id = match &field.ident {
+            Some(id) => id,
+            None => continue,
+        };
+
+        let attributes = parse_child_attributes(&field.attrs)?;
+        let ty = &field.ty;
+
+        if attributes.skip {
+            continue;
+        }
+
+        if attributes.flatten {
+            // the fields of a flattened struct are bound in its place
+            predicates.push(parse_quote!(#ty: sqlx::arguments::ToArguments<DB>));
+
+            adds.push(parse_quote!(
+                <#ty as sqlx::arguments::ToArguments<DB>>::add_to(&self.#id, arguments);
+            ));
+        } else {
+            predicates.push(parse_quote!(#ty: sqlx::encode::Encode<DB>));
+            predicates.push(parse_quote!(#ty: sqlx::types::Type<DB>));
+
+            adds.push(parse_quote!(
+                sqlx::arguments::Arguments::add(arguments, &self.#id);
+            ));
+        }
+    }
+
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+    Ok(quote!(
+        impl #impl_generics sqlx::arguments::ToArguments<DB> for #ident #ty_generics #where_clause {
+            fn add_to(&self, arguments: &mut DB::Arguments) {
+                #(#adds)*
+            }
+        }
+    ))
+}
@@ -13,7 +13,7 @@ macro_rules! assert_attribute {
 
 macro_rules! fail {
     ($t:expr, $m:expr) => {
-        return Err(syn::Error::new_spanned($t, $m));
+        return Err(syn::Error::new_spanned($t, $m))
     };
 }
 
@@ -27,8 +27,14 @@ macro_rules! try_set {
 }
 
 #[derive(Copy, Clone)]
+#[allow(clippy::enum_variant_names)]
 pub enum RenameAll {
     LowerCase,
+    UpperCase,
+    SnakeCase,
+    ScreamingSnakeCase,
+    CamelCase,
+    PascalCase,
 }
 
 pub struct SqlxContainerAttributes {
@@ -40,6 +46,10 @@ pub struct SqlxContainerAttributes {
 
 pub struct SqlxChildAttributes {
     pub rename: Option<String>,
+    pub default: bool,
+    pub flatten: bool,
+    pub prefix: Option<String>,
+    pub skip: bool,
 }
 
 pub fn parse_container_attributes(input: &[Attribute]) -> syn::Result<SqlxContainerAttributes> {
@@ -68,6 +78,11 @@ pub fn parse_container_attributes(input: &[Attribute]) -> syn::Result<SqlxContai
                             }) if path.is_ident("rename_all") => {
                                 let val = match &*val.value() {
                                     "lowercase" => RenameAll::LowerCase,
+                                    "UPPERCASE" => RenameAll::UpperCase,
+                                    "snake_case" => RenameAll::SnakeCase,
+                                    "SCREAMING_SNAKE_CASE" => RenameAll::ScreamingSnakeCase,
+                                    "camelCase" => RenameAll::CamelCase,
+                                    "PascalCase" => RenameAll::PascalCase,
 
                                     _ => fail!(meta, "unexpected value for rename_all"),
                                 };
@@ -112,6 +127,10 @@ pub fn parse_container_attributes(input: &[Attribute]) -> syn::Result<SqlxContai
 
 pub fn parse_child_attributes(input: &[Attribute]) -> syn::Result<SqlxChildAttributes> {
     let mut rename = None;
+    let mut default = None;
+    let mut flatten = None;
+    let mut prefix = None;
+    let mut skip = None;
 
     for attr in input {
         let meta = attr
@@ -129,6 +148,24 @@ pub fn parse_child_attributes(input: &[Attribute]) -> syn::Result<SqlxChildAttri
                                 ..
                             }) if path.is_ident("rename") => try_set!(rename, val.value(), value),
 
+                            Meta::NameValue(MetaNameValue {
+                                path,
+                                lit: Lit::Str(val),
+                                ..
+                            }) if path.is_ident("prefix") => try_set!(prefix, val.clone(), value),
+
+                            Meta::Path(p) if p.is_ident("default") => {
+                                try_set!(default, true, value)
+                            }
+
+                            Meta::Path(p) if p.is_ident("flatten") => {
+                                try_set!(flatten, p.clone(), value)
+                            }
+
+                            Meta::Path(p) if p.is_ident("skip") => {
+                                try_set!(skip, p.clone(), value)
+                            }
+
                             u => fail!(u, "unexpected attribute"),
                         },
                         u => fail!(u, "unexpected attribute"),
@@ -139,7 +176,31 @@ pub fn parse_child_attributes(input: &[Attribute]) -> syn::Result<SqlxChildAttri
         }
     }
 
-    Ok(SqlxChildAttributes { rename })
+    if let (Some(path), Some(_)) = (&flatten, &rename) {
+        fail!(
+            path,
+            "#[sqlx(flatten)] can not be used with #[sqlx(rename)]; rename the fields of the flattened struct instead"
+        );
+    }
+
+    if let (None, Some(lit)) = (&flatten, &prefix) {
+        fail!(
+            lit,
+            "#[sqlx(prefix = ..)] can only be used with #[sqlx(flatten)]"
+        );
+    }
+
+    if let (Some(path), Some(_)) = (&skip, &flatten) {
+        fail!(path, "#[sqlx(skip)] can not be used with #[sqlx(flatten)]");
+    }
+
+    Ok(SqlxChildAttributes {
+        rename,
+        default: default.unwrap_or(false),
+        flatten: flatten.is_some(),
+        prefix: prefix.map(|lit| lit.value()),
+        skip: skip.is_some(),
+    })
 }
 
 pub fn check_transparent_attributes(input: &DeriveInput, field: &Field) -> syn::Result<()> {
@@ -220,6 +281,42 @@ pub fn check_strong_enum_attributes(
     Ok(attributes)
 }
 
+pub fn check_from_row_attributes(input: &DeriveInput) -> syn::Result<SqlxContainerAttributes> {
+    let attributes = parse_container_attributes(&input.attrs)?;
+
+    assert_attribute!(
+        !attributes.transparent,
+        "unexpected #[sqlx(transparent)]",
+        input
+    );
+
+    assert_attribute!(
+        attributes.rename.is_none(),
+        "unexpected #[sqlx(rename = ..)]",
+        input
+    );
+
+    Ok(attributes)
+}
+
+pub fn check_to_arguments_attributes(input: &DeriveInput) -> syn::Result<()> {
+    let attributes = parse_container_attributes(&input.attrs)?;
+
+    assert_attribute!(
+        !attributes.transparent,
+        "unexpected #[sqlx(transparent)]",
+        input
+    );
+
+    assert_attribute!(
+        attributes.rename.is_none(),
+        "unexpected #[sqlx(rename = ..)]",
+        input
+    );
+
+    Ok(())
+}
+
 pub fn check_struct_attributes<'a>(
     input: &'a DeriveInput,
     fields: &Punctuated<Field, Comma>,
@@ -241,13 +338,8 @@ pub fn check_struct_attributes<'a>(
     assert_attribute!(attributes.repr.is_none(), "unexpected #[repr(..)]", input);
 
     for field in fields {
-        let attributes = parse_child_attributes(&field.attrs)?;
-
-        assert_attribute!(
-            attributes.rename.is_none(),
-            "unexpected #[sqlx(rename = ..)]",
-            field
-        );
+        // fields may be renamed
+        parse_child_attributes(&field.attrs)?;
     }
 
     Ok(attributes)
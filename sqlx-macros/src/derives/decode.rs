@@ -122,30 +122,52 @@ fn expand_derive_decode_strong_enum(
     let ident = &input.ident;
     let ident_s = ident.to_string();
 
-    let value_arms = variants.iter().map(|v| -> Arm {
+    let mut names = Vec::new();
+    let mut value_arms = Vec::<Arm>::new();
+
+    for v in variants {
         let id = &v.ident;
-        let attributes = parse_child_attributes(&v.attrs).unwrap();
+        let attributes = parse_child_attributes(&v.attrs)?;
 
-        if let Some(rename) = attributes.rename {
-            parse_quote!(#rename => Ok(#ident :: #id),)
+        let name = if let Some(rename) = attributes.rename {
+            rename
         } else if let Some(pattern) = cattr.rename_all {
-            let name = rename_all(&*id.to_string(), pattern);
-
-            parse_quote!(#name => Ok(#ident :: #id),)
+            rename_all(&*id.to_string(), pattern)
         } else {
-            let name = id.to_string();
-            parse_quote!(#name => Ok(#ident :: #id),)
-        }
-    });
+            id.to_string()
+        };
+
+        value_arms.push(parse_quote!(#name => Ok(#ident :: #id),));
+        names.push(name);
+    }
+
+    let expected = names.join(", ");
 
     Ok(quote!(
-        impl<'de, DB: sqlx::Database> sqlx::decode::Decode<'de, DB> for #ident where &'de str: sqlx::decode::Decode<'de, DB> {
+        impl<'de, DB: sqlx::Database> sqlx::decode::Decode<'de, DB> for #ident
+        where
+            &'de str: sqlx::decode::Decode<'de, DB>,
+            #ident: sqlx::types::Type<DB>,
+        {
             fn decode(value: <DB as sqlx::value::HasRawValue<'de>>::RawValue) -> sqlx::Result<Self> {
+                use sqlx::types::TypeInfo as _;
+                use sqlx::value::RawValue as _;
+
+                // the label of another enum (or text) may decode as a valid variant
+                if let Some(ty) = value.type_info() {
+                    if !ty.compatible(&<#ident as sqlx::types::Type<DB>>::type_info()) {
+                        return Err(sqlx::Error::mismatched_types::<DB, #ident>(ty));
+                    }
+                }
+
                 let value = <&'de str as sqlx::decode::Decode<'de, DB>>::decode(value)?;
                 match value {
                     #(#value_arms)*
 
-                    _ => Err(sqlx::Error::Decode(format!("invalid value {:?} for enum {}", value, #ident_s).into()))
+                    _ => Err(sqlx::Error::Decode(format!(
+                        "invalid value {:?} for enum {}; expected one of: {}",
+                        value, #ident_s, #expected
+                    ).into()))
                 }
             }
         }
@@ -156,7 +178,7 @@ fn expand_derive_decode_struct(
     input: &DeriveInput,
     fields: &Punctuated<Field, Comma>,
 ) -> syn::Result<proc_macro2::TokenStream> {
-    check_struct_attributes(input, fields)?;
+    let cattr = check_struct_attributes(input, fields)?;
 
     let mut tts = proc_macro2::TokenStream::new();
 
@@ -182,14 +204,28 @@ fn expand_derive_decode_struct(
 
         let (impl_generics, _, where_clause) = generics.split_for_impl();
 
-        let reads = fields.iter().map(|field| -> Stmt {
+        let ty_name = cattr.rename.unwrap_or_else(|| ident.to_string());
+
+        let mut reads = Vec::<Stmt>::new();
+
+        for field in fields {
             let id = &field.ident;
             let ty = &field.ty;
 
-            parse_quote!(
-                let #id = decoder.decode::<#ty>()?;
-            )
-        });
+            // fields are read in order; the name is only used to point out a bad field
+            let name = match parse_child_attributes(&field.attrs)?.rename {
+                Some(rename) => rename,
+                None => id.as_ref().unwrap().to_string(),
+            };
+
+            reads.push(parse_quote!(
+                let #id = decoder.decode::<#ty>().map_err(|e| {
+                    sqlx::Error::Decode(
+                        format!("failed to decode field {:?} of {}: {}", #name, #ty_name, e).into(),
+                    )
+                })?;
+            ));
+        }
 
         let names = fields.iter().map(|field| &field.ident);
 
@@ -1,9 +1,11 @@
+mod arguments;
 mod attributes;
 mod decode;
 mod encode;
 mod row;
 mod r#type;
 
+pub(crate) use arguments::expand_derive_to_arguments;
 pub(crate) use decode::expand_derive_decode;
 pub(crate) use encode::expand_derive_encode;
 pub(crate) use r#type::expand_derive_type;
@@ -30,5 +32,52 @@ pub(crate) fn expand_derive_type_encode_decode(
 pub(crate) fn rename_all(s: &str, pattern: RenameAll) -> String {
     match pattern {
         RenameAll::LowerCase => s.to_lowercase(),
+        RenameAll::UpperCase => s.to_uppercase(),
+        RenameAll::SnakeCase => words(s).join("_"),
+        RenameAll::ScreamingSnakeCase => words(s).join("_").to_uppercase(),
+        RenameAll::CamelCase => {
+            let pascal = words(s)
+                .iter()
+                .map(|word| capitalize(word))
+                .collect::<String>();
+            let mut chars = pascal.chars();
+
+            match chars.next() {
+                Some(first) => first.to_lowercase().chain(chars).collect(),
+                None => pascal,
+            }
+        }
+        RenameAll::PascalCase => words(s).iter().map(|word| capitalize(word)).collect(),
+    }
+}
+
+// Split a `snake_case` or `PascalCase` identifier into its lowercase words
+fn words(s: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+
+    for c in s.chars() {
+        if (c == '_' || c.is_uppercase()) && !word.is_empty() {
+            words.push(std::mem::take(&mut word));
+        }
+
+        if c != '_' {
+            word.extend(c.to_lowercase());
+        }
+    }
+
+    if !word.is_empty() {
+        words.push(word);
+    }
+
+    words
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
     }
 }
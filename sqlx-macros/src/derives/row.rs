@@ -1,11 +1,12 @@
 use proc_macro2::Span;
 use quote::quote;
 use syn::{
-    parse_quote, punctuated::Punctuated, token::Comma, Data, DataStruct, DeriveInput, Field,
+    parse_quote, punctuated::Punctuated, token::Comma, Data, DataStruct, DeriveInput, Expr, Field,
     Fields, FieldsNamed, Lifetime, Stmt,
 };
 
-use super::attributes::parse_child_attributes;
+use super::attributes::{check_from_row_attributes, parse_child_attributes};
+use super::rename_all;
 
 pub fn expand_derive_from_row(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
     match &input.data {
@@ -42,6 +43,8 @@ fn expand_derive_from_row_struct(
 ) -> syn::Result<proc_macro2::TokenStream> {
     let ident = &input.ident;
 
+    let container_attributes = check_from_row_attributes(input)?;
+
     let generics = &input.generics;
 
     let (lifetime, provided) = generics
@@ -63,36 +66,88 @@ fn expand_derive_from_row_struct(
 
     let predicates = &mut generics.make_where_clause().predicates;
 
-    predicates.push(parse_quote!(&#lifetime str: sqlx::row::ColumnIndex<#lifetime, R>));
+    predicates.push(parse_quote!(str: sqlx::row::ColumnIndex<#lifetime, R>));
+
+    let mut reads: Vec<Stmt> = Vec::with_capacity(fields.len());
 
     for field in fields {
+        let id = match &field.ident {
+            Some(id) => id,
+            None => continue,
+        };
+
+        let attributes = parse_child_attributes(&field.attrs)?;
         let ty = &field.ty;
 
-        predicates.push(parse_quote!(#ty: sqlx::decode::Decode<#lifetime, R::Database>));
-        predicates.push(parse_quote!(#ty: sqlx::types::Type<R::Database>));
-    }
+        // a skipped field is not read from the row
+        if attributes.skip {
+            predicates.push(parse_quote!(#ty: ::std::default::Default));
 
-    let (impl_generics, _, where_clause) = generics.split_for_impl();
+            reads.push(parse_quote!(
+                let #id: #ty = ::std::default::Default::default();
+            ));
+
+            continue;
+        }
 
-    let reads = fields.iter().filter_map(|field| -> Option<Stmt> {
-        let id = &field.ident.as_ref()?;
-        let attributes = parse_child_attributes(&field.attrs).unwrap();
-        let id_s = match attributes.rename {
-            Some(rename) => rename,
-            None => id.to_string().trim_start_matches("r#").to_owned(),
+        // the columns are named by the prefix of the field that flattened this struct, if any,
+        // followed by the name of the field
+        let expr: Expr = if attributes.flatten {
+            // the fields of a flattened struct are read from the same row
+            predicates.push(parse_quote!(#ty: sqlx::row::FromRow<#lifetime, R>));
+
+            let prefix: Expr = match attributes.prefix {
+                Some(prefix) => parse_quote!(&*sqlx::row::prefixed(__prefix, #prefix)),
+                None => parse_quote!(__prefix),
+            };
+
+            parse_quote!(
+                <#ty as sqlx::row::FromRow<#lifetime, R>>::from_row_with_prefix(row, #prefix)
+            )
+        } else {
+            let name = id.to_string().trim_start_matches("r#").to_owned();
+
+            let id_s = match (attributes.rename, container_attributes.rename_all) {
+                (Some(rename), _) => rename,
+                (None, Some(pattern)) => rename_all(&name, pattern),
+                (None, None) => name,
+            };
+
+            predicates.push(parse_quote!(#ty: sqlx::decode::Decode<#lifetime, R::Database>));
+            predicates.push(parse_quote!(#ty: sqlx::types::Type<R::Database>));
+
+            parse_quote!(row.try_get(&*sqlx::row::prefixed(__prefix, #id_s)))
         };
-        let ty = &field.ty;
 
-        Some(parse_quote!(
-            let #id: #ty = row.try_get(#id_s)?;
-        ))
-    });
+        if attributes.default {
+            predicates.push(parse_quote!(#ty: ::std::default::Default));
+
+            reads.push(parse_quote!(
+                let #id: #ty = match #expr {
+                    Ok(value) => value,
+                    // use the default when the column is not in the row
+                    Err(sqlx::Error::ColumnNotFound { .. }) => ::std::default::Default::default(),
+                    Err(error) => return Err(error),
+                };
+            ));
+        } else {
+            reads.push(parse_quote!(
+                let #id: #ty = #expr?;
+            ));
+        }
+    }
+
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
 
     let names = fields.iter().map(|field| &field.ident);
 
     Ok(quote!(
         impl #impl_generics sqlx::row::FromRow<#lifetime, R> for #ident #ty_generics #where_clause {
             fn from_row(row: &R) -> sqlx::Result<Self> {
+                Self::from_row_with_prefix(row, "")
+            }
+
+            fn from_row_with_prefix(row: &R, __prefix: &str) -> sqlx::Result<Self> {
                 #(#reads)*
 
                 Ok(#ident {
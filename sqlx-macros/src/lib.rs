@@ -11,8 +11,6 @@ use quote::quote;
 #[cfg(feature = "runtime-async-std")]
 use async_std::task::block_on;
 
-use url::Url;
-
 type Error = Box<dyn std::error::Error>;
 
 type Result<T> = std::result::Result<T, Error>;
@@ -51,63 +49,13 @@ fn macro_result(tokens: proc_macro2::TokenStream) -> TokenStream {
 }
 
 macro_rules! async_macro (
-    ($db:ident, $input:ident: $ty:ty => $expr:expr) => {{
+    ($input:ident: $ty:ty => $expr:expr) => {{
         let $input = match syn::parse::<$ty>($input) {
             Ok(input) => input,
             Err(e) => return macro_result(e.to_compile_error()),
         };
 
-        let res: Result<proc_macro2::TokenStream> = block_on(async {
-            use sqlx::connection::Connect;
-
-            let db_url = Url::parse(&dotenv::var("DATABASE_URL").map_err(|_| "DATABASE_URL not set")?)?;
-
-            match db_url.scheme() {
-                #[cfg(feature = "sqlite")]
-                "sqlite" => {
-                    let $db = sqlx::sqlite::SqliteConnection::connect(db_url.as_str())
-                        .await
-                        .map_err(|e| format!("failed to connect to database: {}", e))?;
-
-                    $expr.await
-                }
-                #[cfg(not(feature = "sqlite"))]
-                "sqlite" => Err(format!(
-                    "DATABASE_URL {} has the scheme of a SQLite database but the `sqlite` \
-                     feature of sqlx was not enabled",
-                     db_url
-                ).into()),
-                #[cfg(feature = "postgres")]
-                "postgresql" | "postgres" => {
-                    let $db = sqlx::postgres::PgConnection::connect(db_url.as_str())
-                        .await
-                        .map_err(|e| format!("failed to connect to database: {}", e))?;
-
-                    $expr.await
-                }
-                #[cfg(not(feature = "postgres"))]
-                "postgresql" | "postgres" => Err(format!(
-                    "DATABASE_URL {} has the scheme of a Postgres database but the `postgres` \
-                     feature of sqlx was not enabled",
-                     db_url
-                ).into()),
-                #[cfg(feature = "mysql")]
-                "mysql" | "mariadb" => {
-                    let $db = sqlx::mysql::MySqlConnection::connect(db_url.as_str())
-                            .await
-                            .map_err(|e| format!("failed to connect to database: {}", e))?;
-
-                    $expr.await
-                }
-                #[cfg(not(feature = "mysql"))]
-                "mysql" | "mariadb" => Err(format!(
-                    "DATABASE_URL {} has the scheme of a MySQL/MariaDB database but the `mysql` \
-                     feature of sqlx was not enabled",
-                     db_url
-                ).into()),
-                scheme => Err(format!("unexpected scheme {:?} in DATABASE_URL {}", scheme, db_url).into()),
-            }
-        });
+        let res: Result<proc_macro2::TokenStream> = block_on($expr);
 
         match res {
             Ok(ts) => ts.into(),
@@ -124,43 +72,33 @@ macro_rules! async_macro (
 );
 
 #[proc_macro]
-#[allow(unused_variables)]
 pub fn query(input: TokenStream) -> TokenStream {
-    #[allow(unused_variables)]
-    async_macro!(db, input: QueryMacroInput => expand_query(input, db))
+    async_macro!(input: QueryMacroInput => expand_query(input))
 }
 
 #[proc_macro]
-#[allow(unused_variables)]
 pub fn query_file(input: TokenStream) -> TokenStream {
-    #[allow(unused_variables)]
-    async_macro!(db, input: QueryMacroInput => expand_query_file(input, db))
+    async_macro!(input: QueryMacroInput => expand_query_file(input))
 }
 
 #[proc_macro]
-#[allow(unused_variables)]
 pub fn query_as(input: TokenStream) -> TokenStream {
-    #[allow(unused_variables)]
-    async_macro!(db, input: QueryAsMacroInput => expand_query_as(input, db, true))
+    async_macro!(input: QueryAsMacroInput => expand_query_as(input, true))
 }
 
 #[proc_macro]
-#[allow(unused_variables)]
 pub fn query_file_as(input: TokenStream) -> TokenStream {
-    async_macro!(db, input: QueryAsMacroInput => expand_query_file_as(input, db, true))
+    async_macro!(input: QueryAsMacroInput => expand_query_file_as(input, true))
 }
 
 #[proc_macro]
-#[allow(unused_variables)]
 pub fn query_as_unchecked(input: TokenStream) -> TokenStream {
-    #[allow(unused_variables)]
-    async_macro!(db, input: QueryAsMacroInput => expand_query_as(input, db, false))
+    async_macro!(input: QueryAsMacroInput => expand_query_as(input, false))
 }
 
 #[proc_macro]
-#[allow(unused_variables)]
 pub fn query_file_as_unchecked(input: TokenStream) -> TokenStream {
-    async_macro!(db, input: QueryAsMacroInput => expand_query_file_as(input, db, false))
+    async_macro!(input: QueryAsMacroInput => expand_query_file_as(input, false))
 }
 
 #[proc_macro_derive(Encode, attributes(sqlx))]
@@ -199,3 +137,13 @@ pub fn derive_from_row(input: TokenStream) -> TokenStream {
         Err(e) => e.to_compile_error().into(),
     }
 }
+
+#[proc_macro_derive(ToArguments, attributes(sqlx))]
+pub fn derive_to_arguments(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+
+    match derives::expand_derive_to_arguments(&input) {
+        Ok(ts) => ts.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
@@ -3,16 +3,16 @@ use syn::spanned::Spanned;
 use syn::Expr;
 
 use quote::{quote, quote_spanned, ToTokens};
-use sqlx::describe::Describe;
 
 use crate::database::{DatabaseExt, ParamChecking};
+use crate::query_macros::data::{QueryData, TypeData};
 use crate::query_macros::QueryMacroInput;
 
 /// Returns a tokenstream which typechecks the arguments passed to the macro
 /// and binds them to `DB::Arguments` with the ident `query_args`.
 pub fn quote_args<DB: DatabaseExt>(
     input: &QueryMacroInput,
-    describe: &Describe<DB>,
+    data: &QueryData,
     checked: bool,
 ) -> crate::Result<TokenStream> {
     let db_path = DB::db_path();
@@ -26,35 +26,19 @@ pub fn quote_args<DB: DatabaseExt>(
     let arg_name = &input.arg_names;
 
     let args_check = if checked && DB::PARAM_CHECKING == ParamChecking::Strong {
-        describe
-            .param_types
+        data.input_types
             .iter()
             .zip(input.arg_names.iter().zip(&input.arg_exprs))
-            .enumerate()
-            .map(|(i, (param_ty, (name, expr)))| -> crate::Result<_> {
+            .map(|(param_ty, (name, expr))| -> crate::Result<_> {
                 // TODO: We could remove the ParamChecking flag and just filter to only test params that are non-null
-                let param_ty = param_ty.as_ref().unwrap();
-
-                let param_ty = get_type_override(expr)
-                    .or_else(|| {
-                        Some(
-                            DB::param_type_for_id(&param_ty)?
-                                .parse::<proc_macro2::TokenStream>()
-                                .unwrap(),
-                        )
-                    })
-                    .ok_or_else(|| {
-                        if let Some(feature_gate) = <DB as DatabaseExt>::get_feature_gate(&param_ty) {
-                            format!(
-                                "optional feature `{}` required for type {} of param #{}",
-                                feature_gate,
-                                param_ty,
-                                i + 1,
-                            )
-                        } else {
-                            format!("unsupported type {} for param #{}", param_ty, i + 1)
-                        }
-                    })?;
+                let param_ty = match get_type_override(expr) {
+                    Some(param_ty) => param_ty,
+
+                    None => match param_ty {
+                        TypeData::Rust(param_ty) => param_ty.parse::<TokenStream>().unwrap(),
+                        TypeData::Unsupported(message) => return Err(message.clone().into()),
+                    },
+                };
 
                 Ok(quote_spanned!(expr.span() =>
                     // this shouldn't actually run
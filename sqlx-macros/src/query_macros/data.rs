@@ -0,0 +1,584 @@
+//! Query data cached in `sqlx-data.json` so the query macros can expand without a database.
+//!
+//! The file is a JSON object mapping a hash of each (normalized) query to its [QueryData].
+
+use std::collections::BTreeMap;
+use std::fmt::{self, Display, Formatter};
+use std::fs::{self, OpenOptions};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use sqlx::connection::Connection;
+use sqlx::database::Database;
+use url::Url;
+
+use crate::database::DatabaseExt;
+
+use super::overrides::TypeOverrides;
+
+/// Name of the offline data file.
+pub const DATA_FILE: &str = "sqlx-data.json";
+
+const LOCK_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Everything the query macros need to know about a query after describing it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QueryData {
+    /// Path to the database type the query was described against (e.g. `sqlx::postgres::Postgres`).
+    pub(super) db: String,
+    pub(super) query: String,
+    pub(super) input_types: Vec<TypeData>,
+    pub(super) outputs: Vec<OutputData>,
+}
+
+/// The Rust type for a parameter or column of a query.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TypeData {
+    /// Path of the Rust type.
+    Rust(String),
+
+    /// There is no Rust type; this is the error to report if a type is required.
+    Unsupported(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OutputData {
+    pub(super) name: Option<String>,
+    #[serde(rename = "type")]
+    pub(super) type_: TypeData,
+    pub(super) non_null: Option<bool>,
+}
+
+impl QueryData {
+    /// Connect to the database at `url` and describe `query`.
+    pub async fn from_database_url(url: &str, query: &str) -> crate::Result<Self> {
+        #[allow(unused_imports)]
+        use sqlx::connection::Connect;
+
+        let db_url = Url::parse(url)?;
+
+        match db_url.scheme() {
+            #[cfg(feature = "sqlite")]
+            "sqlite" => {
+                let mut conn = sqlx::sqlite::SqliteConnection::connect(db_url.as_str())
+                    .await
+                    .map_err(|e| format!("failed to connect to database: {}", e))?;
+
+                Self::from_db(&mut conn, query).await
+            }
+            #[cfg(not(feature = "sqlite"))]
+            "sqlite" => Err(format!(
+                "DATABASE_URL {} has the scheme of a SQLite database but the `sqlite` \
+                 feature of sqlx was not enabled",
+                db_url
+            )
+            .into()),
+            #[cfg(feature = "postgres")]
+            "postgresql" | "postgres" => {
+                let mut conn = sqlx::postgres::PgConnection::connect(db_url.as_str())
+                    .await
+                    .map_err(|e| format!("failed to connect to database: {}", e))?;
+
+                Self::from_db(&mut conn, query).await
+            }
+            #[cfg(not(feature = "postgres"))]
+            "postgresql" | "postgres" => Err(format!(
+                "DATABASE_URL {} has the scheme of a Postgres database but the `postgres` \
+                 feature of sqlx was not enabled",
+                db_url
+            )
+            .into()),
+            #[cfg(feature = "mysql")]
+            "mysql" | "mariadb" => {
+                let mut conn = sqlx::mysql::MySqlConnection::connect(db_url.as_str())
+                    .await
+                    .map_err(|e| format!("failed to connect to database: {}", e))?;
+
+                Self::from_db(&mut conn, query).await
+            }
+            #[cfg(not(feature = "mysql"))]
+            "mysql" | "mariadb" => Err(format!(
+                "DATABASE_URL {} has the scheme of a MySQL/MariaDB database but the `mysql` \
+                 feature of sqlx was not enabled",
+                db_url
+            )
+            .into()),
+            scheme => {
+                Err(format!("unexpected scheme {:?} in DATABASE_URL {}", scheme, db_url).into())
+            }
+        }
+    }
+
+    /// Describe `query` against the database of `conn`.
+    pub async fn from_db<C>(conn: &mut C, query: &str) -> crate::Result<Self>
+    where
+        C: Connection,
+        C::Database: DatabaseExt,
+        <C::Database as Database>::TypeInfo: Display,
+    {
+        describe_query(conn, query).await
+    }
+
+    /// Read the data for `query` from the offline data file at `path`.
+    pub fn from_file(path: impl AsRef<Path>, query: &str) -> crate::Result<Self> {
+        let path = path.as_ref();
+
+        let contents = fs::read_to_string(path).map_err(|e| {
+            format!(
+                "failed to read offline query data at {}: {}",
+                path.display(),
+                e
+            )
+        })?;
+
+        let mut data = parse_file(path, &contents)?;
+
+        let normalized = normalize(query);
+
+        match data.remove(&hash(&normalized)) {
+            Some(data) if normalize(&data.query) == normalized => Ok(data),
+
+            _ => Err(format!(
+                "query not found in offline query data at {}: {}",
+                path.display(),
+                query
+            )
+            .into()),
+        }
+    }
+
+    /// Add or update the entry for this query in the offline data file in `dir`,
+    /// keeping the entries of every other query.
+    pub fn save_in(&self, dir: impl AsRef<Path>) -> crate::Result<()> {
+        let path = dir.as_ref().join(DATA_FILE);
+
+        // other macro invocations may be updating the file at the same time
+        let _lock = FileLock::acquire(path.with_extension("json.lock"))?;
+
+        let mut data = match fs::read_to_string(&path) {
+            Ok(contents) => parse_file(&path, &contents)?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(e) => {
+                return Err(format!(
+                    "failed to read offline query data at {}: {}",
+                    path.display(),
+                    e
+                )
+                .into())
+            }
+        };
+
+        data.insert(hash(&normalize(&self.query)), self.clone());
+
+        // write the new contents next to the file and move them into place so that
+        // readers never see a partially written file
+        let tmp = path.with_extension("json.tmp");
+
+        fs::write(&tmp, serde_json::to_string_pretty(&data)?)
+            .and_then(|_| fs::rename(&tmp, &path))
+            .map_err(|e| {
+                format!(
+                    "failed to write offline query data to {}: {}",
+                    path.display(),
+                    e
+                )
+            })?;
+
+        Ok(())
+    }
+}
+
+fn parse_file(path: &Path, contents: &str) -> crate::Result<BTreeMap<String, QueryData>> {
+    serde_json::from_str(contents).map_err(|e| {
+        format!(
+            "failed to parse offline query data at {}: {}",
+            path.display(),
+            e
+        )
+        .into()
+    })
+}
+
+/// Exclusive lock on the offline data file, held by creating a lock file next to it.
+struct FileLock {
+    path: PathBuf,
+}
+
+impl FileLock {
+    fn acquire(path: PathBuf) -> crate::Result<Self> {
+        let start = Instant::now();
+
+        loop {
+            match OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(_) => return Ok(Self { path }),
+
+                Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {
+                    if start.elapsed() > LOCK_TIMEOUT {
+                        return Err(format!(
+                            "timed out waiting for lock on offline query data; \
+                             if no build is running, remove {}",
+                            path.display()
+                        )
+                        .into());
+                    }
+
+                    thread::sleep(Duration::from_millis(10));
+                }
+
+                Err(e) => {
+                    return Err(
+                        format!("failed to create lock file {}: {}", path.display(), e).into(),
+                    )
+                }
+            }
+        }
+    }
+}
+
+impl Drop for FileLock {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/// Describe `query` and resolve the Rust types of its parameters and columns.
+pub async fn describe_query<C>(conn: &mut C, query: &str) -> crate::Result<QueryData>
+where
+    C: Connection,
+    C::Database: DatabaseExt,
+    <C::Database as Database>::TypeInfo: Display,
+{
+    let overrides = TypeOverrides::load(<C::Database as DatabaseExt>::NAME)?;
+
+    let describe = conn.describe(query).await?;
+
+    let input_types = describe
+        .param_types
+        .iter()
+        .enumerate()
+        .map(|(i, param_ty)| {
+            let param_ty = match param_ty {
+                Some(param_ty) => param_ty,
+                None => {
+                    return TypeData::Unsupported(format!(
+                        "database couldn't tell us the type of param #{}",
+                        i + 1
+                    ))
+                }
+            };
+
+            let rust_ty = overrides
+                .param_type(&param_ty.to_string())
+                .or_else(|| <C::Database as DatabaseExt>::param_type_for_id(param_ty));
+
+            match rust_ty {
+                Some(rust_ty) => TypeData::Rust(rust_ty.into()),
+
+                None => TypeData::Unsupported(
+                    if let Some(feature_gate) =
+                        <C::Database as DatabaseExt>::get_feature_gate(param_ty)
+                    {
+                        format!(
+                            "optional feature `{}` required for type {} of param #{}",
+                            feature_gate,
+                            param_ty,
+                            i + 1,
+                        )
+                    } else {
+                        format!("unsupported type {} for param #{}", param_ty, i + 1)
+                    },
+                ),
+            }
+        })
+        .collect();
+
+    let outputs = describe
+        .result_columns
+        .iter()
+        .enumerate()
+        .map(|(i, column)| {
+            let col = DisplayColumn {
+                idx: i,
+                name: column.name.as_deref(),
+            };
+
+            let type_ = match &column.type_info {
+                Some(type_info) => {
+                    let rust_ty = overrides
+                        .return_type(&type_info.to_string())
+                        .or_else(|| <C::Database as DatabaseExt>::return_type_for_id(type_info));
+
+                    match rust_ty {
+                        Some(rust_ty) => TypeData::Rust(rust_ty.into()),
+
+                        None => TypeData::Unsupported(
+                            if let Some(feature_gate) =
+                                <C::Database as DatabaseExt>::get_feature_gate(type_info)
+                            {
+                                format!(
+                                    "optional feature `{feat}` required for type {ty} of {col}",
+                                    ty = type_info,
+                                    feat = feature_gate,
+                                    col = col,
+                                )
+                            } else {
+                                format!("unsupported type {ty} of {col}", ty = type_info, col = col)
+                            },
+                        ),
+                    }
+                }
+
+                None => TypeData::Unsupported(format!(
+                    "database couldn't tell us the type of {col}; \
+                     this can happen for columns that are the result of an expression",
+                    col = col
+                )),
+            };
+
+            OutputData {
+                name: column.name.as_deref().map(Into::into),
+                type_,
+                non_null: column.non_null,
+            }
+        })
+        .collect();
+
+    Ok(QueryData {
+        db: <C::Database as DatabaseExt>::DATABASE_PATH.into(),
+        query: query.into(),
+        input_types,
+        outputs,
+    })
+}
+
+struct DisplayColumn<'a> {
+    // zero-based index, converted to 1-based number
+    idx: usize,
+    name: Option<&'a str>,
+}
+
+impl Display for DisplayColumn<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let num = self.idx + 1;
+
+        if let Some(name) = self.name {
+            write!(f, "column #{} ({:?})", num, name)
+        } else {
+            write!(f, "column #{}", num)
+        }
+    }
+}
+
+/// Key of a query in the offline data file.
+pub fn hash(normalized: &str) -> String {
+    hex::encode(Sha256::digest(normalized.as_bytes()))
+}
+
+/// Collapse each run of whitespace outside of quotes into a single space so that
+/// reformatting a query does not invalidate its cached data.
+pub fn normalize(query: &str) -> String {
+    let mut normalized = String::with_capacity(query.len());
+    let mut quote = None;
+    let mut whitespace = false;
+
+    for c in query.trim().chars() {
+        match quote {
+            // inside quotes everything is kept as-is; a doubled quote simply closes and
+            // re-opens the quoted section
+            Some(q) => {
+                if c == q {
+                    quote = None;
+                }
+            }
+
+            None if c.is_whitespace() => {
+                whitespace = true;
+                continue;
+            }
+
+            None => {
+                if c == '\'' || c == '"' || c == '`' {
+                    quote = Some(c);
+                }
+            }
+        }
+
+        if whitespace {
+            normalized.push(' ');
+            whitespace = false;
+        }
+
+        normalized.push(c);
+    }
+
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    fn temp_file(name: &str) -> PathBuf {
+        env::temp_dir().join(format!("sqlx-macros-{}-{}.json", name, std::process::id()))
+    }
+
+    fn query_data(query: &str) -> QueryData {
+        QueryData {
+            db: "sqlx::postgres::Postgres".into(),
+            query: query.into(),
+            input_types: vec![TypeData::Rust("i32".into())],
+            outputs: vec![
+                OutputData {
+                    name: Some("id".into()),
+                    type_: TypeData::Rust("i32".into()),
+                    non_null: Some(true),
+                },
+                OutputData {
+                    name: Some("location".into()),
+                    type_: TypeData::Unsupported("unsupported type POINT of column #2".into()),
+                    non_null: None,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn it_normalizes_whitespace_outside_of_quotes() {
+        assert_eq!(
+            normalize("\n  SELECT id,\n\tname  FROM  users\n  WHERE id = $1 \n"),
+            "SELECT id, name FROM users WHERE id = $1"
+        );
+
+        assert_eq!(
+            normalize("SELECT  'a  b',  \"c  d\"  FROM  t"),
+            "SELECT 'a  b', \"c  d\" FROM t"
+        );
+
+        assert_eq!(normalize("SELECT 'it''s  ok'  "), "SELECT 'it''s  ok'");
+    }
+
+    #[test]
+    fn it_reads_query_data_from_file() -> crate::Result<()> {
+        let path = temp_file("from-file");
+
+        let query = "SELECT id, location FROM places WHERE id = $1";
+        let other = "SELECT 1";
+
+        let mut data = BTreeMap::new();
+        data.insert(hash(&normalize(query)), query_data(query));
+        data.insert(hash(&normalize(other)), query_data(other));
+
+        fs::write(&path, serde_json::to_string_pretty(&data)?)?;
+
+        let read = QueryData::from_file(&path, query);
+        let reformatted = QueryData::from_file(
+            &path,
+            "SELECT id, location\n    FROM places\n    WHERE id = $1",
+        );
+        let missing = QueryData::from_file(&path, "SELECT 'id, location FROM places'");
+
+        fs::remove_file(&path)?;
+
+        assert_eq!(read?, query_data(query));
+        assert_eq!(reformatted?, query_data(query));
+
+        let err = missing.unwrap_err().to_string();
+        assert!(err.contains("query not found"), "{}", err);
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_saves_query_data_from_concurrent_writers() -> crate::Result<()> {
+        let dir = env::temp_dir().join(format!("sqlx-macros-save-in-{}", std::process::id()));
+        fs::create_dir_all(&dir)?;
+
+        let writers: Vec<_> = (0..2)
+            .map(|writer| {
+                let dir = dir.clone();
+
+                thread::spawn(move || {
+                    for i in 0..25 {
+                        let query = format!("SELECT {} /* writer {} */", i, writer);
+
+                        query_data(&query).save_in(&dir).unwrap();
+                    }
+                })
+            })
+            .collect();
+
+        for writer in writers {
+            writer.join().unwrap();
+        }
+
+        let path = dir.join(DATA_FILE);
+
+        let saved: Vec<_> = (0..2)
+            .flat_map(|writer| (0..25).map(move |i| (i, writer)))
+            .map(|(i, writer)| {
+                QueryData::from_file(&path, &format!("SELECT {} /* writer {} */", i, writer))
+            })
+            .collect();
+
+        let contents = fs::read_to_string(&path)?;
+        let leftover_lock = path.with_extension("json.lock").exists();
+
+        fs::remove_dir_all(&dir)?;
+
+        assert!(!leftover_lock);
+        assert_eq!(parse_file(&path, &contents)?.len(), 50);
+
+        for data in saved {
+            assert_eq!(data?.outputs, query_data("SELECT 1").outputs);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_updates_the_entry_of_a_saved_query() -> crate::Result<()> {
+        let dir = env::temp_dir().join(format!("sqlx-macros-update-{}", std::process::id()));
+        fs::create_dir_all(&dir)?;
+
+        let query = "SELECT id, location FROM places WHERE id = $1";
+
+        query_data("SELECT 1").save_in(&dir)?;
+        query_data(query).save_in(&dir)?;
+
+        let mut updated = query_data(query);
+        updated.input_types = vec![TypeData::Rust("i64".into())];
+        updated.save_in(&dir)?;
+
+        let path = dir.join(DATA_FILE);
+
+        let first = QueryData::from_file(&path, "SELECT 1");
+        let read = QueryData::from_file(&path, query);
+
+        fs::remove_dir_all(&dir)?;
+
+        assert_eq!(first?, query_data("SELECT 1"));
+        assert_eq!(read?, updated);
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_fails_to_read_a_missing_file() {
+        let err = QueryData::from_file(temp_file("missing"), "SELECT 1")
+            .unwrap_err()
+            .to_string();
+
+        assert!(
+            err.starts_with("failed to read offline query data"),
+            "{}",
+            err
+        );
+    }
+}
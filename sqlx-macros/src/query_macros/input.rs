@@ -1,4 +1,5 @@
 use std::env;
+use std::path::Path;
 
 use proc_macro2::{Ident, Span};
 use quote::{format_ident, ToTokens};
@@ -9,9 +10,7 @@ use syn::token::Group;
 use syn::{Expr, ExprLit, ExprPath, Lit};
 use syn::{ExprGroup, Token};
 
-use sqlx::connection::Connection;
-use sqlx::describe::Describe;
-
+use crate::query_macros::data::{QueryData, DATA_FILE};
 use crate::runtime::fs;
 
 /// Macro input shared by `query!()` and `query_file!()`
@@ -78,28 +77,61 @@ impl QueryMacroInput {
 
     /// Run a parse/describe on the query described by this input and validate that it matches the
     /// passed number of args
-    pub async fn describe_validate<C: Connection>(
-        &self,
-        conn: &mut C,
-    ) -> crate::Result<Describe<C::Database>> {
-        let describe = conn
-            .describe(&*self.source)
-            .await
-            .map_err(|e| syn::Error::new(self.source_span, e))?;
-
-        if self.arg_names.len() != describe.param_types.len() {
+    ///
+    /// If `SQLX_OFFLINE_DIR` is set, the result is also saved to `sqlx-data.json` in that directory.
+    /// If `DATABASE_URL` is not set, or `SQLX_OFFLINE` is `true`, the query is read from
+    /// `sqlx-data.json` instead, in `SQLX_OFFLINE_DIR` or else the directory of the crate.
+    pub async fn describe_validate(&self) -> crate::Result<QueryData> {
+        let offline = matches!(dotenv::var("SQLX_OFFLINE").as_deref(), Ok("true") | Ok("1"));
+
+        let data = match dotenv::var("DATABASE_URL") {
+            Ok(db_url) if !offline => {
+                let data = QueryData::from_database_url(&db_url, &self.source)
+                    .await
+                    .map_err(|e| syn::Error::new(self.source_span, e))?;
+
+                // populate the offline query data as queries are described against the database
+                if let Ok(dir) = dotenv::var("SQLX_OFFLINE_DIR") {
+                    data.save_in(dir)?;
+                }
+
+                data
+            }
+
+            _ => {
+                let dir = dotenv::var("SQLX_OFFLINE_DIR")
+                    .or_else(|_| env::var("CARGO_MANIFEST_DIR"))
+                    .map_err(|_| "DATABASE_URL, SQLX_OFFLINE_DIR and CARGO_MANIFEST_DIR not set")?;
+
+                QueryData::from_file(Path::new(&dir).join(DATA_FILE), &self.source).map_err(
+                    |e| {
+                        // without `SQLX_OFFLINE` the data is only read because there is no database
+                        if offline {
+                            syn::Error::new(self.source_span, e)
+                        } else {
+                            syn::Error::new(
+                                self.source_span,
+                                format!("DATABASE_URL not set and {}", e),
+                            )
+                        }
+                    },
+                )?
+            }
+        };
+
+        if self.arg_names.len() != data.input_types.len() {
             return Err(syn::Error::new(
                 Span::call_site(),
                 format!(
                     "expected {} parameters, got {}",
-                    describe.param_types.len(),
+                    data.input_types.len(),
                     self.arg_names.len()
                 ),
             )
             .into());
         }
 
-        Ok(describe)
+        Ok(data)
     }
 }
 
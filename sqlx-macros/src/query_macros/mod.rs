@@ -1,44 +1,83 @@
-use std::fmt::Display;
-
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 
 pub use input::{QueryAsMacroInput, QueryMacroInput};
-pub use query::expand_query;
 
 use crate::database::DatabaseExt;
 
-use sqlx::connection::Connection;
-use sqlx::database::Database;
+use data::QueryData;
 
 mod args;
+mod data;
 mod input;
 mod output;
+mod overrides;
 mod query;
 
-pub async fn expand_query_file<C: Connection>(
+// Dispatch to the expansion for the database the query data was described against.
+macro_rules! expand_with_data {
+    ($data:ident => $expand:ident($($arg:expr),*)) => {{
+        #[cfg(feature = "postgres")]
+        {
+            if $data.db == <sqlx::postgres::Postgres as DatabaseExt>::DATABASE_PATH {
+                return $expand::<sqlx::postgres::Postgres>($($arg),*);
+            }
+        }
+
+        #[cfg(feature = "mysql")]
+        {
+            if $data.db == <sqlx::mysql::MySql as DatabaseExt>::DATABASE_PATH {
+                return $expand::<sqlx::mysql::MySql>($($arg),*);
+            }
+        }
+
+        #[cfg(feature = "sqlite")]
+        {
+            if $data.db == <sqlx::sqlite::Sqlite as DatabaseExt>::DATABASE_PATH {
+                return $expand::<sqlx::sqlite::Sqlite>($($arg),*);
+            }
+        }
+
+        Err(format!(
+            "query data is for database {} but the corresponding feature of sqlx was not enabled",
+            $data.db
+        )
+        .into())
+    }};
+}
+
+pub async fn expand_query(input: QueryMacroInput) -> crate::Result<TokenStream> {
+    let data = input.describe_validate().await?;
+
+    expand_with_data!(data => expand_query_with_data(input, data))
+}
+
+fn expand_query_with_data<DB: DatabaseExt>(
     input: QueryMacroInput,
-    conn: C,
-) -> crate::Result<TokenStream>
-where
-    C::Database: DatabaseExt + Sized,
-    <C::Database as Database>::TypeInfo: Display,
-{
-    expand_query(input.expand_file_src().await?, conn).await
+    data: QueryData,
+) -> crate::Result<TokenStream> {
+    query::expand_query::<DB>(input, data)
+}
+
+pub async fn expand_query_file(input: QueryMacroInput) -> crate::Result<TokenStream> {
+    expand_query(input.expand_file_src().await?).await
+}
+
+pub async fn expand_query_as(
+    input: QueryAsMacroInput,
+    checked: bool,
+) -> crate::Result<TokenStream> {
+    let data = input.query_input.describe_validate().await?;
+
+    expand_with_data!(data => expand_query_as_with_data(input, data, checked))
 }
 
-pub async fn expand_query_as<C: Connection>(
+fn expand_query_as_with_data<DB: DatabaseExt>(
     input: QueryAsMacroInput,
-    mut conn: C,
+    data: QueryData,
     checked: bool,
-) -> crate::Result<TokenStream>
-where
-    C::Database: DatabaseExt + Sized,
-    <C::Database as Database>::TypeInfo: Display,
-{
-    let describe = input.query_input.describe_validate(&mut conn).await?;
-
-    if describe.result_columns.is_empty() {
+) -> crate::Result<TokenStream> {
+    if data.outputs.is_empty() {
         return Err(syn::Error::new(
             input.query_input.source_span,
             "query must output at least one column",
@@ -46,12 +85,12 @@ where
         .into());
     }
 
-    let args_tokens = args::quote_args(&input.query_input, &describe, checked)?;
+    let args_tokens = args::quote_args::<DB>(&input.query_input, &data, checked)?;
 
     let query_args = format_ident!("query_args");
 
-    let columns = output::columns_to_rust(&describe)?;
-    let output = output::quote_query_as::<C::Database>(
+    let columns = output::columns_to_rust(&data)?;
+    let output = output::quote_query_as::<DB>(
         &input.query_input.source,
         &input.as_ty.path,
         &query_args,
@@ -74,14 +113,9 @@ where
     })
 }
 
-pub async fn expand_query_file_as<C: Connection>(
+pub async fn expand_query_file_as(
     input: QueryAsMacroInput,
-    conn: C,
     checked: bool,
-) -> crate::Result<TokenStream>
-where
-    C::Database: DatabaseExt + Sized,
-    <C::Database as Database>::TypeInfo: Display,
-{
-    expand_query_as(input.expand_file_src().await?, conn, checked).await
+) -> crate::Result<TokenStream> {
+    expand_query_as(input.expand_file_src().await?, checked).await
 }
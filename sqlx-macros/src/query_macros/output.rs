@@ -2,38 +2,16 @@ use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
 use syn::Path;
 
-use sqlx::describe::Describe;
-
 use crate::database::DatabaseExt;
-
-use std::fmt::{self, Display, Formatter};
+use crate::query_macros::data::{QueryData, TypeData};
 
 pub struct RustColumn {
     pub(super) ident: Ident,
     pub(super) type_: TokenStream,
 }
 
-struct DisplayColumn<'a> {
-    // zero-based index, converted to 1-based number
-    idx: usize,
-    name: Option<&'a str>,
-}
-
-impl Display for DisplayColumn<'_> {
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        let num = self.idx + 1;
-
-        if let Some(name) = self.name {
-            write!(f, "column #{} ({:?})", num, name)
-        } else {
-            write!(f, "column #{}", num)
-        }
-    }
-}
-
-pub fn columns_to_rust<DB: DatabaseExt>(describe: &Describe<DB>) -> crate::Result<Vec<RustColumn>> {
-    describe
-        .result_columns
+pub fn columns_to_rust(data: &QueryData) -> crate::Result<Vec<RustColumn>> {
+    data.outputs
         .iter()
         .enumerate()
         .map(|(i, column)| -> crate::Result<_> {
@@ -42,53 +20,49 @@ pub fn columns_to_rust<DB: DatabaseExt>(describe: &Describe<DB>) -> crate::Resul
                 .as_deref()
                 .ok_or_else(|| format!("column at position {} must have a name", i))?;
 
-            let ident = parse_ident(name)?;
+            // a column named with a type, e.g. `SELECT id AS "id: UserId"`, is decoded as that
+            // type instead of the one we would pick for its SQL type
+            let (name, override_type) = match name.find(':') {
+                Some(colon) => {
+                    let type_ = name[colon + 1..].trim();
 
-            let mut type_ = if let Some(type_info) = &column.type_info {
-                <DB as DatabaseExt>::return_type_for_id(&type_info).map_or_else(
-                    || {
-                        let message = if let Some(feature_gate) =
-                            <DB as DatabaseExt>::get_feature_gate(&type_info)
-                        {
-                            format!(
-                                "optional feature `{feat}` required for type {ty} of {col}",
-                                ty = &type_info,
-                                feat = feature_gate,
-                                col = DisplayColumn {
-                                    idx: i,
-                                    name: column.name.as_deref()
-                                }
-                            )
-                        } else {
-                            format!(
-                                "unsupported type {ty} of {col}",
-                                ty = type_info,
-                                col = DisplayColumn {
-                                    idx: i,
-                                    name: column.name.as_deref()
-                                }
-                            )
-                        };
-                        syn::Error::new(Span::call_site(), message).to_compile_error()
-                    },
-                    |t| t.parse().unwrap(),
-                )
+                    let type_: syn::Type = syn::parse_str(type_).map_err(|_| {
+                        format!(
+                            "{:?} in the name of column {:?} is not a Rust type",
+                            type_, name
+                        )
+                    })?;
+
+                    (name[..colon].trim_end(), Some(quote! { #type_ }))
+                }
+
+                None => (name, None),
+            };
+
+            // a column named with a `!` or `?` suffix, e.g. `SELECT count(*) AS "count!"`,
+            // overrides the nullability the database told us
+            let (name, non_null) = if let Some(name) = name.strip_suffix('!') {
+                (name, Some(true))
+            } else if let Some(name) = name.strip_suffix('?') {
+                (name, Some(false))
             } else {
-                syn::Error::new(
-                    Span::call_site(),
-                    format!(
-                        "database couldn't tell us the type of {col}; \
-                     this can happen for columns that are the result of an expression",
-                        col = DisplayColumn {
-                            idx: i,
-                            name: column.name.as_deref()
-                        }
-                    ),
-                )
-                .to_compile_error()
+                (name, column.non_null)
+            };
+
+            let ident = parse_ident(name)?;
+
+            let mut type_ = match (override_type, &column.type_) {
+                (Some(type_), _) => type_,
+
+                (None, TypeData::Rust(type_)) => type_.parse().unwrap(),
+
+                // only an error if the type is actually used
+                (None, TypeData::Unsupported(message)) => {
+                    syn::Error::new(Span::call_site(), message).to_compile_error()
+                }
             };
 
-            if !column.non_null.unwrap_or(false) {
+            if !non_null.unwrap_or(false) {
                 type_ = quote! { Option<#type_> };
             }
 
@@ -117,7 +91,9 @@ pub fn quote_query_as<DB: DatabaseExt>(
             // would also perform pointless runtime checks
 
             if checked {
-                quote!( #ident: row.try_get_unchecked::<#type_, _>(#i).try_unwrap_optional()? )
+                quote!( #ident: row.try_get_unchecked::<#type_, _>(#i)
+                    .try_unwrap_optional()
+                    .map_err(|e| sqlx::row::column_decode_error(&row, #i, e))? )
             } else {
                 quote!( #ident: row.try_get_unchecked(#i)? )
             }
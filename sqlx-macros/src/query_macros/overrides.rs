@@ -0,0 +1,191 @@
+//! Rust types for database types, set in `sqlx-types.json` next to the `Cargo.toml` of the
+//! crate that uses the query macros.
+//!
+//! The file is a JSON object mapping the name of each database (e.g. `postgres`) to an object
+//! mapping the names of its types to the paths of the Rust types to use for them. As in the
+//! built-in mapping, a type can be followed by `| Type` for the type of parameters, if it is not
+//! the same as the type of output columns:
+//!
+//! ```json
+//! {
+//!     "postgres": {
+//!         "citext": "String | &str",
+//!         "ltree": "my_crate::Ltree"
+//!     }
+//! }
+//! ```
+
+use std::collections::{BTreeMap, HashMap};
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Name of the type overrides file.
+pub const OVERRIDES_FILE: &str = "sqlx-types.json";
+
+/// The Rust types to use for the types of a database, instead of the built-in mapping.
+#[derive(Debug, Default)]
+pub struct TypeOverrides {
+    // keyed by the lowercase name of the database type
+    types: HashMap<String, TypeOverride>,
+}
+
+#[derive(Debug)]
+struct TypeOverride {
+    return_ty: String,
+    param_ty: String,
+}
+
+impl TypeOverrides {
+    /// Read the overrides for the database `db` from the overrides file of the crate being
+    /// built, if it has one.
+    pub fn load(db: &str) -> crate::Result<Self> {
+        match env::var_os("CARGO_MANIFEST_DIR") {
+            Some(dir) => Self::from_file(PathBuf::from(dir).join(OVERRIDES_FILE), db),
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// Read the overrides for the database `db` from the file at `path`; a missing file has no
+    /// overrides.
+    pub fn from_file(path: impl AsRef<Path>, db: &str) -> crate::Result<Self> {
+        let path = path.as_ref();
+
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => {
+                return Err(
+                    format!("failed to read type overrides at {}: {}", path.display(), e).into(),
+                )
+            }
+        };
+
+        let mut file: BTreeMap<String, BTreeMap<String, String>> = serde_json::from_str(&contents)
+            .map_err(|e| {
+                format!(
+                    "failed to parse type overrides at {}: {}",
+                    path.display(),
+                    e
+                )
+            })?;
+
+        let mut types = HashMap::new();
+
+        for (name, rust_ty) in file.remove(db).unwrap_or_default() {
+            let mut parts = rust_ty.splitn(2, '|').map(str::trim);
+
+            let return_ty = parts.next().unwrap_or_default();
+            let param_ty = parts.next().unwrap_or(return_ty);
+
+            for ty in &[return_ty, param_ty] {
+                if syn::parse_str::<syn::Type>(ty).is_err() {
+                    return Err(format!(
+                        "invalid Rust type {:?} for {} type {} in type overrides at {}",
+                        ty,
+                        db,
+                        name,
+                        path.display()
+                    )
+                    .into());
+                }
+            }
+
+            types.insert(
+                name.to_lowercase(),
+                TypeOverride {
+                    return_ty: return_ty.into(),
+                    param_ty: param_ty.into(),
+                },
+            );
+        }
+
+        Ok(Self { types })
+    }
+
+    /// The Rust type set for parameters of the database type `name`, which is matched
+    /// case-insensitively.
+    pub fn param_type(&self, name: &str) -> Option<&str> {
+        self.types.get(&name.to_lowercase()).map(|ty| &*ty.param_ty)
+    }
+
+    /// The Rust type set for output columns of the database type `name`, which is matched
+    /// case-insensitively.
+    pub fn return_type(&self, name: &str) -> Option<&str> {
+        self.types
+            .get(&name.to_lowercase())
+            .map(|ty| &*ty.return_ty)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_file(name: &str, contents: &str) -> PathBuf {
+        let path = env::temp_dir().join(format!(
+            "sqlx-macros-types-{}-{}.json",
+            name,
+            std::process::id()
+        ));
+
+        fs::write(&path, contents).unwrap();
+
+        path
+    }
+
+    #[test]
+    fn it_reads_the_overrides_of_a_database() -> crate::Result<()> {
+        let path = write_file(
+            "read",
+            r#"{
+                "postgres": { "citext": "String | &str", "LTREE": "my_crate::Ltree<'static>" },
+                "mysql": { "json": "serde_json::Value" }
+            }"#,
+        );
+
+        let overrides = TypeOverrides::from_file(&path, "postgres");
+        fs::remove_file(&path)?;
+        let overrides = overrides?;
+
+        assert_eq!(overrides.return_type("CITEXT"), Some("String"));
+        assert_eq!(overrides.param_type("CITEXT"), Some("&str"));
+        assert_eq!(
+            overrides.return_type("ltree"),
+            Some("my_crate::Ltree<'static>")
+        );
+        assert_eq!(
+            overrides.param_type("ltree"),
+            Some("my_crate::Ltree<'static>")
+        );
+        assert_eq!(overrides.return_type("JSON"), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_has_no_overrides_without_a_file() -> crate::Result<()> {
+        let path = env::temp_dir().join("sqlx-macros-types-missing.json");
+
+        assert_eq!(
+            TypeOverrides::from_file(&path, "postgres")?.return_type("citext"),
+            None
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_rejects_an_invalid_rust_type() {
+        let path = write_file(
+            "invalid",
+            r#"{ "sqlite": { "text": "String | not a type" } }"#,
+        );
+
+        let err = TypeOverrides::from_file(&path, "sqlite").unwrap_err();
+        fs::remove_file(&path).unwrap();
+
+        assert!(err.to_string().contains("invalid Rust type"), "{}", err);
+    }
+}
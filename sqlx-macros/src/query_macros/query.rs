@@ -1,34 +1,27 @@
-use std::fmt::Display;
-
 use proc_macro2::Span;
 use proc_macro2::TokenStream;
 use syn::{Ident, Path};
 
 use quote::{format_ident, quote};
-use sqlx::{connection::Connection, database::Database};
 
+use super::data::QueryData;
 use super::{args, output, QueryMacroInput};
 use crate::database::DatabaseExt;
 
 /// Given an input like `query!("SELECT * FROM accounts WHERE account_id > ?", account_id)`,
 /// expand to an anonymous record
-pub async fn expand_query<C: Connection>(
+pub fn expand_query<DB: DatabaseExt>(
     input: QueryMacroInput,
-    mut conn: C,
-) -> crate::Result<TokenStream>
-where
-    C::Database: DatabaseExt + Sized,
-    <C::Database as Database>::TypeInfo: Display,
-{
-    let describe = input.describe_validate(&mut conn).await?;
+    data: QueryData,
+) -> crate::Result<TokenStream> {
     let sql = &input.source;
 
-    let args = args::quote_args(&input, &describe, true)?;
+    let args = args::quote_args::<DB>(&input, &data, true)?;
 
     let arg_names = &input.arg_names;
-    let db_path = <C::Database as DatabaseExt>::db_path();
+    let db_path = DB::db_path();
 
-    if describe.result_columns.is_empty() {
+    if data.outputs.is_empty() {
         return Ok(quote! {
             macro_rules! macro_result {
                 (#($#arg_names:expr),*) => {{
@@ -42,7 +35,7 @@ where
         });
     }
 
-    let columns = output::columns_to_rust(&describe)?;
+    let columns = output::columns_to_rust(&data)?;
 
     let record_type: Path = Ident::new("Record", Span::call_site()).into();
 
@@ -57,8 +50,7 @@ where
         .collect::<TokenStream>();
 
     let query_args = format_ident!("query_args");
-    let output =
-        output::quote_query_as::<C::Database>(sql, &record_type, &query_args, &columns, true);
+    let output = output::quote_query_as::<DB>(sql, &record_type, &query_args, &columns, true);
 
     Ok(quote! {
         macro_rules! macro_result {
@@ -1,20 +1,19 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
-pub use sqlx_core::arguments;
-pub use sqlx_core::connection::{Connect, Connection};
+pub use sqlx_core::arguments::{self, ToArguments};
+pub use sqlx_core::connection::{Connect, Connection, LogSettings};
 pub use sqlx_core::cursor::{self, Cursor};
 pub use sqlx_core::database::{self, Database};
-pub use sqlx_core::executor::{self, Execute, Executor};
+pub use sqlx_core::describe;
+pub use sqlx_core::executor::{self, Execute, Executor, QueryResult};
 pub use sqlx_core::pool::{self, Pool};
 pub use sqlx_core::query::{self, query, Query};
 pub use sqlx_core::query_as::{query_as, QueryAs};
+pub use sqlx_core::query_builder::{self, QueryBuilder};
 pub use sqlx_core::row::{self, FromRow, Row};
-pub use sqlx_core::transaction::Transaction;
+pub use sqlx_core::transaction::{self, Transaction, TransactionOptions};
 pub use sqlx_core::value;
 
-#[doc(hidden)]
-pub use sqlx_core::describe;
-
 #[doc(inline)]
 pub use sqlx_core::types::{self, Type};
 
@@ -33,12 +32,16 @@ pub use sqlx_core::postgres::{self, PgConnection, PgPool, Postgres};
 #[cfg_attr(docsrs, doc(cfg(feature = "sqlite")))]
 pub use sqlx_core::sqlite::{self, Sqlite, SqliteConnection, SqlitePool};
 
+#[cfg(feature = "any")]
+#[cfg_attr(docsrs, doc(cfg(feature = "any")))]
+pub use sqlx_core::any::{self, Any, AnyConnection, AnyPool};
+
 #[cfg(feature = "macros")]
 #[doc(hidden)]
 pub extern crate sqlx_macros;
 
 #[cfg(feature = "macros")]
-pub use sqlx_macros::{FromRow, Type};
+pub use sqlx_macros::{FromRow, ToArguments, Type};
 
 #[cfg(feature = "macros")]
 mod macros;
@@ -85,4 +88,7 @@ pub mod prelude {
 
     #[cfg(feature = "sqlite")]
     pub use super::sqlite::SqliteQueryAs;
+
+    #[cfg(feature = "any")]
+    pub use super::any::AnyQueryAs;
 }
@@ -92,11 +92,68 @@
 /// `NULL` which then depends on the semantics of what functions are used. Consult the MySQL
 /// manual for the functions you are using to find the cases in which they return `NULL`.
 ///
-/// To override the nullability of an output column, use [query_as!].
+/// To override the nullability of an output column, add a suffix to its name: `!` to use `T`,
+/// e.g. for an expression that is known to never be `NULL`, or `?` to use `Option<T>`. The
+/// suffix is not part of the name of the field:
+///
+/// ```rust,ignore
+/// let rec = sqlx::query!(r#"SELECT count(*) AS "count!", max(name) AS "last?" FROM users"#)
+///     .fetch_one(&mut conn)
+///     .await?;
+///
+/// let count: i64 = rec.count;
+/// let last: Option<String> = rec.last;
+/// ```
+///
+/// A column with a `!` suffix that is `NULL` is an error when the row is decoded. The
+/// nullability can also be overridden with the types of the fields given to [query_as!].
+///
+/// ## Type Overrides: Output Columns
+/// The Rust type of an output column can be set by naming the column `name: Type`, e.g. for a
+/// newtype or a custom domain over a built-in type. The field is decoded as `Type` instead of the
+/// type that would be used for its SQL type; `Type` must implement [`Type`](types/trait.Type.html)
+/// and [`Decode`](decode/trait.Decode.html) for the database. A nullability suffix goes before
+/// the colon:
+///
+/// ```rust,ignore
+/// let rec = sqlx::query!(r#"SELECT id AS "id!: UserId", data AS "data: Vec<u8>" FROM users"#)
+///     .fetch_one(&mut conn)
+///     .await?;
+///
+/// let id: UserId = rec.id;
+/// let data: Option<Vec<u8>> = rec.data;
+/// ```
+///
+/// The type is not checked against the SQL type of the column when the macro is expanded, only
+/// when the row is decoded.
+///
+/// ## Type Overrides: Database Types
+/// The Rust types of the SQL types that have none, e.g. the types of extensions such as
+/// `citext`, or that should use another one everywhere, can be set in a `sqlx-types.json` file
+/// next to the `Cargo.toml` of the crate, which maps the name of each database (`postgres`,
+/// `mysql` or `sqlite`) to the Rust types for its types by name:
+///
+/// ```json
+/// {
+///     "postgres": {
+///         "citext": "String | &str",
+///         "ltree": "my_crate::Ltree"
+///     }
+/// }
+/// ```
+///
+/// The names are matched case-insensitively. A type is used for both the parameters and the
+/// output columns of that SQL type, unless it is followed by `| Type` for the parameters, e.g.
+/// to pass a `&str` for a type decoded as `String`; a type set for a column with `name: Type`
+/// still takes precedence. As with output columns, the type is only checked when a row is decoded.
+///
+/// The file is read when a query is checked, so changing it does not rebuild the crate by
+/// itself.
 ///
 /// ## Requirements
 /// * The `DATABASE_URL` environment variable must be set at build-time to point to a database
-/// server with the schema that the query string will be checked against. (All variants of
+/// server with the schema that the query string will be checked against, unless the query is
+/// checked against the [offline query data](#offline-query-data). (All variants of
 /// `query!()` use [dotenv] so this can be in a `.env` file instead.)
 ///
 /// * The query must be a string literal or else it cannot be introspected (and thus cannot
@@ -109,6 +166,17 @@
 ///     * The schema of the database URL (e.g. `postgres://` or `mysql://`) will be used to
 ///       determine the database type.
 ///
+/// ## Offline Query Data
+/// If the `SQLX_OFFLINE_DIR` environment variable is set at build-time, the result of checking
+/// each query against the database is also saved to `sqlx-data.json` in that directory. The
+/// entry of each query is added or updated as it is checked, so building against a live
+/// database populates the file incrementally.
+///
+/// When `DATABASE_URL` is not set, or `SQLX_OFFLINE` is set to `true`, the queries are checked
+/// against `sqlx-data.json` instead of a database, so the crate can be built without one. The
+/// file is read from `SQLX_OFFLINE_DIR` if it is set and from the directory of the crate
+/// (`CARGO_MANIFEST_DIR`) otherwise; a query that is not in the file is an error.
+///
 /// [dotenv]: https://crates.io/crates/dotenv
 /// ## See Also
 /// * [query_as!] if you want to use a struct you can name,
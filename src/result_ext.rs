@@ -29,6 +29,22 @@ impl<T> ResultExt<Option<T>> for crate::Result<T> {
                 }
             }
 
+            Err(Error::ColumnDecode {
+                index,
+                type_name,
+                source,
+            }) => {
+                if let Some(UnexpectedNullError) = source.downcast_ref() {
+                    Ok(None)
+                } else {
+                    Err(Error::ColumnDecode {
+                        index,
+                        type_name,
+                        source,
+                    })
+                }
+            }
+
             Err(e) => Err(e),
         }
     }
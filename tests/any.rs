@@ -0,0 +1,144 @@
+use futures::TryStreamExt;
+use sqlx::any::{AnyQueryAs, AnyRow};
+use sqlx::{Any, AnyConnection, AnyPool, Connect, Connection, Cursor, Executor, Row};
+use sqlx_test::new;
+
+// The query with the placeholders of the database of the connection; each of them takes `$N`
+// apart from MySQL
+fn sql(conn: &AnyConnection, sql: &str) -> String {
+    match conn.kind() {
+        #[cfg(feature = "mysql")]
+        sqlx::any::AnyKind::MySql => (1..10).rev().fold(sql.to_owned(), |sql, n| {
+            sql.replace(&format!("${}", n), "?")
+        }),
+
+        #[allow(unreachable_patterns)]
+        _ => sql.to_owned(),
+    }
+}
+
+// The URLs of the databases to test, which are the one of `DATABASE_URL` and an in-memory
+// SQLite database
+fn urls() -> anyhow::Result<Vec<String>> {
+    let _ = dotenv::dotenv();
+
+    #[allow(unused_mut)]
+    let mut urls = vec![dotenv::var("DATABASE_URL")?];
+
+    #[cfg(feature = "sqlite")]
+    urls.push("sqlite::memory:".to_owned());
+
+    Ok(urls)
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_connects() -> anyhow::Result<()> {
+    Ok(new::<Any>().await?.ping().await?)
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_fails_to_connect_to_an_unknown_scheme() -> anyhow::Result<()> {
+    let res = AnyConnection::connect("oracle://localhost/db").await;
+
+    assert!(res.is_err());
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_decodes_the_same_values_from_each_database() -> anyhow::Result<()> {
+    for url in urls()? {
+        let mut conn = AnyConnection::connect(&url).await?;
+
+        let query = sql(&conn, "SELECT 'Hello', $1, $2, $3, $4, $5, $6");
+
+        let row: (String, bool, i32, i64, f64, Vec<u8>, Option<String>) = sqlx::query_as(&query)
+            .bind(true)
+            .bind(42_i32)
+            .bind(5_000_000_000_i64)
+            .bind(1.5_f64)
+            .bind(vec![0xDE_u8, 0xAD, 0xBE, 0xEF])
+            .bind(None::<String>)
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert_eq!(
+            row,
+            (
+                "Hello".to_owned(),
+                true,
+                42,
+                5_000_000_000,
+                1.5,
+                vec![0xDE, 0xAD, 0xBE, 0xEF],
+                None
+            ),
+            "{}",
+            url
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_executes_and_fetches_rows() -> anyhow::Result<()> {
+    for url in urls()? {
+        let mut conn = AnyConnection::connect(&url).await?;
+
+        conn.execute("CREATE TEMPORARY TABLE any_users (id INTEGER NOT NULL, name TEXT)")
+            .await?;
+
+        let insert = sql(&conn, "INSERT INTO any_users (id, name) VALUES ($1, $2)");
+
+        for (id, name) in &[(1, Some("Alice")), (2, None)] {
+            let result = sqlx::query(&insert)
+                .bind(*id)
+                .bind(*name)
+                .execute(&mut conn)
+                .await?;
+
+            assert_eq!(result.rows_affected(), 1);
+        }
+
+        let mut cursor = conn.fetch("SELECT id, name FROM any_users ORDER BY id");
+        let mut users = Vec::new();
+
+        while let Some(row) = cursor.next().await? {
+            let id: i32 = row.get("id");
+            let name: Option<String> = row.get(1);
+
+            users.push((id, name));
+        }
+
+        assert_eq!(users, vec![(1, Some("Alice".to_owned())), (2, None)]);
+    }
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_fetches_from_a_pool() -> anyhow::Result<()> {
+    for url in urls()? {
+        let pool = AnyPool::builder().max_size(2).build(&url).await?;
+
+        let names: Vec<String> = sqlx::query("SELECT 'Hello'")
+            .map(|row: AnyRow| row.get(0))
+            .fetch(&pool)
+            .try_collect()
+            .await?;
+
+        assert_eq!(names, vec!["Hello".to_owned()]);
+
+        let mut tx = pool.begin().await?;
+        tx.execute("SELECT 1").await?;
+        tx.commit().await?;
+    }
+
+    Ok(())
+}
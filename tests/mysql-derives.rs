@@ -1,5 +1,6 @@
-use sqlx::MySql;
-use sqlx_test::test_type;
+use sqlx::mysql::MySqlQueryAs;
+use sqlx::{Cursor, Executor, MySql, Row};
+use sqlx_test::{new, test_type};
 use std::fmt::Debug;
 
 // Transparent types are rust-side wrappers over DB types
@@ -41,3 +42,113 @@ test_type!(weak_enum(
 ));
 
 test_type!(strong_color_enum(MySql, Color, "'green'" == Color::Green));
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_enum_column() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE enum_column (
+    id INTEGER PRIMARY KEY,
+    color ENUM('red', 'green', 'blue', 'purple') NOT NULL
+)
+    "#,
+    )
+    .await?;
+
+    sqlx::query("INSERT INTO enum_column (id, color) VALUES (1, ?), (2, 'purple')")
+        .bind(Color::Blue)
+        .execute(&mut conn)
+        .await?;
+
+    let (color,): (Color,) = sqlx::query_as("SELECT color FROM enum_column WHERE id = 1")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(color, Color::Blue);
+
+    // the label is also decoded as a string
+    let (color,): (String,) = sqlx::query_as("SELECT color FROM enum_column WHERE id = 2")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(color, "purple");
+
+    // a label that is not a variant of the Rust enum is an error
+    let err = sqlx::query_as::<_, (Color,)>("SELECT color FROM enum_column WHERE id = 2")
+        .fetch_one(&mut conn)
+        .await
+        .unwrap_err();
+
+    assert!(err
+        .to_string()
+        .contains("invalid value \"purple\" for enum Color"));
+
+    // and a label that is not in the ENUM is rejected by MySQL
+    let res = sqlx::query("INSERT INTO enum_column (id, color) VALUES (3, ?)")
+        .bind("orange")
+        .execute(&mut conn)
+        .await;
+
+    assert!(res.is_err());
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_set_column() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE set_column (
+    id INTEGER PRIMARY KEY,
+    colors SET('red', 'green', 'blue') NOT NULL
+)
+    "#,
+    )
+    .await?;
+
+    sqlx::query("INSERT INTO set_column (id, colors) VALUES (1, ?), (2, ?), (3, 'green,red')")
+        .bind(vec!["red".to_owned(), "blue".to_owned()])
+        .bind(Vec::<String>::new())
+        .execute(&mut conn)
+        .await?;
+
+    let rows: Vec<(i32, Vec<String>)> =
+        sqlx::query_as("SELECT id, colors FROM set_column ORDER BY id")
+            .fetch_all(&mut conn)
+            .await?;
+
+    // labels are returned in the order they are declared in the SET
+    assert_eq!(
+        rows,
+        vec![
+            (1, vec!["red".to_owned(), "blue".to_owned()]),
+            (2, vec![]),
+            (3, vec!["red".to_owned(), "green".to_owned()]),
+        ]
+    );
+
+    // unprepared queries are decoded the same way
+    let mut cursor = conn.fetch("SELECT colors FROM set_column WHERE id = 3");
+    let row = cursor.next().await?.unwrap();
+    let colors: Vec<String> = row.get(0);
+
+    assert_eq!(colors, vec!["red", "green"]);
+
+    drop(cursor);
+
+    // a label that is not in the SET is rejected by MySQL
+    let res = sqlx::query("INSERT INTO set_column (id, colors) VALUES (4, ?)")
+        .bind(vec!["red".to_owned(), "orange".to_owned()])
+        .execute(&mut conn)
+        .await;
+
+    assert!(res.is_err());
+
+    Ok(())
+}
@@ -1,7 +1,8 @@
 extern crate time_ as time;
 
-use sqlx::MySql;
-use sqlx_test::test_type;
+use sqlx::mysql::MySqlQueryAs;
+use sqlx::{Cursor, Executor, MySql, Row};
+use sqlx_test::{new, test_type};
 
 test_type!(null(
     MySql,
@@ -67,7 +68,8 @@ mod chrono {
     test_type!(chrono_time(
         MySql,
         NaiveTime,
-        "TIME '05:10:20.115100'" == NaiveTime::from_hms_micro(5, 10, 20, 115100)
+        "TIME '05:10:20.115100'" == NaiveTime::from_hms_micro(5, 10, 20, 115100),
+        "TIME '05:10:20'" == NaiveTime::from_hms(5, 10, 20)
     ));
 
     test_type!(chrono_date_time(
@@ -90,6 +92,7 @@ mod chrono {
 #[cfg(feature = "time")]
 mod time_tests {
     use super::*;
+    use sqlx::mysql::types::MySqlZeroable;
     use sqlx::types::time::{Date, OffsetDateTime, PrimitiveDateTime, Time};
     use time::{date, time};
 
@@ -103,7 +106,8 @@ mod time_tests {
     test_type!(time_time(
         MySql,
         Time,
-        "TIME '05:10:20.115100'" == time!(5:10:20.115100)
+        "TIME '05:10:20.115100'" == time!(5:10:20.115100),
+        "TIME '05:10:20'" == time!(5:10:20)
     ));
 
     test_type!(time_date_time(
@@ -122,6 +126,71 @@ mod time_tests {
                 .with_time(time!(5:10:20.115100))
                 .assume_utc()
     ));
+
+    test_type!(time_zeroable_date(
+        MySql,
+        MySqlZeroable<Date>,
+        "DATE '2001-01-05'" == MySqlZeroable(Some(date!(2001 - 1 - 5)))
+    ));
+
+    #[cfg_attr(feature = "runtime-async-std", async_std::test)]
+    #[cfg_attr(feature = "runtime-tokio", tokio::test)]
+    async fn test_time_zero_dates() -> anyhow::Result<()> {
+        let mut conn = new::<MySql>().await?;
+
+        // zero dates can only be stored without NO_ZERO_DATE
+        conn.execute(
+            r#"
+SET SESSION sql_mode = '';
+CREATE TEMPORARY TABLE zero_dates (day DATE NOT NULL, at DATETIME NOT NULL);
+INSERT INTO zero_dates (day, at) VALUES ('0000-00-00', '0000-00-00 00:00:00');
+            "#,
+        )
+        .await?;
+
+        sqlx::query("INSERT INTO zero_dates (day, at) VALUES (?, ?)")
+            .bind(MySqlZeroable::<Date>(None))
+            .bind(MySqlZeroable::<PrimitiveDateTime>(None))
+            .execute(&mut conn)
+            .await?;
+
+        // in the binary format
+        let rows: Vec<(MySqlZeroable<Date>, MySqlZeroable<PrimitiveDateTime>)> =
+            sqlx::query_as("SELECT day, at FROM zero_dates WHERE ? = 1")
+                .bind(1_i32)
+                .fetch_all(&mut conn)
+                .await?;
+
+        assert_eq!(rows.len(), 2);
+
+        for (day, at) in rows {
+            assert_eq!(day, MySqlZeroable(None));
+            assert_eq!(at, MySqlZeroable(None));
+        }
+
+        let res = sqlx::query_as::<_, (Date,)>("SELECT day FROM zero_dates WHERE ? = 1")
+            .bind(1_i32)
+            .fetch_one(&mut conn)
+            .await;
+
+        assert!(res.is_err());
+
+        // and in the text format
+        let mut cursor = conn.fetch("SELECT day, at FROM zero_dates");
+        let row = cursor.next().await?.unwrap();
+
+        assert_eq!(
+            row.try_get::<MySqlZeroable<Date>, _>(0)?,
+            MySqlZeroable(None)
+        );
+        assert_eq!(
+            row.try_get::<MySqlZeroable<PrimitiveDateTime>, _>(1)?,
+            MySqlZeroable(None)
+        );
+        assert!(row.try_get::<OffsetDateTime, _>(1).is_err());
+
+        Ok(())
+    }
 }
 
 #[cfg(feature = "bigdecimal")]
@@ -135,3 +204,198 @@ test_type!(decimal(
     "CAST(12.34 AS DECIMAL(4, 2))" == "12.34".parse::<sqlx::types::BigDecimal>().unwrap(),
     "CAST(12345.6789 AS DECIMAL(9, 4))" == "12345.6789".parse::<sqlx::types::BigDecimal>().unwrap(),
 ));
+
+// BIT, YEAR and MEDIUMINT are only returned from columns of those types
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_bit_year_and_medium_int() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE bit_year_medium_int (
+    flags BIT(10) NOT NULL,
+    flag BIT(1) NOT NULL,
+    year YEAR NOT NULL,
+    medium_int MEDIUMINT NOT NULL
+)
+        "#,
+    )
+    .await?;
+
+    conn.execute("INSERT INTO bit_year_medium_int VALUES (b'1000000101', b'1', 2020, -8388608)")
+        .await?;
+
+    let query = "SELECT flags, flag, year, medium_int FROM bit_year_medium_int";
+
+    let mut cursor = conn.fetch(query);
+    let row = cursor.next().await?.unwrap();
+
+    assert_eq!(row.try_get::<u64, _>(0)?, 0b10_0000_0101);
+    assert!(row.try_get::<bool, _>(1)?);
+    assert_eq!(row.try_get::<u16, _>(2)?, 2020);
+    assert_eq!(row.try_get::<i32, _>(3)?, -8_388_608);
+
+    drop(cursor);
+
+    let (flags, flag, year, medium_int): (u64, bool, u16, i32) =
+        sqlx::query_as(query).fetch_one(&mut conn).await?;
+
+    assert_eq!(flags, 0b10_0000_0101);
+    assert!(flag);
+    assert_eq!(year, 2020);
+    assert_eq!(medium_int, -8_388_608);
+
+    Ok(())
+}
+
+// DECIMAL and the temporal types from the columns of a table rather than a literal
+#[cfg(all(feature = "bigdecimal", feature = "chrono"))]
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_unprepared_decimal_and_temporal_columns() -> anyhow::Result<()> {
+    use sqlx::types::chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+    use sqlx::types::BigDecimal;
+
+    let mut conn = new::<MySql>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE decimal_temporal (
+    amount DECIMAL(10, 2) NOT NULL,
+    day DATE NOT NULL,
+    at_time TIME NOT NULL,
+    at DATETIME NOT NULL,
+    created TIMESTAMP(6) NOT NULL
+)
+        "#,
+    )
+    .await?;
+
+    conn.execute(
+        r#"
+INSERT INTO decimal_temporal
+VALUES (-1234.5, '2020-02-29', '23:59:59', '2020-02-29 23:59:59', '2020-02-29 23:59:59.000001')
+        "#,
+    )
+    .await?;
+
+    let mut cursor = conn.fetch("SELECT amount, day, at_time, at, created FROM decimal_temporal");
+    let row = cursor.next().await?.unwrap();
+
+    let date = NaiveDate::from_ymd(2020, 2, 29);
+
+    assert_eq!(
+        row.try_get::<BigDecimal, _>(0)?,
+        "-1234.50".parse::<BigDecimal>()?
+    );
+    assert_eq!(row.try_get::<NaiveDate, _>(1)?, date);
+    assert_eq!(
+        row.try_get::<NaiveTime, _>(2)?,
+        NaiveTime::from_hms(23, 59, 59)
+    );
+    assert_eq!(
+        row.try_get::<NaiveDateTime, _>(3)?,
+        date.and_hms(23, 59, 59)
+    );
+    assert_eq!(
+        row.try_get::<NaiveDateTime, _>(4)?,
+        date.and_hms_micro(23, 59, 59, 1)
+    );
+
+    Ok(())
+}
+
+#[cfg(feature = "json")]
+mod json {
+    use super::*;
+    use serde_json::{json, Value as JsonValue};
+    use sqlx::types::Json;
+
+    #[derive(serde::Deserialize, serde::Serialize, Debug, PartialEq)]
+    struct Friend {
+        name: String,
+        age: u32,
+    }
+
+    #[cfg_attr(feature = "runtime-async-std", async_std::test)]
+    #[cfg_attr(feature = "runtime-tokio", tokio::test)]
+    async fn test_json_round_trip() -> anyhow::Result<()> {
+        let mut conn = new::<MySql>().await?;
+
+        conn.execute(
+            "CREATE TEMPORARY TABLE json_values (id INT PRIMARY KEY, value JSON NOT NULL)",
+        )
+        .await?;
+
+        let values = vec![
+            (
+                json!({ "name": "Joe", "tags": ["a", "b"], "nested": { "x": null } }),
+                "OBJECT",
+            ),
+            (json!([1, "two", 3.5, false, null]), "ARRAY"),
+            (json!("hello"), "STRING"),
+            (json!(42), "INTEGER"),
+            (json!(true), "BOOLEAN"),
+            (json!(null), "NULL"),
+        ];
+
+        for (id, (value, _)) in values.iter().enumerate() {
+            sqlx::query("INSERT INTO json_values (id, value) VALUES (?, ?)")
+                .bind(id as i32)
+                .bind(value)
+                .execute(&mut conn)
+                .await?;
+        }
+
+        let query = "SELECT value, JSON_TYPE(value) FROM json_values ORDER BY id";
+
+        let rows: Vec<(JsonValue, String)> = sqlx::query_as(query).fetch_all(&mut conn).await?;
+
+        for ((value, ty), (expected, expected_ty)) in rows.iter().zip(&values) {
+            assert_eq!(value, expected);
+            assert_eq!(ty, expected_ty);
+        }
+
+        // the simple query protocol
+        let mut cursor = conn.fetch(query);
+        let mut index = 0;
+
+        while let Some(row) = cursor.next().await? {
+            assert_eq!(row.try_get::<JsonValue, _>(0)?, values[index].0);
+            index += 1;
+        }
+
+        assert_eq!(index, values.len());
+
+        Ok(())
+    }
+
+    #[cfg_attr(feature = "runtime-async-std", async_std::test)]
+    #[cfg_attr(feature = "runtime-tokio", tokio::test)]
+    async fn test_json_struct() -> anyhow::Result<()> {
+        let mut conn = new::<MySql>().await?;
+
+        let friend = Friend {
+            name: "Joe".to_owned(),
+            age: 33,
+        };
+
+        let (value, name): (Json<Friend>, String) =
+            sqlx::query_as("SELECT CAST(? AS JSON), JSON_UNQUOTE(JSON_EXTRACT(?, '$.name'))")
+                .bind(Json(&friend))
+                .bind(Json(&friend))
+                .fetch_one(&mut conn)
+                .await?;
+
+        assert_eq!(value.0, friend);
+        assert_eq!(name, "Joe");
+
+        let mut cursor = conn.fetch(r#"SELECT CAST('{"name": "Joe", "age": 33}' AS JSON)"#);
+        let row = cursor.next().await?.unwrap();
+
+        assert_eq!(row.try_get::<Json<Friend>, _>(0)?.0, friend);
+
+        Ok(())
+    }
+}
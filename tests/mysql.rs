@@ -1,5 +1,6 @@
 use futures::TryStreamExt;
-use sqlx::{mysql::MySqlQueryAs, Connection, Executor, MySql, MySqlPool};
+use sqlx::mysql::{MySqlConnectOptions, MySqlConnection, MySqlData, MySqlQueryAs, MySqlRow};
+use sqlx::{Connect, Connection, Cursor, Executor, MySql, MySqlPool, Row};
 use sqlx_test::new;
 use std::time::Duration;
 
@@ -9,6 +10,163 @@ async fn it_connects() -> anyhow::Result<()> {
     Ok(new::<MySql>().await?.ping().await?)
 }
 
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_connects_with_tcp_options() -> anyhow::Result<()> {
+    let url = dotenv::var("DATABASE_URL")?;
+
+    let options = MySqlConnectOptions::new()
+        .tcp_keepalive(Duration::from_secs(60))
+        .tcp_nodelay(true);
+
+    let mut conn = MySqlConnection::connect_with(&url, &options).await?;
+
+    let (value,): (i64,) = sqlx::query_as("SELECT 1 + 1").fetch_one(&mut conn).await?;
+
+    assert_eq!(value, 2);
+
+    Ok(conn.ping().await?)
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_connects_a_pool_with_tcp_parameters() -> anyhow::Result<()> {
+    let url = dotenv::var("DATABASE_URL")?;
+    let separator = if url.contains('?') { '&' } else { '?' };
+
+    let pool = MySqlPool::new(&format!(
+        "{}{}tcp_keepalive=60&tcp_nodelay=true",
+        url, separator
+    ))
+    .await?;
+
+    let (value,): (i64,) = sqlx::query_as("SELECT 1 + 1").fetch_one(&pool).await?;
+
+    assert_eq!(value, 2);
+
+    let pool = MySqlPool::new(&format!("{}{}tcp_keepalive=1m", url, separator)).await?;
+    let res = pool.acquire().await;
+
+    let err = res.err().unwrap();
+
+    assert!(err.to_string().contains("tcp_keepalive"), "{}", err);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_reports_the_server_version() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    let (major, minor, patch) = conn.server_version();
+
+    let (version,): (String,) = sqlx::query_as("SELECT VERSION()")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert!(major >= 5, "{:?}", conn.server_version());
+    assert!(
+        version.starts_with(&format!("{}.{}.{}", major, minor, patch)),
+        "{}",
+        version
+    );
+
+    Ok(())
+}
+
+// Only checked when `DATABASE_URL` asks for the certificate of the server to be verified, e.g.
+// with `ssl-mode=VERIFY_CA&ssl-ca=%2Fpath%2Fto%2Fca.pem`
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_connects_with_a_verified_tls_certificate() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    if !dotenv::var("DATABASE_URL")?.contains("ssl-mode=VERIFY_") {
+        return Ok(());
+    }
+
+    let (_, cipher): (String, String) = sqlx::query_as("SHOW STATUS LIKE 'Ssl_cipher'")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert!(!cipher.is_empty());
+
+    Ok(())
+}
+
+// Only checked when `DATABASE_URL` connects over a socket, e.g. with
+// `socket=%2Fvar%2Frun%2Fmysqld%2Fmysqld.sock`
+#[cfg(unix)]
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_connects_over_a_unix_socket() -> anyhow::Result<()> {
+    let url = dotenv::var("DATABASE_URL")?;
+
+    if !url.contains("socket=") && !url.contains("@%2F") {
+        return Ok(());
+    }
+
+    let mut conn = MySqlConnection::connect(&url).await?;
+
+    // The host of a connection over a socket is `localhost`, without a port
+    let (host,): (String,) = sqlx::query_as(
+        "SELECT host FROM information_schema.processlist WHERE id = CONNECTION_ID()",
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(host, "localhost");
+
+    Ok(())
+}
+
+// `caching_sha2_password` is the default authentication of MySQL 8; a user whose password is
+// not cached by the server yet goes through the full authentication, with the password sent
+// over TLS or encrypted with the public key of the server, and then through the fast one
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_authenticates_with_caching_sha2_password() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    let (version,): (String,) = sqlx::query_as("SELECT VERSION()")
+        .fetch_one(&mut conn)
+        .await?;
+
+    // MariaDB (`10.4.11-MariaDB`) and MySQL 5.7 do not have `caching_sha2_password`
+    if !version.starts_with("8.") {
+        return Ok(());
+    }
+
+    conn.execute("DROP USER IF EXISTS 'sqlx_sha2'@'%'").await?;
+    conn.execute("CREATE USER 'sqlx_sha2'@'%' IDENTIFIED WITH caching_sha2_password BY 'password'")
+        .await?;
+
+    // The URL of the user, without a database as the user has no privileges on any
+    let url = dotenv::var("DATABASE_URL")?;
+    let (scheme, rest) = url.split_at(url.find("://").unwrap() + 3);
+    let rest = &rest[rest.find('@').unwrap() + 1..];
+    let host = &rest[..rest.find('/').unwrap_or(rest.len())];
+    let query = &rest[rest.find('?').unwrap_or(rest.len())..];
+
+    let url = format!("{}sqlx_sha2:password@{}/{}", scheme, host, query);
+    let url_without_tls = format!("{}sqlx_sha2:password@{}/?ssl-mode=DISABLED", scheme, host);
+
+    for url in &[url_without_tls, url] {
+        // Clears the passwords cached by the server
+        conn.execute("FLUSH PRIVILEGES").await?;
+
+        // The full authentication and then the fast one
+        for _ in 0..2 {
+            MySqlConnection::connect(url).await?.ping().await?;
+        }
+    }
+
+    conn.execute("DROP USER 'sqlx_sha2'@'%'").await?;
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "runtime-async-std", async_std::test)]
 #[cfg_attr(feature = "runtime-tokio", tokio::test)]
 async fn it_drops_results_in_affected_rows() -> anyhow::Result<()> {
@@ -18,7 +176,7 @@ async fn it_drops_results_in_affected_rows() -> anyhow::Result<()> {
     let affected = conn.execute("select * from mysql.time_zone").await?;
 
     // In MySQL, rows being returned isn't enough to flag it as an _affected_ row
-    assert_eq!(0, affected);
+    assert_eq!(0, affected.rows_affected());
 
     Ok(())
 }
@@ -42,7 +200,7 @@ CREATE TEMPORARY TABLE users (id INTEGER PRIMARY KEY)
             .execute(&mut conn)
             .await?;
 
-        assert_eq!(cnt, 1);
+        assert_eq!(cnt.rows_affected(), 1);
     }
 
     let sum: i32 = sqlx::query_as("SELECT id FROM users")
@@ -232,3 +390,454 @@ async fn test_fetch_one_and_ping() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_rolls_back_savepoints() -> anyhow::Result<()> {
+    macro_rules! count {
+        ($e:expr) => {{
+            let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM savepoints")
+                .fetch_one(&mut $e)
+                .await?;
+
+            count
+        }};
+    }
+
+    let mut conn = new::<MySql>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE savepoints (id INTEGER PRIMARY KEY)")
+        .await?;
+
+    let mut tx = conn.begin().await?;
+    tx.execute("INSERT INTO savepoints (id) VALUES (1)").await?;
+
+    // SAVEPOINT
+    let mut tx = tx.begin().await?;
+    tx.execute("INSERT INTO savepoints (id) VALUES (2)").await?;
+
+    // a nested SAVEPOINT that is rolled back
+    let mut tx = tx.begin().await?;
+    tx.execute("INSERT INTO savepoints (id) VALUES (3)").await?;
+
+    let mut tx = tx.rollback().await?;
+
+    assert_eq!(count!(tx), 2);
+
+    // another SAVEPOINT at the same depth that is released
+    let mut tx = tx.begin().await?;
+    tx.execute("INSERT INTO savepoints (id) VALUES (4)").await?;
+
+    let mut tx = tx.commit().await?;
+
+    assert_eq!(count!(tx), 3);
+
+    // rolling back the outer SAVEPOINT also drops the changes of the one it contained
+    let mut tx = tx.rollback().await?;
+
+    assert_eq!(count!(tx), 1);
+
+    let mut conn = tx.commit().await?;
+
+    assert_eq!(count!(conn), 1);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_rolls_back_dropped_transactions() -> anyhow::Result<()> {
+    // with a single connection the dropped transaction's connection is the one used next
+    let pool = MySqlPool::builder()
+        .max_size(1)
+        .build(&dotenv::var("DATABASE_URL")?)
+        .await?;
+
+    sqlx::query("CREATE TEMPORARY TABLE dropped_transactions (id INTEGER PRIMARY KEY)")
+        .execute(&pool)
+        .await?;
+
+    let mut tx = pool.begin().await?;
+
+    sqlx::query("INSERT INTO dropped_transactions (id) VALUES (1)")
+        .execute(&mut tx)
+        .await?;
+
+    drop(tx);
+
+    // the connection (and its temporary table) is still there but the INSERT is not
+    let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM dropped_transactions")
+        .fetch_one(&pool)
+        .await?;
+
+    assert_eq!(count, 0);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_resets_connections_returned_to_the_pool() -> anyhow::Result<()> {
+    for reset_on_release in &[true, false] {
+        // with a single connection the one that is returned is the one acquired next
+        let pool = MySqlPool::builder()
+            .max_size(1)
+            .reset_on_release(*reset_on_release)
+            .build(&dotenv::var("DATABASE_URL")?)
+            .await?;
+
+        let mut conn = pool.acquire().await?;
+
+        conn.execute("SET @sqlx_reset = 42, SESSION sql_mode = ''")
+            .await?;
+
+        drop(conn);
+
+        let (variable, sql_mode): (Option<i64>, String) =
+            sqlx::query_as("SELECT @sqlx_reset, @@SESSION.sql_mode")
+                .fetch_one(&pool)
+                .await?;
+
+        if *reset_on_release {
+            // the variables of the session are gone and those set by sqlx are set again
+            assert_eq!(variable, None);
+            assert!(sql_mode.contains("PIPES_AS_CONCAT"), "{}", sql_mode);
+        } else {
+            assert_eq!(variable, Some(42));
+            assert_eq!(sql_mode, "");
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_begins_transactions_with_options() -> anyhow::Result<()> {
+    use sqlx::transaction::{AccessMode, IsolationLevel, TransactionOptions};
+
+    let mut conn = new::<MySql>().await?;
+
+    // a read only transaction may still write to a temporary table
+    conn.execute("CREATE TABLE IF NOT EXISTS _sqlx_read_only_4213 (id INTEGER PRIMARY KEY)")
+        .await?;
+
+    let options = TransactionOptions::new()
+        .isolation_level(IsolationLevel::Serializable)
+        .access_mode(AccessMode::ReadOnly);
+
+    let mut tx = conn.begin_with(options).await?;
+
+    let err = tx
+        .execute("INSERT INTO _sqlx_read_only_4213 (id) VALUES (1)")
+        .await
+        .unwrap_err();
+
+    match err {
+        sqlx::Error::Database(err) => assert_eq!(err.code(), Some("25006")),
+        err => panic!("expected a database error, got {:?}", err),
+    }
+
+    let conn = tx.rollback().await?;
+
+    let tx = conn
+        .begin_with(TransactionOptions::new().deferrable(true))
+        .await;
+
+    assert!(tx.is_err());
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_classifies_constraint_violations() -> anyhow::Result<()> {
+    use sqlx::error::DatabaseErrorKind;
+    use sqlx::mysql::MySqlError;
+
+    let mut conn = new::<MySql>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE error_users (name VARCHAR(255) NOT NULL UNIQUE);
+INSERT INTO error_users (name) VALUES ('Alice');
+        "#,
+    )
+    .await?;
+
+    match conn
+        .execute("INSERT INTO error_users (name) VALUES ('Alice')")
+        .await
+        .unwrap_err()
+    {
+        sqlx::Error::Database(err) => {
+            assert_eq!(err.code(), Some("23000"));
+            assert_eq!(err.kind(), DatabaseErrorKind::UniqueViolation);
+            assert_eq!(err.downcast_ref::<MySqlError>().number(), 1062);
+        }
+
+        err => panic!("expected a database error, got {:?}", err),
+    }
+
+    match conn
+        .execute("INSERT INTO error_users (name) VALUES (NULL)")
+        .await
+        .unwrap_err()
+    {
+        sqlx::Error::Database(err) => {
+            assert_eq!(err.kind(), DatabaseErrorKind::NotNullViolation);
+        }
+
+        err => panic!("expected a database error, got {:?}", err),
+    }
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_detects_constraint_violations() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    // foreign keys are not supported by temporary tables; `CHECK` needs MySQL 8.0.16 or MariaDB
+    conn.execute(
+        r#"
+DROP TABLE IF EXISTS _sqlx_violation_users, _sqlx_violation_teams;
+CREATE TABLE _sqlx_violation_teams (id INT PRIMARY KEY) ENGINE = InnoDB;
+CREATE TABLE _sqlx_violation_users (
+    name VARCHAR(255) NOT NULL UNIQUE,
+    age INT CHECK (age >= 0),
+    team INT,
+    FOREIGN KEY (team) REFERENCES _sqlx_violation_teams (id)
+) ENGINE = InnoDB;
+INSERT INTO _sqlx_violation_users (name) VALUES ('Alice');
+        "#,
+    )
+    .await?;
+
+    let err = conn
+        .execute("INSERT INTO _sqlx_violation_users (name) VALUES ('Alice')")
+        .await
+        .unwrap_err();
+
+    assert!(err.is_unique_violation(), "{:?}", err);
+    assert!(!err.is_foreign_key_violation());
+
+    let err = conn
+        .execute("INSERT INTO _sqlx_violation_users (name, team) VALUES ('Bob', 1)")
+        .await
+        .unwrap_err();
+
+    assert!(err.is_foreign_key_violation(), "{:?}", err);
+    assert!(!err.is_unique_violation());
+
+    let err = conn
+        .execute("INSERT INTO _sqlx_violation_users (name, age) VALUES ('Bob', -1)")
+        .await
+        .unwrap_err();
+
+    assert!(err.is_check_violation(), "{:?}", err);
+
+    let err = conn
+        .execute("INSERT INTO _sqlx_violation_users (name) VALUES (NULL)")
+        .await
+        .unwrap_err();
+
+    assert!(err.is_not_null_violation(), "{:?}", err);
+    assert!(!err.is_check_violation());
+
+    assert!(!sqlx::Error::RowNotFound.is_unique_violation());
+
+    conn.execute("DROP TABLE _sqlx_violation_users, _sqlx_violation_teams")
+        .await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_returns_the_query_result() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    conn.execute(
+        "CREATE TEMPORARY TABLE query_result (id INTEGER PRIMARY KEY AUTO_INCREMENT, name TEXT)",
+    )
+    .await?;
+
+    let result = sqlx::query("INSERT INTO query_result (name) VALUES (?)")
+        .bind("Alice")
+        .execute(&mut conn)
+        .await?;
+
+    assert_eq!(result.rows_affected(), 1);
+    assert_eq!(result.last_insert_id(), Some(1));
+
+    // the ID of the first row inserted by a statement is reported
+    let result = sqlx::query("INSERT INTO query_result (name) VALUES (?), (?)")
+        .bind("Bob")
+        .bind("Carol")
+        .execute(&mut conn)
+        .await?;
+
+    assert_eq!(result.rows_affected(), 2);
+    assert_eq!(result.last_insert_id(), Some(2));
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_builds_queries_with_in_lists() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE query_builder (id INTEGER PRIMARY KEY, name TEXT)")
+        .await?;
+
+    conn.execute(
+        "INSERT INTO query_builder (id, name) VALUES (1, 'a'), (2, 'b'), (3, 'c'), (4, 'd')",
+    )
+    .await?;
+
+    let mut builder = sqlx::QueryBuilder::<MySql>::new("SELECT name FROM query_builder WHERE ");
+
+    builder
+        .push("name <> ")
+        .push_bind("c")
+        .push(" AND id IN (")
+        .push_bind_separated(&[1_i32, 3, 4], ", ")
+        .push(") ORDER BY id");
+
+    assert_eq!(
+        builder.sql(),
+        "SELECT name FROM query_builder WHERE name <> ? AND id IN (?, ?, ?) ORDER BY id"
+    );
+
+    let names = builder
+        .build()
+        .try_map(|row: MySqlRow| row.try_get::<String, _>(0))
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert_eq!(names, ["a", "d"]);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_inspects_columns() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE users (id INT PRIMARY KEY, name TEXT NOT NULL, email TEXT);
+INSERT INTO users (id, name) VALUES (1, 'Herp Derpinson');
+        "#,
+    )
+    .await?;
+
+    let mut cursor = sqlx::query("SELECT id, name, email FROM users").fetch(&mut conn);
+    let row = cursor.next().await?.unwrap();
+
+    let id = row.column(0);
+    assert_eq!(id.name(), Some("id"));
+    assert_eq!(id.ordinal(), 0);
+    assert_eq!(id.type_info().unwrap().to_string(), "INT");
+
+    let name = row.column("name");
+    assert_eq!(name.ordinal(), 1);
+    assert_eq!(name.type_info().unwrap().to_string(), "TEXT");
+
+    assert_eq!(row.column(2).type_info().unwrap().to_string(), "TEXT");
+    assert!(row.try_column("missing").is_err());
+
+    let raw = row.try_get_raw(0)?;
+    assert!(matches!(raw.get(), Some(MySqlData::Binary(_))));
+
+    // a NULL has no data
+    assert!(row.try_get_raw(2)?.get().is_none());
+
+    Ok(())
+}
+
+#[cfg(feature = "json")]
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_converts_rows_to_json() -> anyhow::Result<()> {
+    use serde_json::json;
+    use sqlx::types::json::row_to_json;
+
+    let mut conn = new::<MySql>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE users (
+    id INT PRIMARY KEY,
+    name TEXT NOT NULL,
+    visits BIGINT UNSIGNED NOT NULL,
+    ratio DOUBLE NOT NULL,
+    balance DECIMAL(10, 2) NOT NULL,
+    avatar BLOB NOT NULL,
+    meta JSON NOT NULL,
+    email TEXT
+);
+
+INSERT INTO users (id, name, visits, ratio, balance, avatar, meta)
+VALUES (1, 'Herp Derpinson', 18446744073709551615, 1.5, 12.50, X'DEADBEEF', '{"admin": false}');
+        "#,
+    )
+    .await?;
+
+    let expected = json!({
+        "id": 1,
+        "name": "Herp Derpinson",
+        "visits": 18446744073709551615_u64,
+        "ratio": 1.5,
+        "balance": "12.50",
+        "avatar": "3q2+7w==",
+        "meta": { "admin": false },
+        "email": null,
+    });
+
+    // prepared queries receive values in the binary format
+    let mut cursor = sqlx::query("SELECT * FROM users").fetch(&mut conn);
+    let row = cursor.next().await?.unwrap();
+    assert_eq!(row_to_json(&row)?, expected);
+    drop(cursor);
+
+    // and simple queries in the text format
+    let mut cursor = conn.fetch("SELECT * FROM users");
+    let row = cursor.next().await?.unwrap();
+    assert_eq!(row_to_json(&row)?, expected);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_executes_many_statements() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    let rows_affected: Vec<u64> = conn
+        .execute_many(
+            r#"
+CREATE TEMPORARY TABLE numbers (n INTEGER NOT NULL);
+INSERT INTO numbers (n) VALUES (1), (2), (3);
+SELECT n FROM numbers;
+DELETE FROM numbers WHERE n > 1;
+            "#,
+        )
+        .map_ok(|result| result.rows_affected())
+        .try_collect()
+        .await?;
+
+    assert_eq!(rows_affected, [0, 3, 0, 2]);
+
+    let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM numbers")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 1);
+
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use sqlx::{postgres::PgQueryAs, Connection, Cursor, Executor, FromRow, Postgres};
+use sqlx::{postgres::PgQueryAs, Connection, Cursor, Executor, FromRow, Postgres, Row};
 use sqlx_test::{new, test_type};
 use std::fmt::Debug;
 
@@ -48,6 +48,25 @@ struct InventoryItem {
     price: Option<i64>,
 }
 
+// Records may contain other custom types and fields may be renamed;
+// fields are matched to attributes by position
+#[derive(PartialEq, Debug, sqlx::Type)]
+#[sqlx(rename = "shipping")]
+#[sqlx(rename_all = "lowercase")]
+enum Shipping {
+    Ground,
+    Air,
+}
+
+#[derive(PartialEq, Debug, sqlx::Type)]
+#[sqlx(rename = "parcel")]
+struct Parcel {
+    #[sqlx(rename = "weight")]
+    weight_grams: i32,
+    method: Shipping,
+    note: Option<String>,
+}
+
 test_type!(transparent(
     Postgres,
     Transparent,
@@ -159,6 +178,32 @@ SELECT $1 = 'happy'::mood, $1
     assert!(rec.0);
     assert_eq!(rec.1, Mood::Happy);
 
+    // A valid label of another type is still not a `mood`
+    let mut cursor = sqlx::query("SELECT 'happy'::text").fetch(&mut conn);
+    let row = cursor.next().await?.unwrap();
+    let err = row.try_get_unchecked::<Mood, _>(0).unwrap_err();
+
+    assert!(err.to_string().contains("mismatched types"), "{}", err);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_enum_unknown_label() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let err = sqlx::query_as::<_, (Strong,)>("SELECT 'three'::text")
+        .fetch_one(&mut conn)
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "error decoding column \"text\" of SQL type TEXT: \
+         invalid value \"three\" for enum Strong; expected one of: one, two, four"
+    );
+
     Ok(())
 }
 
@@ -202,6 +247,89 @@ END $$;
     assert!(rec.0);
     assert_eq!(rec.1, value);
 
+    // NULL fields
+    let value = InventoryItem {
+        name: "fuzzy dice".to_owned(),
+        supplier_id: None,
+        price: None,
+    };
+
+    let rec: (bool, InventoryItem) = sqlx::query_as(
+        "
+        SELECT $1 IS NOT DISTINCT FROM ROW('fuzzy dice', NULL, NULL)::inventory_item, $1
+        ",
+    )
+    .bind(&value)
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert!(rec.0);
+    assert_eq!(rec.1, value);
+
+    let mut cursor = conn.fetch("SELECT ROW('fuzzy dice', NULL, NULL)::inventory_item");
+    let row = cursor.next().await?.unwrap();
+
+    assert_eq!(row.try_get::<InventoryItem, _>(0)?, value);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_nested_record_type() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute(
+        r#"
+DROP TYPE IF EXISTS parcel;
+
+DROP TYPE IF EXISTS shipping CASCADE;
+
+CREATE TYPE shipping AS ENUM ( 'ground', 'air' );
+
+CREATE TYPE parcel AS (
+    weight          int,
+    method          shipping,
+    note            text
+);
+    "#,
+    )
+    .await?;
+
+    // Drop and re-acquire the connection
+    conn.close().await?;
+    let mut conn = new::<Postgres>().await?;
+
+    let value = Parcel {
+        weight_grams: 500,
+        method: Shipping::Air,
+        note: None,
+    };
+
+    let rec: (bool, Parcel) = sqlx::query_as(
+        "
+        SELECT $1 IS NOT DISTINCT FROM ROW(500, 'air', NULL)::parcel, $1
+        ",
+    )
+    .bind(&value)
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert!(rec.0);
+    assert_eq!(rec.1, value);
+
+    // a bad field is reported by name
+    let mut cursor = sqlx::query("SELECT ROW(500, 'air', NULL)::parcel").fetch(&mut conn);
+    let row = cursor.next().await?.unwrap();
+    let err = row.try_get_unchecked::<InventoryItem, _>(0).unwrap_err();
+
+    assert!(
+        err.to_string()
+            .contains("failed to decode field \"name\" of inventory_item: mismatched types"),
+        "{}",
+        err
+    );
+
     Ok(())
 }
 
@@ -325,3 +453,185 @@ async fn test_from_row_with_rename() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[cfg(feature = "macros")]
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_from_row_with_flatten() -> anyhow::Result<()> {
+    use sqlx::prelude::*;
+
+    #[derive(Debug, sqlx::FromRow)]
+    struct Account {
+        id: i32,
+        name: String,
+    }
+
+    #[derive(Debug, Default, PartialEq, sqlx::FromRow)]
+    struct Address {
+        #[sqlx(rename = "address_city")]
+        city: String,
+
+        #[sqlx(default)]
+        country: Option<String>,
+    }
+
+    #[derive(Debug, sqlx::FromRow)]
+    struct AccountWithAddress {
+        #[sqlx(flatten)]
+        account: Account,
+
+        #[sqlx(flatten)]
+        address: Address,
+
+        #[sqlx(default)]
+        visits: i32,
+    }
+
+    let mut conn = new::<Postgres>().await?;
+
+    let account: AccountWithAddress = sqlx::query_as(
+        r#"
+SELECT accounts.id, accounts.name, addresses.city AS address_city
+FROM (VALUES (1, 'Herp Derpinson')) accounts(id, name)
+JOIN (VALUES (1, 'Portland')) addresses(account_id, city) ON addresses.account_id = accounts.id
+        "#,
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(account.account.id, 1);
+    assert_eq!(account.account.name, "Herp Derpinson");
+    assert_eq!(account.address.city, "Portland");
+    assert_eq!(account.address.country, None);
+    assert_eq!(account.visits, 0);
+
+    // an error other than a missing column is not replaced by the default
+    let res: sqlx::Result<AccountWithAddress> = sqlx::query_as(
+        "SELECT 1 AS id, 'Herp Derpinson' AS name, 'Portland' AS address_city, 'many' AS visits",
+    )
+    .fetch_one(&mut conn)
+    .await;
+
+    assert!(res.is_err());
+
+    Ok(())
+}
+
+#[cfg(feature = "macros")]
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_from_row_with_prefix() -> anyhow::Result<()> {
+    use sqlx::prelude::*;
+
+    #[derive(Debug, sqlx::FromRow)]
+    #[sqlx(rename_all = "camelCase")]
+    struct Account {
+        id: i32,
+        display_name: String,
+
+        #[sqlx(rename = "mail")]
+        email: Option<String>,
+    }
+
+    #[derive(Debug, sqlx::FromRow)]
+    struct Post {
+        id: i32,
+        title: String,
+
+        #[sqlx(flatten, prefix = "author_")]
+        author: Account,
+    }
+
+    let mut conn = new::<Postgres>().await?;
+
+    let post: Post = sqlx::query_as(
+        r#"
+SELECT posts.id, posts.title, accounts.id AS author_id,
+    accounts.name AS "author_displayName", accounts.email AS author_mail
+FROM (VALUES (1, 'Hello', 2)) posts(id, title, author_id)
+JOIN (VALUES (2, 'Herp Derpinson', NULL::TEXT)) accounts(id, name, email)
+    ON accounts.id = posts.author_id
+        "#,
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(post.id, 1);
+    assert_eq!(post.title, "Hello");
+    assert_eq!(post.author.id, 2);
+    assert_eq!(post.author.display_name, "Herp Derpinson");
+    assert_eq!(post.author.email, None);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_to_arguments() -> anyhow::Result<()> {
+    #[derive(Debug, PartialEq, sqlx::FromRow, sqlx::ToArguments)]
+    struct Location {
+        city: String,
+        country: Option<String>,
+    }
+
+    #[derive(Debug, PartialEq, sqlx::FromRow, sqlx::ToArguments)]
+    struct Account {
+        id: i32,
+        name: String,
+
+        #[sqlx(flatten)]
+        location: Location,
+
+        // not a column of the table
+        #[sqlx(skip)]
+        is_cached: bool,
+    }
+
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE to_arguments_accounts (
+    id INTEGER PRIMARY KEY,
+    name TEXT NOT NULL,
+    city TEXT NOT NULL,
+    country TEXT
+)
+        "#,
+    )
+    .await?;
+
+    let account = Account {
+        id: 1,
+        name: "Herp Derpinson".to_owned(),
+        location: Location {
+            city: "Berlin".to_owned(),
+            country: None,
+        },
+        is_cached: true,
+    };
+
+    let result = sqlx::query(
+        "INSERT INTO to_arguments_accounts (id, name, city, country) VALUES ($1, $2, $3, $4)",
+    )
+    .bind_struct(&account)
+    .execute(&mut conn)
+    .await?;
+
+    assert_eq!(result.rows_affected(), 1);
+
+    let read: Account = sqlx::query_as("SELECT * FROM to_arguments_accounts WHERE id = $1")
+        .bind(1_i32)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(
+        read,
+        Account {
+            is_cached: false,
+            ..account
+        }
+    );
+
+    Ok(())
+}
@@ -191,8 +191,101 @@ async fn test_nullable_err() -> anyhow::Result<()> {
     .await
     .unwrap_err();
 
-    if let sqlx::Error::Decode(err) = &err {
-        if let Some(sqlx::error::UnexpectedNullError) = err.downcast_ref() {
+    if let sqlx::Error::ColumnDecode { index, source, .. } = &err {
+        if let Some(sqlx::error::UnexpectedNullError) = source.downcast_ref() {
+            assert_eq!(&**index, "\"name\"");
+
+            return Ok(());
+        }
+    }
+
+    panic!("expected `UnexpectedNullError`, got {}", err)
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_nullability_of_columns() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    // `name` is `NOT NULL` and `is_active` is nullable
+    let accounts = sqlx::query!("SELECT id, name, is_active FROM accounts")
+        .fetch_all(&mut conn)
+        .await?;
+
+    for account in accounts {
+        let _: i64 = account.id;
+        let _: String = account.name;
+        let _: Option<bool> = account.is_active;
+    }
+
+    // an expression is nullable unless it is overridden with a `!` suffix
+    let rec = sqlx::query!(
+        r#"SELECT 1::int4 AS one, 2::int4 AS "two!", name AS "name?" FROM (VALUES ('Herp')) t(name)"#
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(rec.one, Some(1));
+    assert_eq!(rec.two, 2);
+    assert_eq!(rec.name.as_deref(), Some("Herp"));
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_column_type_override() -> anyhow::Result<()> {
+    #[derive(PartialEq, Debug, sqlx::Type)]
+    #[sqlx(transparent)]
+    struct AccountId(i32);
+
+    let mut conn = new::<Postgres>().await?;
+
+    let rec = sqlx::query!(
+        r#"
+SELECT id AS "id!: AccountId", data AS "data: Vec<u8>", data AS "other_data!: Vec<u8>"
+FROM (VALUES (1, '\x0102'::bytea)) accounts(id, data)
+        "#
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(rec.id, AccountId(1));
+    assert_eq!(rec.data, Some(vec![1, 2]));
+    assert_eq!(rec.other_data, vec![1, 2]);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_database_type_override() -> anyhow::Result<()> {
+    // `XML` is mapped to `String` by the `sqlx-types.json` of this crate
+    let mut conn = new::<Postgres>().await?;
+
+    let rec = sqlx::query!(r#"SELECT $1::xml AS "doc!""#, "<a>sqlx</a>")
+        .fetch_one(&mut conn)
+        .await?;
+
+    let doc: String = rec.doc;
+
+    assert_eq!(doc, "<a>sqlx</a>");
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_nullable_override_err() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let err = sqlx::query!(r#"SELECT NULL::text AS "name!""#)
+        .fetch_one(&mut conn)
+        .await
+        .unwrap_err();
+
+    if let sqlx::Error::ColumnDecode { source, .. } = &err {
+        if let Some(sqlx::error::UnexpectedNullError) = source.downcast_ref() {
             return Ok(());
         }
     }
@@ -13,7 +13,7 @@ async fn test_empty_query() -> anyhow::Result<()> {
     let mut conn = new::<Postgres>().await?;
     let affected = conn.execute("").await?;
 
-    assert_eq!(affected, 0);
+    assert_eq!(affected.rows_affected(), 0);
 
     Ok(())
 }
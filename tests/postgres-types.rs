@@ -1,11 +1,15 @@
 extern crate time_ as time;
 
+use std::ops::Bound;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
 use sqlx::decode::Decode;
 use sqlx::encode::Encode;
 use sqlx::postgres::types::raw::{PgNumeric, PgNumericSign, PgRecordDecoder, PgRecordEncoder};
+use sqlx::postgres::types::{PgBox, PgInterval, PgPath, PgPoint, PgPolygon, PgRange};
 use sqlx::postgres::{PgQueryAs, PgRawBuffer, PgTypeInfo, PgValue};
-use sqlx::{Cursor, Executor, Postgres, Row, Type};
-use sqlx_test::{new, test_prepared_type, test_type};
+use sqlx::{Connection, Cursor, Executor, Postgres, Row, Type};
+use sqlx_test::{new, test_type, test_unprepared_type};
 
 // TODO: With support for concatenation of sql literals in query! macros this should be updated
 macro_rules! array_macro_test {
@@ -99,8 +103,324 @@ test_type!(bytea(
         == vec![0_u8, 0, 0, 0, 0x52]
 ));
 
-// PgNumeric only works on the wire protocol
-test_prepared_type!(numeric(
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_bytea_text_formats() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let expected = vec![0_u8, b'a', b'\\', 0, 0xDE, b'\'', 0xFF, 0];
+
+    for output in &["hex", "escape"] {
+        conn.execute(&*format!("SET bytea_output = '{}'", output))
+            .await?;
+
+        let mut cursor = conn.fetch("SELECT E'\\\\x00615c00de27ff00'::bytea, ''::bytea");
+        let row = cursor.next().await?.unwrap();
+
+        assert_eq!(row.try_get::<Vec<u8>, _>(0)?, expected, "{}", output);
+        assert_eq!(
+            row.try_get::<Vec<u8>, _>(1)?,
+            Vec::<u8>::new(),
+            "{}",
+            output
+        );
+    }
+
+    Ok(())
+}
+
+test_type!(interval(
+    Postgres,
+    PgInterval,
+    "INTERVAL '0'"
+        == PgInterval {
+            months: 0,
+            days: 0,
+            microseconds: 0
+        },
+    "INTERVAL '1 year 2 months 3 days 04:05:06.789'"
+        == PgInterval {
+            months: 14,
+            days: 3,
+            microseconds: 14_706_789_000
+        },
+    "INTERVAL '-3 days'"
+        == PgInterval {
+            months: 0,
+            days: -3,
+            microseconds: 0
+        },
+    "INTERVAL '1 day -2 hours'"
+        == PgInterval {
+            months: 0,
+            days: 1,
+            microseconds: -7_200_000_000
+        },
+    "INTERVAL '1 mon 2 days ago'"
+        == PgInterval {
+            months: -1,
+            days: -2,
+            microseconds: 0
+        },
+    "INTERVAL '0.000001 seconds'"
+        == PgInterval {
+            months: 0,
+            days: 0,
+            microseconds: 1
+        },
+));
+
+// around the Postgres epoch of 2000-01-01, from which the microseconds of a TIMESTAMPTZ count
+test_type!(system_time(
+    Postgres,
+    SystemTime,
+    "'2000-01-01 00:00:00+00'::timestamptz" == UNIX_EPOCH + Duration::from_secs(946_684_800),
+    "'1999-12-31 23:59:59.999999+00'::timestamptz"
+        == UNIX_EPOCH + Duration::from_micros(946_684_799_999_999),
+    "'2000-01-01 00:00:00.000001+00'::timestamptz"
+        == UNIX_EPOCH + Duration::from_micros(946_684_800_000_001),
+    "'2000-01-01 01:30:00+01:30'::timestamptz" == UNIX_EPOCH + Duration::from_secs(946_684_800),
+    "'1970-01-01 00:00:00+00'::timestamptz" == UNIX_EPOCH,
+    "'1969-12-31 23:59:59.5+00'::timestamptz" == UNIX_EPOCH - Duration::from_millis(500),
+    "'0001-01-01 00:00:00+00 BC'::timestamptz"
+        == UNIX_EPOCH - Duration::from_secs(719_528 * 24 * 60 * 60),
+));
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_system_time_out_of_range() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    for value in &["'infinity'::timestamptz", "'-infinity'::timestamptz"] {
+        let res: sqlx::Result<(SystemTime,)> = sqlx::query_as(&format!("SELECT {}", value))
+            .fetch_one(&mut conn)
+            .await;
+
+        assert!(res.is_err(), "{} decoded as {:?}", value, res);
+    }
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_unprepared_interval_styles() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let expected = PgInterval {
+        months: -14,
+        days: 3,
+        microseconds: -14_706_789_000,
+    };
+
+    for style in &["postgres", "postgres_verbose", "iso_8601"] {
+        conn.execute(&*format!("SET IntervalStyle = {}", style))
+            .await?;
+
+        let mut cursor =
+            conn.fetch("SELECT INTERVAL '-1 year -2 mons +3 days -04:05:06.789' AS _1");
+        let row = cursor.next().await?.unwrap();
+        let value = row.try_get::<PgInterval, _>("_1")?;
+
+        assert_eq!(value, expected, "IntervalStyle = {}", style);
+    }
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_interval_justify() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let values = [
+        PgInterval::hours(27),
+        PgInterval::days(35),
+        PgInterval::months(1) - PgInterval::hours(1),
+        PgInterval::hours(1) - PgInterval::months(1),
+        PgInterval::months(1) - PgInterval::days(2),
+        PgInterval::days(-1) + PgInterval::hours(25),
+        PgInterval::days(29) + PgInterval::hours(25),
+        PgInterval::builder()
+            .months(-3)
+            .days(65)
+            .minutes(-90)
+            .build()
+            .unwrap(),
+    ];
+
+    for value in &values {
+        let (hours, days, interval): (PgInterval, PgInterval, PgInterval) =
+            sqlx::query_as("SELECT justify_hours($1), justify_days($1), justify_interval($1)")
+                .bind(*value)
+                .fetch_one(&mut conn)
+                .await?;
+
+        assert_eq!(value.justify_hours(), hours, "justify_hours({:?})", value);
+        assert_eq!(value.justify_days(), days, "justify_days({:?})", value);
+        assert_eq!(
+            value.justify_interval(),
+            interval,
+            "justify_interval({:?})",
+            value
+        );
+    }
+
+    Ok(())
+}
+
+test_unprepared_type!(point(
+    Postgres,
+    PgPoint,
+    "point(0, 0)" == PgPoint { x: 0.0, y: 0.0 },
+    "point(1.5, -2)" == PgPoint { x: 1.5, y: -2.0 },
+    "point(1e300, -0.125)"
+        == PgPoint {
+            x: 1e300,
+            y: -0.125
+        },
+));
+
+test_unprepared_type!(box(
+    Postgres,
+    PgBox,
+    "box '(0,0),(1,2)'"
+        == PgBox {
+            high: PgPoint { x: 1.0, y: 2.0 },
+            low: PgPoint { x: 0.0, y: 0.0 }
+        },
+));
+
+test_unprepared_type!(path(
+    Postgres,
+    PgPath,
+    "path '[(0,0),(1,1),(2,0.5)]'"
+        == PgPath {
+            closed: false,
+            points: vec![
+                PgPoint { x: 0.0, y: 0.0 },
+                PgPoint { x: 1.0, y: 1.0 },
+                PgPoint { x: 2.0, y: 0.5 }
+            ]
+        },
+    "path '((0,0),(1,1))'"
+        == PgPath {
+            closed: true,
+            points: vec![PgPoint { x: 0.0, y: 0.0 }, PgPoint { x: 1.0, y: 1.0 }]
+        },
+));
+
+test_unprepared_type!(polygon(
+    Postgres,
+    PgPolygon,
+    "polygon '((0,0),(1,1),(1,0))'"
+        == PgPolygon {
+            points: vec![
+                PgPoint { x: 0.0, y: 0.0 },
+                PgPoint { x: 1.0, y: 1.0 },
+                PgPoint { x: 1.0, y: 0.0 }
+            ]
+        },
+));
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_prepared_geometry() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    for &value in &[
+        PgPoint { x: 0.0, y: 0.0 },
+        PgPoint { x: 1.5, y: -2.0 },
+        PgPoint {
+            x: f64::MAX,
+            y: f64::MIN_POSITIVE,
+        },
+    ] {
+        let (same, decoded): (bool, PgPoint) =
+            sqlx::query_as("SELECT $1 ~= point($2, $3), $1::point")
+                .bind(value)
+                .bind(value.x)
+                .bind(value.y)
+                .fetch_one(&mut conn)
+                .await?;
+
+        assert!(same);
+        assert_eq!(decoded, value);
+    }
+
+    let points = vec![PgPoint { x: 0.0, y: 0.0 }, PgPoint { x: -1.0, y: 2.5 }];
+
+    let (text, decoded): (String, Vec<PgPoint>) =
+        sqlx::query_as("SELECT $1::point[]::text, $1::point[]")
+            .bind(&points)
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(text, "{\"(0,0)\",\"(-1,2.5)\"}");
+    assert_eq!(decoded, points);
+
+    // the corners are reordered by the server
+    let value = PgBox {
+        high: PgPoint { x: -1.0, y: 0.5 },
+        low: PgPoint { x: 2.0, y: 3.0 },
+    };
+
+    let (text, decoded): (String, PgBox) = sqlx::query_as("SELECT $1::box::text, $1::box")
+        .bind(value)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(text, "(2,3),(-1,0.5)");
+    assert_eq!(
+        decoded,
+        PgBox {
+            high: PgPoint { x: 2.0, y: 3.0 },
+            low: PgPoint { x: -1.0, y: 0.5 },
+        }
+    );
+
+    for &closed in &[false, true] {
+        let value = PgPath {
+            closed,
+            points: vec![PgPoint { x: 0.0, y: 0.0 }, PgPoint { x: 1.5, y: -1.0 }],
+        };
+
+        let (text, decoded): (String, PgPath) = sqlx::query_as("SELECT $1::path::text, $1::path")
+            .bind(value.clone())
+            .fetch_one(&mut conn)
+            .await?;
+
+        let expected = if closed {
+            "((0,0),(1.5,-1))"
+        } else {
+            "[(0,0),(1.5,-1)]"
+        };
+
+        assert_eq!(text, expected);
+        assert_eq!(decoded, value);
+    }
+
+    let value = PgPolygon {
+        points: vec![
+            PgPoint { x: 0.0, y: 0.0 },
+            PgPoint { x: 0.0, y: 1.0 },
+            PgPoint { x: 1.0, y: 0.0 },
+        ],
+    };
+
+    let (text, decoded): (String, PgPolygon) =
+        sqlx::query_as("SELECT $1::polygon::text, $1::polygon")
+            .bind(value.clone())
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(text, "((0,0),(0,1),(1,0))");
+    assert_eq!(decoded, value);
+
+    Ok(())
+}
+
+test_type!(numeric(
     Postgres,
     PgNumeric,
     "0::numeric"
@@ -159,21 +479,183 @@ test_prepared_type!(numeric(
             scale: 2,
             digits: vec![12, 3400]
         },
+    "'-1.5e-10'::numeric"
+        == PgNumeric::Number {
+            sign: PgNumericSign::Negative,
+            weight: -3,
+            scale: 11,
+            digits: vec![150]
+        },
     "'NaN'::numeric" == PgNumeric::NotANumber,
 ));
 
+test_type!(int4range(
+    Postgres,
+    PgRange<i32>,
+    "'[1,10)'::int4range" == PgRange::from(1..10),
+    // discrete ranges are stored with an inclusive lower and exclusive upper bound
+    "'(1,10]'::int4range" == PgRange::from(2..11),
+    "'[-5,)'::int4range" == PgRange::from(-5..),
+    "'(,)'::int4range" == PgRange::<i32>::from((Bound::Unbounded, Bound::Unbounded)),
+));
+
+test_type!(int8range(
+    Postgres,
+    PgRange<i64>,
+    "'[-9358295312,9358295312)'::int8range" == PgRange::from(-9358295312_i64..9358295312),
+    "'(,42)'::int8range" == PgRange::from(..42_i64),
+));
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_empty_range() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    for &value in &[
+        PgRange::from((Bound::Excluded(1), Bound::Excluded(1))),
+        PgRange::from((Bound::Included(1), Bound::Excluded(1))),
+    ] {
+        let (empty, text): (bool, String) =
+            sqlx::query_as("SELECT isempty($1::int4range), $1::int4range::text")
+                .bind(value)
+                .fetch_one(&mut conn)
+                .await?;
+
+        assert!(empty);
+        assert_eq!(text, "empty");
+    }
+
+    let res = sqlx::query_as::<_, (PgRange<i32>,)>("SELECT 'empty'::int4range")
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(res.is_err());
+
+    Ok(())
+}
+
 #[cfg(feature = "bigdecimal")]
 test_type!(decimal(
     Postgres,
     sqlx::types::BigDecimal,
+    "0::numeric" == "0".parse::<sqlx::types::BigDecimal>().unwrap(),
     "1::numeric" == "1".parse::<sqlx::types::BigDecimal>().unwrap(),
+    "1234::numeric" == "1234".parse::<sqlx::types::BigDecimal>().unwrap(),
     "10000::numeric" == "10000".parse::<sqlx::types::BigDecimal>().unwrap(),
     "0.1::numeric" == "0.1".parse::<sqlx::types::BigDecimal>().unwrap(),
     "0.01234::numeric" == "0.01234".parse::<sqlx::types::BigDecimal>().unwrap(),
     "12.34::numeric" == "12.34".parse::<sqlx::types::BigDecimal>().unwrap(),
     "12345.6789::numeric" == "12345.6789".parse::<sqlx::types::BigDecimal>().unwrap(),
+    "-1.5e-10::numeric" == "-0.00000000015".parse::<sqlx::types::BigDecimal>().unwrap(),
+    "123456789012345678901234567890.000000000000000000001::numeric"
+        == "123456789012345678901234567890.000000000000000000001"
+            .parse::<sqlx::types::BigDecimal>()
+            .unwrap(),
+    "1e100::numeric" == "1e100".parse::<sqlx::types::BigDecimal>().unwrap(),
 ));
 
+#[cfg(feature = "bigdecimal")]
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_decimal_round_trip() -> anyhow::Result<()> {
+    use sqlx::types::BigDecimal;
+
+    let mut conn = new::<Postgres>().await?;
+
+    // a xorshift generator so a failure can be reproduced
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let mut next = move |n: u64| {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state % n
+    };
+
+    let mut texts = vec![
+        "-0.00001".to_owned(),
+        "12345678901234567890.123456789".to_owned(),
+        "1.50".to_owned(),
+        "-100.000".to_owned(),
+        "0.0000".to_owned(),
+    ];
+
+    for _ in 0..500 {
+        // without leading zeros, which Postgres does not keep
+        let integer: String = (0..next(25))
+            .map(|i| (b'0' + if i == 0 { 1 + next(9) } else { next(10) } as u8) as char)
+            .collect();
+
+        let fraction: String = (0..next(25))
+            .map(|_| (b'0' + next(10) as u8) as char)
+            .collect();
+
+        texts.push(format!(
+            "{}{}{}{}",
+            if next(2) == 0 { "-" } else { "" },
+            if integer.is_empty() { "0" } else { &integer },
+            if fraction.is_empty() { "" } else { "." },
+            fraction
+        ));
+    }
+
+    for text in &texts {
+        let value: BigDecimal = text.parse()?;
+
+        // binary, in both directions
+        let (decoded, pg_text): (BigDecimal, String) = sqlx::query_as("SELECT $1, $1::text")
+            .bind(&value)
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert_eq!(decoded, value, "{}", text);
+        assert_eq!(decoded.to_string(), pg_text, "{}", text);
+
+        // text
+        let query = format!("SELECT '{}'::numeric", text);
+        let mut cursor = conn.fetch(&*query);
+        let row = cursor.next().await?.unwrap();
+        let decoded = row.try_get::<BigDecimal, _>(0)?;
+
+        assert_eq!(decoded.to_string(), pg_text, "{}", text);
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "bigdecimal")]
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_decimal_nan() -> anyhow::Result<()> {
+    use sqlx::types::BigDecimal;
+
+    let mut conn = new::<Postgres>().await?;
+
+    // binary
+    let res = sqlx::query_as::<_, (BigDecimal,)>("SELECT 'NaN'::numeric")
+        .fetch_one(&mut conn)
+        .await;
+
+    let err = res.unwrap_err().to_string();
+    assert!(
+        err.contains("BigDecimal does not support NaN values"),
+        "{}",
+        err
+    );
+
+    // text
+    let mut cursor = conn.fetch("SELECT 'NaN'::numeric");
+    let row = cursor.next().await?.unwrap();
+
+    let err = row.try_get::<BigDecimal, _>(0).unwrap_err().to_string();
+    assert!(
+        err.contains("BigDecimal does not support NaN values"),
+        "{}",
+        err
+    );
+
+    Ok(())
+}
+
 // TODO: This is a minimal example that reproduces a typechecking error with
 // arrays of BigDecimal in macros.
 //
@@ -216,6 +698,39 @@ array_macro_test!(uuid(sqlx::types::Uuid, "select '{b731678f-636f-4135-bc6f-1944
         sqlx::types::Uuid::parse_str("00000000-0000-0000-0000-000000000000").unwrap()
     ]));
 
+#[cfg(feature = "uuid")]
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_uuid_text_and_binary() -> anyhow::Result<()> {
+    use sqlx::postgres::PgData;
+    use sqlx::types::Uuid;
+
+    let mut conn = new::<Postgres>().await?;
+
+    let expected = Uuid::parse_str("b731678f-636f-4135-bc6f-19440c13bd19")?;
+
+    // a prepared query returns the 16 bytes of the UUID
+    let mut cursor = sqlx::query("SELECT $1::uuid")
+        .bind(expected)
+        .fetch(&mut conn);
+
+    let row = cursor.next().await?.unwrap();
+
+    assert!(matches!(row.try_get_raw(0)?.get(), Some(PgData::Binary(_))));
+    assert_eq!(row.try_get::<Uuid, _>(0)?, expected);
+
+    drop(cursor);
+
+    // and the simple query protocol returns it as text
+    let mut cursor = conn.fetch("SELECT 'b731678f-636f-4135-bc6f-19440c13bd19'::uuid");
+    let row = cursor.next().await?.unwrap();
+
+    assert!(matches!(row.try_get_raw(0)?.get(), Some(PgData::Text(_))));
+    assert_eq!(row.try_get::<Uuid, _>(0)?, expected);
+
+    Ok(())
+}
+
 #[cfg(feature = "ipnetwork")]
 test_type!(ipnetwork(
     Postgres,
@@ -244,7 +759,128 @@ test_type!(ipnetwork(
         == "::ffff:1.2.3.0/120"
             .parse::<sqlx::types::ipnetwork::IpNetwork>()
             .unwrap(),
+    "'10.1.2.0/24'::cidr"
+        == "10.1.2.0/24"
+            .parse::<sqlx::types::ipnetwork::IpNetwork>()
+            .unwrap(),
+    "'2001:db8:1:2::/64'::cidr"
+        == "2001:db8:1:2::/64"
+            .parse::<sqlx::types::ipnetwork::IpNetwork>()
+            .unwrap(),
+));
+
+#[cfg(feature = "bitvec")]
+fn bits(s: &str) -> sqlx::types::bitvec::BitVec<sqlx::types::bitvec::Msb0, u8> {
+    s.chars().map(|ch| ch == '1').collect()
+}
+
+#[cfg(feature = "bitvec")]
+test_type!(varbit(
+    Postgres,
+    sqlx::types::bitvec::BitVec<sqlx::types::bitvec::Msb0, u8>,
+    "B'1011001110'::varbit" == bits("1011001110"),
+    "B''::varbit" == bits(""),
+    "B'11111111'::varbit" == bits("11111111"),
+));
+
+#[cfg(feature = "bitvec")]
+test_type!(bit(
+    Postgres,
+    sqlx::types::bitvec::BitVec<sqlx::types::bitvec::Msb0, u8>,
+    "B'1011001110'::bit(10)" == bits("1011001110"),
+    "B'1'::bit" == bits("1"),
+));
+
+test_type!(money(
+    Postgres,
+    sqlx::postgres::types::PgMoney,
+    "'$123.45'::money" == sqlx::postgres::types::PgMoney(12345),
+    "'-$1,234.50'::money" == sqlx::postgres::types::PgMoney(-123450),
+    "'0'::money" == sqlx::postgres::types::PgMoney(0),
+));
+
+test_type!(mac_address(
+    Postgres,
+    sqlx::postgres::types::PgMacAddress,
+    "'08:00:2b:01:02:03'::macaddr"
+        == sqlx::postgres::types::PgMacAddress([0x08, 0x00, 0x2b, 0x01, 0x02, 0x03]),
+    "'ff:ff:ff:ff:ff:ff'::macaddr" == sqlx::postgres::types::PgMacAddress([0xff; 6]),
+));
+
+test_type!(mac_address8(
+    Postgres,
+    sqlx::postgres::types::PgMacAddress8,
+    "'08:00:2b:01:02:03:04:05'::macaddr8"
+        == sqlx::postgres::types::PgMacAddress8([0x08, 0x00, 0x2b, 0x01, 0x02, 0x03, 0x04, 0x05]),
+    "'00:00:00:00:00:00:00:00'::macaddr8" == sqlx::postgres::types::PgMacAddress8([0; 8]),
+));
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_mac_address_width_mismatch() -> anyhow::Result<()> {
+    use sqlx::postgres::types::{PgMacAddress, PgMacAddress8};
+
+    let mut conn = new::<Postgres>().await?;
+
+    let mut cursor =
+        sqlx::query("SELECT '08:00:2b:01:02:03'::macaddr, '08:00:2b:01:02:03:04:05'::macaddr8")
+            .fetch(&mut conn);
+    let row = cursor.next().await?.unwrap();
+
+    assert!(row.try_get::<PgMacAddress8, _>(0).is_err());
+    assert!(row.try_get::<PgMacAddress, _>(1).is_err());
+
+    assert!(row.try_get_unchecked::<PgMacAddress8, _>(0).is_err());
+    assert!(row.try_get_unchecked::<PgMacAddress, _>(1).is_err());
+
+    Ok(())
+}
+
+test_type!(ip_addr(
+    Postgres,
+    std::net::IpAddr,
+    "'127.0.0.1'::inet" == "127.0.0.1".parse::<std::net::IpAddr>().unwrap(),
+    "'10.1.2.3/32'::cidr" == "10.1.2.3".parse::<std::net::IpAddr>().unwrap(),
+    "'::1'::inet" == "::1".parse::<std::net::IpAddr>().unwrap(),
+    "'2001:db8::1/128'::cidr" == "2001:db8::1".parse::<std::net::IpAddr>().unwrap(),
 ));
+
+test_type!(ipv4_addr(
+    Postgres,
+    std::net::Ipv4Addr,
+    "'192.168.0.1'::inet" == std::net::Ipv4Addr::new(192, 168, 0, 1),
+));
+
+test_type!(ipv6_addr(
+    Postgres,
+    std::net::Ipv6Addr,
+    "'::ffff:1.2.3.4'::inet" == std::net::Ipv4Addr::new(1, 2, 3, 4).to_ipv6_mapped(),
+));
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_ip_addr_network() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    for network in &["'10.1.2.0/24'::cidr", "'2001:db8::/64'::inet"] {
+        let query = format!("SELECT {}", network);
+        let mut cursor = sqlx::query(&query).fetch(&mut conn);
+        let row = cursor.next().await?.unwrap();
+
+        assert!(
+            row.try_get::<std::net::IpAddr, _>(0).is_err(),
+            "{}",
+            network
+        );
+    }
+
+    let mut cursor = sqlx::query("SELECT '::1'::inet").fetch(&mut conn);
+    let row = cursor.next().await?.unwrap();
+
+    assert!(row.try_get::<std::net::Ipv4Addr, _>(0).is_err());
+
+    Ok(())
+}
 #[cfg(feature = "ipnetwork")]
 array_macro_test!(ipnetwork(
     sqlx::types::ipnetwork::IpNetwork,
@@ -298,6 +934,85 @@ mod chrono {
                 Utc,
             )
     ));
+    test_type!(chrono_daterange(
+        Postgres,
+        PgRange<NaiveDate>,
+        "'[2001-01-05,2050-11-23)'::daterange"
+            == PgRange::from(NaiveDate::from_ymd(2001, 1, 5)..NaiveDate::from_ymd(2050, 11, 23)),
+        "'[2001-01-05,)'::daterange" == PgRange::from(NaiveDate::from_ymd(2001, 1, 5)..)
+    ));
+
+    test_type!(chrono_tsrange(
+        Postgres,
+        PgRange<NaiveDateTime>,
+        "'[2019-01-02 05:10:20,2019-01-02 05:10:20.115100]'::tsrange"
+            == PgRange::from(
+                NaiveDate::from_ymd(2019, 1, 2).and_hms(5, 10, 20)
+                    ..=NaiveDate::from_ymd(2019, 1, 2).and_hms_micro(5, 10, 20, 115100)
+            )
+    ));
+
+    test_type!(chrono_tstzrange(
+        Postgres,
+        PgRange<DateTime<Utc>>,
+        "'(\"2019-01-02 05:10:20.115100+00\",)'::tstzrange"
+            == PgRange {
+                start: Bound::Excluded(DateTime::<Utc>::from_utc(
+                    NaiveDate::from_ymd(2019, 1, 2).and_hms_micro(5, 10, 20, 115100),
+                    Utc,
+                )),
+                end: Bound::Unbounded,
+            },
+        "'[2020-01-01 00:00:00+00,2021-01-01 00:00:00+00)'::tstzrange"
+            == PgRange::from(
+                DateTime::<Utc>::from_utc(NaiveDate::from_ymd(2020, 1, 1).and_hms(0, 0, 0), Utc)
+                    ..DateTime::<Utc>::from_utc(
+                        NaiveDate::from_ymd(2021, 1, 1).and_hms(0, 0, 0),
+                        Utc
+                    )
+            ),
+        "'[2020-01-01 00:00:00+00,)'::tstzrange"
+            == PgRange::from(
+                DateTime::<Utc>::from_utc(NaiveDate::from_ymd(2020, 1, 1).and_hms(0, 0, 0), Utc)..
+            )
+    ));
+
+    #[cfg_attr(feature = "runtime-async-std", async_std::test)]
+    #[cfg_attr(feature = "runtime-tokio", tokio::test)]
+    async fn test_chrono_tstzrange_with_infinity() -> anyhow::Result<()> {
+        let mut conn = new::<Postgres>().await?;
+
+        let start =
+            DateTime::<Utc>::from_utc(NaiveDate::from_ymd(2020, 1, 1).and_hms(0, 0, 0), Utc);
+
+        let sql = "SELECT '[2020-01-01 00:00:00+00,infinity)'::tstzrange";
+
+        // the infinite bound is unbounded in the binary and the text format
+        let (range, _): (PgRange<DateTime<Utc>>, i32) = sqlx::query_as(&format!("{}, $1", sql))
+            .bind(1_i32)
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert_eq!(range, PgRange::from(start..));
+
+        {
+            let mut cursor = conn.fetch(sql);
+            let row = cursor.next().await?.unwrap();
+
+            assert_eq!(row.try_get::<PgRange<DateTime<Utc>>, _>(0)?, range);
+        }
+
+        // and it is sent back as a missing bound
+        let (upper_inf,): (bool,) = sqlx::query_as("SELECT upper_inf($1)")
+            .bind(range)
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert!(upper_inf);
+
+        Ok(())
+    }
+
     // TODO: Can't seem to get this to work
     // array_macro_test!(chrono_date_time_tz(
     //     DateTime::<Utc>,
@@ -338,11 +1053,39 @@ mod time_tests {
 
     test_type!(time_timestamp(
         Postgres,
-        OffsetDateTime,
-        "TIMESTAMPTZ '2019-01-02 05:10:20.115100'"
-            == date!(2019 - 1 - 2)
-                .with_time(time!(5:10:20.115100))
-                .assume_utc()
+        OffsetDateTime,
+        "TIMESTAMPTZ '2019-01-02 05:10:20.115100'"
+            == date!(2019 - 1 - 2)
+                .with_time(time!(5:10:20.115100))
+                .assume_utc()
+    ));
+
+    test_type!(time_daterange(
+        Postgres,
+        PgRange<Date>,
+        "'[2001-01-05,2050-11-23)'::daterange"
+            == PgRange::from(date!(2001 - 1 - 5)..date!(2050 - 11 - 23))
+    ));
+
+    test_type!(time_tsrange(
+        Postgres,
+        PgRange<PrimitiveDateTime>,
+        "'(,2019-01-02 05:10:20]'::tsrange"
+            == PgRange::from(..=date!(2019 - 1 - 2).with_time(time!(5:10:20)))
+    ));
+
+    test_type!(time_tstzrange(
+        Postgres,
+        PgRange<OffsetDateTime>,
+        "'[2019-01-02 05:10:20.115100+00,2019-01-03 05:10:20.115100+00)'::tstzrange"
+            == PgRange::from(
+                date!(2019 - 1 - 2)
+                    .with_time(time!(5:10:20.115100))
+                    .assume_utc()
+                    ..date!(2019 - 1 - 3)
+                        .with_time(time!(5:10:20.115100))
+                        .assume_utc()
+            )
     ));
 }
 
@@ -709,3 +1452,527 @@ mod json {
         Ok(())
     }
 }
+
+#[cfg(feature = "hstore")]
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_hstore() -> anyhow::Result<()> {
+    use std::collections::HashMap;
+
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE EXTENSION IF NOT EXISTS hstore")
+        .await?;
+
+    let cases: Vec<(&str, HashMap<String, Option<String>>)> = vec![
+        ("''", HashMap::new()),
+        (
+            r#"'a=>1, b=>NULL, c=>""'"#,
+            vec![
+                ("a".to_owned(), Some("1".to_owned())),
+                ("b".to_owned(), None),
+                ("c".to_owned(), Some("".to_owned())),
+            ]
+            .into_iter()
+            .collect(),
+        ),
+        (
+            r#"E'"x,\\"y\\""=>"NULL", "=>"=>"\\\\"'"#,
+            vec![
+                (r#"x,"y""#.to_owned(), Some("NULL".to_owned())),
+                ("=>".to_owned(), Some(r#"\"#.to_owned())),
+            ]
+            .into_iter()
+            .collect(),
+        ),
+    ];
+
+    for (text, value) in cases {
+        let query = format!("SELECT $1 = {}::hstore, $1", text);
+
+        let (same, decoded): (bool, HashMap<String, Option<String>>) = sqlx::query_as(&query)
+            .bind(&value)
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert!(same, "{}", text);
+        assert_eq!(decoded, value);
+
+        let query = format!("SELECT {}::hstore", text);
+        let mut cursor = conn.fetch(&*query);
+        let row = cursor.next().await?.unwrap();
+
+        assert_eq!(row.try_get::<HashMap<String, Option<String>>, _>(0)?, value);
+    }
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_ltree() -> anyhow::Result<()> {
+    use sqlx::postgres::types::{PgLQuery, PgLTree};
+
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE EXTENSION IF NOT EXISTS ltree").await?;
+
+    for path in &["Top.Science.Astronomy", "Top", ""] {
+        let value: PgLTree = path.parse()?;
+        let query = format!("SELECT $1 = '{}'::ltree, $1", path);
+
+        let (same, decoded): (bool, PgLTree) = sqlx::query_as(&query)
+            .bind(&value)
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert!(same, "{}", path);
+        assert_eq!(decoded, value);
+
+        let query = format!("SELECT '{}'::ltree", path);
+        let mut cursor = conn.fetch(&*query);
+        let row = cursor.next().await?.unwrap();
+
+        assert_eq!(row.try_get::<PgLTree, _>(0)?, value);
+    }
+
+    let path = PgLTree::from_labels(vec!["Top", "Science", "Astronomy"])?;
+
+    let (matches, lquery): (bool, PgLQuery) = sqlx::query_as("SELECT $1 ~ $2, $2")
+        .bind(&path)
+        .bind(PgLQuery("Top.*.Astronomy".to_owned()))
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert!(matches);
+    assert_eq!(lquery.0, "Top.*.Astronomy");
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_json_path() -> anyhow::Result<()> {
+    use sqlx::postgres::types::PgJsonPath;
+
+    let mut conn = new::<Postgres>().await?;
+
+    let (matched, path): (String, PgJsonPath) =
+        sqlx::query_as(r#"SELECT jsonb_path_query_array('{"a": [1, 2, 3, 4]}', $1)::text, $1"#)
+            .bind(PgJsonPath::from("$.a[*] ? (@ > 2)"))
+            .fetch_one(&mut conn)
+            .await?;
+
+    // Postgres returns the path in its normalized form
+    assert_eq!(matched, "[3, 4]");
+    assert_eq!(path, PgJsonPath::from(r#"$."a"[*]?(@ > 2)"#));
+
+    let mut cursor = conn.fetch("SELECT '$.a[*] ? (@ > 2)'::jsonpath");
+    let row = cursor.next().await?.unwrap();
+
+    assert_eq!(row.try_get::<PgJsonPath, _>(0)?, path);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_cube() -> anyhow::Result<()> {
+    use sqlx::postgres::types::PgCube;
+
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE EXTENSION IF NOT EXISTS cube").await?;
+
+    let cases = vec![
+        ("(1, 2.5, -3)", PgCube::point(vec![1.0, 2.5, -3.0])),
+        (
+            "(1, 2),(3, 4)",
+            PgCube::new(vec![1.0, 2.0], vec![3.0, 4.0]).unwrap(),
+        ),
+        ("(0)", PgCube::point(vec![0.0])),
+    ];
+
+    for (text, value) in cases {
+        let query = format!("SELECT $1 = '{}'::cube, cube_is_point($1), $1", text);
+
+        let (same, is_point, decoded): (bool, bool, PgCube) = sqlx::query_as(&query)
+            .bind(&value)
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert!(same, "{}", text);
+        assert_eq!(is_point, value.is_point(), "{}", text);
+        assert_eq!(decoded, value);
+
+        let query = format!("SELECT '{}'::cube", text);
+        let mut cursor = conn.fetch(&*query);
+        let row = cursor.next().await?.unwrap();
+
+        assert_eq!(row.try_get::<PgCube, _>(0)?, value);
+    }
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_citext() -> anyhow::Result<()> {
+    use sqlx::postgres::types::PgCiText;
+
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE EXTENSION IF NOT EXISTS citext")
+        .await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE citext_users (name CITEXT NOT NULL);
+INSERT INTO citext_users (name) VALUES ('Alice'), ('bOB');
+        "#,
+    )
+    .await?;
+
+    let names: Vec<(PgCiText,)> =
+        sqlx::query_as("SELECT name FROM citext_users WHERE name = $1 OR name = $2 ORDER BY name")
+            .bind(PgCiText::from("ALICE"))
+            .bind(PgCiText::from("bob"))
+            .fetch_all(&mut conn)
+            .await?;
+
+    // the values keep the case they were inserted with
+    let names: Vec<_> = names.into_iter().map(|(name,)| name.0).collect();
+
+    assert_eq!(names, vec!["Alice", "bOB"]);
+
+    let (same, decoded): (bool, PgCiText) = sqlx::query_as("SELECT $1 = 'mIxEd'::citext, $1")
+        .bind(PgCiText::from("Mixed"))
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert!(same);
+    assert_eq!(decoded, PgCiText::from("Mixed"));
+
+    let mut cursor = conn.fetch("SELECT 'Mixed'::citext");
+    let row = cursor.next().await?.unwrap();
+
+    assert_eq!(row.try_get::<PgCiText, _>(0)?, PgCiText::from("Mixed"));
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_domain() -> anyhow::Result<()> {
+    // the domain is dropped with the transaction
+    let mut tx = new::<Postgres>().await?.begin().await?;
+
+    tx.execute(
+        r#"
+CREATE DOMAIN sqlx_test_name AS TEXT CHECK (VALUE <> '');
+CREATE TEMPORARY TABLE domain_users (name sqlx_test_name NOT NULL, aliases sqlx_test_name[]);
+        "#,
+    )
+    .await?;
+
+    sqlx::query("INSERT INTO domain_users (name, aliases) VALUES ($1, $2)")
+        .bind("Alice")
+        .bind(vec!["Al".to_owned(), "Ali".to_owned()])
+        .execute(&mut tx)
+        .await?;
+
+    let (name, aliases): (String, Vec<String>) =
+        sqlx::query_as("SELECT name, aliases FROM domain_users")
+            .fetch_one(&mut tx)
+            .await?;
+
+    assert_eq!(name, "Alice");
+    assert_eq!(aliases, vec!["Al", "Ali"]);
+
+    // the parameters and columns of the domain are described as its base type
+    let describe = tx
+        .describe("SELECT name, aliases FROM domain_users WHERE name = $1::sqlx_test_name")
+        .await?;
+
+    assert_eq!(
+        describe.param_types[0],
+        Some(<String as Type<Postgres>>::type_info())
+    );
+    assert_eq!(
+        describe.result_columns[1].type_info,
+        Some(<Vec<String> as Type<Postgres>>::type_info())
+    );
+
+    let res = sqlx::query("INSERT INTO domain_users (name) VALUES ($1)")
+        .bind("")
+        .execute(&mut tx)
+        .await;
+
+    assert!(res.is_err());
+
+    tx.rollback().await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_text_search() -> anyhow::Result<()> {
+    use sqlx::postgres::types::{PgTsLexeme, PgTsQuery, PgTsVector, PgTsWeight};
+
+    let mut conn = new::<Postgres>().await?;
+
+    let lexeme = |text: &str, position| PgTsLexeme {
+        text: text.to_owned(),
+        positions: vec![(position, PgTsWeight::D)],
+    };
+
+    let expected = PgTsVector {
+        lexemes: vec![lexeme("brown", 3), lexeme("fox", 4), lexeme("quick", 2)],
+    };
+
+    let (vector,): (PgTsVector,) =
+        sqlx::query_as("SELECT to_tsvector('english', 'the quick brown fox')")
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(vector, expected);
+
+    let mut cursor = conn.fetch("SELECT to_tsvector('english', 'the quick brown fox')");
+    let row = cursor.next().await?.unwrap();
+
+    assert_eq!(row.try_get::<PgTsVector, _>(0)?, expected);
+
+    // Postgres sorts the lexemes of a vector that is sent to it
+    let weighted = PgTsVector {
+        lexemes: vec![
+            PgTsLexeme {
+                text: "it's".to_owned(),
+                positions: vec![(1, PgTsWeight::A), (5, PgTsWeight::C)],
+            },
+            lexeme("a", 2),
+        ],
+    };
+
+    let (same, vector): (bool, PgTsVector) =
+        sqlx::query_as(r"SELECT $1 = 'a:2 ''it''''s'':1A,5C'::tsvector, $1")
+            .bind(&weighted)
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert!(same);
+    assert_eq!(vector.lexemes.len(), 2);
+    assert_eq!(vector.lexemes[0].text, "a");
+
+    let query = PgTsQuery::And(
+        Box::new(PgTsQuery::lexeme("quick")),
+        Box::new(PgTsQuery::Not(Box::new(PgTsQuery::lexeme("slow")))),
+    );
+
+    let (matches, same, decoded): (bool, bool, PgTsQuery) = sqlx::query_as(
+        "SELECT to_tsvector('english', 'the quick brown fox') @@ $1, $1 = 'quick & !slow'::tsquery, $1",
+    )
+    .bind(&query)
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert!(matches);
+    assert!(same);
+    assert_eq!(decoded, query);
+
+    for text in &["'quick' & !'slow'", "'fox':*AB <2> ( 'a' | 'b' )", ""] {
+        let sql = format!("SELECT '{}'::tsquery", text.replace('\'', "''"));
+
+        let (prepared,): (PgTsQuery,) = sqlx::query_as(&sql).fetch_one(&mut conn).await?;
+
+        let mut cursor = conn.fetch(&*sql);
+        let row = cursor.next().await?.unwrap();
+
+        assert_eq!(row.try_get::<PgTsQuery, _>(0)?, prepared, "{}", text);
+    }
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_array_nulls() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let (ints, texts): (Vec<Option<i32>>, Vec<Option<String>>) =
+        sqlx::query_as("SELECT '{1,NULL,3}'::int4[], '{NULL,\"NULL\",\"a,b\"}'::text[]")
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(ints, vec![Some(1), None, Some(3)]);
+    assert_eq!(
+        texts,
+        vec![None, Some("NULL".to_owned()), Some("a,b".to_owned())]
+    );
+
+    let mut cursor = conn.fetch("SELECT '{1,NULL,3}'::int4[], '{NULL,\"NULL\",\"a,b\"}'::text[]");
+    let row = cursor.next().await?.unwrap();
+
+    assert_eq!(row.try_get::<Vec<Option<i32>>, _>(0)?, ints);
+    assert_eq!(row.try_get::<Vec<Option<String>>, _>(1)?, texts);
+
+    // NULL elements can only be decoded into an `Option`
+    let res: Result<(Vec<i32>,), _> = sqlx::query_as("SELECT '{1,NULL}'::int4[]")
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(res.is_err());
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_array_dimensionality() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let res: Result<(Vec<i32>,), _> = sqlx::query_as("SELECT '{{1,2},{3,4}}'::int4[]")
+        .fetch_one(&mut conn)
+        .await;
+
+    let err = res.unwrap_err().to_string();
+    assert!(err.contains("unsupported dimensionality"), "{}", err);
+
+    let mut cursor = conn.fetch("SELECT '{{1,2},{3,4}}'::int4[]");
+    let row = cursor.next().await?.unwrap();
+
+    let err = row.try_get::<Vec<i32>, _>(0).unwrap_err().to_string();
+    assert!(err.contains("unsupported dimensionality"), "{}", err);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_bind_array() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let ints = vec![1_i32, 2, 3];
+    let (same, decoded): (bool, Vec<i32>) = sqlx::query_as("SELECT $1 = '{1,2,3}'::int4[], $1")
+        .bind(&ints)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert!(same);
+    assert_eq!(decoded, ints);
+
+    let texts = vec!["a".to_owned(), "b,c".to_owned(), "NULL".to_owned()];
+    let (same, decoded): (bool, Vec<String>) =
+        sqlx::query_as("SELECT $1 = '{a,\"b,c\",\"NULL\"}'::text[], $1")
+            .bind(&texts[..])
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert!(same);
+    assert_eq!(decoded, texts);
+
+    let empty: Vec<i32> = Vec::new();
+    let (same, dims, decoded): (bool, Option<String>, Vec<i32>) =
+        sqlx::query_as("SELECT $1 = '{}'::int4[], array_dims($1), $1")
+            .bind(&empty)
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert!(same);
+    assert_eq!(dims, None);
+    assert_eq!(decoded, empty);
+
+    let nullable = vec![Some(1_i32), None];
+    let (same, decoded): (bool, Vec<Option<i32>>) =
+        sqlx::query_as("SELECT $1 IS NOT DISTINCT FROM '{1,NULL}'::int4[], $1")
+            .bind(&nullable)
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert!(same);
+    assert_eq!(decoded, nullable);
+
+    let mixed = vec![None, Some(1_i32), None, None, Some(-5_i32), None];
+    let (same, nulls, decoded): (bool, i64, Vec<Option<i32>>) = sqlx::query_as(
+        "SELECT $1 IS NOT DISTINCT FROM '{NULL,1,NULL,NULL,-5,NULL}'::int4[], \
+         (SELECT COUNT(*) FROM UNNEST($1) AS value WHERE value IS NULL), $1",
+    )
+    .bind(&mixed)
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert!(same);
+    assert_eq!(nulls, 4);
+    assert_eq!(decoded, mixed);
+
+    // the NULL elements can only be decoded into an `Option`
+    let res: Result<(Vec<i32>,), _> = sqlx::query_as("SELECT $1")
+        .bind(&mixed)
+        .fetch_one(&mut conn)
+        .await;
+
+    let err = res.unwrap_err().to_string();
+    assert!(err.contains("unexpected null"), "{}", err);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_multi_dimensional_array() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let value = vec![vec![1_i32, 2, 3], vec![4, 5, 6]];
+
+    let (same, dims, decoded): (bool, String, Vec<Vec<i32>>) =
+        sqlx::query_as("SELECT $1 = '{{1,2,3},{4,5,6}}'::int4[], array_dims($1), $1")
+            .bind(&value)
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert!(same);
+    assert_eq!(dims, "[1:2][1:3]");
+    assert_eq!(decoded, value);
+
+    let mut cursor = conn.fetch("SELECT '{{1,2,3},{4,5,6}}'::int4[]");
+    let row = cursor.next().await?.unwrap();
+
+    assert_eq!(row.try_get::<Vec<Vec<i32>>, _>(0)?, value);
+
+    let texts = vec![
+        vec![Some("a".to_owned()), None],
+        vec![Some("{}".to_owned()), Some("".to_owned())],
+    ];
+
+    let (decoded,): (Vec<Vec<Option<String>>>,) = sqlx::query_as("SELECT $1::text[]")
+        .bind(&texts)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(decoded, texts);
+
+    let empty: Vec<Vec<i32>> = Vec::new();
+    let (decoded,): (Vec<Vec<i32>>,) = sqlx::query_as("SELECT $1::int4[]")
+        .bind(&empty)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(decoded, empty);
+
+    // the rows of a multi-dimensional array must have the same length
+    let res: Result<(Vec<Vec<i32>>,), _> = sqlx::query_as("SELECT $1::int4[] AS ragged")
+        .bind(vec![vec![1_i32, 2], vec![3]])
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(res.is_err());
+
+    // a one-dimensional array has no rows
+    let res: Result<(Vec<Vec<i32>>,), _> = sqlx::query_as("SELECT '{1,2}'::int4[]")
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(res.is_err());
+
+    Ok(())
+}
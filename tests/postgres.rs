@@ -1,6 +1,9 @@
 use futures::TryStreamExt;
-use sqlx::postgres::{PgPool, PgQueryAs, PgRow};
-use sqlx::{Connection, Cursor, Executor, Postgres, Row};
+use sqlx::postgres::{
+    PgConnectOptions, PgConnection, PgData, PgListener, PgPool, PgQueryAs, PgRow,
+};
+use sqlx::value::RawValue;
+use sqlx::{Connect, Connection, Cursor, Executor, Postgres, Row};
 use sqlx_test::new;
 use std::time::Duration;
 
@@ -21,6 +24,335 @@ async fn it_connects() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_connects_with_tcp_options() -> anyhow::Result<()> {
+    let url = dotenv::var("DATABASE_URL")?;
+
+    let options = PgConnectOptions::new()
+        .tcp_keepalive(Duration::from_secs(60))
+        .tcp_nodelay(true);
+
+    let mut conn = PgConnection::connect_with(&url, &options).await?;
+
+    let (value,): (i32,) = sqlx::query_as("select 1 + 1").fetch_one(&mut conn).await?;
+
+    assert_eq!(value, 2);
+
+    Ok(conn.ping().await?)
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_connects_a_pool_with_tcp_parameters() -> anyhow::Result<()> {
+    let url = dotenv::var("DATABASE_URL")?;
+    let separator = if url.contains('?') { '&' } else { '?' };
+
+    let pool = PgPool::new(&format!(
+        "{}{}tcp_keepalive=60&tcp_nodelay=true",
+        url, separator
+    ))
+    .await?;
+
+    let (value,): (i32,) = sqlx::query_as("select 1 + 1").fetch_one(&pool).await?;
+
+    assert_eq!(value, 2);
+
+    let pool = PgPool::new(&format!("{}{}tcp_keepalive=1m", url, separator)).await?;
+    let res = pool.acquire().await;
+
+    let err = res.err().unwrap();
+
+    assert!(err.to_string().contains("tcp_keepalive"), "{}", err);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_connects_with_a_search_path() -> anyhow::Result<()> {
+    let url = dotenv::var("DATABASE_URL")?;
+
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute(
+        r#"
+CREATE SCHEMA IF NOT EXISTS "_sqlx_Tenant";
+CREATE TABLE IF NOT EXISTS "_sqlx_Tenant"._sqlx_tenant_accounts (name TEXT NOT NULL);
+TRUNCATE "_sqlx_Tenant"._sqlx_tenant_accounts;
+INSERT INTO "_sqlx_Tenant"._sqlx_tenant_accounts (name) VALUES ('alice');
+        "#,
+    )
+    .await?;
+
+    let options = PgConnectOptions::new().search_path(&["_sqlx_Tenant", "public"]);
+    let mut tenant = PgConnection::connect_with(&url, &options).await?;
+
+    let (name,): (String,) = sqlx::query_as("SELECT name FROM _sqlx_tenant_accounts")
+        .fetch_one(&mut tenant)
+        .await?;
+
+    assert_eq!(name, "alice");
+
+    // the search path is the default of the session, which a reset goes back to
+    tenant
+        .execute("SET search_path = public; RESET search_path")
+        .await?;
+
+    let (search_path,): (String,) = sqlx::query_as("SHOW search_path")
+        .fetch_one(&mut tenant)
+        .await?;
+
+    assert_eq!(search_path, r#""_sqlx_Tenant", "public""#);
+
+    let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM _sqlx_tenant_accounts")
+        .fetch_one(&mut tenant)
+        .await?;
+
+    assert_eq!(count, 1);
+
+    // without it the table is not found
+    assert!(conn
+        .execute("SELECT name FROM _sqlx_tenant_accounts")
+        .await
+        .is_err());
+
+    conn.execute(r#"DROP SCHEMA "_sqlx_Tenant" CASCADE"#)
+        .await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_reports_the_server_version() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let (major, minor, patch) = conn.server_version();
+
+    let (version_num,): (String,) = sqlx::query_as("SHOW server_version_num")
+        .fetch_one(&mut conn)
+        .await?;
+
+    // e.g. 120003 for 12.3 and 90618 for 9.6.18
+    let expected = if major >= 10 {
+        major * 10000 + minor
+    } else {
+        major * 10000 + minor * 100 + patch
+    };
+
+    assert!(major >= 9, "{:?}", conn.server_version());
+    assert_eq!(version_num.parse::<u32>()?, expected);
+
+    Ok(())
+}
+
+// Only checked when `DATABASE_URL` asks for the certificate of the server to be verified, e.g.
+// with `sslmode=verify-full&sslrootcert=tests%2Fcerts%2Fca.crt` for a server using the
+// certificate in `tests/certs`
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_connects_with_a_verified_tls_certificate() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    if !dotenv::var("DATABASE_URL")?.contains("sslmode=verify-") {
+        return Ok(());
+    }
+
+    let (ssl,): (bool,) =
+        sqlx::query_as("SELECT ssl FROM pg_stat_ssl WHERE pid = pg_backend_pid()")
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert!(ssl);
+
+    Ok(())
+}
+
+// The URL of `DATABASE_URL` with another `sslmode`, if it is `verify-ca` or `verify-full` for a
+// server with TLS
+fn url_with_sslmode(mode: &str) -> anyhow::Result<Option<String>> {
+    let url = dotenv::var("DATABASE_URL")?;
+
+    Ok(["sslmode=verify-ca", "sslmode=verify-full"]
+        .iter()
+        .find(|verify| url.contains(*verify))
+        .map(|verify| url.replace(verify, &format!("sslmode={}", mode))))
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_upgrades_to_tls_as_the_sslmode_asks() -> anyhow::Result<()> {
+    for (mode, ssl) in &[
+        ("disable", false),
+        ("allow", false),
+        ("prefer", true),
+        ("require", true),
+        ("verify-ca", true),
+    ] {
+        let url = match url_with_sslmode(mode)? {
+            Some(url) => url,
+            None => return Ok(()),
+        };
+
+        let mut conn = PgConnection::connect(&url).await?;
+
+        let (is_ssl,): (bool,) =
+            sqlx::query_as("SELECT ssl FROM pg_stat_ssl WHERE pid = pg_backend_pid()")
+                .fetch_one(&mut conn)
+                .await?;
+
+        assert_eq!(is_ssl, *ssl, "sslmode={}", mode);
+    }
+
+    Ok(())
+}
+
+// Only checked when `DATABASE_URL` is for `localhost`, which must be the only host of the
+// certificate of the server, as it is for the one in `tests/certs`
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_verifies_the_host_of_the_certificate_with_verify_full() -> anyhow::Result<()> {
+    let url = match url_with_sslmode("verify-full")? {
+        Some(url) if url.contains("@localhost") => url,
+        _ => return Ok(()),
+    };
+
+    PgConnection::connect(&url).await?.ping().await?;
+
+    // The same server, by a host the certificate is not for
+    let mismatched = url.replacen("@localhost", "@127.0.0.1", 1);
+
+    match PgConnection::connect(&mismatched).await {
+        Err(sqlx::Error::Tls(_)) => {}
+        Err(e) => panic!("expected a TLS error, got {:?}", e),
+        Ok(_) => panic!("expected a TLS error, got a connection"),
+    }
+
+    // which is fine when only the authority of the certificate is verified
+    let mismatched = mismatched.replace("sslmode=verify-full", "sslmode=verify-ca");
+
+    PgConnection::connect(&mismatched).await?.ping().await?;
+
+    Ok(())
+}
+
+// Only checked when `DATABASE_URL` has the `sslcert` and `sslkey` of a server that authenticates
+// the user with the `cert` method, as for `tests/certs/client.crt` and the user `postgres`
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_authenticates_with_a_client_certificate() -> anyhow::Result<()> {
+    let url = dotenv::var("DATABASE_URL")?;
+
+    if !url.contains("sslcert=") {
+        return Ok(());
+    }
+
+    PgConnection::connect(&url).await?.ping().await?;
+
+    // The same URL without the certificate, which is then given by the options, and without
+    // the password, which the server does not ask for
+    let (base, query) = url.split_at(url.find('?').unwrap());
+    let query: Vec<&str> = query[1..]
+        .split('&')
+        .filter(|param| !param.starts_with("sslcert=") && !param.starts_with("sslkey="))
+        .collect();
+
+    let (scheme, rest) = base.split_at(base.find("://").unwrap() + 3);
+    let (user_info, host) = rest.split_at(rest.find('@').unwrap());
+    let user = user_info.split(':').next().unwrap();
+
+    let url = format!("{}{}{}?{}", scheme, user, host, query.join("&"));
+
+    let options = PgConnectOptions::new()
+        .ssl_client_cert_from_pem(std::fs::read("tests/certs/client.crt")?)
+        .ssl_client_key("tests/certs/client.key");
+
+    PgConnection::connect_with(&url, &options)
+        .await?
+        .ping()
+        .await?;
+
+    // The server refuses the connection without a certificate
+    assert!(PgConnection::connect(&url).await.is_err());
+
+    Ok(())
+}
+
+// A role with a `scram-sha-256` password is authenticated with SCRAM-SHA-256 for an `md5` (or
+// `scram-sha-256`) line of `pg_hba.conf`; over TLS, with SCRAM-SHA-256-PLUS
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_authenticates_with_scram_sha_256() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let (version,): (i32,) = sqlx::query_as("SELECT current_setting('server_version_num')::int4")
+        .fetch_one(&mut conn)
+        .await?;
+
+    // `scram-sha-256` passwords are new in Postgres 10
+    if version < 100000 {
+        return Ok(());
+    }
+
+    conn.execute("DROP ROLE IF EXISTS sqlx_scram").await?;
+    conn.execute(
+        "SET password_encryption = 'scram-sha-256'; CREATE ROLE sqlx_scram LOGIN PASSWORD 'password'",
+    )
+    .await?;
+
+    let url = dotenv::var("DATABASE_URL")?;
+    let (scheme, rest) = url.split_at(url.find("://").unwrap() + 3);
+    let host = &rest[rest.find('@').unwrap()..];
+
+    // Only for a password, not a client certificate
+    if !url.contains("sslcert=") {
+        let mut scram =
+            PgConnection::connect(&format!("{}sqlx_scram:password{}", scheme, host)).await?;
+
+        let (user,): (String,) = sqlx::query_as("SELECT current_user::text")
+            .fetch_one(&mut scram)
+            .await?;
+
+        assert_eq!(user, "sqlx_scram");
+
+        let res = PgConnection::connect(&format!("{}sqlx_scram:wrong{}", scheme, host)).await;
+
+        assert!(res.is_err());
+    }
+
+    conn.execute("DROP ROLE sqlx_scram").await?;
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_connects_over_a_unix_socket() -> anyhow::Result<()> {
+    let url = dotenv::var("DATABASE_URL")?;
+
+    // Only when the tests are run over a socket, e.g. with `?host=/var/run/postgresql`
+    if !["host=/", "host=%2F", "://%2F"]
+        .iter()
+        .any(|host| url.contains(host))
+    {
+        return Ok(());
+    }
+
+    let mut conn = PgConnection::connect(&url).await?;
+
+    // The address of the server is NULL for a connection over a socket
+    let over_socket: bool = sqlx::query("SELECT inet_server_addr() IS NULL")
+        .try_map(|row: PgRow| row.try_get(0))
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert!(over_socket);
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "runtime-async-std", async_std::test)]
 #[cfg_attr(feature = "runtime-tokio", tokio::test)]
 async fn it_executes() -> anyhow::Result<()> {
@@ -40,7 +372,7 @@ CREATE TEMPORARY TABLE users (id INTEGER PRIMARY KEY);
             .execute(&mut conn)
             .await?;
 
-        assert_eq!(cnt, 1);
+        assert_eq!(cnt.rows_affected(), 1);
     }
 
     let sum: i32 = sqlx::query("SELECT id FROM users")
@@ -383,3 +715,2220 @@ async fn test_describe() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_copies_in() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE copy_in (id INTEGER PRIMARY KEY, name TEXT)")
+        .await?;
+
+    let mut copy = conn.copy_in("COPY copy_in (id, name) FROM STDIN").await?;
+
+    for id in 1..=5000 {
+        copy.send(format!("{}\tname {}\n", id, id).as_bytes())
+            .await?;
+    }
+
+    // a chunk does not have to end at a row
+    copy.send(b"5001\tsplit ").await?;
+    copy.send(b"row\n").await?;
+
+    assert_eq!(copy.finish().await?, 5001);
+
+    let (count, name): (i64, String) =
+        sqlx::query_as("SELECT COUNT(*), MAX(name) FILTER (WHERE id = 5001) FROM copy_in")
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(count, 5001);
+    assert_eq!(name, "split row");
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_aborts_copy_in() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE copy_in (id INTEGER PRIMARY KEY)")
+        .await?;
+
+    let mut copy = conn.copy_in("COPY copy_in FROM STDIN").await?;
+    copy.send(b"1\n").await?;
+    copy.abort("changed my mind").await?;
+
+    // a dropped copy is aborted as well
+    let mut copy = conn.copy_in("COPY copy_in FROM STDIN").await?;
+    copy.send(b"2\n").await?;
+    drop(copy);
+
+    // errors in the data are returned when finishing
+    let mut copy = conn.copy_in("COPY copy_in FROM STDIN").await?;
+    copy.send(b"not a number\n").await?;
+    assert!(copy.finish().await.is_err());
+
+    // the statement must be a COPY FROM STDIN
+    assert!(conn.copy_in("SELECT 1").await.is_err());
+
+    let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM copy_in")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 0);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_copies_in_binary() -> anyhow::Result<()> {
+    struct Reading {
+        id: i64,
+        sensor: Option<String>,
+        value: f64,
+        valid: bool,
+        samples: Vec<i32>,
+        raw: Vec<u8>,
+    }
+
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE copy_in_binary (
+    id BIGINT PRIMARY KEY,
+    sensor TEXT,
+    value DOUBLE PRECISION NOT NULL,
+    valid BOOLEAN NOT NULL,
+    samples INTEGER[] NOT NULL,
+    raw BYTEA NOT NULL
+)
+        "#,
+    )
+    .await?;
+
+    // enough rows to be sent in several chunks
+    let readings: Vec<Reading> = (1..=5000)
+        .map(|id| Reading {
+            id,
+            sensor: if id % 3 == 0 {
+                None
+            } else {
+                Some(format!("sensor {}", id % 7))
+            },
+            value: id as f64 / 3.0,
+            valid: id % 2 == 0,
+            samples: vec![id as i32, -(id as i32)],
+            raw: vec![0, 0xFF, id as u8],
+        })
+        .collect();
+
+    let mut copy = conn
+        .copy_in_binary(
+            "COPY copy_in_binary (id, sensor, value, valid, samples, raw) FROM STDIN (FORMAT binary)",
+        )
+        .await?;
+
+    for r in &readings {
+        copy.send_row((r.id, &r.sensor, r.value, r.valid, &r.samples, &r.raw))
+            .await?;
+    }
+
+    assert_eq!(copy.finish().await?, 5000);
+
+    let rows: Vec<(i64, Option<String>, f64, bool, Vec<i32>, Vec<u8>)> = sqlx::query_as(
+        "SELECT id, sensor, value, valid, samples, raw FROM copy_in_binary ORDER BY id",
+    )
+    .fetch_all(&mut conn)
+    .await?;
+
+    assert_eq!(rows.len(), readings.len());
+
+    for (row, r) in rows.iter().zip(&readings) {
+        assert_eq!(row.0, r.id);
+        assert_eq!(row.1, r.sensor);
+        assert_eq!(row.2, r.value);
+        assert_eq!(row.3, r.valid);
+        assert_eq!(row.4, r.samples);
+        assert_eq!(row.5, r.raw);
+    }
+
+    // a field of the wrong type is an error of the copy, as postgres reads the binary data
+    // of the type of its column
+    let mut copy = conn
+        .copy_in_binary(
+            "COPY copy_in_binary (id, value, valid, samples, raw) FROM STDIN (FORMAT binary)",
+        )
+        .await?;
+
+    copy.send_row((10_000_i32, 1.5_f64, true, vec![1_i32], vec![0_u8]))
+        .await?;
+
+    assert!(copy.finish().await.is_err());
+
+    // an aborted copy inserts none of its rows
+    let mut copy = conn
+        .copy_in_binary(
+            "COPY copy_in_binary (id, value, valid, samples, raw) FROM STDIN (FORMAT binary)",
+        )
+        .await?;
+
+    copy.send_row((10_001_i64, 1.5_f64, true, vec![1_i32], vec![0_u8]))
+        .await?;
+
+    copy.abort("changed my mind").await?;
+
+    let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM copy_in_binary")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 5000);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_contends_for_an_advisory_lock() -> anyhow::Result<()> {
+    #[cfg(feature = "runtime-tokio")]
+    use tokio::time::delay_for as sleep;
+
+    #[cfg(feature = "runtime-async-std")]
+    use async_std::task::sleep;
+
+    use futures::TryFutureExt;
+    use sqlx::postgres::PgAdvisoryLock;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    let mut conn1 = new::<Postgres>().await?;
+    let mut conn2 = new::<Postgres>().await?;
+
+    let lock = PgAdvisoryLock::new(0x5351_4c58);
+
+    let mut guard1 = lock.acquire(&mut conn1).await?;
+    assert!(lock.try_acquire(&mut conn2).await?.is_none());
+
+    // the guard can be used as the connection
+    guard1.execute("SELECT 1").await?;
+
+    // the second connection waits until the first one releases the lock
+    let released = AtomicBool::new(false);
+
+    let (guard2, ()) = futures::try_join!(
+        lock.acquire(&mut conn2).map_ok(|guard| {
+            assert!(released.load(Ordering::SeqCst));
+            guard
+        }),
+        async {
+            sleep(Duration::from_millis(200)).await;
+            released.store(true, Ordering::SeqCst);
+
+            guard1.release().await
+        }
+    )?;
+
+    assert!(lock.try_acquire(&mut conn1).await?.is_none());
+
+    // a dropped guard releases the lock before the next query of its connection
+    drop(guard2);
+    conn2.execute("SELECT 1").await?;
+
+    let guard1 = lock.try_acquire(&mut conn1).await?;
+    assert!(guard1.is_some());
+    drop(guard1);
+
+    // a pair of keys is a different lock
+    let pair = PgAdvisoryLock::with_key_pair(0x5351, 0x4c58);
+    let _guard = pair.acquire(&mut conn2).await?;
+
+    assert!(pair.try_acquire(&mut conn1).await?.is_none());
+    assert!(lock.try_acquire(&mut conn1).await?.is_some());
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_holds_an_advisory_lock_for_a_transaction() -> anyhow::Result<()> {
+    use sqlx::postgres::PgAdvisoryLock;
+
+    let lock = PgAdvisoryLock::new(0x5351_4c59);
+
+    let mut tx1 = new::<Postgres>().await?.begin().await?;
+    lock.acquire_xact(&mut tx1).await?;
+
+    let mut tx2 = new::<Postgres>().await?.begin().await?;
+    assert!(!lock.try_acquire_xact(&mut tx2).await?);
+    assert!(lock.try_acquire(&mut tx2).await?.is_none());
+
+    let conn1 = tx1.commit().await?;
+
+    assert!(lock.try_acquire_xact(&mut tx2).await?);
+
+    // the lock is released by a rollback as well
+    tx2.rollback().await?;
+
+    let mut tx1 = conn1.begin().await?;
+    assert!(lock.try_acquire_xact(&mut tx1).await?);
+    tx1.commit().await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_runs_a_pipeline() -> anyhow::Result<()> {
+    use futures::FutureExt;
+
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE pipeline (id INTEGER PRIMARY KEY, name TEXT)")
+        .await?;
+
+    let results = conn
+        .pipeline(|p| {
+            p.query(
+                sqlx::query("INSERT INTO pipeline (id, name) VALUES ($1, $2)")
+                    .bind(1_i32)
+                    .bind("Alice"),
+            );
+
+            // fails on the primary key, without stopping the queries after it
+            p.query(
+                sqlx::query("INSERT INTO pipeline (id, name) VALUES ($1, $2)")
+                    .bind(1_i32)
+                    .bind("Bob"),
+            );
+
+            p.query(
+                sqlx::query("INSERT INTO pipeline (id) SELECT generate_series(2, $1)").bind(4_i32),
+            );
+            p.query("SELECT 1 / 0");
+            p.query("UPDATE pipeline SET name = 'Carol' WHERE id > 2");
+        })
+        .await?;
+
+    assert_eq!(results.len(), 5);
+
+    let rows: Vec<_> = results
+        .iter()
+        .map(|result| result.as_ref().ok().map(|result| result.rows_affected()))
+        .collect();
+
+    assert_eq!(rows, vec![Some(1), None, Some(3), None, Some(2)]);
+
+    match &results[1] {
+        Err(sqlx::Error::Database(err)) => assert_eq!(err.code(), Some("23505")),
+        result => panic!("expected a unique violation but received {:?}", result),
+    }
+
+    match &results[3] {
+        Err(sqlx::Error::Database(err)) => assert_eq!(err.code(), Some("22012")),
+        result => panic!("expected a division by zero but received {:?}", result),
+    }
+
+    assert!(conn.pipeline(|_| {}).await?.is_empty());
+
+    // a pipeline dropped before its results are read leaves the connection usable
+    let dropped = conn
+        .pipeline(|p| {
+            p.query("SELECT pg_sleep(0.1)");
+            p.query("SELECT 1 / 0");
+        })
+        .now_or_never();
+
+    assert!(dropped.is_none());
+
+    let names: Vec<(i32, Option<String>)> =
+        sqlx::query_as("SELECT id, name FROM pipeline ORDER BY id")
+            .fetch_all(&mut conn)
+            .await?;
+
+    assert_eq!(
+        names,
+        vec![
+            (1, Some("Alice".to_owned())),
+            (2, None),
+            (3, Some("Carol".to_owned())),
+            (4, Some("Carol".to_owned())),
+        ]
+    );
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_fetches_all_in_chunks() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let mut chunks = conn.fetch_all_chunked(
+        sqlx::query_as::<_, (i32,)>("SELECT generate_series(1, $1)").bind(100_500_i32),
+        1000,
+    );
+
+    let mut sizes = Vec::new();
+    let mut expected = 1;
+
+    while let Some(chunk) = chunks.try_next().await? {
+        for (id,) in &chunk {
+            assert_eq!(*id, expected);
+            expected += 1;
+        }
+
+        sizes.push(chunk.len());
+    }
+
+    drop(chunks);
+
+    assert_eq!(expected, 100_501);
+    assert_eq!(sizes.len(), 101);
+    assert!(sizes[..100].iter().all(|size| *size == 1000));
+    assert_eq!(sizes[100], 500);
+
+    // a stream dropped before its last chunk closes the portal
+    let mut chunks = conn.fetch_all_chunked(
+        sqlx::query_as::<_, (i32,)>("SELECT generate_series(1, 1000000)"),
+        100,
+    );
+
+    assert_eq!(chunks.try_next().await?.map(|chunk| chunk.len()), Some(100));
+    drop(chunks);
+
+    let (sum,): (i64,) = sqlx::query_as("SELECT SUM(x) FROM generate_series(1, 10) AS x")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(sum, 55);
+
+    // an error is returned after the chunks that come before it
+    let mut chunks = conn.fetch_all_chunked(
+        sqlx::query_as::<_, (i32,)>("SELECT 1 / (150 - x) FROM generate_series(1, 300) AS x"),
+        100,
+    );
+
+    assert_eq!(chunks.try_next().await?.map(|chunk| chunk.len()), Some(100));
+    assert!(chunks.try_next().await.is_err());
+    drop(chunks);
+
+    // in a transaction, the query sees the rows of the transaction
+    let mut tx = conn.begin().await?;
+
+    tx.execute("CREATE TEMPORARY TABLE chunked AS SELECT generate_series(1, 25) AS id")
+        .await?;
+
+    let chunks: Vec<Vec<(i32,)>> = tx
+        .fetch_all_chunked(sqlx::query_as("SELECT id FROM chunked ORDER BY id"), 10)
+        .try_collect()
+        .await?;
+
+    assert_eq!(
+        chunks.iter().map(Vec::len).collect::<Vec<_>>(),
+        vec![10, 10, 5]
+    );
+
+    tx.commit().await?;
+
+    Ok(())
+}
+
+// Compares the time of queries run one at a time, with a round-trip each, to that of the same
+// queries in a pipeline, with a single round-trip; the difference grows with the latency to
+// the server
+//
+// run with `cargo test --features postgres -- --ignored --nocapture pipeline_round_trips`
+#[ignore]
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn pipeline_round_trips() -> anyhow::Result<()> {
+    use std::time::Instant;
+
+    const QUERIES: i32 = 1000;
+
+    let mut conn = new::<Postgres>().await?;
+
+    // warm up the statement cache and the lookup of the types
+    sqlx::query("SELECT $1")
+        .bind(0_i32)
+        .execute(&mut conn)
+        .await?;
+
+    let start = Instant::now();
+
+    for i in 0..QUERIES {
+        sqlx::query("SELECT $1").bind(i).execute(&mut conn).await?;
+    }
+
+    let sequential = start.elapsed();
+    let start = Instant::now();
+
+    let results = conn
+        .pipeline(|p| {
+            for i in 0..QUERIES {
+                p.query(sqlx::query("SELECT $1").bind(i));
+            }
+        })
+        .await?;
+
+    let pipelined = start.elapsed();
+
+    assert_eq!(results.len(), QUERIES as usize);
+
+    for result in results {
+        assert_eq!(result?.rows_affected(), 1);
+    }
+
+    eprintln!(
+        "{} queries: {:?} one at a time ({} round-trips), {:?} pipelined (1 round-trip)",
+        QUERIES, sequential, QUERIES, pipelined
+    );
+
+    assert!(pipelined < sequential);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_copies_out() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE copy_out (id INTEGER PRIMARY KEY, name TEXT);
+INSERT INTO copy_out SELECT id, 'name ' || id FROM generate_series(1, 1000) id;
+        "#,
+    )
+    .await?;
+
+    let chunks: Vec<_> = conn
+        .copy_out("COPY (SELECT * FROM copy_out ORDER BY id) TO STDOUT (FORMAT csv)")
+        .try_collect()
+        .await?;
+
+    let expected: String = (1..=1000)
+        .map(|id| format!("{},name {}\n", id, id))
+        .collect();
+
+    assert_eq!(chunks.len(), 1000);
+    assert_eq!(chunks.concat(), expected.as_bytes());
+
+    // an error while copying ends the stream
+    let mut stream = conn.copy_out("COPY (SELECT 1 / (3 - id) FROM copy_out) TO STDOUT");
+
+    assert_eq!(&*stream.try_next().await?.unwrap(), b"0\n");
+    assert_eq!(&*stream.try_next().await?.unwrap(), b"1\n");
+    assert!(stream.try_next().await.is_err());
+
+    drop(stream);
+
+    // the statement must be a COPY TO STDOUT
+    assert!(conn.copy_out("SELECT 1").try_next().await.is_err());
+
+    let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM copy_out")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 1000);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_listens_for_notifications() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+    let mut listener = PgListener::new(&dotenv::var("DATABASE_URL")?).await?;
+
+    listener.listen("sqlx test").await?;
+    listener
+        .listen_all(vec!["sqlx_test_0", "sqlx_test_1"])
+        .await?;
+
+    conn.execute("NOTIFY \"sqlx test\", 'hello'").await?;
+    conn.execute("NOTIFY sqlx_test_1").await?;
+
+    let notification = listener.recv().await?;
+
+    assert_eq!(notification.channel(), "sqlx test");
+    assert_eq!(notification.payload(), "hello");
+
+    let (pid,): (i32,) = sqlx::query_as("SELECT pg_backend_pid()")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(notification.process_id(), pid as u32);
+
+    let notification = listener.recv().await?;
+
+    assert_eq!(notification.channel(), "sqlx_test_1");
+    assert_eq!(notification.payload(), "");
+
+    // kill the connection of the listener; it should reconnect and listen again
+    let (listener_pid,): (i32,) = sqlx::query_as("SELECT pg_backend_pid()")
+        .fetch_one(&mut listener)
+        .await?;
+
+    sqlx::query("SELECT pg_terminate_backend($1)")
+        .bind(listener_pid)
+        .execute(&mut conn)
+        .await?;
+
+    assert!(listener.try_recv().await?.is_none());
+
+    // this is sent before the listener has reconnected
+    conn.execute("NOTIFY sqlx_test_0, 'lost'").await?;
+
+    listener.listen("sqlx_test_2").await?;
+
+    conn.execute("NOTIFY sqlx_test_0, 'after reconnect'")
+        .await?;
+    conn.execute("NOTIFY sqlx_test_2").await?;
+
+    let mut stream = listener.into_stream();
+
+    let notification = stream.try_next().await?.unwrap();
+
+    assert_eq!(notification.channel(), "sqlx_test_0");
+    assert_eq!(notification.payload(), "after reconnect");
+
+    let notification = stream.try_next().await?.unwrap();
+
+    assert_eq!(notification.channel(), "sqlx_test_2");
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_closes_statements_over_the_cache_capacity() -> anyhow::Result<()> {
+    let url = dotenv::var("DATABASE_URL")?;
+    let separator = if url.contains('?') { '&' } else { '?' };
+
+    let mut conn =
+        PgConnection::connect(format!("{}{}statement_cache_capacity=2", url, separator)).await?;
+
+    for i in 0..5_i32 {
+        let (value,): (i32,) = sqlx::query_as(&format!("SELECT $1 + {}", i))
+            .bind(1_i32)
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert_eq!(value, i + 1);
+    }
+
+    assert_eq!(conn.cached_statements_size(), 2);
+
+    // the statements that were removed from the cache are closed on the server
+    let mut cursor = conn.fetch("SELECT statement FROM pg_prepared_statements ORDER BY statement");
+    let mut statements = Vec::new();
+
+    while let Some(row) = cursor.next().await? {
+        statements.push(row.try_get::<String, _>(0)?);
+    }
+
+    assert_eq!(statements, vec!["SELECT $1 + 3", "SELECT $1 + 4"]);
+
+    // an evicted statement is prepared again when it is used
+    let (value,): (i32,) = sqlx::query_as("SELECT $1 + 0")
+        .bind(1_i32)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, 1);
+    assert_eq!(conn.cached_statements_size(), 2);
+
+    assert!(
+        PgConnection::connect(format!("{}{}statement_cache_capacity=many", url, separator))
+            .await
+            .is_err()
+    );
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_closes_statements_without_a_statement_cache() -> anyhow::Result<()> {
+    let url = dotenv::var("DATABASE_URL")?;
+    let separator = if url.contains('?') { '&' } else { '?' };
+
+    let mut conn =
+        PgConnection::connect(format!("{}{}statement_cache_capacity=0", url, separator)).await?;
+
+    for i in 0..50_i32 {
+        let (value,): (i32,) = sqlx::query_as(&format!("SELECT $1 + {}", i))
+            .bind(1_i32)
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert_eq!(value, i + 1);
+
+        sqlx::query(&format!("SELECT $1 + {}", i))
+            .bind(1_i32)
+            .execute(&mut conn)
+            .await?;
+    }
+
+    // a statement that fails while executing is closed as well
+    let res: Result<(i32,), _> = sqlx::query_as("SELECT 1 / $1")
+        .bind(0_i32)
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(res.is_err());
+
+    let (value,): (i32,) = sqlx::query_as("SELECT $1")
+        .bind(2_i32)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, 2);
+
+    let describe = conn.describe("SELECT $1::int4 AS value").await?;
+
+    assert_eq!(describe.result_columns.len(), 1);
+    assert_eq!(conn.cached_statements_size(), 0);
+
+    let mut cursor = conn.fetch("SELECT COUNT(*) FROM pg_prepared_statements");
+    let row = cursor.next().await?.unwrap();
+
+    assert_eq!(row.try_get::<i64, _>(0)?, 0);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_executes_without_prepared_statements_for_pgbouncer() -> anyhow::Result<()> {
+    let url = dotenv::var("DATABASE_URL")?;
+    let separator = if url.contains('?') { '&' } else { '?' };
+
+    let mut conn = PgConnection::connect(format!("{}{}pgbouncer=true", url, separator)).await?;
+
+    for i in 0..5_i32 {
+        let (value, text): (i32, String) = sqlx::query_as("SELECT $1 + 1, $2 || '!'")
+            .bind(i)
+            .bind("Hello")
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert_eq!((value, &*text), (i + 1, "Hello!"));
+    }
+
+    // a query that fails does not leave a statement behind either
+    let res: Result<(i32,), _> = sqlx::query_as("SELECT 1 / $1")
+        .bind(0_i32)
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(res.is_err());
+
+    let mut tx = conn.begin().await?;
+
+    let values: Vec<i32> = sqlx::query("SELECT generate_series(1, $1)")
+        .bind(3_i32)
+        .map(|row: PgRow| row.get::<i32, _>(0))
+        .fetch(&mut tx)
+        .try_collect()
+        .await?;
+
+    assert_eq!(values, vec![1, 2, 3]);
+
+    let mut conn = tx.commit().await?;
+
+    let describe = conn.describe("SELECT $1::int4 AS value").await?;
+
+    assert_eq!(describe.result_columns.len(), 1);
+    assert_eq!(conn.cached_statements_size(), 0);
+
+    let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM pg_prepared_statements")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 0);
+
+    assert!(
+        PgConnection::connect(format!("{}{}pgbouncer=yes", url, separator))
+            .await
+            .is_err()
+    );
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_sends_runtime_parameters() -> anyhow::Result<()> {
+    let url = dotenv::var("DATABASE_URL")?;
+    let separator = if url.contains('?') { '&' } else { '?' };
+
+    let mut conn = PgConnection::connect(format!(
+        "{}{}application_name=sqlx%20test&search_path=pg_catalog,public",
+        url, separator
+    ))
+    .await?;
+
+    let mut cursor = conn.fetch("SHOW application_name");
+    let row = cursor.next().await?.unwrap();
+
+    assert_eq!(row.try_get::<String, _>(0)?, "sqlx test");
+
+    let mut cursor = conn.fetch("SHOW search_path");
+    let row = cursor.next().await?.unwrap();
+
+    assert_eq!(row.try_get::<String, _>(0)?, "pg_catalog,public");
+
+    let res = PgConnection::connect(format!("{}{}not_a_parameter=1", url, separator)).await;
+
+    let err = res.err().unwrap();
+
+    assert!(err.to_string().contains("not_a_parameter"), "{}", err);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_times_out_statements() -> anyhow::Result<()> {
+    let url = dotenv::var("DATABASE_URL")?;
+    let separator = if url.contains('?') { '&' } else { '?' };
+    let url = format!("{}{}statement_timeout=100ms", url, separator);
+
+    let mut conn = PgConnection::connect(&url).await?;
+
+    let err = conn.execute("SELECT pg_sleep(1)").await.unwrap_err();
+
+    match err {
+        sqlx::Error::Database(err) => assert_eq!(err.code(), Some("57014")),
+        err => panic!("expected a database error, got {:?}", err),
+    }
+
+    // the timeout is set for every connection of a pool
+    let pool = PgPool::builder().max_size(2).build(&url).await?;
+
+    let (timeout,): (String,) = sqlx::query_as("SELECT current_setting('statement_timeout')")
+        .fetch_one(&pool)
+        .await?;
+
+    assert_eq!(timeout, "100ms");
+
+    let err = sqlx::query("SELECT pg_sleep($1)")
+        .bind(1.0_f64)
+        .execute(&pool)
+        .await
+        .unwrap_err();
+
+    match err {
+        sqlx::Error::Database(err) => assert_eq!(err.code(), Some("57014")),
+        err => panic!("expected a database error, got {:?}", err),
+    }
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_runs_after_connect_once_per_connection() -> anyhow::Result<()> {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    let connects = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&connects);
+
+    let pool = PgPool::builder()
+        .max_size(2)
+        .after_connect(move |conn| {
+            counter.fetch_add(1, Ordering::SeqCst);
+
+            Box::pin(async move {
+                conn.execute("SET application_name = 'sqlx after connect'")
+                    .await?;
+
+                Ok(())
+            })
+        })
+        .build(&dotenv::var("DATABASE_URL")?)
+        .await?;
+
+    for _ in 0..5 {
+        let (name,): (String,) = sqlx::query_as("SELECT current_setting('application_name')")
+            .fetch_one(&pool)
+            .await?;
+
+        assert_eq!(name, "sqlx after connect");
+    }
+
+    assert_eq!(connects.load(Ordering::SeqCst), 1);
+
+    // a second connection is opened while the first is in use
+    for _ in 0..3 {
+        let conn_0 = pool.acquire().await?;
+        let conn_1 = pool.acquire().await?;
+
+        drop((conn_0, conn_1));
+    }
+
+    assert_eq!(connects.load(Ordering::SeqCst), 2);
+    assert_eq!(pool.size(), 2);
+
+    // an error from the callback is returned and the connection is discarded
+    let pool = PgPool::builder()
+        .after_connect(|conn| {
+            Box::pin(async move {
+                conn.execute("SET not_a_parameter = 1").await?;
+
+                Ok(())
+            })
+        })
+        .build(&dotenv::var("DATABASE_URL")?)
+        .await?;
+
+    assert!(pool.acquire().await.is_err());
+    assert_eq!(pool.size(), 0);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_resets_connections_returned_to_the_pool() -> anyhow::Result<()> {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[cfg(feature = "runtime-tokio")]
+    use tokio::time::delay_for as sleep;
+
+    #[cfg(feature = "runtime-async-std")]
+    use async_std::task::sleep;
+
+    async fn session(conn: &mut PgConnection) -> anyhow::Result<(i32, String, String, bool)> {
+        Ok(sqlx::query_as(
+            "
+SELECT pg_backend_pid(),
+       current_setting('statement_timeout'),
+       current_setting('application_name'),
+       to_regclass('pg_temp._sqlx_reset') IS NOT NULL
+            ",
+        )
+        .fetch_one(conn)
+        .await?)
+    }
+
+    for reset_on_release in &[true, false] {
+        let connects = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&connects);
+
+        // with a single connection the one that is returned is the one acquired next
+        let pool = PgPool::builder()
+            .max_size(1)
+            .reset_on_release(*reset_on_release)
+            .after_connect(move |conn| {
+                counter.fetch_add(1, Ordering::SeqCst);
+
+                Box::pin(async move {
+                    conn.execute("SET application_name = 'sqlx after connect'")
+                        .await?;
+
+                    Ok(())
+                })
+            })
+            .after_reset(|conn| {
+                Box::pin(async move {
+                    conn.execute("SET application_name = 'sqlx after reset'")
+                        .await?;
+
+                    Ok(())
+                })
+            })
+            .build(&dotenv::var("DATABASE_URL")?)
+            .await?;
+
+        let mut conn = pool.acquire().await?;
+
+        let (pid_0, _, application_name, _) = session(&mut conn).await?;
+        assert_eq!(application_name, "sqlx after connect");
+
+        conn.execute(
+            "SET statement_timeout = '1234ms'; CREATE TEMPORARY TABLE _sqlx_reset (id INT)",
+        )
+        .await?;
+
+        let statements = conn.cached_statements_size();
+        assert!(statements > 0);
+
+        drop(conn);
+
+        // the connection is idle again once it has been reset
+        while pool.num_idle() == 0 {
+            sleep(Duration::from_millis(10)).await;
+        }
+
+        let mut conn = pool.try_acquire().expect("the released connection is idle");
+        let (pid_1, statement_timeout, application_name, has_table) = session(&mut conn).await?;
+
+        // the connection is the same, with its prepared statements
+        assert_eq!(pid_0, pid_1);
+        assert_eq!(conn.cached_statements_size(), statements);
+
+        if *reset_on_release {
+            assert_eq!(statement_timeout, "0");
+            assert_eq!(application_name, "sqlx after reset");
+            assert!(!has_table);
+        } else {
+            assert_eq!(statement_timeout, "1234ms");
+            assert_eq!(application_name, "sqlx after connect");
+            assert!(has_table);
+        }
+
+        assert_eq!(connects.load(Ordering::SeqCst), 1);
+    }
+
+    // a connection can be reset on its own and keeps its prepared statements
+    let mut conn = new::<Postgres>().await?;
+
+    let (value,): (i32,) = sqlx::query_as("SELECT $1")
+        .bind(1_i32)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, 1);
+
+    conn.execute("SET statement_timeout = '1234ms'").await?;
+    conn.reset().await?;
+
+    assert_eq!(conn.cached_statements_size(), 1);
+
+    let (value,): (i32,) = sqlx::query_as("SELECT $1")
+        .bind(2_i32)
+        .fetch_one(&mut conn)
+        .await?;
+
+    let (statement_timeout,): (String,) =
+        sqlx::query_as("SELECT current_setting('statement_timeout')")
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(value, 2);
+    assert_eq!(statement_timeout, "0");
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_replaces_dead_connections_before_acquire() -> anyhow::Result<()> {
+    async fn terminate(conn: &mut PgConnection, pid: i32) -> anyhow::Result<()> {
+        sqlx::query("SELECT pg_terminate_backend($1)")
+            .bind(pid)
+            .execute(&mut *conn)
+            .await?;
+
+        // wait for the backend to exit
+        loop {
+            let (count,): (i64,) =
+                sqlx::query_as("SELECT COUNT(*) FROM pg_stat_activity WHERE pid = $1")
+                    .bind(pid)
+                    .fetch_one(&mut *conn)
+                    .await?;
+
+            if count == 0 {
+                return Ok(());
+            }
+        }
+    }
+
+    let mut conn = new::<Postgres>().await?;
+
+    for test_before_acquire in &[true, false] {
+        let pool = PgPool::builder()
+            .max_size(1)
+            .test_before_acquire(*test_before_acquire)
+            .build(&dotenv::var("DATABASE_URL")?)
+            .await?;
+
+        let (pid,): (i32,) = sqlx::query_as("SELECT pg_backend_pid()")
+            .fetch_one(&pool)
+            .await?;
+
+        terminate(&mut conn, pid).await?;
+
+        let res: Result<(i32,), _> = sqlx::query_as("SELECT pg_backend_pid()")
+            .fetch_one(&pool)
+            .await;
+
+        if *test_before_acquire {
+            // the dead connection was replaced with a new one
+            assert_ne!(res?.0, pid);
+        } else {
+            assert!(res.is_err());
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_retires_connections_past_max_lifetime() -> anyhow::Result<()> {
+    #[cfg(feature = "runtime-tokio")]
+    use tokio::time::delay_for as sleep;
+
+    #[cfg(feature = "runtime-async-std")]
+    use async_std::task::sleep;
+
+    let pool = PgPool::builder()
+        .max_size(1)
+        .max_lifetime(Duration::from_millis(200))
+        .build(&dotenv::var("DATABASE_URL")?)
+        .await?;
+
+    let mut conn = pool.acquire().await?;
+
+    let (pid,): (i32,) = sqlx::query_as("SELECT pg_backend_pid()")
+        .fetch_one(&mut conn)
+        .await?;
+
+    sleep(Duration::from_millis(300)).await;
+
+    // the connection is closed as soon as it is returned
+    drop(conn);
+
+    assert_eq!(pool.size(), 0);
+
+    let (new_pid,): (i32,) = sqlx::query_as("SELECT pg_backend_pid()")
+        .fetch_one(&pool)
+        .await?;
+
+    assert_ne!(pid, new_pid);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_reaps_idle_connections() -> anyhow::Result<()> {
+    #[cfg(feature = "runtime-tokio")]
+    use tokio::time::delay_for as sleep;
+
+    #[cfg(feature = "runtime-async-std")]
+    use async_std::task::sleep;
+
+    let pool = PgPool::builder()
+        .min_size(1)
+        .max_size(3)
+        .idle_timeout(Duration::from_millis(100))
+        .build(&dotenv::var("DATABASE_URL")?)
+        .await?;
+
+    let conns = vec![
+        pool.acquire().await?,
+        pool.acquire().await?,
+        pool.acquire().await?,
+    ];
+
+    assert_eq!(pool.size(), 3);
+
+    drop(conns);
+
+    assert_eq!(pool.num_idle(), 3);
+
+    sleep(Duration::from_millis(500)).await;
+
+    // connections are reaped down to the minimum size
+    assert_eq!(pool.size(), 1);
+    assert_eq!(pool.num_idle(), 1);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_reports_pool_metrics() -> anyhow::Result<()> {
+    #[cfg(feature = "runtime-tokio")]
+    use tokio::{task::spawn, time::delay_for as sleep};
+
+    #[cfg(feature = "runtime-async-std")]
+    use async_std::task::{sleep, spawn};
+
+    let pool = PgPool::builder()
+        .max_size(2)
+        .build(&dotenv::var("DATABASE_URL")?)
+        .await?;
+
+    assert_eq!(
+        (pool.size(), pool.num_idle(), pool.num_waiting()),
+        (0, 0, 0)
+    );
+
+    let conns = vec![pool.acquire().await?, pool.acquire().await?];
+
+    assert_eq!(
+        (pool.size(), pool.num_idle(), pool.num_waiting()),
+        (2, 0, 0)
+    );
+
+    // these have to wait for one of the connections above to be returned
+    for _ in 0..3 {
+        let pool = pool.clone();
+
+        spawn(async move {
+            let conn = pool.acquire().await.unwrap();
+
+            sleep(Duration::from_millis(10)).await;
+
+            drop(conn);
+        });
+    }
+
+    for _ in 0..100 {
+        if pool.num_waiting() == 3 {
+            break;
+        }
+
+        sleep(Duration::from_millis(10)).await;
+    }
+
+    assert_eq!(
+        (pool.size(), pool.num_idle(), pool.num_waiting()),
+        (2, 0, 3)
+    );
+
+    drop(conns);
+
+    for _ in 0..100 {
+        if pool.num_idle() == 2 && pool.num_waiting() == 0 {
+            break;
+        }
+
+        sleep(Duration::from_millis(10)).await;
+    }
+
+    assert_eq!(
+        (pool.size(), pool.num_idle(), pool.num_waiting()),
+        (2, 2, 0)
+    );
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_times_out_acquire_on_a_saturated_pool() -> anyhow::Result<()> {
+    use std::time::Instant;
+
+    let pool = PgPool::builder()
+        .max_size(1)
+        .acquire_timeout(Duration::from_secs(1))
+        .build(&dotenv::var("DATABASE_URL")?)
+        .await?;
+
+    let conn = pool.acquire().await?;
+
+    let start = Instant::now();
+    let res = pool.acquire().await;
+    let elapsed = start.elapsed();
+
+    match res {
+        Err(sqlx::Error::PoolTimedOut(_)) => {}
+        Err(e) => panic!("expected a pool timeout, got {:?}", e),
+        Ok(_) => panic!("expected a pool timeout, got a connection"),
+    }
+
+    assert!(elapsed >= Duration::from_secs(1), "{:?}", elapsed);
+    assert!(elapsed < Duration::from_secs(5), "{:?}", elapsed);
+
+    // the connection is available again once it is returned
+    drop(conn);
+
+    pool.acquire().await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_closes_all_pool_connections() -> anyhow::Result<()> {
+    #[cfg(feature = "runtime-tokio")]
+    use tokio::{task::spawn, time::delay_for as sleep};
+
+    #[cfg(feature = "runtime-async-std")]
+    use async_std::task::{sleep, spawn};
+
+    async fn sessions(conn: &mut PgConnection) -> anyhow::Result<i64> {
+        let (count,): (i64,) = sqlx::query_as(
+            "SELECT COUNT(*) FROM pg_stat_activity WHERE application_name = 'sqlx pool close'",
+        )
+        .fetch_one(conn)
+        .await?;
+
+        Ok(count)
+    }
+
+    let url = dotenv::var("DATABASE_URL")?;
+    let separator = if url.contains('?') { '&' } else { '?' };
+
+    let mut conn = new::<Postgres>().await?;
+
+    let pool = PgPool::builder()
+        .max_size(3)
+        .build(&format!(
+            "{}{}application_name=sqlx%20pool%20close",
+            url, separator
+        ))
+        .await?;
+
+    let mut conns = vec![
+        pool.acquire().await?,
+        pool.acquire().await?,
+        pool.acquire().await?,
+    ];
+
+    // keep one connection in use and return the others to the pool
+    let in_use = conns.pop().unwrap();
+    drop(conns);
+
+    assert_eq!(sessions(&mut conn).await?, 3);
+
+    let closing = {
+        let pool = pool.clone();
+
+        spawn(async move {
+            pool.close().await;
+        })
+    };
+
+    // closing waits for the connection in use
+    sleep(Duration::from_millis(50)).await;
+
+    assert!(pool.is_closed());
+    assert_eq!(pool.size(), 1);
+
+    match pool.acquire().await {
+        Err(sqlx::Error::PoolClosed) => {}
+        Err(e) => panic!("expected the pool to be closed, got {:?}", e),
+        Ok(_) => panic!("expected the pool to be closed, got a connection"),
+    }
+
+    drop(in_use);
+
+    #[cfg(feature = "runtime-tokio")]
+    closing.await?;
+
+    #[cfg(feature = "runtime-async-std")]
+    closing.await;
+
+    assert_eq!(pool.size(), 0);
+
+    // the server removes the sessions shortly after they are terminated
+    for _ in 0..100 {
+        if sessions(&mut conn).await? == 0 {
+            break;
+        }
+
+        sleep(Duration::from_millis(10)).await;
+    }
+
+    assert_eq!(sessions(&mut conn).await?, 0);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_rolls_back_savepoints() -> anyhow::Result<()> {
+    macro_rules! count {
+        ($e:expr) => {{
+            let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM savepoints")
+                .fetch_one(&mut $e)
+                .await?;
+
+            count
+        }};
+    }
+
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE savepoints (id INTEGER PRIMARY KEY)")
+        .await?;
+
+    let mut tx = conn.begin().await?;
+    tx.execute("INSERT INTO savepoints (id) VALUES (1)").await?;
+
+    // SAVEPOINT
+    let mut tx = tx.begin().await?;
+    tx.execute("INSERT INTO savepoints (id) VALUES (2)").await?;
+
+    // a nested SAVEPOINT that is rolled back
+    let mut tx = tx.begin().await?;
+    tx.execute("INSERT INTO savepoints (id) VALUES (3)").await?;
+
+    let mut tx = tx.rollback().await?;
+
+    assert_eq!(count!(tx), 2);
+
+    // another SAVEPOINT at the same depth that is released
+    let mut tx = tx.begin().await?;
+    tx.execute("INSERT INTO savepoints (id) VALUES (4)").await?;
+
+    let mut tx = tx.commit().await?;
+
+    assert_eq!(count!(tx), 3);
+
+    // rolling back the outer SAVEPOINT also drops the changes of the one it contained
+    let mut tx = tx.rollback().await?;
+
+    assert_eq!(count!(tx), 1);
+
+    let mut conn = tx.commit().await?;
+
+    assert_eq!(count!(conn), 1);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_rolls_back_dropped_transactions() -> anyhow::Result<()> {
+    // with a single connection the dropped transaction's connection is the one used next
+    let pool = PgPool::builder()
+        .max_size(1)
+        .build(&dotenv::var("DATABASE_URL")?)
+        .await?;
+
+    sqlx::query("CREATE TEMPORARY TABLE dropped_transactions (id INTEGER PRIMARY KEY)")
+        .execute(&pool)
+        .await?;
+
+    let mut tx = pool.begin().await?;
+
+    sqlx::query("INSERT INTO dropped_transactions (id) VALUES (1)")
+        .execute(&mut tx)
+        .await?;
+
+    drop(tx);
+
+    // the connection (and its temporary table) is still there but the INSERT is not
+    let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM dropped_transactions")
+        .fetch_one(&pool)
+        .await?;
+
+    assert_eq!(count, 0);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_begins_transactions_with_options() -> anyhow::Result<()> {
+    use sqlx::transaction::{AccessMode, IsolationLevel, TransactionOptions};
+
+    let mut conn = new::<Postgres>().await?;
+
+    // a read only transaction may still write to a temporary table
+    conn.execute("CREATE TABLE IF NOT EXISTS _sqlx_read_only_4213 (id INTEGER PRIMARY KEY)")
+        .await?;
+
+    let options = TransactionOptions::new()
+        .isolation_level(IsolationLevel::Serializable)
+        .access_mode(AccessMode::ReadOnly)
+        .deferrable(true);
+
+    let mut tx = conn.begin_with(options).await?;
+
+    let (isolation_level, read_only, deferrable): (String, String, String) = sqlx::query_as(
+        "SELECT current_setting('transaction_isolation'), current_setting('transaction_read_only'), current_setting('transaction_deferrable')",
+    )
+    .fetch_one(&mut tx)
+    .await?;
+
+    assert_eq!(isolation_level, "serializable");
+    assert_eq!(read_only, "on");
+    assert_eq!(deferrable, "on");
+
+    let err = tx
+        .execute("INSERT INTO _sqlx_read_only_4213 (id) VALUES (1)")
+        .await
+        .unwrap_err();
+
+    match err {
+        sqlx::Error::Database(err) => assert_eq!(err.code(), Some("25006")),
+        err => panic!("expected a database error, got {:?}", err),
+    }
+
+    let mut conn = tx.rollback().await?;
+
+    let options = TransactionOptions::new()
+        .isolation_level(IsolationLevel::RepeatableRead)
+        .access_mode(AccessMode::ReadWrite);
+
+    let mut tx = conn.begin_with(options).await?;
+
+    let (isolation_level,): (String,) =
+        sqlx::query_as("SELECT current_setting('transaction_isolation')")
+            .fetch_one(&mut tx)
+            .await?;
+
+    assert_eq!(isolation_level, "repeatable read");
+
+    // a save point can not have its own options
+    let tx = tx.begin_with(TransactionOptions::new()).await;
+
+    assert!(tx.is_err());
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_retries_transactions_on_serialization_failures() -> anyhow::Result<()> {
+    use sqlx::transaction::{IsolationLevel, TransactionOptions};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    let pool = PgPool::builder()
+        .max_size(1)
+        .build(&dotenv::var("DATABASE_URL")?)
+        .await?;
+
+    let options = TransactionOptions::new().isolation_level(IsolationLevel::Serializable);
+    let attempts = Arc::new(AtomicUsize::new(0));
+
+    // the first attempt fails as if it conflicted with another transaction
+    let value: i32 = pool
+        .transaction_with_retry(options.clone(), 3, |tx| {
+            let attempts = Arc::clone(&attempts);
+
+            Box::pin(async move {
+                if attempts.fetch_add(1, Ordering::SeqCst) == 0 {
+                    tx.execute("DO $$ BEGIN RAISE SQLSTATE '40001'; END $$")
+                        .await?;
+                }
+
+                let (value,): (i32,) = sqlx::query_as("SELECT 1").fetch_one(tx).await?;
+
+                Ok(value)
+            })
+        })
+        .await?;
+
+    assert_eq!(value, 1);
+    assert_eq!(attempts.load(Ordering::SeqCst), 2);
+
+    // the error is returned once there are no retries left
+    attempts.store(0, Ordering::SeqCst);
+
+    let err = pool
+        .transaction_with_retry(options.clone(), 2, |tx| {
+            let attempts = Arc::clone(&attempts);
+
+            Box::pin(async move {
+                attempts.fetch_add(1, Ordering::SeqCst);
+
+                tx.execute("DO $$ BEGIN RAISE SQLSTATE '40001'; END $$")
+                    .await?;
+
+                Ok(())
+            })
+        })
+        .await
+        .unwrap_err();
+
+    match err {
+        sqlx::Error::Database(err) => assert_eq!(err.code(), Some("40001")),
+        err => panic!("expected a database error, got {:?}", err),
+    }
+
+    assert_eq!(attempts.load(Ordering::SeqCst), 3);
+
+    // other errors are not retried
+    attempts.store(0, Ordering::SeqCst);
+
+    let res = pool
+        .transaction_with_retry(options, 2, |tx| {
+            let attempts = Arc::clone(&attempts);
+
+            Box::pin(async move {
+                attempts.fetch_add(1, Ordering::SeqCst);
+
+                tx.execute("SELECT 1 / 0").await?;
+
+                Ok(())
+            })
+        })
+        .await;
+
+    assert!(res.is_err());
+    assert_eq!(attempts.load(Ordering::SeqCst), 1);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_calls_the_notice_handler() -> anyhow::Result<()> {
+    use sqlx::postgres::PgSeverity;
+    use std::sync::{Arc, Mutex};
+
+    let notices = Arc::new(Mutex::new(Vec::new()));
+    let mut conn = new::<Postgres>().await?;
+
+    conn.set_notice_handler({
+        let notices = Arc::clone(&notices);
+
+        move |notice| {
+            notices.lock().unwrap().push((
+                notice.severity(),
+                notice.code().to_owned(),
+                notice.message().to_owned(),
+            ))
+        }
+    });
+
+    conn.execute("DROP TABLE IF EXISTS _sqlx_does_not_exist_8174")
+        .await?;
+
+    // notices sent in between the rows of a query do not get in the way of reading them
+    conn.execute(
+        r#"
+CREATE FUNCTION pg_temp.notice_row(i INTEGER) RETURNS INTEGER AS $$
+BEGIN
+    RAISE NOTICE 'row %', i;
+    RETURN i;
+END
+$$ LANGUAGE plpgsql;
+    "#,
+    )
+    .await?;
+
+    let rows: Vec<(i32,)> =
+        sqlx::query_as("SELECT pg_temp.notice_row(i) FROM generate_series(1, 3) AS i")
+            .fetch_all(&mut conn)
+            .await?;
+
+    assert_eq!(rows, vec![(1,), (2,), (3,)]);
+
+    conn.execute("DO $$ BEGIN RAISE WARNING 'careful'; END $$")
+        .await?;
+
+    let notices = notices.lock().unwrap();
+
+    let messages: Vec<&str> = notices.iter().map(|(_, _, message)| &**message).collect();
+
+    assert_eq!(
+        messages,
+        [
+            "table \"_sqlx_does_not_exist_8174\" does not exist, skipping",
+            "row 1",
+            "row 2",
+            "row 3",
+            "careful"
+        ]
+    );
+
+    assert_eq!(notices[0].0, PgSeverity::Notice);
+    assert_eq!(notices[0].1, "00000");
+
+    assert_eq!(notices[4].0, PgSeverity::Warning);
+    assert_eq!(notices[4].1, "01000");
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_returns_the_query_result() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE query_result (id SERIAL PRIMARY KEY, name TEXT)")
+        .await?;
+
+    let result = sqlx::query("INSERT INTO query_result (name) VALUES ($1), ($2)")
+        .bind("Alice")
+        .bind("Bob")
+        .execute(&mut conn)
+        .await?;
+
+    assert_eq!(result.rows_affected(), 2);
+
+    // postgres does not report the ID of inserted rows
+    assert_eq!(result.last_insert_id(), None);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_fetches_exactly_one_row() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let err = sqlx::query_as::<_, (i32,)>("SELECT * FROM generate_series(1, $1)")
+        .bind(0_i32)
+        .fetch_exactly_one(&mut conn)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, sqlx::Error::RowNotFound), "{:?}", err);
+
+    let (value,): (i32,) = sqlx::query_as("SELECT * FROM generate_series(1, $1)")
+        .bind(1_i32)
+        .fetch_exactly_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, 1);
+
+    let err = sqlx::query("SELECT * FROM generate_series(1, $1)")
+        .bind(2_i32)
+        .try_map(|row: PgRow| row.try_get::<i32, _>(0))
+        .fetch_exactly_one(&mut conn)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, sqlx::Error::TooManyRows), "{:?}", err);
+
+    // the connection is still usable after the remaining rows were left unread
+    let (value,): (i32,) = sqlx::query_as("SELECT 5").fetch_one(&mut conn).await?;
+
+    assert_eq!(value, 5);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_builds_queries_with_in_lists() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE query_builder (id INTEGER PRIMARY KEY, name TEXT)")
+        .await?;
+
+    conn.execute(
+        "INSERT INTO query_builder (id, name) VALUES (1, 'a'), (2, 'b'), (3, 'c'), (4, 'd')",
+    )
+    .await?;
+
+    let mut builder = sqlx::QueryBuilder::<Postgres>::new("SELECT name FROM query_builder WHERE ");
+
+    builder
+        .push("name <> ")
+        .push_bind("c")
+        .push(" AND id IN (")
+        .push_bind_separated(&[1_i32, 3, 4], ", ")
+        .push(") ORDER BY id");
+
+    assert_eq!(
+        builder.sql(),
+        "SELECT name FROM query_builder WHERE name <> $1 AND id IN ($2, $3, $4) ORDER BY id"
+    );
+
+    let names = builder
+        .build()
+        .try_map(|row: PgRow| row.try_get::<String, _>(0))
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert_eq!(names, ["a", "d"]);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_executes_values_in_chunks() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE values_chunks (id INTEGER PRIMARY KEY, name TEXT)")
+        .await?;
+
+    let mut builder = sqlx::QueryBuilder::<Postgres>::new("INSERT INTO values_chunks (id, name) ");
+
+    builder.push_values(1..=2, |mut b, id: i32| {
+        b.push_bind(id).push_bind(format!("user {}", id));
+    });
+
+    assert_eq!(
+        builder.sql(),
+        "INSERT INTO values_chunks (id, name) VALUES ($1, $2), ($3, $4)"
+    );
+
+    assert_eq!(builder.build().execute(&mut conn).await?.rows_affected(), 2);
+
+    // 200,000 bind parameters are well over the limit of 65,535 for a single statement
+    let rows = sqlx::QueryBuilder::execute_values(
+        &mut conn,
+        "INSERT INTO values_chunks (id, name) ",
+        3..=100_002,
+        2,
+        |mut b, id: i32| {
+            b.push_bind(id).push_bind(format!("user {}", id));
+        },
+    )
+    .await?;
+
+    assert_eq!(rows, 100_000);
+
+    let (count, name): (i64, String) =
+        sqlx::query_as("SELECT COUNT(*), MAX(name) FILTER (WHERE id = 100002) FROM values_chunks")
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(count, 100_002);
+    assert_eq!(name, "user 100002");
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_inspects_columns() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let mut cursor =
+        sqlx::query("SELECT 1::INT4 AS id, 'hello'::TEXT AS name, NULL::INT4").fetch(&mut conn);
+
+    let row = cursor.next().await?.unwrap();
+
+    let id = row.column(0);
+    assert_eq!(id.name(), Some("id"));
+    assert_eq!(id.ordinal(), 0);
+    assert_eq!(id.type_info().unwrap().to_string(), "INT4");
+
+    let name = row.column("name");
+    assert_eq!(name.ordinal(), 1);
+    assert_eq!(name.type_info().unwrap().to_string(), "TEXT");
+
+    assert_eq!(row.column(2).type_info().unwrap().to_string(), "INT4");
+    assert!(row.try_column(3).is_err());
+
+    let raw = row.try_get_raw(0)?;
+    assert!(matches!(raw.get(), Some(PgData::Binary(_))));
+    assert_eq!(raw.type_info().unwrap().to_string(), "INT4");
+
+    // a NULL has no type
+    assert!(row.try_get_raw(2)?.type_info().is_none());
+
+    Ok(())
+}
+
+#[cfg(feature = "json")]
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_converts_rows_to_json() -> anyhow::Result<()> {
+    use serde_json::json;
+    use sqlx::types::json::row_to_json;
+
+    let mut conn = new::<Postgres>().await?;
+
+    let query = r#"
+SELECT 1::INT4 AS id, 'Herp Derpinson'::TEXT AS name, TRUE AS active, 1.5::FLOAT8 AS ratio,
+    '\xdeadbeef'::BYTEA AS avatar, '{"admin": false}'::JSONB AS meta, NULL::TEXT AS email
+    "#;
+
+    let expected = json!({
+        "id": 1,
+        "name": "Herp Derpinson",
+        "active": true,
+        "ratio": 1.5,
+        "avatar": "3q2+7w==",
+        "meta": { "admin": false },
+        "email": null,
+    });
+
+    // prepared queries receive values in the binary format
+    let mut cursor = sqlx::query(query).fetch(&mut conn);
+    let row = cursor.next().await?.unwrap();
+    assert_eq!(row_to_json(&row)?, expected);
+    drop(cursor);
+
+    // and simple queries in the text format
+    let mut cursor = conn.fetch(query);
+    let row = cursor.next().await?.unwrap();
+    assert_eq!(row_to_json(&row)?, expected);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_reports_the_column_of_errors() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let mut cursor =
+        sqlx::query("SELECT 1::INT4 AS id, 'Herp Derpinson'::TEXT AS name, NULL::TEXT AS email")
+            .fetch(&mut conn);
+
+    let row = cursor.next().await?.unwrap();
+
+    let err = row.try_get::<i32, _>("mail").unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "no column found with the name \"mail\"; the columns are \"id\", \"name\", \"email\""
+    );
+
+    let err = row.try_get::<i32, _>("name").unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "error decoding column \"name\" of SQL type TEXT: mismatched types; \
+         Rust type `i32` (as SQL type INT4) is not compatible with SQL type TEXT"
+    );
+
+    let err = row.try_get::<String, _>("email").unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "error decoding column \"email\" of SQL type TEXT: \
+         unexpected null; try decoding as an `Option`"
+    );
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_classifies_constraint_violations() -> anyhow::Result<()> {
+    use sqlx::error::DatabaseErrorKind;
+
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE error_teams (id INT4 PRIMARY KEY);
+CREATE TEMPORARY TABLE error_users (
+    name TEXT NOT NULL CONSTRAINT error_users_name_key UNIQUE,
+    team INT4 CONSTRAINT error_users_team_fkey REFERENCES error_teams (id)
+);
+INSERT INTO error_users (name) VALUES ('Alice');
+        "#,
+    )
+    .await?;
+
+    let err = sqlx::query("INSERT INTO error_users (name) VALUES ($1)")
+        .bind("Alice")
+        .execute(&mut conn)
+        .await
+        .unwrap_err();
+
+    let err = match err {
+        sqlx::Error::Database(err) => err,
+        err => panic!("expected a database error but received {:?}", err),
+    };
+
+    assert_eq!(err.code(), Some("23505"));
+    assert_eq!(err.kind(), DatabaseErrorKind::UniqueViolation);
+    assert_eq!(err.constraint_name(), Some("error_users_name_key"));
+    assert_eq!(err.table_name(), Some("error_users"));
+
+    let err = sqlx::query("INSERT INTO error_users (name, team) VALUES ($1, $2)")
+        .bind("Bob")
+        .bind(1_i32)
+        .execute(&mut conn)
+        .await
+        .unwrap_err();
+
+    match err {
+        sqlx::Error::Database(err) => {
+            assert_eq!(err.kind(), DatabaseErrorKind::ForeignKeyViolation);
+            assert_eq!(err.constraint_name(), Some("error_users_team_fkey"));
+        }
+
+        err => panic!("expected a database error but received {:?}", err),
+    }
+
+    let err = conn
+        .execute("INSERT INTO error_users (name) VALUES (NULL)")
+        .await
+        .unwrap_err();
+
+    match err {
+        sqlx::Error::Database(err) => {
+            assert_eq!(err.kind(), DatabaseErrorKind::NotNullViolation);
+            assert_eq!(err.column_name(), Some("name"));
+        }
+
+        err => panic!("expected a database error but received {:?}", err),
+    }
+
+    match conn.execute("SELECT 1 / 0").await.unwrap_err() {
+        sqlx::Error::Database(err) => assert_eq!(err.kind(), DatabaseErrorKind::Other),
+        err => panic!("expected a database error but received {:?}", err),
+    }
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_detects_constraint_violations() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE violation_teams (id INT4 PRIMARY KEY);
+CREATE TEMPORARY TABLE violation_users (
+    name TEXT NOT NULL UNIQUE,
+    age INT4 CHECK (age >= 0),
+    team INT4 REFERENCES violation_teams (id)
+);
+INSERT INTO violation_users (name) VALUES ('Alice');
+        "#,
+    )
+    .await?;
+
+    let err = conn
+        .execute("INSERT INTO violation_users (name) VALUES ('Alice')")
+        .await
+        .unwrap_err();
+
+    assert!(err.is_unique_violation(), "{:?}", err);
+    assert!(!err.is_foreign_key_violation());
+
+    let err = conn
+        .execute("INSERT INTO violation_users (name, team) VALUES ('Bob', 1)")
+        .await
+        .unwrap_err();
+
+    assert!(err.is_foreign_key_violation(), "{:?}", err);
+    assert!(!err.is_unique_violation());
+
+    let err = conn
+        .execute("INSERT INTO violation_users (name, age) VALUES ('Bob', -1)")
+        .await
+        .unwrap_err();
+
+    assert!(err.is_check_violation(), "{:?}", err);
+
+    let err = conn
+        .execute("INSERT INTO violation_users (name) VALUES (NULL)")
+        .await
+        .unwrap_err();
+
+    assert!(err.is_not_null_violation(), "{:?}", err);
+    assert!(!err.is_check_violation());
+
+    assert!(!sqlx::Error::RowNotFound.is_unique_violation());
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_binds_a_typed_null() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let (value, ty, is_null): (Option<i32>, String, bool) =
+        sqlx::query_as("SELECT $1, pg_typeof($1)::text, $1 IS NULL")
+            .bind_null::<i32>()
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(value, None);
+    assert_eq!(ty, "integer");
+    assert!(is_null);
+
+    let mut cursor = sqlx::query("SELECT COALESCE($1, 42)")
+        .bind_null::<i32>()
+        .fetch(&mut conn);
+
+    let row = cursor.next().await?.unwrap();
+
+    assert_eq!(row.get::<i32, _>(0), 42);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_binds_a_value_as_another_type() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    // a `TEXT` parameter can not be added to an integer
+    let res = sqlx::query("SELECT $1 + 1")
+        .bind("41")
+        .execute(&mut conn)
+        .await;
+
+    assert!(res.is_err());
+
+    let (value,): (i64,) = sqlx::query_as("SELECT $1 + 1")
+        .bind_as::<i64, _>("41")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, 42);
+
+    // the other parameters are still sent in binary
+    let (sum, ty, is_null): (i64, String, bool) =
+        sqlx::query_as("SELECT $1 + $2, pg_typeof($2)::text, $3 IS NULL")
+            .bind(40_i64)
+            .bind_as::<i64, _>(2_i32)
+            .bind_null::<i32>()
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(sum, 42);
+    assert_eq!(ty, "bigint");
+    assert!(is_null);
+
+    Ok(())
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_maps_the_rows_of_insert_returning() -> anyhow::Result<()> {
+    use sqlx::types::chrono::{DateTime, Utc};
+
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE accounts (
+    id SERIAL PRIMARY KEY,
+    name TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+        "#,
+    )
+    .await?;
+
+    let before: DateTime<Utc> = sqlx::query_as::<_, (DateTime<Utc>,)>("SELECT now()")
+        .fetch_one(&mut conn)
+        .await?
+        .0;
+
+    for (expected_id, name) in (1..).zip(&["Herp Derpinson", "Derp Herpinson"]) {
+        let (id, created_at): (i32, DateTime<Utc>) =
+            sqlx::query_as("INSERT INTO accounts (name) VALUES ($1) RETURNING id, created_at")
+                .bind(name)
+                .fetch_one(&mut conn)
+                .await?;
+
+        assert_eq!(id, expected_id);
+        assert!(created_at >= before);
+    }
+
+    let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM accounts")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 2);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_executes_many_statements() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let rows_affected: Vec<u64> = conn
+        .execute_many(
+            r#"
+CREATE TEMPORARY TABLE numbers (n INTEGER NOT NULL);
+INSERT INTO numbers (n) VALUES (1), (2), (3);
+DELETE FROM numbers WHERE n > 1;
+            "#,
+        )
+        .map_ok(|result| result.rows_affected())
+        .try_collect()
+        .await?;
+
+    assert_eq!(rows_affected, [0, 3, 2]);
+
+    // a statement that fails rolls back the statements before it
+    let mut results = conn.execute_many(
+        "INSERT INTO numbers (n) VALUES (4); INSERT INTO numbers (n) VALUES (NULL); \
+         INSERT INTO numbers (n) VALUES (5);",
+    );
+
+    assert_eq!(results.try_next().await?.unwrap().rows_affected(), 1);
+    assert!(results.try_next().await.is_err());
+
+    drop(results);
+
+    let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM numbers")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 1);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_describes_a_query() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE describe_test (
+    id INTEGER PRIMARY KEY,
+    name TEXT NOT NULL,
+    hash BYTEA
+)
+        "#,
+    )
+    .await?;
+
+    let describe = conn
+        .describe("SELECT id, name, hash FROM describe_test WHERE id = $1")
+        .await?;
+
+    let type_name = |type_info: &Option<_>| type_info.as_ref().map(ToString::to_string);
+
+    assert_eq!(describe.param_types.len(), 1);
+    assert_eq!(type_name(&describe.param_types[0]).as_deref(), Some("INT4"));
+
+    let columns: Vec<_> = describe
+        .result_columns
+        .iter()
+        .map(|column| {
+            (
+                column.name.as_deref(),
+                type_name(&column.type_info),
+                column.non_null,
+            )
+        })
+        .collect();
+
+    assert_eq!(
+        columns,
+        vec![
+            (Some("id"), Some("INT4".to_owned()), Some(true)),
+            (Some("name"), Some("TEXT".to_owned()), Some(true)),
+            (Some("hash"), Some("BYTEA".to_owned()), Some(false)),
+        ]
+    );
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_describes_the_nullability_of_columns() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE nullable_test (
+    id INTEGER NOT NULL,
+    name TEXT
+)
+        "#,
+    )
+    .await?;
+
+    let describe = conn
+        .describe("SELECT id, name, id + 1 AS next_id FROM nullable_test")
+        .await?;
+
+    assert_eq!(describe.nullable(), vec![Some(false), Some(true), None]);
+
+    Ok(())
+}
@@ -1,5 +1,6 @@
-use sqlx::Sqlite;
-use sqlx_test::test_type;
+use sqlx::sqlite::SqliteQueryAs;
+use sqlx::{Executor, Sqlite};
+use sqlx_test::{new, test_type};
 use std::fmt::Debug;
 
 // Transparent types are rust-side wrappers over DB types
@@ -41,3 +42,146 @@ test_type!(weak_enum(
 ));
 
 test_type!(strong_color_enum(Sqlite, Color, "'green'" == Color::Green));
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_from_row_with_flatten() -> anyhow::Result<()> {
+    #[derive(Debug, sqlx::FromRow)]
+    struct Account {
+        id: i32,
+        name: String,
+    }
+
+    #[derive(Debug, Default, sqlx::FromRow)]
+    struct Address {
+        #[sqlx(rename = "address_city")]
+        city: String,
+
+        #[sqlx(default)]
+        country: Option<String>,
+    }
+
+    #[derive(Debug, sqlx::FromRow)]
+    struct AccountWithAddress {
+        #[sqlx(flatten)]
+        account: Account,
+
+        #[sqlx(flatten)]
+        address: Address,
+
+        #[sqlx(default)]
+        visits: i32,
+    }
+
+    let mut conn = new::<Sqlite>().await?;
+
+    let account: AccountWithAddress =
+        sqlx::query_as("SELECT 1 AS id, 'Herp Derpinson' AS name, 'Portland' AS address_city")
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(account.account.id, 1);
+    assert_eq!(account.account.name, "Herp Derpinson");
+    assert_eq!(account.address.city, "Portland");
+    assert_eq!(account.address.country, None);
+    assert_eq!(account.visits, 0);
+
+    let account: AccountWithAddress = sqlx::query_as(
+        "SELECT 1 AS id, 'Herp Derpinson' AS name, 'Portland' AS address_city, 'US' AS country, 5 AS visits",
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(account.address.country.as_deref(), Some("US"));
+    assert_eq!(account.visits, 5);
+
+    // the flattened struct still needs the columns without a default
+    let res: sqlx::Result<AccountWithAddress> =
+        sqlx::query_as("SELECT 1 AS id, 'Herp Derpinson' AS name")
+            .fetch_one(&mut conn)
+            .await;
+
+    assert!(matches!(res, Err(sqlx::Error::ColumnNotFound { .. })));
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_from_row_with_prefix() -> anyhow::Result<()> {
+    #[derive(Debug, sqlx::FromRow)]
+    #[sqlx(rename_all = "camelCase")]
+    struct Account {
+        id: i32,
+        display_name: String,
+
+        #[sqlx(rename = "mail")]
+        email: Option<String>,
+    }
+
+    #[derive(Debug, sqlx::FromRow)]
+    struct Comment {
+        id: i32,
+
+        #[sqlx(flatten, prefix = "author_")]
+        author: Account,
+    }
+
+    #[derive(Debug, sqlx::FromRow)]
+    struct Post {
+        id: i32,
+        title: String,
+
+        #[sqlx(flatten, prefix = "author_")]
+        author: Account,
+
+        // prefixes are joined, e.g. `comment_author_id`
+        #[sqlx(flatten, prefix = "comment_")]
+        comment: Comment,
+    }
+
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE accounts (id INTEGER PRIMARY KEY, name TEXT NOT NULL, email TEXT);
+CREATE TEMPORARY TABLE posts (id INTEGER PRIMARY KEY, title TEXT NOT NULL, author_id INTEGER NOT NULL);
+CREATE TEMPORARY TABLE comments (id INTEGER PRIMARY KEY, post_id INTEGER NOT NULL, author_id INTEGER NOT NULL);
+
+INSERT INTO accounts (id, name, email) VALUES (1, 'Herp Derpinson', NULL), (2, 'Derp Herpinson', 'derp@example.com');
+INSERT INTO posts (id, title, author_id) VALUES (1, 'Hello', 1);
+INSERT INTO comments (id, post_id, author_id) VALUES (3, 1, 2);
+        "#,
+    )
+    .await?;
+
+    let post: Post = sqlx::query_as(
+        r#"
+SELECT posts.id, posts.title,
+    authors.id AS author_id, authors.name AS author_displayName, authors.email AS author_mail,
+    comments.id AS comment_id, commenters.id AS comment_author_id,
+    commenters.name AS comment_author_displayName, commenters.email AS comment_author_mail
+FROM posts
+JOIN accounts authors ON authors.id = posts.author_id
+JOIN comments ON comments.post_id = posts.id
+JOIN accounts commenters ON commenters.id = comments.author_id
+        "#,
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(post.id, 1);
+    assert_eq!(post.title, "Hello");
+    assert_eq!(post.author.id, 1);
+    assert_eq!(post.author.display_name, "Herp Derpinson");
+    assert_eq!(post.author.email, None);
+    assert_eq!(post.comment.id, 3);
+    assert_eq!(post.comment.author.id, 2);
+    assert_eq!(post.comment.author.display_name, "Derp Herpinson");
+    assert_eq!(
+        post.comment.author.email.as_deref(),
+        Some("derp@example.com")
+    );
+
+    Ok(())
+}
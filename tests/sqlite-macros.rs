@@ -17,6 +17,26 @@ async fn macro_select() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn macro_select_nullability_override() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let account = sqlx::query!(
+        r#"select id as "id?", name, score as "score!" from accounts where score is not null"#
+    )
+    .fetch_optional(&mut conn)
+    .await?;
+
+    if let Some(account) = account {
+        let _: Option<i32> = account.id;
+        let _: String = account.name;
+        let _: f32 = account.score;
+    }
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "runtime-async-std", async_std::test)]
 #[cfg_attr(feature = "runtime-tokio", tokio::test)]
 async fn macro_select_bind() -> anyhow::Result<()> {
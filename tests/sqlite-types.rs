@@ -44,3 +44,82 @@ test_type!(bytes(
     "X'0000000052'"
         == vec![0_u8, 0, 0, 0, 0x52]
 ));
+
+#[cfg(feature = "chrono")]
+mod chrono {
+    use super::*;
+    use sqlx::sqlite::SqliteQueryAs;
+    use sqlx::types::chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+    use sqlx_test::test_unprepared_type;
+
+    test_type!(chrono_naive_date_time(
+        Sqlite,
+        NaiveDateTime,
+        "SELECT datetime({0}) is datetime(?), cast(? as text) as _1, {0} as _2, ? as _3",
+        "'2019-01-02 05:10:20'" == NaiveDate::from_ymd(2019, 1, 2).and_hms(5, 10, 20),
+        "'2019-01-02T05:10:20.115'"
+            == NaiveDate::from_ymd(2019, 1, 2).and_hms_milli(5, 10, 20, 115),
+        "datetime('2019-01-02 05:10:20', '+1 day')"
+            == NaiveDate::from_ymd(2019, 1, 3).and_hms(5, 10, 20)
+    ));
+
+    test_type!(chrono_date_time_utc(
+        Sqlite,
+        DateTime<Utc>,
+        "SELECT datetime({0}) is datetime(?), cast(? as text) as _1, {0} as _2, ? as _3",
+        "'2019-01-02T05:10:20Z'"
+            == DateTime::<Utc>::from_utc(NaiveDate::from_ymd(2019, 1, 2).and_hms(5, 10, 20), Utc),
+        "'2019-01-02 07:10:20.115+02:00'"
+            == DateTime::<Utc>::from_utc(
+                NaiveDate::from_ymd(2019, 1, 2).and_hms_milli(5, 10, 20, 115),
+                Utc
+            ),
+        "datetime('2019-01-02 05:10:20')"
+            == DateTime::<Utc>::from_utc(NaiveDate::from_ymd(2019, 1, 2).and_hms(5, 10, 20), Utc)
+    ));
+
+    test_type!(chrono_naive_date(
+        Sqlite,
+        NaiveDate,
+        "SELECT date({0}) is date(?), cast(? as text) as _1, {0} as _2, ? as _3",
+        "'2019-01-02'" == NaiveDate::from_ymd(2019, 1, 2),
+        "date('2019-01-02', '+1 month')" == NaiveDate::from_ymd(2019, 2, 2)
+    ));
+
+    // Dates are also read from Julian day numbers and Unix timestamps
+    test_unprepared_type!(chrono_naive_date_time_numeric(
+        Sqlite,
+        NaiveDateTime,
+        "julianday('2019-01-02 05:10:20.115')"
+            == NaiveDate::from_ymd(2019, 1, 2).and_hms_milli(5, 10, 20, 115),
+        "CAST(strftime('%s', '2019-01-02 05:10:20') AS INTEGER)"
+            == NaiveDate::from_ymd(2019, 1, 2).and_hms(5, 10, 20),
+        "datetime('2019-01-02 05:10:20')" == NaiveDate::from_ymd(2019, 1, 2).and_hms(5, 10, 20)
+    ));
+
+    test_unprepared_type!(chrono_naive_date_datetime(
+        Sqlite,
+        NaiveDate,
+        "'2019-01-02 05:10:20'" == NaiveDate::from_ymd(2019, 1, 2),
+        "julianday('2019-01-02')" == NaiveDate::from_ymd(2019, 1, 2)
+    ));
+
+    #[cfg_attr(feature = "runtime-async-std", async_std::test)]
+    #[cfg_attr(feature = "runtime-tokio", tokio::test)]
+    async fn test_invalid_datetime_text() -> anyhow::Result<()> {
+        let mut conn = sqlx_test::new::<Sqlite>().await?;
+
+        let err = sqlx::query_as::<_, (NaiveDateTime,)>("SELECT '01/02/2019 05:10'")
+            .fetch_one(&mut conn)
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "error decoding column \"'01/02/2019 05:10'\" of SQL type TEXT: \
+             invalid date and time \"01/02/2019 05:10\""
+        );
+
+        Ok(())
+    }
+}
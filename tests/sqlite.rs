@@ -1,5 +1,13 @@
 use futures::TryStreamExt;
-use sqlx::{sqlite::SqliteQueryAs, Connect, Connection, Executor, Sqlite, SqliteConnection};
+use std::time::Duration;
+
+use sqlx::{
+    sqlite::{
+        SqliteConnectOptions, SqliteJournalMode, SqliteQueryAs, SqliteRow, SqliteSynchronous,
+    },
+    value::RawValue,
+    Connect, Connection, Cursor, Executor, Row, Sqlite, SqliteConnection, SqlitePool,
+};
 use sqlx_test::new;
 
 #[cfg_attr(feature = "runtime-async-std", async_std::test)]
@@ -37,13 +45,115 @@ async fn it_fails_to_parse() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_classifies_constraint_violations() -> anyhow::Result<()> {
+    use sqlx::error::DatabaseErrorKind;
+
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE error_users (name TEXT NOT NULL UNIQUE, age INTEGER CHECK (age >= 0));
+INSERT INTO error_users (name) VALUES ('Alice');
+        "#,
+    )
+    .await?;
+
+    let cases = vec![
+        (
+            "INSERT INTO error_users (name) VALUES ('Alice')",
+            "2067",
+            DatabaseErrorKind::UniqueViolation,
+        ),
+        (
+            "INSERT INTO error_users (name) VALUES (NULL)",
+            "1299",
+            DatabaseErrorKind::NotNullViolation,
+        ),
+        (
+            "INSERT INTO error_users (name, age) VALUES ('Bob', -1)",
+            "275",
+            DatabaseErrorKind::CheckViolation,
+        ),
+    ];
+
+    for (query, code, kind) in cases {
+        match conn.execute(query).await.unwrap_err() {
+            sqlx::Error::Database(err) => {
+                assert_eq!(err.code(), Some(code), "{}", query);
+                assert_eq!(err.kind(), kind, "{}", query);
+            }
+
+            err => panic!("expected a database error but received {:?}", err),
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_detects_constraint_violations() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute(
+        r#"
+PRAGMA foreign_keys = ON;
+CREATE TEMPORARY TABLE violation_teams (id INTEGER PRIMARY KEY);
+CREATE TEMPORARY TABLE violation_users (
+    name TEXT NOT NULL UNIQUE,
+    age INTEGER CHECK (age >= 0),
+    team INTEGER REFERENCES violation_teams (id)
+);
+INSERT INTO violation_users (name) VALUES ('Alice');
+        "#,
+    )
+    .await?;
+
+    let err = conn
+        .execute("INSERT INTO violation_users (name) VALUES ('Alice')")
+        .await
+        .unwrap_err();
+
+    assert!(err.is_unique_violation(), "{:?}", err);
+    assert!(!err.is_foreign_key_violation());
+
+    let err = conn
+        .execute("INSERT INTO violation_users (name, team) VALUES ('Bob', 1)")
+        .await
+        .unwrap_err();
+
+    assert!(err.is_foreign_key_violation(), "{:?}", err);
+    assert!(!err.is_unique_violation());
+
+    let err = conn
+        .execute("INSERT INTO violation_users (name, age) VALUES ('Bob', -1)")
+        .await
+        .unwrap_err();
+
+    assert!(err.is_check_violation(), "{:?}", err);
+
+    let err = conn
+        .execute("INSERT INTO violation_users (name) VALUES (NULL)")
+        .await
+        .unwrap_err();
+
+    assert!(err.is_not_null_violation(), "{:?}", err);
+    assert!(!err.is_check_violation());
+
+    assert!(!sqlx::Error::RowNotFound.is_unique_violation());
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "runtime-async-std", async_std::test)]
 #[cfg_attr(feature = "runtime-tokio", tokio::test)]
 async fn it_handles_empty_queries() -> anyhow::Result<()> {
     let mut conn = new::<Sqlite>().await?;
     let affected = conn.execute("").await?;
 
-    assert_eq!(affected, 0);
+    assert_eq!(affected.rows_affected(), 0);
 
     Ok(())
 }
@@ -67,7 +177,7 @@ CREATE TEMPORARY TABLE users (id INTEGER PRIMARY KEY)
             .execute(&mut conn)
             .await?;
 
-        assert_eq!(cnt, 1);
+        assert_eq!(cnt.rows_affected(), 1);
     }
 
     let sum: i32 = sqlx::query_as("SELECT id FROM users")
@@ -94,7 +204,7 @@ INSERT INTO users DEFAULT VALUES;
         )
         .await?;
 
-    assert_eq!(affected, 1);
+    assert_eq!(affected.rows_affected(), 1);
 
     for index in 2..5_i32 {
         let (id, other): (i32, i32) = sqlx::query_as(
@@ -210,3 +320,853 @@ CREATE TEMPORARY TABLE describe_test (
 
     Ok(())
 }
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_rolls_back_savepoints() -> anyhow::Result<()> {
+    macro_rules! count {
+        ($e:expr) => {{
+            let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM savepoints")
+                .fetch_one(&mut $e)
+                .await?;
+
+            count
+        }};
+    }
+
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE savepoints (id INTEGER PRIMARY KEY)")
+        .await?;
+
+    let mut tx = conn.begin().await?;
+    tx.execute("INSERT INTO savepoints (id) VALUES (1)").await?;
+
+    // SAVEPOINT
+    let mut tx = tx.begin().await?;
+    tx.execute("INSERT INTO savepoints (id) VALUES (2)").await?;
+
+    // a nested SAVEPOINT that is rolled back
+    let mut tx = tx.begin().await?;
+    tx.execute("INSERT INTO savepoints (id) VALUES (3)").await?;
+
+    let mut tx = tx.rollback().await?;
+
+    assert_eq!(count!(tx), 2);
+
+    // another SAVEPOINT at the same depth that is released
+    let mut tx = tx.begin().await?;
+    tx.execute("INSERT INTO savepoints (id) VALUES (4)").await?;
+
+    let mut tx = tx.commit().await?;
+
+    assert_eq!(count!(tx), 3);
+
+    // rolling back the outer SAVEPOINT also drops the changes of the one it contained
+    let mut tx = tx.rollback().await?;
+
+    assert_eq!(count!(tx), 1);
+
+    let mut conn = tx.commit().await?;
+
+    assert_eq!(count!(conn), 1);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_rolls_back_dropped_transactions() -> anyhow::Result<()> {
+    // with a single connection the dropped transaction's connection is the one used next
+    let pool = SqlitePool::builder()
+        .max_size(1)
+        .build(&dotenv::var("DATABASE_URL")?)
+        .await?;
+
+    sqlx::query("CREATE TEMPORARY TABLE dropped_transactions (id INTEGER PRIMARY KEY)")
+        .execute(&pool)
+        .await?;
+
+    let mut tx = pool.begin().await?;
+
+    sqlx::query("INSERT INTO dropped_transactions (id) VALUES (1)")
+        .execute(&mut tx)
+        .await?;
+
+    drop(tx);
+
+    // the connection (and its temporary table) is still there but the INSERT is not
+    let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM dropped_transactions")
+        .fetch_one(&pool)
+        .await?;
+
+    assert_eq!(count, 0);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_resets_connections_returned_to_the_pool() -> anyhow::Result<()> {
+    for reset_on_release in &[true, false] {
+        // with a single connection the one that is returned is the one acquired next
+        let pool = SqlitePool::builder()
+            .max_size(1)
+            .reset_on_release(*reset_on_release)
+            .build(&dotenv::var("DATABASE_URL")?)
+            .await?;
+
+        let mut conn = pool.acquire().await?;
+
+        conn.execute(
+            "
+CREATE TEMPORARY TABLE reset_users (id INTEGER PRIMARY KEY);
+CREATE TEMPORARY VIEW reset_view AS SELECT id FROM reset_users;
+            ",
+        )
+        .await?;
+
+        drop(conn);
+
+        let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM temp.sqlite_master")
+            .fetch_one(&pool)
+            .await?;
+
+        assert_eq!(count, if *reset_on_release { 0 } else { 2 });
+    }
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_begins_transactions_with_options() -> anyhow::Result<()> {
+    use sqlx::transaction::{AccessMode, IsolationLevel, TransactionOptions};
+
+    let conn = new::<Sqlite>().await?;
+
+    let options = TransactionOptions::new()
+        .isolation_level(IsolationLevel::ReadCommitted)
+        .access_mode(AccessMode::ReadWrite);
+
+    let tx = conn.begin_with(options).await?;
+    let conn = tx.rollback().await?;
+
+    // read only transactions are not supported
+    let tx = conn
+        .begin_with(TransactionOptions::new().access_mode(AccessMode::ReadOnly))
+        .await;
+
+    assert!(tx.is_err());
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_returns_the_query_result() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE query_result (id INTEGER PRIMARY KEY, name TEXT)")
+        .await?;
+
+    let result = sqlx::query("INSERT INTO query_result (name) VALUES (?)")
+        .bind("Alice")
+        .execute(&mut conn)
+        .await?;
+
+    assert_eq!(result.rows_affected(), 1);
+    assert_eq!(result.last_insert_id(), Some(1));
+
+    // the ROWID of the last row inserted by a statement is reported
+    let result = sqlx::query("INSERT INTO query_result (name) VALUES (?), (?)")
+        .bind("Bob")
+        .bind("Carol")
+        .execute(&mut conn)
+        .await?;
+
+    assert_eq!(result.rows_affected(), 2);
+    assert_eq!(result.last_insert_id(), Some(3));
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_fetches_exactly_one_row() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE exactly_one (id INTEGER PRIMARY KEY, name TEXT)")
+        .await?;
+
+    conn.execute("INSERT INTO exactly_one (name) VALUES ('Alice'), ('Bob'), ('Bob')")
+        .await?;
+
+    let err = sqlx::query_as::<_, (i32,)>("SELECT id FROM exactly_one WHERE name = ?")
+        .bind("Carol")
+        .fetch_exactly_one(&mut conn)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, sqlx::Error::RowNotFound), "{:?}", err);
+
+    let (id,): (i32,) = sqlx::query_as("SELECT id FROM exactly_one WHERE name = ?")
+        .bind("Alice")
+        .fetch_exactly_one(&mut conn)
+        .await?;
+
+    assert_eq!(id, 1);
+
+    let err = sqlx::query_as::<_, (i32,)>("SELECT id FROM exactly_one WHERE name = ?")
+        .bind("Bob")
+        .fetch_exactly_one(&mut conn)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, sqlx::Error::TooManyRows), "{:?}", err);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_builds_queries_with_in_lists() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE query_builder (id INTEGER PRIMARY KEY, name TEXT)")
+        .await?;
+
+    conn.execute(
+        "INSERT INTO query_builder (id, name) VALUES (1, 'a'), (2, 'b'), (3, 'c'), (4, 'd')",
+    )
+    .await?;
+
+    let mut builder = sqlx::QueryBuilder::<Sqlite>::new("SELECT name FROM query_builder WHERE ");
+
+    builder
+        .push("name <> ")
+        .push_bind("c")
+        .push(" AND id IN (")
+        .push_bind_separated(&[1_i32, 3, 4], ", ")
+        .push(") ORDER BY id");
+
+    assert_eq!(
+        builder.sql(),
+        "SELECT name FROM query_builder WHERE name <> ? AND id IN (?, ?, ?) ORDER BY id"
+    );
+
+    let names = builder
+        .build()
+        .try_map(|row: SqliteRow| row.try_get::<String, _>(0))
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert_eq!(names, ["a", "d"]);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_executes_values_in_chunks() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE values_chunks (id INTEGER PRIMARY KEY, name TEXT)")
+        .await?;
+
+    // SQLite allows no more than 999 bind parameters in a single statement
+    let rows = sqlx::QueryBuilder::execute_values(
+        &mut conn,
+        "INSERT INTO values_chunks (id, name) ",
+        1..=5_000,
+        2,
+        |mut b, id: i32| {
+            b.push_bind(id).push_bind(format!("user {}", id));
+        },
+    )
+    .await?;
+
+    assert_eq!(rows, 5_000);
+
+    let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM values_chunks")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 5_000);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_streams_blobs() -> anyhow::Result<()> {
+    #[cfg(feature = "runtime-async-std")]
+    use async_std::io::prelude::{ReadExt, WriteExt};
+    #[cfg(feature = "runtime-tokio")]
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE blobs (id INTEGER PRIMARY KEY, data BLOB NOT NULL)")
+        .await?;
+
+    let data: Vec<u8> = (0..4 * 1024 * 1024).map(|i| (i % 251) as u8).collect();
+
+    sqlx::query("INSERT INTO blobs (id, data) VALUES (1, zeroblob(?))")
+        .bind(data.len() as i64)
+        .execute(&mut conn)
+        .await?;
+
+    let mut blob = conn.blob_open("temp", "blobs", "data", 1, false).await?;
+
+    assert_eq!(blob.len(), data.len());
+
+    for chunk in data.chunks(64 * 1024) {
+        blob.write_all(chunk).await?;
+    }
+
+    // the BLOB can not grow
+    assert!(blob.write_all(b"more").await.is_err());
+
+    drop(blob);
+
+    let mut blob = conn.blob_open("temp", "blobs", "data", 1, true).await?;
+    let mut read = Vec::new();
+    let mut chunk = vec![0; 100_000];
+
+    loop {
+        let len = blob.read(&mut chunk).await?;
+
+        if len == 0 {
+            break;
+        }
+
+        read.extend_from_slice(&chunk[..len]);
+    }
+
+    assert!(read == data);
+
+    drop(blob);
+
+    // the data is visible to queries
+    let (stored,): (Vec<u8>,) = sqlx::query_as("SELECT data FROM blobs WHERE id = 1")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert!(stored == data);
+
+    // a read only BLOB can not be written to
+    let mut blob = conn.blob_open("temp", "blobs", "data", 1, true).await?;
+
+    assert!(blob.write_all(b"data").await.is_err());
+
+    drop(blob);
+
+    // there is no such row or column
+    let err = conn
+        .blob_open("temp", "blobs", "data", 2, true)
+        .await
+        .err()
+        .unwrap();
+
+    assert_eq!(err.to_string(), "no such rowid: 2");
+
+    let err = conn
+        .blob_open("temp", "blobs", "other", 1, true)
+        .await
+        .err()
+        .unwrap();
+
+    assert_eq!(err.to_string(), "no such column: \"other\"");
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_calls_user_defined_functions() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.create_scalar_function("rust_upper", 1, |ctx| {
+        Ok(ctx.try_get::<Option<&str>>(0)?.map(str::to_uppercase))
+    })?;
+
+    let (upper, null): (String, Option<String>) =
+        sqlx::query_as("SELECT rust_upper(?), rust_upper(NULL)")
+            .bind("hello")
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(upper, "HELLO");
+    assert_eq!(null, None);
+
+    // any number of arguments
+    conn.create_scalar_function("rust_sum", -1, |ctx| {
+        (0..ctx.len()).try_fold(0_i64, |sum, index| Ok(sum + ctx.try_get::<i64>(index)?))
+    })?;
+
+    let (sum,): (i64,) = sqlx::query_as("SELECT rust_sum(1, 2, 3, 4)")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(sum, 10);
+
+    // an error from the function is an error of the statement
+    conn.create_scalar_function("rust_fail", 0, |_| -> sqlx::Result<i32> {
+        Err(sqlx::Error::Protocol("it failed".into()))
+    })?;
+
+    let err = sqlx::query("SELECT rust_fail()")
+        .execute(&mut conn)
+        .await
+        .unwrap_err();
+
+    assert!(err.to_string().contains("it failed"), "{}", err);
+
+    // only deterministic functions can be used in an index
+    conn.create_deterministic_function("rust_add", 2, |ctx| {
+        Ok(ctx.try_get::<i32>(0)? + ctx.try_get::<i32>(1)?)
+    })?;
+
+    conn.execute("CREATE TEMPORARY TABLE functions (a INTEGER, b INTEGER)")
+        .await?;
+
+    conn.execute("CREATE INDEX temp.functions_sum ON functions (rust_add(a, b))")
+        .await?;
+
+    let res = conn
+        .execute("CREATE INDEX temp.functions_upper ON functions (rust_upper(a))")
+        .await;
+
+    assert!(res.is_err());
+
+    conn.execute("INSERT INTO functions (a, b) VALUES (1, 2), (3, 4)")
+        .await?;
+
+    let (a,): (i32,) = sqlx::query_as("SELECT a FROM functions WHERE rust_add(a, b) = 7")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(a, 3);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_shares_in_memory_databases() -> anyhow::Result<()> {
+    let pool = SqlitePool::builder()
+        .max_size(2)
+        .build("sqlite::memory:?cache=shared")
+        .await?;
+
+    let mut a = pool.acquire().await?;
+    let mut b = pool.acquire().await?;
+
+    a.execute("CREATE TABLE shared_memory (id INTEGER PRIMARY KEY)")
+        .await?;
+    a.execute("INSERT INTO shared_memory (id) VALUES (1), (2)")
+        .await?;
+
+    let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM shared_memory")
+        .fetch_one(&mut b)
+        .await?;
+
+    assert_eq!(count, 2);
+
+    // a named in-memory database is kept apart from the others
+    let mut named = SqliteConnection::connect("sqlite://named?mode=memory&cache=shared").await?;
+
+    assert!(sqlx::query("SELECT * FROM shared_memory")
+        .execute(&mut named)
+        .await
+        .is_err());
+
+    // and without `cache=shared` every connection has its own database
+    let mut private = SqliteConnection::connect("sqlite::memory:").await?;
+
+    assert!(sqlx::query("SELECT * FROM shared_memory")
+        .execute(&mut private)
+        .await
+        .is_err());
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_connects_with_options() -> anyhow::Result<()> {
+    let path = std::env::temp_dir().join("sqlx-connect-options.sqlite");
+    let _ = std::fs::remove_file(&path);
+
+    let options = SqliteConnectOptions::new()
+        .journal_mode(SqliteJournalMode::Truncate)
+        .synchronous(SqliteSynchronous::Full)
+        .foreign_keys(true)
+        .busy_timeout(Duration::from_millis(2500));
+
+    let mut conn =
+        SqliteConnection::connect_with(&*format!("sqlite://{}", path.display()), &options).await?;
+
+    let (journal_mode,): (String,) = sqlx::query_as("PRAGMA journal_mode")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(journal_mode, "truncate");
+
+    // FULL
+    let (synchronous,): (i32,) = sqlx::query_as("PRAGMA synchronous")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(synchronous, 2);
+
+    let (busy_timeout,): (i32,) = sqlx::query_as("PRAGMA busy_timeout")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(busy_timeout, 2500);
+
+    conn.execute(
+        r#"
+CREATE TABLE parents (id INTEGER PRIMARY KEY);
+CREATE TABLE children (id INTEGER PRIMARY KEY, parent_id INTEGER NOT NULL REFERENCES parents (id));
+INSERT INTO parents (id) VALUES (1);
+INSERT INTO children (id, parent_id) VALUES (1, 1);
+        "#,
+    )
+    .await?;
+
+    let res = conn
+        .execute("INSERT INTO children (id, parent_id) VALUES (2, 2)")
+        .await;
+
+    assert!(res.is_err());
+
+    conn.close().await?;
+
+    // and can be turned off
+    let options = SqliteConnectOptions::new().foreign_keys(false);
+    let mut conn =
+        SqliteConnection::connect_with(&*format!("sqlite://{}", path.display()), &options).await?;
+
+    conn.execute("INSERT INTO children (id, parent_id) VALUES (2, 2)")
+        .await?;
+
+    conn.close().await?;
+
+    let _ = std::fs::remove_file(&path);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_inspects_columns() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE users (id INTEGER PRIMARY KEY, name TEXT NOT NULL);
+INSERT INTO users (id, name) VALUES (1, 'Herp Derpinson');
+        "#,
+    )
+    .await?;
+
+    let mut cursor =
+        sqlx::query("SELECT id, name, 1.5 AS ratio, NULL AS email FROM users").fetch(&mut conn);
+
+    let row = cursor.next().await?.unwrap();
+
+    let id = row.column(0);
+    assert_eq!(id.name(), Some("id"));
+    assert_eq!(id.ordinal(), 0);
+    assert_eq!(id.type_info().unwrap().to_string(), "INTEGER");
+
+    let name = row.column("name");
+    assert_eq!(name.ordinal(), 1);
+    assert_eq!(name.type_info().unwrap().to_string(), "TEXT");
+
+    // an expression has the type of its value
+    assert_eq!(row.column(2).type_info().unwrap().to_string(), "DOUBLE");
+    assert!(row.column(3).type_info().is_none());
+
+    assert!(row.try_column(4).is_err());
+    assert!(row.try_column("nothing").is_err());
+
+    assert_eq!(
+        row.try_get_raw("ratio")?.type_info().unwrap().to_string(),
+        "DOUBLE"
+    );
+
+    assert!(row.try_get_raw(3)?.type_info().is_none());
+
+    Ok(())
+}
+
+#[cfg(feature = "json")]
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_converts_rows_to_json() -> anyhow::Result<()> {
+    use serde_json::json;
+    use sqlx::types::json::row_to_json;
+
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE users (
+    id INTEGER PRIMARY KEY,
+    name TEXT NOT NULL,
+    active BOOLEAN NOT NULL,
+    ratio REAL NOT NULL,
+    avatar BLOB NOT NULL,
+    email TEXT
+);
+
+INSERT INTO users (id, name, active, ratio, avatar)
+VALUES (1, 'Herp Derpinson', TRUE, 1.5, X'DEADBEEF');
+        "#,
+    )
+    .await?;
+
+    let mut cursor = sqlx::query("SELECT *, 10 * id FROM users").fetch(&mut conn);
+    let row = cursor.next().await?.unwrap();
+
+    assert_eq!(
+        row_to_json(&row)?,
+        json!({
+            "id": 1,
+            "name": "Herp Derpinson",
+            "active": true,
+            "ratio": 1.5,
+            "avatar": "3q2+7w==",
+            "email": null,
+            "10 * id": 10,
+        })
+    );
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_reports_the_column_of_errors() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE users (id INTEGER PRIMARY KEY, name TEXT NOT NULL, email TEXT);
+INSERT INTO users (id, name) VALUES (1, 'Herp Derpinson');
+        "#,
+    )
+    .await?;
+
+    let mut cursor = sqlx::query("SELECT id, name, email FROM users").fetch(&mut conn);
+    let row = cursor.next().await?.unwrap();
+
+    let err = row.try_get::<i32, _>("mail").unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "no column found with the name \"mail\"; the columns are \"id\", \"name\", \"email\""
+    );
+
+    let err = row.try_get::<String, _>("email").unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "error decoding column \"email\" of SQL type TEXT: \
+         unexpected null; try decoding as an `Option`"
+    );
+
+    assert!(matches!(err, sqlx::Error::ColumnDecode { .. }));
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_executes_many_statements() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let rows_affected: Vec<u64> = conn
+        .execute_many(
+            r#"
+CREATE TEMPORARY TABLE numbers (n INTEGER NOT NULL);
+INSERT INTO numbers (n) VALUES (1), (2), (3);
+DELETE FROM numbers WHERE n > 1;
+-- the results of these statements
+            "#,
+        )
+        .map_ok(|result| result.rows_affected())
+        .try_collect()
+        .await?;
+
+    assert_eq!(rows_affected, [0, 3, 2]);
+
+    // a statement that fails stops the rest
+    let mut results = conn.execute_many(
+        "INSERT INTO numbers (n) VALUES (4); INSERT INTO numbers (n) VALUES (NULL); \
+         INSERT INTO numbers (n) VALUES (5);",
+    );
+
+    assert_eq!(results.try_next().await?.unwrap().rows_affected(), 1);
+    assert!(results.try_next().await.is_err());
+
+    drop(results);
+
+    let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM numbers")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 2);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_binds_named_parameters() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE users (id INTEGER PRIMARY KEY, parent_id INTEGER, name TEXT NOT NULL);
+INSERT INTO users (id, parent_id, name) VALUES (1, NULL, 'a'), (2, 1, 'b'), (3, 2, 'c');
+        "#,
+    )
+    .await?;
+
+    let names: Vec<(String,)> =
+        sqlx::query_as("SELECT name FROM users WHERE id = :id OR parent_id = :id ORDER BY id")
+            .bind_named("id", 2)
+            .fetch_all(&mut conn)
+            .await?;
+
+    assert_eq!(names, [("b".to_owned(),), ("c".to_owned(),)]);
+
+    // a name with a prefix only binds that parameter
+    let mut cursor = sqlx::query("SELECT @a + $b, :a, :b")
+        .bind_named("$b", 10)
+        .bind_named("a", 1)
+        .fetch(&mut conn);
+
+    let row = cursor.next().await?.unwrap();
+
+    assert_eq!(row.get::<i32, _>(0), 11);
+    assert_eq!(row.get::<i32, _>(1), 1);
+    assert_eq!(row.get::<Option<i32>, _>(2), None);
+
+    drop(cursor);
+
+    let res = sqlx::query("SELECT :id")
+        .bind_named("name", 1)
+        .execute(&mut conn)
+        .await;
+
+    assert!(res.is_err());
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_describes_a_query() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE describe_test (
+    id INTEGER PRIMARY KEY,
+    name TEXT NOT NULL,
+    hash BLOB
+)
+        "#,
+    )
+    .await?;
+
+    let describe = conn
+        .describe("SELECT id, name, hash, 1 + 1 AS two FROM describe_test WHERE id = ?")
+        .await?;
+
+    // the types of parameters are not known to SQLite, only how many there are
+    assert_eq!(describe.param_types.len(), 1);
+    assert!(describe.param_types[0].is_none());
+
+    let columns: Vec<_> = describe
+        .result_columns
+        .iter()
+        .map(|column| {
+            (
+                column.name.as_deref(),
+                column.type_info.as_ref().map(ToString::to_string),
+            )
+        })
+        .collect();
+
+    assert_eq!(
+        columns,
+        vec![
+            (Some("id"), Some("INTEGER".to_owned())),
+            (Some("name"), Some("TEXT".to_owned())),
+            (Some("hash"), Some("BLOB".to_owned())),
+            (Some("two"), None),
+        ]
+    );
+
+    Ok(())
+}
+
+#[cfg(feature = "tracing")]
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+#[tracing_test::traced_test]
+async fn it_traces_queries() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE traced (id INTEGER)")
+        .await?;
+
+    sqlx::query("INSERT INTO traced (id) VALUES (?), (?)")
+        .bind(1)
+        .bind(2)
+        .execute(&mut conn)
+        .await?;
+
+    let rows: Vec<(i32,)> = sqlx::query_as("SELECT id FROM traced")
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert_eq!(rows.len(), 2);
+
+    let error = sqlx::query_as::<_, (i32,)>("SELECT id FROM not_traced")
+        .fetch_all(&mut conn)
+        .await
+        .unwrap_err();
+
+    logs_assert(|lines| {
+        let span = |statement: &str| {
+            lines
+                .iter()
+                .find(|line| line.contains(&format!("db.statement={:?}", statement)))
+                .ok_or_else(|| format!("no span for {:?}", statement))
+        };
+
+        let insert = span("INSERT INTO traced (id) VALUES (?), (?)")?;
+        let select = span("SELECT id FROM traced")?;
+        let failed = span("SELECT id FROM not_traced")?;
+
+        for line in &[insert, select, failed] {
+            assert!(line.contains("sqlx.query{db.system=\"sqlite\""), "{}", line);
+        }
+
+        assert!(insert.contains("db.rows_affected=2"), "{}", insert);
+        assert!(select.contains("db.rows_affected=2"), "{}", select);
+        assert!(failed.contains(&format!("error={}", error)), "{}", failed);
+
+        Ok(())
+    });
+
+    Ok(())
+}
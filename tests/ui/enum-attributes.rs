@@ -0,0 +1,16 @@
+#[derive(sqlx::Type)]
+#[sqlx(rename = "mood", rename_all = "snake_case")]
+enum Mood {
+    Ok,
+    Happy,
+    Sad,
+}
+
+#[derive(sqlx::Type)]
+#[sqlx(rename = "color")]
+enum Color {
+    #[sqlx(rename = 1)]
+    Red,
+}
+
+fn main() {}